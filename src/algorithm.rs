@@ -0,0 +1,236 @@
+//! Module containing the [`Algorithm`] trait of the block-based hash functions with fixed-length digests.
+//!
+//! The trait exposes the block and digest lengths of a hash function next to its `Update` type, so generic code can be written once over all of them. The [`hmac`](crate::hmac), [`hkdf`](crate::hkdf), [`pbkdf2`](crate::pbkdf2), [`otp`](crate::otp) and [`merkle`](crate::merkle) modules are generic over it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::algorithm::Algorithm;
+//! use chksum_hash::{md4, sha2_256};
+//!
+//! fn describe<A>(data: &str) -> String
+//! where
+//!     A: Algorithm,
+//! {
+//!     let digest = A::hash(data);
+//!     format!(
+//!         "{digest} ({}/{})",
+//!         A::BLOCK_LENGTH_BYTES,
+//!         A::DIGEST_LENGTH_BYTES
+//!     )
+//! }
+//!
+//! assert_eq!(
+//!     describe::<md4::Update>("abc"),
+//!     "a448017aaf21d8525fc10ae87aa6729d (64/16)"
+//! );
+//! assert_eq!(
+//!     describe::<sha2_256::Update>("abc"),
+//!     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad (64/32)"
+//! );
+//! ```
+
+use chksum_hash_core as core;
+
+/// A block-based hash function with a fixed-length digest.
+///
+/// The trait is implemented by the `Update` types of the hash functions, the associated `Digest` type is the one of [`Update`](core::Update). Hash functions with configurable digest lengths, like HAVAL or Skein, implement it with their default parameters, which are the ones used by the generic code over this trait. Keyed hash functions, like GHASH, do not implement it.
+pub trait Algorithm: core::Update<Digest = <Self as Algorithm>::Output> + Clone {
+    /// Digest type of the hash function, the same as the `Digest` type of [`Update`](core::Update) with access to its bytes.
+    type Output: core::Digest + AsRef<[u8]>;
+
+    /// Block length of the hash function in bytes.
+    const BLOCK_LENGTH_BYTES: usize;
+
+    /// Digest length of the hash function in bytes.
+    const DIGEST_LENGTH_BYTES: usize;
+
+    /// Computes the hash of the given input.
+    fn hash(data: impl AsRef<[u8]>) -> Self::Digest {
+        core::hash::<Self>(data)
+    }
+}
+
+#[cfg(feature = "blake-256")]
+impl Algorithm for crate::blake_256::Update {
+    type Output = crate::blake_256::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::blake_256::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::blake_256::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "blake-512")]
+impl Algorithm for crate::blake_512::Update {
+    type Output = crate::blake_512::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::blake_512::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::blake_512::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "haval")]
+impl Algorithm for crate::haval::Update {
+    type Output = crate::haval::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::haval::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::haval::params::DEFAULT_DIGEST_LENGTH_BITS / 8;
+}
+
+#[cfg(feature = "jh-224")]
+impl Algorithm for crate::jh_224::Update {
+    type Output = crate::jh_224::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::jh_224::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::jh_224::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "jh-256")]
+impl Algorithm for crate::jh_256::Update {
+    type Output = crate::jh_256::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::jh_256::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::jh_256::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "jh-384")]
+impl Algorithm for crate::jh_384::Update {
+    type Output = crate::jh_384::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::jh_384::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::jh_384::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "jh-512")]
+impl Algorithm for crate::jh_512::Update {
+    type Output = crate::jh_512::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::jh_512::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::jh_512::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "lsh-256")]
+impl Algorithm for crate::lsh_256::Update {
+    type Output = crate::lsh_256::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::lsh_256::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::lsh_256::DIGEST_LENGTH_BITS / 8;
+}
+
+#[cfg(feature = "lsh-512")]
+impl Algorithm for crate::lsh_512::Update {
+    type Output = crate::lsh_512::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::lsh_512::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::lsh_512::DIGEST_LENGTH_BITS / 8;
+}
+
+#[cfg(feature = "md4")]
+impl Algorithm for crate::md4::Update {
+    type Output = crate::md4::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::md4::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::md4::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "md5")]
+impl Algorithm for crate::md5::Update {
+    type Output = crate::md5::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::md5::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::md5::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "md6")]
+impl Algorithm for crate::md6::Update {
+    type Output = crate::md6::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::md6::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::md6::params::DEFAULT_DIGEST_LENGTH_BITS / 8;
+}
+
+#[cfg(feature = "sha0")]
+impl Algorithm for crate::sha0::Update {
+    type Output = crate::sha0::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::sha0::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::sha0::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "sha1")]
+impl Algorithm for crate::sha1::Update {
+    type Output = crate::sha1::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::sha1::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::sha1::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "sha2-224")]
+impl Algorithm for crate::sha2_224::Update {
+    type Output = crate::sha2_224::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::sha2_224::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::sha2_224::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "sha2-256")]
+impl Algorithm for crate::sha2_256::Update {
+    type Output = crate::sha2_256::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::sha2_256::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::sha2_256::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "sha2-384")]
+impl Algorithm for crate::sha2_384::Update {
+    type Output = crate::sha2_384::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::sha2_384::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::sha2_384::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "sha2-512")]
+impl Algorithm for crate::sha2_512::Update {
+    type Output = crate::sha2_512::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::sha2_512::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::sha2_512::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "skein-256")]
+impl Algorithm for crate::skein_256::Update {
+    type Output = crate::skein_256::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::skein_256::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::skein_256::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "skein-512")]
+impl Algorithm for crate::skein_512::Update {
+    type Output = crate::skein_512::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::skein_512::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::skein_512::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "skein-1024")]
+impl Algorithm for crate::skein_1024::Update {
+    type Output = crate::skein_1024::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::skein_1024::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::skein_1024::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "tiger")]
+impl Algorithm for crate::tiger::Update {
+    type Output = crate::tiger::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::tiger::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::tiger::DIGEST_LENGTH_BYTES;
+}
+
+#[cfg(feature = "tiger2")]
+impl Algorithm for crate::tiger2::Update {
+    type Output = crate::tiger2::Digest;
+
+    const BLOCK_LENGTH_BYTES: usize = crate::tiger2::BLOCK_LENGTH_BYTES;
+    const DIGEST_LENGTH_BYTES: usize = crate::tiger2::DIGEST_LENGTH_BYTES;
+}
@@ -0,0 +1,92 @@
+//! Module containing items related to the base32 representation of digests.
+//!
+//! The encodings use the alphabet of [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648), used e.g. by magnet links and Tor onion addresses, or the alphabet of [Crockford](https://www.crockford.com/base32.html), which avoids the letters `I`, `L`, `O` and `U`. Digests are usually written without the `=` padding, so the padding is opt-in.
+//!
+//! Digests of this crate provide the `to_base32*` functions and the functions of this module can encode any other data, e.g. digests of the [`md5`](crate::md5), [`sha1`](crate::sha1) and `sha2_*` modules.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::{base32, sha1};
+//!
+//! // The `urn:sha1:` URN of magnet links
+//! let digest = sha1::hash("example data");
+//! assert_eq!(
+//!     format!("urn:sha1:{}", base32::encode(digest)),
+//!     "urn:sha1:T7CCVWWDCMB5NC2EJZQST4J7MCJ2BYCF"
+//! );
+//! ```
+
+/// Alphabet of the base32 encoding of RFC 4648.
+const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+/// Alphabet of the base32 encoding of Crockford.
+const ALPHABET_CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+/// Padding character of the base32 encoding of RFC 4648.
+const PADDING: char = '=';
+
+/// Encodes the data with the alphabet of RFC 4648 and without padding.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::base32;
+///
+/// assert_eq!(base32::encode("foob"), "MZXW6YQ");
+/// ```
+#[must_use]
+pub fn encode(data: impl AsRef<[u8]>) -> String {
+    encode_with(data.as_ref(), ALPHABET)
+}
+
+/// Encodes the data with the alphabet of RFC 4648 and padding.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::base32;
+///
+/// assert_eq!(base32::encode_padded("foob"), "MZXW6YQ=");
+/// ```
+#[must_use]
+pub fn encode_padded(data: impl AsRef<[u8]>) -> String {
+    let mut encoded = encode_with(data.as_ref(), ALPHABET);
+    // Groups of 5 bytes are encoded as 8 characters.
+    while encoded.len() % 8 != 0 {
+        encoded.push(PADDING);
+    }
+    encoded
+}
+
+/// Encodes the data with the alphabet of Crockford.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::base32;
+///
+/// assert_eq!(base32::encode_crockford("foob"), "CSQPYRG");
+/// ```
+#[must_use]
+pub fn encode_crockford(data: impl AsRef<[u8]>) -> String {
+    encode_with(data.as_ref(), ALPHABET_CROCKFORD)
+}
+
+/// Encodes the data with the given alphabet, 5 bits per character.
+fn encode_with(data: &[u8], alphabet: &[u8; 32]) -> String {
+    let mut output = String::with_capacity((data.len() * 8 + 4) / 5);
+    let mut buffer = 0u16;
+    let mut bits = 0;
+    for &byte in data {
+        buffer = buffer << 8 | u16::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            output.push(char::from(alphabet[usize::from(buffer >> bits & 0x1F)]));
+        }
+    }
+    // The remaining bits are padded with zeros to a full symbol.
+    if bits > 0 {
+        output.push(char::from(alphabet[usize::from(buffer << (5 - bits) & 0x1F)]));
+    }
+    output
+}
@@ -0,0 +1,173 @@
+//! Module containing items related to the base64 representation of digests.
+//!
+//! The encodings follow [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648): the standard alphabet, used e.g. by the `Content-MD5` and `Digest` HTTP headers and by Subresource Integrity, and the URL and filename safe alphabet, which replaces `+` and `/` with `-` and `_`. Both are available with and without the `=` padding.
+//!
+//! Digests of this crate provide the `to_base64*` functions and the functions of this module can encode and decode any other data, e.g. digests of the [`md5`](crate::md5), [`sha1`](crate::sha1) and `sha2_*` modules.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::{base64, sha2_256};
+//!
+//! // Subresource Integrity
+//! let digest = sha2_256::hash("alert('Hello, world.');");
+//! assert_eq!(
+//!     format!("sha256-{}", base64::encode(digest)),
+//!     "sha256-qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng="
+//! );
+//! ```
+
+/// Standard alphabet of the base64 encoding.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+/// URL and filename safe alphabet of the base64 encoding.
+const ALPHABET_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+/// Padding character of the base64 encoding.
+const PADDING: char = '=';
+
+/// Encodes the data with the standard alphabet and padding.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::base64;
+///
+/// assert_eq!(base64::encode("foob"), "Zm9vYg==");
+/// assert_eq!(base64::encode([0xFB, 0xFF]), "+/8=");
+/// ```
+#[must_use]
+pub fn encode(data: impl AsRef<[u8]>) -> String {
+    encode_with(data.as_ref(), ALPHABET, true)
+}
+
+/// Encodes the data with the standard alphabet and without padding.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::base64;
+///
+/// assert_eq!(base64::encode_unpadded("foob"), "Zm9vYg");
+/// assert_eq!(base64::encode_unpadded([0xFB, 0xFF]), "+/8");
+/// ```
+#[must_use]
+pub fn encode_unpadded(data: impl AsRef<[u8]>) -> String {
+    encode_with(data.as_ref(), ALPHABET, false)
+}
+
+/// Encodes the data with the URL and filename safe alphabet and padding.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::base64;
+///
+/// assert_eq!(base64::encode_url("foob"), "Zm9vYg==");
+/// assert_eq!(base64::encode_url([0xFB, 0xFF]), "-_8=");
+/// ```
+#[must_use]
+pub fn encode_url(data: impl AsRef<[u8]>) -> String {
+    encode_with(data.as_ref(), ALPHABET_URL, true)
+}
+
+/// Encodes the data with the URL and filename safe alphabet and without padding.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::base64;
+///
+/// assert_eq!(base64::encode_url_unpadded("foob"), "Zm9vYg");
+/// assert_eq!(base64::encode_url_unpadded([0xFB, 0xFF]), "-_8");
+/// ```
+#[must_use]
+pub fn encode_url_unpadded(data: impl AsRef<[u8]>) -> String {
+    encode_with(data.as_ref(), ALPHABET_URL, false)
+}
+
+/// Decodes the data encoded with the standard alphabet, with or without padding.
+///
+/// Returns `None` if the data contains a character outside of the alphabet, misplaced padding or non-zero trailing bits.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::base64;
+///
+/// assert_eq!(base64::decode("Zm9vYg=="), Some(b"foob".to_vec()));
+/// assert_eq!(base64::decode("Zm9vYg"), Some(b"foob".to_vec()));
+/// assert_eq!(base64::decode("Zm9vYh"), None);
+/// ```
+#[must_use]
+pub fn decode(encoded: &str) -> Option<Vec<u8>> {
+    decode_with(encoded, ALPHABET)
+}
+
+/// Decodes the data encoded with the URL and filename safe alphabet, with or without padding.
+///
+/// Returns `None` if the data contains a character outside of the alphabet, misplaced padding or non-zero trailing bits.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::base64;
+///
+/// assert_eq!(base64::decode_url("-_8"), Some(vec![0xFB, 0xFF]));
+/// assert_eq!(base64::decode_url("+/8="), None);
+/// ```
+#[must_use]
+pub fn decode_url(encoded: &str) -> Option<Vec<u8>> {
+    decode_with(encoded, ALPHABET_URL)
+}
+
+/// Encodes the data with the given alphabet, groups of 3 bytes are encoded as 4 characters.
+fn encode_with(data: &[u8], alphabet: &[u8; 64], padding: bool) -> String {
+    let mut encoded = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (index, &byte)| {
+            group | u32::from(byte) << (16 - 8 * index)
+        });
+        // A chunk of n bytes is encoded as n + 1 characters.
+        for index in 0..=chunk.len() {
+            let value = (group >> (18 - 6 * index)) & 0x3F;
+            encoded.push(char::from(alphabet[value as usize]));
+        }
+        if padding {
+            for _ in chunk.len()..3 {
+                encoded.push(PADDING);
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes the data encoded with the given alphabet, groups of 4 characters are decoded as 3 bytes.
+fn decode_with(encoded: &str, alphabet: &[u8; 64]) -> Option<Vec<u8>> {
+    let padded = encoded.trim_end_matches(PADDING);
+    let padding = encoded.len() - padded.len();
+    if padding > 0 && (padding > 2 || encoded.len() % 4 != 0) {
+        return None;
+    }
+    let encoded = padded.as_bytes();
+    if encoded.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut output = Vec::with_capacity(encoded.len() / 4 * 3 + 2);
+    for chunk in encoded.chunks(4) {
+        let mut group = 0u32;
+        for &byte in chunk {
+            let value = alphabet.iter().position(|&symbol| symbol == byte)?;
+            #[allow(clippy::cast_possible_truncation)]
+            let value = value as u32;
+            group = group << 6 | value;
+        }
+        group <<= 6 * (4 - chunk.len());
+        // A chunk of n characters is decoded as n - 1 bytes and the remaining bits have to be zero.
+        let length = chunk.len() - 1;
+        if group & (0x00FF_FFFF >> (8 * length)) != 0 {
+            return None;
+        }
+        output.extend_from_slice(&group.to_be_bytes()[1..=length]);
+    }
+    Some(output)
+}
@@ -0,0 +1,194 @@
+//! This module is optional and can be enabled using the `bitcoin` Cargo feature.
+//!
+//! An implementation of the Merkle roots of Bitcoin blocks.
+//!
+//! Bitcoin hashes with double [`sha2_256`](crate::sha2_256), see [`hash256`]. The Merkle root of a block is computed from the transaction identifiers: pairs of digests are concatenated and hashed level by level, and the last digest of a level with an odd number of digests is paired with itself. Since the segregated witness upgrade ([BIP 141](https://github.com/bitcoin/bips/blob/master/bip-0141.mediawiki)) blocks also commit to the Merkle root of the witness transaction identifiers.
+//!
+//! All digests are in the internal byte order, the one used in the serialized blocks. Block explorers and RPC interfaces display them reversed.
+//!
+//! # Merkle Root
+//!
+//! The Merkle root can be computed from the transaction identifiers with the [`merkle_root`] function.
+//!
+//! ```rust
+//! use chksum_hash::bitcoin;
+//!
+//! // Transaction identifiers of block 100000, as displayed by block explorers
+//! let txids = [
+//!     "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+//!     "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+//!     "6359f0868171b1d194cbee1af2f16ea598ae8fad666d9b012c8ed2b79a236ec4",
+//!     "e9a66845e05d5abc0ad04ec80f774a7e585c6e8db975962d069a522137b80c1d",
+//! ];
+//! let txids = txids.iter().map(|txid| {
+//!     let mut digest = [0u8; 32];
+//!     for (byte, index) in digest.iter_mut().zip((0..64).step_by(2).rev()) {
+//!         *byte = u8::from_str_radix(&txid[index..index + 2], 16).unwrap();
+//!     }
+//!     digest
+//! });
+//!
+//! let root = bitcoin::merkle_root(txids).unwrap();
+//! let root = root
+//!     .iter()
+//!     .rev()
+//!     .map(|byte| format!("{byte:02x}"))
+//!     .collect::<String>();
+//! assert_eq!(
+//!     root,
+//!     "f3e94742aca4b5ef85488dc37c06c3282295ffec960994b2c0d5ac2a25a95766"
+//! );
+//! ```
+//!
+//! # Witness Commitment
+//!
+//! The witness Merkle root is computed with the [`witness_merkle_root`] function and committed to in the coinbase transaction with the [`witness_commitment`] and the [`witness_commitment_script`] functions.
+//!
+//! ```rust
+//! use chksum_hash::bitcoin;
+//!
+//! let wtxids = [[0x00; 32], [0x01; 32], [0x02; 32]];
+//! let root = bitcoin::witness_merkle_root(wtxids).unwrap();
+//! let commitment = bitcoin::witness_commitment(root, [0x00; 32]);
+//! let script = bitcoin::witness_commitment_script(commitment);
+//! assert_eq!(script[..6], [0x6A, 0x24, 0xAA, 0x21, 0xA9, 0xED]);
+//! assert_eq!(script[6..], commitment);
+//! ```
+//!
+//! # Disclaimer
+//!
+//! Pairing the last digest with itself makes different lists of transactions share the Merkle root, e.g. `[a, b, c]` and `[a, b, c, c]` ([CVE-2012-2459](https://nvd.nist.gov/vuln/detail/CVE-2012-2459)). Validating nodes have to reject blocks with duplicated transactions, this module only computes the roots.
+
+use crate::sha2_256;
+
+/// Length of the digests in bytes.
+pub const DIGEST_LENGTH_BYTES: usize = sha2_256::DIGEST_LENGTH_BYTES;
+
+/// Header of the witness commitment in the coinbase transaction output.
+pub const WITNESS_COMMITMENT_HEADER: [u8; 4] = [0xAA, 0x21, 0xA9, 0xED];
+
+/// Computes the double SHA-256 hash of the given input, called `HASH256` by Bitcoin.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bitcoin;
+///
+/// let digest = bitcoin::hash256("hello");
+/// let digest = digest
+///     .iter()
+///     .map(|byte| format!("{byte:02x}"))
+///     .collect::<String>();
+/// assert_eq!(
+///     digest,
+///     "9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50"
+/// );
+/// ```
+#[must_use]
+pub fn hash256(data: impl AsRef<[u8]>) -> [u8; DIGEST_LENGTH_BYTES] {
+    let digest = sha2_256::hash(sha2_256::hash(data));
+    let mut output = [0u8; DIGEST_LENGTH_BYTES];
+    output.copy_from_slice(digest.as_ref());
+    output
+}
+
+/// Computes the Merkle root of the given transaction identifiers.
+///
+/// Returns `None` if there are no identifiers. The root of a single identifier is the identifier itself.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bitcoin;
+///
+/// let root = bitcoin::merkle_root([[0x01; 32]]);
+/// assert_eq!(root, Some([0x01; 32]));
+///
+/// // The last identifier is paired with itself
+/// let root = bitcoin::merkle_root([[0x01; 32], [0x02; 32], [0x03; 32]]);
+/// let duplicated = bitcoin::merkle_root([[0x01; 32], [0x02; 32], [0x03; 32], [0x03; 32]]);
+/// assert_eq!(root, duplicated);
+///
+/// assert_eq!(bitcoin::merkle_root([]), None);
+/// ```
+#[must_use]
+pub fn merkle_root<I>(txids: I) -> Option<[u8; DIGEST_LENGTH_BYTES]>
+where
+    I: IntoIterator<Item = [u8; DIGEST_LENGTH_BYTES]>,
+{
+    let mut level = txids.into_iter().collect::<Vec<_>>();
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            let last = level[level.len() - 1];
+            level.push(last);
+        }
+        level = level
+            .chunks_exact(2)
+            .map(|pair| {
+                let mut data = [0u8; 2 * DIGEST_LENGTH_BYTES];
+                data[..DIGEST_LENGTH_BYTES].copy_from_slice(&pair[0]);
+                data[DIGEST_LENGTH_BYTES..].copy_from_slice(&pair[1]);
+                hash256(data)
+            })
+            .collect();
+    }
+    level.first().copied()
+}
+
+/// Computes the witness Merkle root of the given witness transaction identifiers.
+///
+/// The identifiers are in the order of the transactions in the block. The first one belongs to the coinbase transaction and it is replaced with zeros, as specified by BIP 141.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bitcoin;
+///
+/// let root = bitcoin::witness_merkle_root([[0xFF; 32], [0x01; 32]]);
+/// let expected = bitcoin::merkle_root([[0x00; 32], [0x01; 32]]);
+/// assert_eq!(root, expected);
+/// ```
+#[must_use]
+pub fn witness_merkle_root<I>(wtxids: I) -> Option<[u8; DIGEST_LENGTH_BYTES]>
+where
+    I: IntoIterator<Item = [u8; DIGEST_LENGTH_BYTES]>,
+{
+    let wtxids = wtxids
+        .into_iter()
+        .enumerate()
+        .map(|(index, wtxid)| if index == 0 { [0u8; DIGEST_LENGTH_BYTES] } else { wtxid });
+    merkle_root(wtxids)
+}
+
+/// Computes the witness commitment of the witness Merkle root and the witness reserved value from the coinbase witness.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bitcoin;
+///
+/// let root = bitcoin::witness_merkle_root([[0x00; 32]]).unwrap();
+/// let commitment = bitcoin::witness_commitment(root, [0x00; 32]);
+/// assert_eq!(commitment, bitcoin::hash256([0x00; 64]));
+/// ```
+#[must_use]
+pub fn witness_commitment(
+    witness_root: [u8; DIGEST_LENGTH_BYTES],
+    reserved_value: [u8; DIGEST_LENGTH_BYTES],
+) -> [u8; DIGEST_LENGTH_BYTES] {
+    let mut data = [0u8; 2 * DIGEST_LENGTH_BYTES];
+    data[..DIGEST_LENGTH_BYTES].copy_from_slice(&witness_root);
+    data[DIGEST_LENGTH_BYTES..].copy_from_slice(&reserved_value);
+    hash256(data)
+}
+
+/// Returns the output script of the coinbase transaction carrying the witness commitment, i.e. `OP_RETURN` followed by the pushed [`WITNESS_COMMITMENT_HEADER`] and the commitment.
+#[must_use]
+pub fn witness_commitment_script(commitment: [u8; DIGEST_LENGTH_BYTES]) -> [u8; 38] {
+    let mut script = [0u8; 38];
+    script[0] = 0x6A; // OP_RETURN
+    script[1] = 0x24; // push of 36 bytes
+    script[2..6].copy_from_slice(&WITNESS_COMMITMENT_HEADER);
+    script[6..].copy_from_slice(&commitment);
+    script
+}
@@ -0,0 +1,385 @@
+//! This module is optional and can be enabled using the `bittorrent` Cargo feature.
+//!
+//! An implementation of the piece hashes of BitTorrent metainfo files.
+//!
+//! BitTorrent v1 ([BEP 3](https://www.bittorrent.org/beps/bep_0003.html)) splits the concatenated files into pieces and stores the concatenated [`sha1`](crate::sha1) digests of the pieces in the `pieces` field, see [`V1Pieces`]. BitTorrent v2 ([BEP 52](https://www.bittorrent.org/beps/bep_0052.html)) hashes each file separately: the file is split into blocks of [`BLOCK_LENGTH_BYTES`] (16 KiB) hashed with [`sha2_256`](crate::sha2_256), which are the leaves of a binary Merkle tree padded with zero digests. The root of the tree is the `pieces root` of the file and the layer of the tree covering whole pieces is stored in the `piece layers` field, see [`V2File`].
+//!
+//! # Version 1
+//!
+//! ```rust
+//! use chksum_hash::{bittorrent, sha1};
+//!
+//! let pieces = bittorrent::V1Pieces::new(4)
+//!     .update("example ")
+//!     .update("data")
+//!     .finalize();
+//! assert_eq!(pieces.len(), 3 * sha1::DIGEST_LENGTH_BYTES);
+//! assert_eq!(pieces[..20], *sha1::hash("exam").as_ref());
+//! assert_eq!(pieces[40..], *sha1::hash("data").as_ref());
+//! ```
+//!
+//! # Version 2
+//!
+//! ```rust
+//! use chksum_hash::bittorrent;
+//!
+//! let data = vec![0x00; 100_000];
+//!
+//! let hashes = bittorrent::V2File::new(32 * 1024).update(&data).finalize();
+//! assert_eq!(
+//!     hashes.pieces_root().unwrap().to_hex_lowercase(),
+//!     "e92dc22e05df5bb6a89aecc0c58f10d0d270d676a648b1ea47258121fd79a9d3"
+//! );
+//! assert_eq!(hashes.piece_layer().len(), 4);
+//! ```
+
+use crate::error::ParameterError;
+use crate::{merkle, sha1, sha2_256};
+
+/// Block length of BitTorrent v2 in bytes, i.e. the data length of a leaf of the Merkle tree.
+pub const BLOCK_LENGTH_BYTES: usize = 16 * 1024;
+
+/// A consumer that produces the concatenated SHA-1 piece hashes of BitTorrent v1.
+///
+/// The data of all files of the torrent is passed in the order of the files, pieces span file boundaries.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{bittorrent, sha1};
+///
+/// let mut pieces = bittorrent::V1Pieces::new(16 * 1024);
+/// pieces.update(vec![0x00; 20_000]);
+///
+/// let hashes = pieces.finalize();
+/// assert_eq!(hashes.len(), 2 * sha1::DIGEST_LENGTH_BYTES);
+/// assert_eq!(
+///     hashes[20..],
+///     *sha1::hash(vec![0x00; 20_000 - 16 * 1024]).as_ref()
+/// );
+///
+/// // Reset to no data
+/// pieces.reset();
+/// assert!(pieces.finalize().is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct V1Pieces {
+    piece_length: usize,
+    piece: sha1::Update,
+    current_length: usize,
+    pieces: Vec<u8>,
+}
+
+impl V1Pieces {
+    /// Creates a new consumer for the given piece length in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the piece length is zero.
+    #[must_use]
+    pub fn new(piece_length: usize) -> Self {
+        assert!(piece_length > 0, "piece length must not be zero");
+        Self {
+            piece_length,
+            piece: sha1::Update::new(),
+            current_length: 0,
+            pieces: Vec::new(),
+        }
+    }
+
+    /// Creates a new consumer for the given piece length in bytes, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the piece length is zero.
+    pub fn try_new(piece_length: usize) -> Result<Self, ParameterError> {
+        if piece_length > 0 {
+            Ok(Self::new(piece_length))
+        } else {
+            Err(ParameterError {
+                parameter: "piece length",
+            })
+        }
+    }
+
+    /// Returns the piece length in bytes.
+    #[must_use]
+    pub const fn piece_length(&self) -> usize {
+        self.piece_length
+    }
+
+    /// Updates the internal state with an input data.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+        while !data.is_empty() {
+            let missing = self.piece_length - self.current_length;
+            let (fillment, remainder) = data.split_at(missing.min(data.len()));
+            self.piece.update(fillment);
+            self.current_length += fillment.len();
+            data = remainder;
+
+            if self.current_length == self.piece_length {
+                self.pieces.extend_from_slice(self.piece.digest().as_ref());
+                self.piece.reset();
+                self.current_length = 0;
+            }
+        }
+        self
+    }
+
+    /// Returns the concatenated piece hashes, the content of the `pieces` field.
+    ///
+    /// The last piece is shorter than the piece length unless the data length is a multiple of it.
+    #[must_use]
+    pub fn finalize(&self) -> Vec<u8> {
+        let mut pieces = self.pieces.clone();
+        if self.current_length > 0 {
+            pieces.extend_from_slice(self.piece.digest().as_ref());
+        }
+        pieces
+    }
+
+    /// Resets the internal state to no data.
+    ///
+    /// The piece length is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.piece.reset();
+        self.current_length = 0;
+        self.pieces.clear();
+        self
+    }
+}
+
+/// A consumer that produces the Merkle tree hashes of a single file of BitTorrent v2.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{bittorrent, sha2_256};
+///
+/// let mut file = bittorrent::V2File::new(16 * 1024);
+///
+/// // A file of a single block is identified by the digest of the block
+/// file.update("data");
+/// let hashes = file.finalize();
+/// assert_eq!(hashes.pieces_root(), Some(sha2_256::hash("data")));
+/// assert!(hashes.piece_layer().is_empty());
+///
+/// // Empty files have no hashes
+/// file.reset();
+/// assert_eq!(file.finalize().pieces_root(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct V2File {
+    piece_length: usize,
+    block: sha2_256::Update,
+    block_length: usize,
+    piece: merkle::Tree<sha2_256::Update>,
+    layer: Vec<sha2_256::Digest>,
+    length: u64,
+}
+
+impl V2File {
+    /// Creates a new consumer for the given piece length in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the piece length is not a power of two of at least [`BLOCK_LENGTH_BYTES`], as required by BEP 52.
+    #[must_use]
+    pub fn new(piece_length: usize) -> Self {
+        assert!(
+            piece_length >= BLOCK_LENGTH_BYTES && piece_length.is_power_of_two(),
+            "piece length must be a power of two of at least 16 KiB"
+        );
+        Self {
+            piece_length,
+            block: sha2_256::Update::new(),
+            block_length: 0,
+            piece: merkle::Tree::with_prefixes([], []),
+            layer: Vec::new(),
+            length: 0,
+        }
+    }
+
+    /// Creates a new consumer for the given piece length in bytes, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the piece length is not a power of two of at least [`BLOCK_LENGTH_BYTES`].
+    pub fn try_new(piece_length: usize) -> Result<Self, ParameterError> {
+        if piece_length >= BLOCK_LENGTH_BYTES && piece_length.is_power_of_two() {
+            Ok(Self::new(piece_length))
+        } else {
+            Err(ParameterError {
+                parameter: "piece length",
+            })
+        }
+    }
+
+    /// Returns the piece length in bytes.
+    #[must_use]
+    pub const fn piece_length(&self) -> usize {
+        self.piece_length
+    }
+
+    /// Updates the internal state with an input data.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+        self.length += data.len() as u64;
+        while !data.is_empty() {
+            let missing = BLOCK_LENGTH_BYTES - self.block_length;
+            let (fillment, remainder) = data.split_at(missing.min(data.len()));
+            self.block.update(fillment);
+            self.block_length += fillment.len();
+            data = remainder;
+
+            // Full blocks are processed immediately, so the current block is empty only at block boundaries.
+            if self.block_length == BLOCK_LENGTH_BYTES {
+                let digest = self.block.digest();
+                Self::push(self.piece_length, &mut self.piece, &mut self.layer, digest);
+                self.block.reset();
+                self.block_length = 0;
+            }
+        }
+        self
+    }
+
+    /// Combines the block digests and produces the hashes of the file.
+    #[must_use]
+    pub fn finalize(&self) -> V2FileHashes {
+        if self.length == 0 {
+            return V2FileHashes {
+                pieces_root: None,
+                piece_layer: Vec::new(),
+            };
+        }
+
+        let mut piece = self.piece.clone();
+        let mut layer = self.layer.clone();
+        if self.block_length > 0 {
+            Self::push(self.piece_length, &mut piece, &mut layer, self.block.digest());
+        }
+
+        let zero = sha2_256::Digest::new([0u8; sha2_256::DIGEST_LENGTH_BYTES]);
+        if layer.is_empty() {
+            // Files of at most one piece pad the blocks only to the next power of two.
+            let length = piece.len().next_power_of_two();
+            pad(&mut piece, length, zero);
+            return V2FileHashes {
+                pieces_root: Some(piece.root()),
+                piece_layer: Vec::new(),
+            };
+        }
+
+        let blocks = (self.piece_length / BLOCK_LENGTH_BYTES) as u64;
+        if !piece.is_empty() {
+            pad(&mut piece, blocks, zero);
+            layer.push(piece.root());
+        }
+
+        // The pieces beyond the end of the file consist of zero blocks.
+        let mut tree = merkle::Tree::<sha2_256::Update>::with_prefixes([], []);
+        for digest in &layer {
+            tree.push_digest(*digest);
+        }
+        if !tree.len().is_power_of_two() {
+            let mut padding = merkle::Tree::<sha2_256::Update>::with_prefixes([], []);
+            pad(&mut padding, blocks, zero);
+            let length = tree.len().next_power_of_two();
+            pad(&mut tree, length, padding.root());
+        }
+
+        // Only files longer than a piece have a piece layer.
+        if self.length <= self.piece_length as u64 {
+            layer.clear();
+        }
+        V2FileHashes {
+            pieces_root: Some(tree.root()),
+            piece_layer: layer,
+        }
+    }
+
+    /// Resets the internal state to an empty file.
+    ///
+    /// The piece length is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.block.reset();
+        self.block_length = 0;
+        self.piece.reset();
+        self.layer.clear();
+        self.length = 0;
+        self
+    }
+
+    /// Appends the block digest to the current piece and moves full pieces to the piece layer.
+    fn push(
+        piece_length: usize,
+        piece: &mut merkle::Tree<sha2_256::Update>,
+        layer: &mut Vec<sha2_256::Digest>,
+        digest: sha2_256::Digest,
+    ) {
+        piece.push_digest(digest);
+        if piece.len() == (piece_length / BLOCK_LENGTH_BYTES) as u64 {
+            layer.push(piece.root());
+            piece.reset();
+        }
+    }
+}
+
+/// The Merkle tree hashes of a single file of BitTorrent v2.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct V2FileHashes {
+    pieces_root: Option<sha2_256::Digest>,
+    piece_layer: Vec<sha2_256::Digest>,
+}
+
+impl V2FileHashes {
+    /// Returns the root of the Merkle tree, the `pieces root` field of the file.
+    ///
+    /// Returns `None` for empty files, which have no `pieces root` field.
+    #[must_use]
+    pub const fn pieces_root(&self) -> Option<sha2_256::Digest> {
+        self.pieces_root
+    }
+
+    /// Returns the hashes of the pieces, the layer of the Merkle tree stored in the `piece layers` field.
+    ///
+    /// The layer is empty for files not longer than a piece, which are not included in the `piece layers` field.
+    #[must_use]
+    pub fn piece_layer(&self) -> &[sha2_256::Digest] {
+        &self.piece_layer
+    }
+
+    /// Returns the concatenated hashes of the pieces, the value of the file in the `piece layers` field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::bittorrent;
+    ///
+    /// let hashes = bittorrent::V2File::new(16 * 1024)
+    ///     .update(vec![0x00; 40_000])
+    ///     .finalize();
+    /// assert_eq!(hashes.piece_layer_bytes().len(), 3 * 32);
+    /// ```
+    #[must_use]
+    pub fn piece_layer_bytes(&self) -> Vec<u8> {
+        self.piece_layer
+            .iter()
+            .flat_map(|digest| digest.as_ref().iter().copied())
+            .collect()
+    }
+}
+
+/// Pushes the padding digest to the tree until it has the given number of leaves.
+fn pad(tree: &mut merkle::Tree<sha2_256::Update>, length: u64, padding: sha2_256::Digest) {
+    while tree.len() < length {
+        tree.push_digest(padding);
+    }
+}
@@ -0,0 +1,22 @@
+//! Module containing items related to the BLAKE-256 digest.
+
+/// Digest length in bits.
+pub const LENGTH_BITS: usize = 256;
+/// Digest length in bytes.
+pub const LENGTH_BYTES: usize = LENGTH_BITS / 8;
+/// Digest length in words (double bytes).
+pub const LENGTH_WORDS: usize = LENGTH_BYTES / 2;
+/// Digest length in double words (quadruple bytes).
+pub const LENGTH_DWORDS: usize = LENGTH_WORDS / 2;
+/// Digest length in hexadecimal format.
+pub const LENGTH_HEX: usize = LENGTH_BYTES * 2;
+
+/// Marker type of the BLAKE-256 digest.
+///
+/// It tags [`Digest`] so BLAKE-256 digests are distinct from digests of other hash functions with the same length.
+pub enum Blake256 {}
+
+/// A hash digest.
+///
+/// Check [`blake_256`](super) for more information.
+pub type Digest = crate::digest::Digest<Blake256, LENGTH_BYTES>;
@@ -0,0 +1,512 @@
+//! This module is optional and can be enabled using the `blake-512` Cargo feature.
+//!
+//! An implementation of the BLAKE-512 hash function.
+//!
+//! This is the final, 16-round version of BLAKE submitted to the SHA-3 competition, still required by a few systems such as Decred. It is not compatible with BLAKE2, which produces different digests.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::blake_512;
+//!
+//! let digest = blake_512::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "7a5f20325f5b5e38508327c655bc334dbe20463a29a361f9cb926e1786f9b09750a8a3afede46ad46a9430e232201437e81c9042c49d49d884f57c9d322cc3cc"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::blake_512;
+//!
+//! let digest = blake_512::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "7a5f20325f5b5e38508327c655bc334dbe20463a29a361f9cb926e1786f9b09750a8a3afede46ad46a9430e232201437e81c9042c49d49d884f57c9d322cc3cc"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized due to the unknown size of data chunks.
+//!
+//! The size of this buffer is at least as large as one hash block of data processed at a time.
+//!
+//! To mitigate buffering, and consequently reduce memory overhead, data chunks should be aligned to multiples of the block size, or processed in block-sized chunks.
+//!
+//! The [`Update::is_block_aligned`] and [`Update::buffered_len`] functions report whether data is buffered, and [`Update::processed_bytes`] returns the number of bytes passed to the hash so far.
+//!
+//! # Disclaimer
+//!
+//! BLAKE was a SHA-3 finalist but it is not standardized. It is provided for interoperability with existing systems; new designs should prefer SHA-2.
+
+pub mod block;
+pub mod digest;
+pub mod state;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::block::{Block, LENGTH_BYTES as BLOCK_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+use crate::buffer::Buffer;
+use crate::error::{MessageLengthError, StateError};
+use crate::export::{Reader, Writer};
+use crate::verify::Expected;
+
+/// Maximum message length in bytes, so that the number of processed bytes fits into 64 bits.
+///
+/// The 128-bit counter of BLAKE-512 allows longer messages, but the hash counts the processed bytes in 64 bits. The [`Update::update`] function does not check it and wraps the length of longer messages, the [`Update::try_update`] function rejects them.
+pub const MAX_MESSAGE_LENGTH_BYTES: u64 = u64::MAX;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::blake_512;
+///
+/// let digest = blake_512::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "a8cfbbd73726062df0c6864dda65defe58ef0cc52a5625090fa17601e1eecd1b628e94f396ae402a00acc9eab77b4d4c2e852aaaa25a636d80af3fc7913ef5b8"
+/// );
+///
+/// let digest = blake_512::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "1ab585e57d098b803f02947b36cc3980d8ae7cdf74e25fb44e318d13e607c4c634aa5be753e6169bd735f110d53e5f65659450f5557b6a7635a979a47d587941"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::blake_512;
+///
+/// let digest = blake_512::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "a8cfbbd73726062df0c6864dda65defe58ef0cc52a5625090fa17601e1eecd1b628e94f396ae402a00acc9eab77b4d4c2e852aaaa25a636d80af3fc7913ef5b8"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::blake_512;
+///
+/// let digest = blake_512::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "14266c7c704a3b58fb421ee69fd005fcc6eeff742136be67435df995b7c986e7cbde4dbde135e7689c354d2bc5b8d260536c554b4f84c118e61efc576fed7cd3"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::blake_512;
+///
+/// let digest = blake_512::hash("data");
+/// assert!(blake_512::verify("data", digest));
+/// assert!(blake_512::verify("data", digest.to_hex_uppercase()));
+/// assert!(!blake_512::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::blake_512;
+///
+/// // Create a new hash instance
+/// let mut hash = blake_512::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "1ab585e57d098b803f02947b36cc3980d8ae7cdf74e25fb44e318d13e607c4c634aa5be753e6169bd735f110d53e5f65659450f5557b6a7635a979a47d587941"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "a8cfbbd73726062df0c6864dda65defe58ef0cc52a5625090fa17601e1eecd1b628e94f396ae402a00acc9eab77b4d4c2e852aaaa25a636d80af3fc7913ef5b8"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    state: State,
+    unprocessed: Buffer<BLOCK_LENGTH_BYTES>,
+    processed: u64,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        let state = state::new();
+        let unprocessed = Buffer::new();
+        let processed = 0;
+        Self {
+            state,
+            unprocessed,
+            processed,
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+
+        if !self.unprocessed.is_empty() {
+            let missing = BLOCK_LENGTH_BYTES - self.unprocessed.len();
+            if data.len() < missing {
+                // Not enough data even for one block.
+                self.unprocessed.extend_from_slice(data);
+                return self;
+            }
+
+            // Complete the buffered block with the beginning of incoming data.
+            let (fillment, remainder) = data.split_at(missing);
+            self.unprocessed.extend_from_slice(fillment);
+            let block = Block::try_from(&self.unprocessed[..])
+                .expect("unprocessed data length must be exact size as block")
+                .into();
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u64);
+            self.state = self.state.update(block, u128::from(self.processed).wrapping_mul(8));
+            self.unprocessed.clear();
+            data = remainder;
+        }
+
+        // Internal buffer is empty, incoming data can be processed without buffering.
+        let mut chunks = data.chunks_exact(BLOCK_LENGTH_BYTES);
+        for chunk in chunks.by_ref() {
+            let block = Block::try_from(chunk)
+                .expect("chunk length must be exact size as block")
+                .into();
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u64);
+            self.state = self.state.update(block, u128::from(self.processed).wrapping_mul(8));
+        }
+        self.unprocessed.extend_from_slice(chunks.remainder());
+
+        self
+    }
+
+    /// Updates the internal state with an input data, unless the message would exceed [`MAX_MESSAGE_LENGTH_BYTES`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the state unchanged if the total length of the message would exceed the maximum.
+    pub fn try_update<T>(&mut self, data: T) -> Result<&mut Self, MessageLengthError>
+    where
+        T: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+        // The maximum is `u64::MAX`, so only an overflow of the number of processed bytes exceeds it.
+        match self.processed_bytes().checked_add(data.len() as u64) {
+            Some(_) => Ok(self.update(data)),
+            None => Err(MessageLengthError),
+        }
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        assert!(
+            self.unprocessed.len() < BLOCK_LENGTH_BYTES,
+            "unprocessed data length should be less than block length"
+        );
+
+        let length = {
+            let length = u128::from(self.processed) + self.unprocessed.len() as u128;
+            length.wrapping_mul(8) // convert byte-length into bits-length
+        };
+
+        let mut padding = [0u8; BLOCK_LENGTH_BYTES * 2];
+        padding[..self.unprocessed.len()].copy_from_slice(&self.unprocessed);
+        padding[self.unprocessed.len()] = 0x80;
+        let length_bytes = length.to_be_bytes();
+        let padding = if (self.unprocessed.len() + 1 + length_bytes.len()) <= BLOCK_LENGTH_BYTES {
+            &mut padding[..BLOCK_LENGTH_BYTES]
+        } else {
+            &mut padding[..]
+        };
+        let offset = padding.len() - length_bytes.len();
+        // The last padding bit before the length marks the full-length variant of BLAKE.
+        padding[offset - 1] |= 0x01;
+        padding[offset..].copy_from_slice(&length_bytes);
+
+        // Blocks that contain nothing but padding are processed with a zero counter.
+        let counters = [if self.unprocessed.is_empty() { 0 } else { length }, 0];
+        let state =
+            padding
+                .chunks_exact(BLOCK_LENGTH_BYTES)
+                .zip(counters)
+                .fold(self.state, |state, (chunk, counter)| {
+                    let block = Block::try_from(chunk)
+                        .expect("padding length must be multiple of block length")
+                        .into();
+                    state.update(block, counter)
+                });
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self.unprocessed.clear();
+        self.processed = 0;
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+
+    /// Returns the number of bytes passed to the hash so far, including the buffered ones.
+    #[must_use]
+    pub fn processed_bytes(&self) -> u64 {
+        self.processed.wrapping_add(self.unprocessed.len() as u64)
+    }
+
+    /// Returns the number of bytes buffered until a whole block is available.
+    ///
+    /// Updating with chunks that are multiples of the block length keeps the buffer empty.
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.unprocessed.len()
+    }
+
+    /// Returns `true` if no data is buffered, i.e. all bytes passed so far form whole blocks.
+    #[must_use]
+    pub fn is_block_aligned(&self) -> bool {
+        self.unprocessed.is_empty()
+    }
+
+    /// Exports the in-progress state in the versioned binary format described in the [crate documentation](crate#state-export).
+    #[must_use]
+    pub fn export_state(&self) -> Vec<u8> {
+        Writer::new("blake-512")
+            .u64s(&self.state.h)
+            .finish(self.processed, &self.unprocessed)
+    }
+
+    /// Imports an in-progress state exported by [`export_state`](Self::export_state), e.g. in another process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid exported state of this hash function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::blake_512;
+    ///
+    /// let state = blake_512::default().update("example ").export_state();
+    /// let digest = blake_512::Update::import_state(&state)
+    ///     .unwrap()
+    ///     .update("data")
+    ///     .digest();
+    /// assert_eq!(digest, blake_512::hash("example data"));
+    /// ```
+    pub fn import_state(bytes: &[u8]) -> Result<Self, StateError> {
+        let mut reader = Reader::new(bytes, "blake-512")?;
+        let h = reader.u64s()?;
+        let (processed, unprocessed) = reader.finish(BLOCK_LENGTH_BYTES)?;
+        Ok(Self {
+            state: State::from_raw(h),
+            unprocessed: Buffer::from_slice(unprocessed),
+            processed,
+        })
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let state = u.arbitrary()?;
+        // Full blocks are processed immediately, so less than a block is buffered.
+        let length = u.int_in_range(0..=BLOCK_LENGTH_BYTES - 1)?;
+        let unprocessed = Buffer::from_slice(u.bytes(length)?);
+        let processed = u.arbitrary::<u64>()?;
+        let processed = processed - processed % BLOCK_LENGTH_BYTES as u64;
+        Ok(Self {
+            state,
+            unprocessed,
+            processed,
+        })
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::blake_512;
+///
+/// // Create a new hash instance
+/// let mut hash = blake_512::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "14266c7c704a3b58fb421ee69fd005fcc6eeff742136be67435df995b7c986e7cbde4dbde135e7689c354d2bc5b8d260536c554b4f84c118e61efc576fed7cd3"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+        self.digest_into(&mut digest);
+        Digest::new(digest)
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        for (bytes, word) in out.chunks_exact_mut(8).zip(self.state.digest()) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+    }
+
+    /// Resets the hash state to the in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::new()
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the SHA-3 submission, appendix A.
+    #[test]
+    fn submission() {
+        assert_eq!(
+            hash("").to_hex_lowercase(),
+            "a8cfbbd73726062df0c6864dda65defe58ef0cc52a5625090fa17601e1eecd1b628e94f396ae402a00acc9eab77b4d4c2e852aaaa25a636d80af3fc7913ef5b8"
+        );
+        assert_eq!(
+            hash([0u8]).to_hex_lowercase(),
+            "97961587f6d970faba6d2478045de6d1fabd09b61ae50932054d52bc29d31be4ff9102b9f69e2bbdb83be13d4b9c06091e5fa0b48bd081b634058be0ec49beb3"
+        );
+        assert_eq!(
+            hash([0u8; 144]).to_hex_lowercase(),
+            "313717d608e9cf758dcb1eb0f0c3cf9fc150b2d500fb33f51c52afc99d358a2f1374b8a38bba7974e7f6ef79cab16f22ce1e649d6e01ad9589c213045d545dde"
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Module contains items related to the [`State`] structure which allows to the direct BLAKE-512 state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::blake_512;
+//!
+//! // Create new state
+//! let mut state = blake_512::state::default();
+//!
+//! // By default it returns initialization values
+//! assert_eq!(
+//!     state.digest(),
+//!     [
+//!         0x6A09E667F3BCC908,
+//!         0xBB67AE8584CAA73B,
+//!         0x3C6EF372FE94F82B,
+//!         0xA54FF53A5F1D36F1,
+//!         0x510E527FADE682D1,
+//!         0x9B05688C2B3E6C1F,
+//!         0x1F83D9ABFB41BD6B,
+//!         0x5BE0CD19137E2179
+//!     ]
+//! );
+//!
+//! // Create a block of data with proper padding
+//! let mut block = [0u8; blake_512::BLOCK_LENGTH_BYTES];
+//! block[0] = 0x80;
+//! block[111] = 0x01;
+//! let data = blake_512::Block::new(block).into();
+//!
+//! // Update state with the number of message bits in the block and own new value
+//! state = state.update(data, 0);
+//!
+//! // Proper digest of empty input
+//! assert_eq!(
+//!     state.digest(),
+//!     [
+//!         0xA8CFBBD73726062D,
+//!         0xF0C6864DDA65DEFE,
+//!         0x58EF0CC52A562509,
+//!         0x0FA17601E1EECD1B,
+//!         0x628E94F396AE402A,
+//!         0x00ACC9EAB77B4D4C,
+//!         0x2E852AAAA25A636D,
+//!         0x80AF3FC7913EF5B8
+//!     ]
+//! );
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(
+//!     state.digest(),
+//!     [
+//!         0x6A09E667F3BCC908,
+//!         0xBB67AE8584CAA73B,
+//!         0x3C6EF372FE94F82B,
+//!         0xA54FF53A5F1D36F1,
+//!         0x510E527FADE682D1,
+//!         0x9B05688C2B3E6C1F,
+//!         0x1F83D9ABFB41BD6B,
+//!         0x5BE0CD19137E2179
+//!     ]
+//! );
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_QWORDS as BLOCK_LENGTH_QWORDS;
+use super::digest::LENGTH_QWORDS as DIGEST_LENGTH_QWORDS;
+
+#[allow(clippy::unreadable_literal)]
+const H: [u64; 8] = [
+    0x6A09E667F3BCC908,
+    0xBB67AE8584CAA73B,
+    0x3C6EF372FE94F82B,
+    0xA54FF53A5F1D36F1,
+    0x510E527FADE682D1,
+    0x9B05688C2B3E6C1F,
+    0x1F83D9ABFB41BD6B,
+    0x5BE0CD19137E2179,
+];
+
+#[allow(clippy::unreadable_literal)]
+const C: [u64; 16] = [
+    0x243F6A8885A308D3,
+    0x13198A2E03707344,
+    0xA4093822299F31D0,
+    0x082EFA98EC4E6C89,
+    0x452821E638D01377,
+    0xBE5466CF34E90C6C,
+    0xC0AC29B7C97C50DD,
+    0x3F84D5B5B5470917,
+    0x9216D5D98979FB1B,
+    0xD1310BA698DFB5AC,
+    0x2FFD72DBD01ADFB7,
+    0xB8E1AFED6A267E96,
+    0xBA7C9045F12C7F99,
+    0x24A19947B3916CF7,
+    0x0801F2E2858EFC16,
+    0x636920D871574E69,
+];
+
+#[rustfmt::skip]
+const SIGMA: [[usize; 16]; 10] = [
+    [ 0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15],
+    [14, 10,  4,  8,  9, 15, 13,  6,  1, 12,  0,  2, 11,  7,  5,  3],
+    [11,  8, 12,  0,  5,  2, 15, 13, 10, 14,  3,  6,  7,  1,  9,  4],
+    [ 7,  9,  3,  1, 13, 12, 11, 14,  2,  6,  5, 10,  4,  0, 15,  8],
+    [ 9,  0,  5,  7,  2,  4, 10, 15, 14,  1, 11, 12,  6,  8,  3, 13],
+    [ 2, 12,  6, 10,  0, 11,  8,  3,  4, 13,  7,  5, 15, 14,  1,  9],
+    [12,  5,  1, 15, 14, 13,  4, 10,  0,  7,  6,  3,  9,  2,  8, 11],
+    [13, 11,  7, 14, 12,  1,  3,  9,  5,  0, 15,  4,  8,  6,  2, 10],
+    [ 6, 15, 14,  9, 11,  3,  0,  8, 12,  2, 13,  7,  1,  4, 10,  5],
+    [10,  2,  8,  4,  7,  6,  1,  5, 15, 11,  9, 14,  3, 12, 13,  0],
+];
+
+const ROUNDS: usize = 16;
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// Returns the chaining values after processing a single block with the zero salt.
+///
+/// The counter has the same meaning as in [`State::update`].
+///
+/// **Warning!** Input block must be in the big-endian byte order.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::blake_512;
+///
+/// let state = blake_512::state::new().digest();
+/// let block = blake_512::Block::new([0u8; blake_512::BLOCK_LENGTH_BYTES]).into();
+/// assert_eq!(
+///     blake_512::state::compress(state, block, 1024),
+///     blake_512::state::new().update(block, 1024).digest()
+/// );
+/// ```
+#[must_use]
+pub const fn compress(
+    state: [u64; DIGEST_LENGTH_QWORDS],
+    block: [u64; BLOCK_LENGTH_QWORDS],
+    counter: u128,
+) -> [u64; DIGEST_LENGTH_QWORDS] {
+    State::from_raw(state).update(block, counter).digest()
+}
+
+/// A low-level hash state.
+///
+/// The salt is always zero.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) h: [u64; 8],
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(H)
+    }
+
+    /// Creates a state from the raw chaining values, e.g. a custom initialization vector.
+    ///
+    /// The state uses the zero salt.
+    #[must_use]
+    pub const fn from_raw(h: [u64; 8]) -> Self {
+        Self { h }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// The counter is the number of message bits processed so far, including the ones in this block but excluding the padding. A block that contains nothing but padding must be processed with a zero counter.
+    ///
+    /// **Warning!** Input block must be in the big-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u64; BLOCK_LENGTH_QWORDS], counter: u128) -> Self {
+        let (low, high) = (counter as u64, (counter >> 64) as u64);
+        let mut v = [
+            self.h[0],
+            self.h[1],
+            self.h[2],
+            self.h[3],
+            self.h[4],
+            self.h[5],
+            self.h[6],
+            self.h[7],
+            C[0],
+            C[1],
+            C[2],
+            C[3],
+            low ^ C[4],
+            low ^ C[5],
+            high ^ C[6],
+            high ^ C[7],
+        ];
+
+        let mut round = 0;
+        while round < ROUNDS {
+            let sigma = &SIGMA[round % 10];
+            v = g(v, [0, 4, 8, 12], block, sigma, 0);
+            v = g(v, [1, 5, 9, 13], block, sigma, 1);
+            v = g(v, [2, 6, 10, 14], block, sigma, 2);
+            v = g(v, [3, 7, 11, 15], block, sigma, 3);
+            v = g(v, [0, 5, 10, 15], block, sigma, 4);
+            v = g(v, [1, 6, 11, 12], block, sigma, 5);
+            v = g(v, [2, 7, 8, 13], block, sigma, 6);
+            v = g(v, [3, 4, 9, 14], block, sigma, 7);
+            round += 1;
+        }
+
+        let mut h = self.h;
+        let mut i = 0;
+        while i < 8 {
+            h[i] ^= v[i] ^ v[i + 8];
+            i += 1;
+        }
+        Self::from_raw(h)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns a digest.
+    #[must_use]
+    pub const fn digest(&self) -> [u64; DIGEST_LENGTH_QWORDS] {
+        self.h
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The G function applied to four words of the work vector.
+const fn g(
+    mut v: [u64; 16],
+    [a, b, c, d]: [usize; 4],
+    block: [u64; BLOCK_LENGTH_QWORDS],
+    sigma: &[usize; 16],
+    i: usize,
+) -> [u64; 16] {
+    let (x, y) = (sigma[2 * i], sigma[2 * i + 1]);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(block[x] ^ C[y]);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(25);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(block[y] ^ C[x]);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(11);
+    v
+}
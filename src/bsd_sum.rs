@@ -0,0 +1,292 @@
+//! This module is optional and can be enabled using the `bsd-sum` Cargo feature.
+//!
+//! An implementation of the BSD sum checksum.
+//!
+//! The BSD checksum is a 16-bit checksum that rotates the intermediate value by one bit before adding each byte. It is the default algorithm of the `sum` utility on BSD systems and in GNU coreutils, and is selected by `sum -r` elsewhere.
+//!
+//! The `sum` utility prints the checksum in decimal, followed by the number of 1024-byte blocks of the input; only the checksum is calculated by this module.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::bsd_sum;
+//!
+//! let digest = bsd_sum::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "40e8");
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::bsd_sum;
+//!
+//! let digest = bsd_sum::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "40e8");
+//! ```
+//!
+//! # Decimal Representation
+//!
+//! The digest can be converted into an integer to compare it with the output of the `sum -r` command.
+//!
+//! ```rust
+//! use chksum_hash::bsd_sum;
+//!
+//! let digest = bsd_sum::hash("example data");
+//! assert_eq!(u16::from(digest), 16616);
+//! ```
+//!
+//! # Disclaimer
+//!
+//! The BSD checksum is a weak error-detecting code, not a cryptographic hash function. It misses many kinds of accidental corruption, such as reordered data, and must not be used to protect against intentional modification.
+
+pub mod digest;
+pub mod state;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+use crate::verify::Expected;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bsd_sum;
+///
+/// let digest = bsd_sum::new().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "0000");
+///
+/// let digest = bsd_sum::new().update("data").digest();
+/// assert_eq!(digest.to_hex_lowercase(), "c0bf");
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bsd_sum;
+///
+/// let digest = bsd_sum::default().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "0000");
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bsd_sum;
+///
+/// let digest = bsd_sum::hash("abc");
+/// assert_eq!(digest.to_hex_lowercase(), "40ac");
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bsd_sum;
+///
+/// let digest = bsd_sum::hash("data");
+/// assert!(bsd_sum::verify("data", digest));
+/// assert!(bsd_sum::verify("data", digest.to_hex_uppercase()));
+/// assert!(!bsd_sum::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A checksum state that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bsd_sum;
+///
+/// // Create a new hash instance
+/// let mut hash = bsd_sum::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "c0bf");
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(digest.to_hex_lowercase(), "0000");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    state: State,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        let state = state::new();
+        Self { state }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// The data is processed immediately, no internal buffer is used.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.state = self.state.update(data.as_ref());
+        self
+    }
+
+    /// Produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let state = self.state;
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized checksum state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::bsd_sum;
+///
+/// // Create a new hash instance
+/// let mut hash = bsd_sum::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "40ac");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the checksum digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        Digest::new(self.state.digest().to_be_bytes())
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        *out = self.state.digest().to_be_bytes();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::new()
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // There are no published test vectors, the expected value is computed by hand from the definition.
+    #[test]
+    fn definition() {
+        assert_eq!(hash("").to_hex_lowercase(), "0000");
+        assert_eq!(hash("abc").to_hex_lowercase(), "40ac");
+    }
+}
@@ -0,0 +1,93 @@
+//! Module contains items related to the [`State`] structure which allows to the direct BSD sum state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::bsd_sum;
+//!
+//! // Create new state
+//! let mut state = bsd_sum::state::default();
+//!
+//! // By default it returns the checksum of empty input
+//! assert_eq!(state.digest(), 0x0000);
+//!
+//! // Update state with any amount of data and own new value
+//! state = state.update(b"example data");
+//! assert_eq!(state.digest(), 0x40E8);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state.digest(), 0x0000);
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level checksum state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) checksum: u16,
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(0)
+    }
+
+    /// Creates a state from the raw checksum, e.g. a custom initial value.
+    #[must_use]
+    pub const fn from_raw(checksum: u16) -> Self {
+        Self { checksum }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// Unlike hash functions, the data does not need to be split into blocks.
+    #[must_use]
+    pub const fn update(&self, data: &[u8]) -> Self {
+        let mut checksum = self.checksum;
+        let mut i = 0;
+        while i < data.len() {
+            checksum = checksum.rotate_right(1).wrapping_add(data[i] as u16);
+            i += 1;
+        }
+        Self::from_raw(checksum)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns a checksum.
+    #[must_use]
+    pub const fn digest(&self) -> u16 {
+        self.checksum
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,120 @@
+//! A fixed-capacity buffer of the unprocessed data of hash states.
+//!
+//! The buffer is stored inline, so the hash states that keep at most one block of unprocessed data do not allocate.
+
+use std::fmt::{self, Debug, Formatter};
+use std::ops::{Deref, DerefMut};
+
+/// A buffer of at most `N` bytes with an API resembling the one of `Vec<u8>`.
+#[derive(Clone)]
+pub(crate) struct Buffer<const N: usize> {
+    bytes: [u8; N],
+    length: usize,
+}
+
+impl<const N: usize> Buffer<N> {
+    /// Creates an empty buffer.
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0u8; N],
+            length: 0,
+        }
+    }
+
+    /// Creates a buffer containing the data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is longer than the capacity of the buffer.
+    pub(crate) fn from_slice(data: &[u8]) -> Self {
+        let mut buffer = Self::new();
+        buffer.extend_from_slice(data);
+        buffer
+    }
+
+    /// Appends the data to the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data does not fit into the buffer.
+    pub(crate) fn extend_from_slice(&mut self, data: &[u8]) {
+        let length = self.length + data.len();
+        assert!(length <= N, "buffered data length must not exceed buffer capacity");
+        self.bytes[self.length..length].copy_from_slice(data);
+        self.length = length;
+    }
+
+    /// Resizes the buffer to the given length, filling the new bytes with the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is greater than the capacity of the buffer.
+    pub(crate) fn resize(&mut self, length: usize, value: u8) {
+        assert!(length <= N, "buffered data length must not exceed buffer capacity");
+        if length > self.length {
+            self.bytes[self.length..length].fill(value);
+        }
+        self.length = length;
+    }
+
+    /// Removes all data from the buffer.
+    pub(crate) fn clear(&mut self) {
+        self.length = 0;
+    }
+}
+
+impl<const N: usize> Default for Buffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for Buffer<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes[..self.length]
+    }
+}
+
+impl<const N: usize> DerefMut for Buffer<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.bytes[..self.length]
+    }
+}
+
+impl<const N: usize> Debug for Buffer<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<const N: usize> PartialEq for Buffer<N> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<const N: usize> Eq for Buffer<N> {}
+
+/// Serializes the buffered data, as the lowercase hexadecimal representation in human-readable formats and as bytes in binary formats.
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Buffer<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serialization::serialize(self, false, serializer)
+    }
+}
+
+/// Deserializes the buffered data, rejecting a full buffer, since the hash states process full blocks immediately.
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Buffer<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serialization::deserialize_bytes(deserializer, "buffer", 0..=N - 1).map(|data| Self::from_slice(&data))
+    }
+}
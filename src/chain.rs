@@ -0,0 +1,196 @@
+//! This module is optional and can be enabled using the `chain` Cargo feature.
+//!
+//! An implementation of hash chains, i.e. iterated hashing.
+//!
+//! The first digest of the chain is the digest of the input data and each following digest is the digest of the previous one. Hash chains are the base of one-time password schemes, like S/KEY ([RFC 1760](https://www.rfc-editor.org/rfc/rfc1760)), and of commitment chains, where the values are revealed in the reverse order. The chain is generic over the hash functions of this crate, i.e. any type implementing the `Update` trait of the core crate.
+//!
+//! # Iterated Hashing
+//!
+//! The digest after the given number of iterations can be calculated with the [`hash_iter`] function.
+//!
+//! ```rust
+//! use chksum_hash::{chain, sha2_256};
+//!
+//! let digest = chain::hash_iter::<sha2_256::Update>("example data", 3);
+//! assert_eq!(
+//!     digest,
+//!     sha2_256::hash(sha2_256::hash(sha2_256::hash("example data")))
+//! );
+//! ```
+//!
+//! # Intermediate Digests
+//!
+//! The digests of the chain can be iterated lazily with the iterator created by calling the [`new`] function.
+//!
+//! ```rust
+//! use chksum_hash::{chain, md5};
+//!
+//! let digests = chain::new::<md5::Update>("example data")
+//!     .take(3)
+//!     .collect::<Vec<_>>();
+//! assert_eq!(digests[0], md5::hash("example data"));
+//! assert_eq!(digests[1], md5::hash(digests[0]));
+//! assert_eq!(digests[2], md5::hash(digests[1]));
+//! ```
+
+use chksum_hash_core as core;
+
+use crate::error::ParameterError;
+
+/// Creates a new chain starting with the digest of the given data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{chain, sha1};
+///
+/// let mut chain = chain::new::<sha1::Update>("data");
+/// assert_eq!(chain.next(), Some(sha1::hash("data")));
+/// ```
+#[must_use]
+pub fn new<H>(data: impl AsRef<[u8]>) -> Chain<H>
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]> + Clone,
+{
+    Chain::new(data)
+}
+
+/// Computes the digest after applying the hash function the given number of times.
+///
+/// The state of the hash function is reused for all iterations and no memory is allocated.
+///
+/// # Panics
+///
+/// Panics if the number of iterations is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{chain, sha2_256};
+///
+/// let digest = chain::hash_iter::<sha2_256::Update>("data", 1);
+/// assert_eq!(digest, sha2_256::hash("data"));
+///
+/// let digest = chain::hash_iter::<sha2_256::Update>("data", 1000);
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "b204b64dda5a751f7b12333f8b848a8a543da234805f6ced2e5235ccb8e0b4bd"
+/// );
+/// ```
+#[must_use]
+pub fn hash_iter<H>(data: impl AsRef<[u8]>, iterations: u32) -> H::Digest
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]>,
+{
+    assert!(iterations > 0, "number of iterations must be greater than zero");
+
+    let mut hash = H::default();
+    hash.update(data);
+    let mut digest = hash.digest();
+    for _ in 1..iterations {
+        hash.reset();
+        hash.update(digest);
+        digest = hash.digest();
+    }
+    digest
+}
+
+/// Computes the digest after applying the hash function the given number of times, e.g. with a number of iterations read from untrusted input.
+///
+/// # Errors
+///
+/// Returns an error if the number of iterations is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{chain, sha2_256};
+///
+/// let digest = chain::try_hash_iter::<sha2_256::Update>("data", 1).unwrap();
+/// assert_eq!(digest, sha2_256::hash("data"));
+///
+/// assert!(chain::try_hash_iter::<sha2_256::Update>("data", 0).is_err());
+/// ```
+pub fn try_hash_iter<H>(data: impl AsRef<[u8]>, iterations: u32) -> Result<H::Digest, ParameterError>
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]>,
+{
+    if iterations > 0 {
+        Ok(hash_iter::<H>(data, iterations))
+    } else {
+        Err(ParameterError {
+            parameter: "number of iterations",
+        })
+    }
+}
+
+/// An infinite iterator over the digests of a hash chain.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{chain, sha2_256};
+///
+/// // The last digest is committed to and the previous ones are revealed one by one
+/// let mut digests = chain::Chain::<sha2_256::Update>::new("seed")
+///     .take(100)
+///     .collect::<Vec<_>>();
+/// let commitment = digests.pop().unwrap();
+///
+/// let revealed = digests.pop().unwrap();
+/// assert_eq!(sha2_256::hash(revealed), commitment);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Chain<H>
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]> + Clone,
+{
+    hash: H,
+    // The input data until the first digest is produced.
+    data: Option<Vec<u8>>,
+    digest: Option<H::Digest>,
+}
+
+impl<H> Chain<H>
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]> + Clone,
+{
+    /// Creates a new chain starting with the digest of the given data.
+    #[must_use]
+    pub fn new(data: impl AsRef<[u8]>) -> Self {
+        Self {
+            hash: H::default(),
+            data: Some(data.as_ref().to_vec()),
+            digest: None,
+        }
+    }
+}
+
+impl<H> Iterator for Chain<H>
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]> + Clone,
+{
+    type Item = H::Digest;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.hash.reset();
+        match (self.data.take(), self.digest.take()) {
+            (Some(data), _) => self.hash.update(data),
+            (None, Some(digest)) => self.hash.update(digest),
+            (None, None) => unreachable!("chain holds either data or the previous digest"),
+        }
+        let digest = self.hash.digest();
+        self.digest = Some(digest.clone());
+        Some(digest)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
@@ -0,0 +1,373 @@
+//! This module is optional and can be enabled using the `checksum-file` Cargo feature.
+//!
+//! An implementation of the checksum files of GNU coreutils, i.e. the output of `sha256sum`, `md5sum` and similar tools.
+//!
+//! Every line of a checksum file is an [`Entry`], the lowercase hexadecimal digest, a space, the [`Mode`] marker and the path, e.g. `<hex>  file.txt`. The format does not name the hash function, so it has to be known from the context, e.g. from the name of the file like `SHA256SUMS`.
+//!
+//! The BSD format, also written by the `--tag` option of coreutils, names the hash function in every line, e.g. `SHA256 (file.txt) = <hex>`, see [`TaggedEntry`] and [`Algorithm`].
+//!
+//! Paths containing backslashes, line feeds or carriage returns are escaped and the line is prefixed with a backslash, the same way as by coreutils. Lines ending with `\r\n` are accepted when parsing.
+//!
+//! # Emitting
+//!
+//! ```rust
+//! use chksum_hash::checksum_file::{self, Entry, Mode};
+//! use chksum_hash::sha2_256;
+//!
+//! let entries = [
+//!     Entry::new(sha2_256::hash(""), "empty.txt", Mode::Text),
+//!     Entry::new(sha2_256::hash("example data"), "data.bin", Mode::Binary),
+//! ];
+//! let contents = checksum_file::format(&entries);
+//! assert_eq!(
+//!     contents.lines().nth(1),
+//!     Some("44752f37272e944fd2c913a35342eaccdd1aaf189bae50676b301ab213fc5061 *data.bin")
+//! );
+//! ```
+//!
+//! # Parsing and Verification
+//!
+//! ```rust
+//! use chksum_hash::{checksum_file, sha2_256};
+//!
+//! let contents = "44752f37272e944fd2c913a35342eaccdd1aaf189bae50676b301ab213fc5061  data.txt\r\n";
+//! for entry in checksum_file::parse(contents) {
+//!     let entry = entry.unwrap();
+//!     assert_eq!(entry.path(), "data.txt");
+//!     assert!(entry.verify::<sha2_256::Update>("example data"));
+//! }
+//! ```
+//!
+//! # Tagged Entries
+//!
+//! ```rust
+//! use chksum_hash::checksum_file::{self, Algorithm, TaggedEntry};
+//!
+//! let entry = TaggedEntry::hash(Algorithm::Md5, "empty.txt", "");
+//! assert_eq!(
+//!     entry.to_string(),
+//!     "MD5 (empty.txt) = d41d8cd98f00b204e9800998ecf8427e"
+//! );
+//!
+//! for entry in checksum_file::parse_tagged(&checksum_file::format(&[entry])) {
+//!     assert!(entry.unwrap().verify(""));
+//! }
+//! ```
+
+#[cfg(any(
+    feature = "md4",
+    feature = "md5",
+    feature = "sha1",
+    feature = "sha2-224",
+    feature = "sha2-256",
+    feature = "sha2-384",
+    feature = "sha2-512",
+    feature = "skein-256",
+    feature = "skein-512",
+    feature = "skein-1024",
+))]
+mod tagged;
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter, Write};
+use std::io::{self, Read};
+use std::str::FromStr;
+
+use chksum_hash_core as core;
+
+#[cfg(any(
+    feature = "md4",
+    feature = "md5",
+    feature = "sha1",
+    feature = "sha2-224",
+    feature = "sha2-256",
+    feature = "sha2-384",
+    feature = "sha2-512",
+    feature = "skein-256",
+    feature = "skein-512",
+    feature = "skein-1024",
+))]
+pub use self::tagged::{parse_tagged, Algorithm, TaggedEntry};
+use crate::hex;
+
+/// A mode of reading the file, marked between the digest and the path.
+///
+/// The modes differ only on systems distinguishing text and binary files, on all other systems both mean the same.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Text mode, marked with a space, the default mode.
+    #[default]
+    Text,
+    /// Binary mode, marked with an asterisk.
+    Binary,
+}
+
+impl Mode {
+    /// Returns the character marking the mode.
+    #[must_use]
+    pub const fn marker(&self) -> char {
+        match self {
+            Self::Text => ' ',
+            Self::Binary => '*',
+        }
+    }
+}
+
+/// Returns the lines of the entries, every line terminated with a line feed.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::checksum_file::{self, Entry, Mode};
+/// use chksum_hash::md5;
+///
+/// let entries = [Entry::new(md5::hash(""), "a\nb", Mode::Text)];
+/// assert_eq!(
+///     checksum_file::format(&entries),
+///     "\\d41d8cd98f00b204e9800998ecf8427e  a\\nb\n"
+/// );
+/// ```
+#[must_use]
+pub fn format<'a, E>(entries: impl IntoIterator<Item = &'a E>) -> String
+where
+    E: Display + 'a,
+{
+    entries.into_iter().map(|entry| format!("{entry}\n")).collect()
+}
+
+/// Parses the entries of the checksum file lazily, one per non-empty line.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::checksum_file::{self, ParseError};
+///
+/// let contents = "d41d8cd98f00b204e9800998ecf8427e  empty.txt\n\ninvalid\n";
+/// let entries = checksum_file::parse(contents).collect::<Vec<_>>();
+/// assert_eq!(entries.len(), 2);
+/// assert_eq!(entries[0].as_ref().unwrap().path(), "empty.txt");
+/// assert_eq!(entries[1], Err(ParseError::MissingSeparator));
+/// ```
+pub fn parse(contents: &str) -> impl Iterator<Item = Result<Entry, ParseError>> + '_ {
+    lines(contents).map(str::parse)
+}
+
+/// An entry of a checksum file, i.e. a digest of the file at the given path.
+///
+/// The [`Display`] and [`FromStr`] implementations use a single line without the line terminator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    digest: Vec<u8>,
+    path: String,
+    mode: Mode,
+}
+
+impl Entry {
+    /// Creates a new entry of the digest of the file at the given path.
+    #[must_use]
+    pub fn new(digest: impl AsRef<[u8]>, path: impl Into<String>, mode: Mode) -> Self {
+        let digest = digest.as_ref().to_vec();
+        let path = path.into();
+        Self { digest, path, mode }
+    }
+
+    /// Returns the digest.
+    #[must_use]
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    /// Returns the path of the file.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the mode of reading the file.
+    #[must_use]
+    pub const fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns `true` if the digest of the entry is the digest of the given input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::checksum_file::{Entry, Mode};
+    /// use chksum_hash::sha1;
+    ///
+    /// let entry = Entry::new(sha1::hash("data"), "data.txt", Mode::Text);
+    /// assert!(entry.verify::<sha1::Update>("data"));
+    /// assert!(!entry.verify::<sha1::Update>("other data"));
+    /// ```
+    #[must_use]
+    pub fn verify<H>(&self, data: impl AsRef<[u8]>) -> bool
+    where
+        H: core::Update,
+        H::Digest: AsRef<[u8]>,
+    {
+        core::hash::<H>(data).as_ref() == self.digest
+    }
+
+    /// Returns `true` if the digest of the entry is the digest of the data read from the reader until the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::checksum_file::{Entry, Mode};
+    /// use chksum_hash::sha2_256;
+    ///
+    /// let entry = Entry::new(sha2_256::hash("example data"), "data.txt", Mode::Text);
+    /// assert!(entry
+    ///     .verify_reader::<sha2_256::Update>(&b"example data"[..])
+    ///     .unwrap());
+    /// ```
+    pub fn verify_reader<H>(&self, reader: impl Read) -> io::Result<bool>
+    where
+        H: core::Update,
+        H::Digest: AsRef<[u8]>,
+    {
+        Ok(hash_reader::<H>(reader)? == self.digest)
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let escaped = is_escaped(&self.path);
+        if escaped {
+            f.write_char('\\')?;
+        }
+        write_hex(f, &self.digest)?;
+        write!(f, " {}", self.mode.marker())?;
+        write_path(f, &self.path, escaped)
+    }
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (escaped, line) = match s.strip_prefix('\\') {
+            Some(line) => (true, line),
+            None => (false, s),
+        };
+        let (encoded, rest) = line.split_once(' ').ok_or(ParseError::MissingSeparator)?;
+        let digest = decode_digest(encoded)?;
+        let mode = match rest.chars().next() {
+            Some(' ') => Mode::Text,
+            Some('*') => Mode::Binary,
+            _ => return Err(ParseError::MissingSeparator),
+        };
+        let path = &rest[1..];
+        if path.is_empty() {
+            return Err(ParseError::EmptyPath);
+        }
+        let path = if escaped { unescape(path)? } else { path.to_owned() };
+        Ok(Self { digest, path, mode })
+    }
+}
+
+/// An error of parsing an entry of a checksum file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A digest that is not an even number of hexadecimal digits or, in tagged entries, of a wrong length.
+    InvalidDigest,
+    /// No separator between the digest and the path, i.e. a space and the mode marker or, in tagged entries, the parentheses and the equals sign.
+    MissingSeparator,
+    /// A hash function of a tagged entry that is unknown or whose module is disabled.
+    UnsupportedAlgorithm,
+    /// No path after the mode marker.
+    EmptyPath,
+    /// A backslash in an escaped path not followed by `\`, `n` or `r`.
+    InvalidEscape,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDigest => f.write_str("invalid digest"),
+            Self::MissingSeparator => f.write_str("missing separator between digest and path"),
+            Self::UnsupportedAlgorithm => f.write_str("unsupported hash algorithm"),
+            Self::EmptyPath => f.write_str("empty path"),
+            Self::InvalidEscape => f.write_str("invalid escape sequence in path"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returns the non-empty lines without the line terminators.
+fn lines(contents: &str) -> impl Iterator<Item = &str> {
+    contents
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .filter(|line| !line.is_empty())
+}
+
+/// Computes the digest of the data read from the reader until the end.
+fn hash_reader<H>(reader: impl Read) -> io::Result<Vec<u8>>
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]>,
+{
+    crate::io::hash_reader::<H>(reader).map(|digest| digest.as_ref().to_vec())
+}
+
+/// Decodes the hexadecimal digest.
+fn decode_digest(encoded: &str) -> Result<Vec<u8>, ParseError> {
+    // The digest is case-insensitive, but the prefix of the hex module is not a part of the format.
+    if encoded.is_empty() || !encoded.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        return Err(ParseError::InvalidDigest);
+    }
+    hex::decode(encoded).map_err(|_| ParseError::InvalidDigest)
+}
+
+/// Writes the digest in the lowercase hexadecimal representation.
+fn write_hex(f: &mut Formatter<'_>, digest: &[u8]) -> fmt::Result {
+    digest.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+}
+
+/// Returns `true` if the path has to be escaped.
+fn is_escaped(path: &str) -> bool {
+    path.contains(['\\', '\n', '\r'])
+}
+
+/// Writes the path, escaping backslashes, line feeds and carriage returns if requested.
+fn write_path(f: &mut Formatter<'_>, path: &str, escaped: bool) -> fmt::Result {
+    if !escaped {
+        return f.write_str(path);
+    }
+    for character in path.chars() {
+        match character {
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            character => f.write_char(character)?,
+        }
+    }
+    Ok(())
+}
+
+/// Reverts the escaping of backslashes, line feeds and carriage returns.
+fn unescape(path: &str) -> Result<String, ParseError> {
+    let mut unescaped = String::with_capacity(path.len());
+    let mut characters = path.chars();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            unescaped.push(character);
+            continue;
+        }
+        match characters.next() {
+            Some('\\') => unescaped.push('\\'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            _ => return Err(ParseError::InvalidEscape),
+        }
+    }
+    Ok(unescaped)
+}
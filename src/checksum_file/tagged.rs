@@ -0,0 +1,411 @@
+//! Tagged entries of the BSD format.
+
+use std::fmt::{self, Display, Formatter, Write};
+use std::io::{self, Read};
+use std::str::FromStr;
+
+use super::{decode_digest, hash_reader, is_escaped, lines, unescape, write_hex, write_path, ParseError};
+use crate::error::LengthError;
+#[cfg(feature = "md4")]
+use crate::md4;
+#[cfg(feature = "md5")]
+use crate::md5;
+#[cfg(feature = "sha1")]
+use crate::sha1;
+#[cfg(feature = "sha2-224")]
+use crate::sha2_224;
+#[cfg(feature = "sha2-256")]
+use crate::sha2_256;
+#[cfg(feature = "sha2-384")]
+use crate::sha2_384;
+#[cfg(feature = "sha2-512")]
+use crate::sha2_512;
+#[cfg(feature = "skein-1024")]
+use crate::skein_1024;
+#[cfg(feature = "skein-256")]
+use crate::skein_256;
+#[cfg(feature = "skein-512")]
+use crate::skein_512;
+
+/// A hash function of tagged entries, available if the module of the hash function is enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// MD4, `MD4`.
+    #[cfg(feature = "md4")]
+    Md4,
+    /// MD5, `MD5`.
+    #[cfg(feature = "md5")]
+    Md5,
+    /// SHA-1, `SHA1`.
+    #[cfg(feature = "sha1")]
+    Sha1,
+    /// SHA-2 224, `SHA224`.
+    #[cfg(feature = "sha2-224")]
+    Sha2_224,
+    /// SHA-2 256, `SHA256`.
+    #[cfg(feature = "sha2-256")]
+    Sha2_256,
+    /// SHA-2 384, `SHA384`.
+    #[cfg(feature = "sha2-384")]
+    Sha2_384,
+    /// SHA-2 512, `SHA512`.
+    #[cfg(feature = "sha2-512")]
+    Sha2_512,
+    /// Skein-256 with 256-bit digests, `SKEIN256`.
+    #[cfg(feature = "skein-256")]
+    Skein256,
+    /// Skein-512 with 512-bit digests, `SKEIN512`.
+    #[cfg(feature = "skein-512")]
+    Skein512,
+    /// Skein-1024 with 1024-bit digests, `SKEIN1024`.
+    #[cfg(feature = "skein-1024")]
+    Skein1024,
+}
+
+impl Algorithm {
+    /// Returns the name of the hash function used in tagged entries, e.g. `"SHA256"`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "md4")]
+            Self::Md4 => "MD4",
+            #[cfg(feature = "md5")]
+            Self::Md5 => "MD5",
+            #[cfg(feature = "sha1")]
+            Self::Sha1 => "SHA1",
+            #[cfg(feature = "sha2-224")]
+            Self::Sha2_224 => "SHA224",
+            #[cfg(feature = "sha2-256")]
+            Self::Sha2_256 => "SHA256",
+            #[cfg(feature = "sha2-384")]
+            Self::Sha2_384 => "SHA384",
+            #[cfg(feature = "sha2-512")]
+            Self::Sha2_512 => "SHA512",
+            #[cfg(feature = "skein-256")]
+            Self::Skein256 => "SKEIN256",
+            #[cfg(feature = "skein-512")]
+            Self::Skein512 => "SKEIN512",
+            #[cfg(feature = "skein-1024")]
+            Self::Skein1024 => "SKEIN1024",
+        }
+    }
+
+    /// Returns the hash function with the given name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::checksum_file::Algorithm;
+    ///
+    /// assert_eq!(Algorithm::from_name("SHA1"), Some(Algorithm::Sha1));
+    /// assert_eq!(Algorithm::from_name("sha1"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let algorithm = match name {
+            #[cfg(feature = "md4")]
+            "MD4" => Self::Md4,
+            #[cfg(feature = "md5")]
+            "MD5" => Self::Md5,
+            #[cfg(feature = "sha1")]
+            "SHA1" => Self::Sha1,
+            #[cfg(feature = "sha2-224")]
+            "SHA224" => Self::Sha2_224,
+            #[cfg(feature = "sha2-256")]
+            "SHA256" => Self::Sha2_256,
+            #[cfg(feature = "sha2-384")]
+            "SHA384" => Self::Sha2_384,
+            #[cfg(feature = "sha2-512")]
+            "SHA512" => Self::Sha2_512,
+            #[cfg(feature = "skein-256")]
+            "SKEIN256" => Self::Skein256,
+            #[cfg(feature = "skein-512")]
+            "SKEIN512" => Self::Skein512,
+            #[cfg(feature = "skein-1024")]
+            "SKEIN1024" => Self::Skein1024,
+            _ => return None,
+        };
+        Some(algorithm)
+    }
+
+    /// Returns the length of the digest in bytes.
+    #[must_use]
+    pub const fn digest_length(&self) -> usize {
+        match *self {
+            #[cfg(feature = "md4")]
+            Self::Md4 => md4::DIGEST_LENGTH_BYTES,
+            #[cfg(feature = "md5")]
+            Self::Md5 => md5::DIGEST_LENGTH_BYTES,
+            #[cfg(feature = "sha1")]
+            Self::Sha1 => sha1::DIGEST_LENGTH_BYTES,
+            #[cfg(feature = "sha2-224")]
+            Self::Sha2_224 => sha2_224::DIGEST_LENGTH_BYTES,
+            #[cfg(feature = "sha2-256")]
+            Self::Sha2_256 => sha2_256::DIGEST_LENGTH_BYTES,
+            #[cfg(feature = "sha2-384")]
+            Self::Sha2_384 => sha2_384::DIGEST_LENGTH_BYTES,
+            #[cfg(feature = "sha2-512")]
+            Self::Sha2_512 => sha2_512::DIGEST_LENGTH_BYTES,
+            #[cfg(feature = "skein-256")]
+            Self::Skein256 => skein_256::DIGEST_LENGTH_BYTES,
+            #[cfg(feature = "skein-512")]
+            Self::Skein512 => skein_512::DIGEST_LENGTH_BYTES,
+            #[cfg(feature = "skein-1024")]
+            Self::Skein1024 => skein_1024::DIGEST_LENGTH_BYTES,
+        }
+    }
+
+    /// Computes the digest of the given input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::checksum_file::Algorithm;
+    /// use chksum_hash::sha2_256;
+    ///
+    /// let digest = Algorithm::Sha2_256.hash("data");
+    /// assert_eq!(digest, sha2_256::hash("data").as_ref());
+    /// ```
+    #[must_use]
+    pub fn hash(&self, data: impl AsRef<[u8]>) -> Vec<u8> {
+        match *self {
+            #[cfg(feature = "md4")]
+            Self::Md4 => md4::hash(data).as_ref().to_vec(),
+            #[cfg(feature = "md5")]
+            Self::Md5 => md5::hash(data).as_ref().to_vec(),
+            #[cfg(feature = "sha1")]
+            Self::Sha1 => sha1::hash(data).as_ref().to_vec(),
+            #[cfg(feature = "sha2-224")]
+            Self::Sha2_224 => sha2_224::hash(data).as_ref().to_vec(),
+            #[cfg(feature = "sha2-256")]
+            Self::Sha2_256 => sha2_256::hash(data).as_ref().to_vec(),
+            #[cfg(feature = "sha2-384")]
+            Self::Sha2_384 => sha2_384::hash(data).as_ref().to_vec(),
+            #[cfg(feature = "sha2-512")]
+            Self::Sha2_512 => sha2_512::hash(data).as_ref().to_vec(),
+            #[cfg(feature = "skein-256")]
+            Self::Skein256 => skein_256::hash(data).as_ref().to_vec(),
+            #[cfg(feature = "skein-512")]
+            Self::Skein512 => skein_512::hash(data).as_ref().to_vec(),
+            #[cfg(feature = "skein-1024")]
+            Self::Skein1024 => skein_1024::hash(data).as_ref().to_vec(),
+        }
+    }
+
+    /// Computes the digest of the data read from the reader until the end.
+    fn hash_reader(self, reader: impl Read) -> io::Result<Vec<u8>> {
+        match self {
+            #[cfg(feature = "md4")]
+            Self::Md4 => hash_reader::<md4::Update>(reader),
+            #[cfg(feature = "md5")]
+            Self::Md5 => hash_reader::<md5::Update>(reader),
+            #[cfg(feature = "sha1")]
+            Self::Sha1 => hash_reader::<sha1::Update>(reader),
+            #[cfg(feature = "sha2-224")]
+            Self::Sha2_224 => hash_reader::<sha2_224::Update>(reader),
+            #[cfg(feature = "sha2-256")]
+            Self::Sha2_256 => hash_reader::<sha2_256::Update>(reader),
+            #[cfg(feature = "sha2-384")]
+            Self::Sha2_384 => hash_reader::<sha2_384::Update>(reader),
+            #[cfg(feature = "sha2-512")]
+            Self::Sha2_512 => hash_reader::<sha2_512::Update>(reader),
+            #[cfg(feature = "skein-256")]
+            Self::Skein256 => hash_reader::<skein_256::Update>(reader),
+            #[cfg(feature = "skein-512")]
+            Self::Skein512 => hash_reader::<skein_512::Update>(reader),
+            #[cfg(feature = "skein-1024")]
+            Self::Skein1024 => hash_reader::<skein_1024::Update>(reader),
+        }
+    }
+}
+
+impl Display for Algorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the tagged entries of the checksum file lazily, one per non-empty line.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::checksum_file::{self, Algorithm, ParseError};
+///
+/// let contents =
+///     "SHA1 (empty.txt) = da39a3ee5e6b4b0d3255bfef95601890afd80709\nCRC32 (empty.txt) = 0\n";
+/// let entries = checksum_file::parse_tagged(contents).collect::<Vec<_>>();
+/// assert_eq!(entries[0].as_ref().unwrap().algorithm(), Algorithm::Sha1);
+/// assert_eq!(entries[1], Err(ParseError::UnsupportedAlgorithm));
+/// ```
+pub fn parse_tagged(contents: &str) -> impl Iterator<Item = Result<TaggedEntry, ParseError>> + '_ {
+    lines(contents).map(str::parse)
+}
+
+/// A tagged entry of a checksum file, i.e. a digest of the file at the given path together with the hash function.
+///
+/// The [`Display`] and [`FromStr`] implementations use a single line without the line terminator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TaggedEntry {
+    algorithm: Algorithm,
+    digest: Vec<u8>,
+    path: String,
+}
+
+impl TaggedEntry {
+    /// Creates a new tagged entry of the digest of the file at the given path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the digest differs from the digest length of the hash function.
+    #[must_use]
+    pub fn new(algorithm: Algorithm, digest: impl AsRef<[u8]>, path: impl Into<String>) -> Self {
+        let digest = digest.as_ref().to_vec();
+        assert_eq!(digest.len(), algorithm.digest_length(), "invalid digest length");
+        let path = path.into();
+        Self {
+            algorithm,
+            digest,
+            path,
+        }
+    }
+
+    /// Creates a new tagged entry of the digest of the file at the given path, e.g. of a digest read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of the digest differs from the digest length of the hash function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::checksum_file::{Algorithm, TaggedEntry};
+    /// use chksum_hash::error::LengthError;
+    ///
+    /// let entry = TaggedEntry::try_new(Algorithm::Md5, [0u8; 16], "empty.txt").unwrap();
+    /// assert_eq!(entry.digest(), [0u8; 16]);
+    ///
+    /// let entry = TaggedEntry::try_new(Algorithm::Md5, [0u8; 20], "empty.txt");
+    /// assert_eq!(entry, Err(LengthError { length: 20 }));
+    /// ```
+    pub fn try_new(
+        algorithm: Algorithm,
+        digest: impl AsRef<[u8]>,
+        path: impl Into<String>,
+    ) -> Result<Self, LengthError> {
+        let digest = digest.as_ref();
+        if digest.len() == algorithm.digest_length() {
+            Ok(Self::new(algorithm, digest, path))
+        } else {
+            Err(LengthError { length: digest.len() })
+        }
+    }
+
+    /// Computes the digest of the given input and creates a new tagged entry of the file at the given path.
+    #[must_use]
+    pub fn hash(algorithm: Algorithm, path: impl Into<String>, data: impl AsRef<[u8]>) -> Self {
+        let digest = algorithm.hash(data);
+        let path = path.into();
+        Self {
+            algorithm,
+            digest,
+            path,
+        }
+    }
+
+    /// Returns the hash function.
+    #[must_use]
+    pub const fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Returns the digest.
+    #[must_use]
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    /// Returns the path of the file.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns `true` if the digest of the entry is the digest of the given input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::checksum_file::TaggedEntry;
+    ///
+    /// let entry = "SHA256 (data.txt) = \
+    ///              44752f37272e944fd2c913a35342eaccdd1aaf189bae50676b301ab213fc5061"
+    ///     .parse::<TaggedEntry>()
+    ///     .unwrap();
+    /// assert!(entry.verify("example data"));
+    /// assert!(!entry.verify("other data"));
+    /// ```
+    #[must_use]
+    pub fn verify(&self, data: impl AsRef<[u8]>) -> bool {
+        self.algorithm.hash(data) == self.digest
+    }
+
+    /// Returns `true` if the digest of the entry is the digest of the data read from the reader until the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::checksum_file::{Algorithm, TaggedEntry};
+    ///
+    /// let entry = TaggedEntry::hash(Algorithm::Sha1, "data.txt", "example data");
+    /// assert!(entry.verify_reader(&b"example data"[..]).unwrap());
+    /// ```
+    pub fn verify_reader(&self, reader: impl Read) -> io::Result<bool> {
+        Ok(self.algorithm.hash_reader(reader)? == self.digest)
+    }
+}
+
+impl Display for TaggedEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let escaped = is_escaped(&self.path);
+        if escaped {
+            f.write_char('\\')?;
+        }
+        write!(f, "{} (", self.algorithm)?;
+        write_path(f, &self.path, escaped)?;
+        f.write_str(") = ")?;
+        write_hex(f, &self.digest)
+    }
+}
+
+impl FromStr for TaggedEntry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (escaped, line) = match s.strip_prefix('\\') {
+            Some(line) => (true, line),
+            None => (false, s),
+        };
+        let (name, rest) = line.split_once(" (").ok_or(ParseError::MissingSeparator)?;
+        let algorithm = Algorithm::from_name(name).ok_or(ParseError::UnsupportedAlgorithm)?;
+        // The path may contain the separator, so the digest follows the last one.
+        let (path, encoded) = rest.rsplit_once(") = ").ok_or(ParseError::MissingSeparator)?;
+        let digest = decode_digest(encoded)?;
+        if digest.len() != algorithm.digest_length() {
+            return Err(ParseError::InvalidDigest);
+        }
+        if path.is_empty() {
+            return Err(ParseError::EmptyPath);
+        }
+        let path = if escaped { unescape(path)? } else { path.to_owned() };
+        Ok(Self {
+            algorithm,
+            digest,
+            path,
+        })
+    }
+}
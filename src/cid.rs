@@ -0,0 +1,355 @@
+//! This module is optional and can be enabled using the `cid` Cargo feature.
+//!
+//! An implementation of version 1 of the [content identifiers](https://github.com/multiformats/cid) (CIDs) of IPFS.
+//!
+//! A CIDv1 is the version, the [`Codec`] of the content and the [`multihash`](crate::multihash) of the content, all prefixed with unsigned varints. The textual form is the binary form in one of the multibase encodings, see [`Base`], prefixed with the character identifying the encoding.
+//!
+//! # Batch Processing
+//!
+//! The identifier of known content can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::cid::{self, Codec};
+//! use chksum_hash::sha2_256;
+//!
+//! let cid = cid::hash::<sha2_256::Update>(Codec::Raw, "");
+//! assert_eq!(
+//!     cid.to_string(),
+//!     "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+//! );
+//! ```
+//!
+//! # Digests
+//!
+//! Digests calculated by other means can be wrapped with [`Cid::wrap`].
+//!
+//! ```rust
+//! use chksum_hash::cid::{Base, Cid, Codec};
+//! use chksum_hash::sha2_256;
+//!
+//! let digest = sha2_256::default()
+//!     .update("example")
+//!     .update(" data")
+//!     .digest();
+//! let cid = Cid::wrap(Codec::Raw, &digest);
+//! assert_eq!(cid.multihash().digest(), digest.as_ref());
+//! assert!(cid.to_string_of_base(Base::Base58Btc).starts_with('z'));
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use chksum_hash_core as core;
+
+use crate::multihash::{self, Multihash};
+use crate::{base32, base64};
+
+/// Version of the content identifiers.
+const VERSION: u64 = 1;
+
+/// Alphabet of the base58 encoding of Bitcoin.
+const ALPHABET_BASE58BTC: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// A content type of the multicodec table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Codec {
+    /// Raw binary data, `raw`.
+    Raw,
+    /// MerkleDAG protobuf, used by UnixFS files and directories, `dag-pb`.
+    DagPb,
+    /// MerkleDAG CBOR, `dag-cbor`.
+    DagCbor,
+    /// MerkleDAG JSON, `dag-json`.
+    DagJson,
+    /// Public key of libp2p, `libp2p-key`.
+    Libp2pKey,
+    /// Raw Git object, `git-raw`.
+    GitRaw,
+}
+
+impl Codec {
+    /// Returns the code of the content type in the multicodec table.
+    #[must_use]
+    pub const fn code(&self) -> u64 {
+        match self {
+            Self::Raw => 0x55,
+            Self::DagPb => 0x70,
+            Self::DagCbor => 0x71,
+            Self::DagJson => 0x0129,
+            Self::Libp2pKey => 0x72,
+            Self::GitRaw => 0x78,
+        }
+    }
+
+    /// Returns the content type with the given code of the multicodec table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::cid::Codec;
+    ///
+    /// assert_eq!(Codec::from_code(0x55), Some(Codec::Raw));
+    /// assert_eq!(Codec::from_code(0x12), None);
+    /// ```
+    #[must_use]
+    pub const fn from_code(code: u64) -> Option<Self> {
+        let codec = match code {
+            0x55 => Self::Raw,
+            0x70 => Self::DagPb,
+            0x71 => Self::DagCbor,
+            0x0129 => Self::DagJson,
+            0x72 => Self::Libp2pKey,
+            0x78 => Self::GitRaw,
+            _ => return None,
+        };
+        Some(codec)
+    }
+}
+
+impl Display for Codec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Raw => "raw",
+            Self::DagPb => "dag-pb",
+            Self::DagCbor => "dag-cbor",
+            Self::DagJson => "dag-json",
+            Self::Libp2pKey => "libp2p-key",
+            Self::GitRaw => "git-raw",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A multibase encoding of the textual form.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Base {
+    /// Lowercase hexadecimal, prefixed with `f`.
+    Base16,
+    /// Lowercase base32 of RFC 4648 without padding, prefixed with `b`, the default encoding of CIDv1.
+    #[default]
+    Base32,
+    /// Base58 with the alphabet of Bitcoin, prefixed with `z`.
+    Base58Btc,
+    /// Base64 of RFC 4648 without padding, prefixed with `m`.
+    Base64,
+    /// URL-safe base64 of RFC 4648 without padding, prefixed with `u`.
+    Base64Url,
+}
+
+impl Base {
+    /// Returns the character that prefixes the encoded data.
+    #[must_use]
+    pub const fn prefix(&self) -> char {
+        match self {
+            Self::Base16 => 'f',
+            Self::Base32 => 'b',
+            Self::Base58Btc => 'z',
+            Self::Base64 => 'm',
+            Self::Base64Url => 'u',
+        }
+    }
+
+    /// Encodes the data with the prefix.
+    fn encode(self, data: &[u8]) -> String {
+        let encoded = match self {
+            Self::Base16 => data.iter().map(|byte| format!("{byte:02x}")).collect(),
+            Self::Base32 => base32::encode(data).to_ascii_lowercase(),
+            Self::Base58Btc => encode_base58btc(data),
+            Self::Base64 => base64::encode_unpadded(data),
+            Self::Base64Url => base64::encode_url_unpadded(data),
+        };
+        format!("{}{encoded}", self.prefix())
+    }
+}
+
+/// Computes the digest of the given input and creates the identifier of the content of the given type.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::cid::{self, Codec};
+/// use chksum_hash::sha2_256;
+///
+/// let cid = cid::hash::<sha2_256::Update>(Codec::Raw, "data");
+/// assert_eq!(cid.codec(), Codec::Raw);
+/// assert_eq!(cid.multihash().digest(), sha2_256::hash("data").as_ref());
+/// ```
+pub fn hash<H>(codec: Codec, data: impl AsRef<[u8]>) -> Cid
+where
+    H: core::Update,
+    H::Digest: multihash::Digest,
+{
+    Cid::new(codec, multihash::hash::<H>(data))
+}
+
+/// A content identifier of version 1.
+///
+/// The [`Display`] implementation writes the textual form in the default base32 encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cid {
+    codec: Codec,
+    multihash: Multihash,
+}
+
+impl Cid {
+    /// Creates a new identifier of the content of the given type with the multihash.
+    #[must_use]
+    pub const fn new(codec: Codec, multihash: Multihash) -> Self {
+        Self { codec, multihash }
+    }
+
+    /// Creates a new identifier of the content of the given type with the digest.
+    #[must_use]
+    pub fn wrap<D>(codec: Codec, digest: &D) -> Self
+    where
+        D: multihash::Digest,
+    {
+        Self::new(codec, Multihash::wrap(digest))
+    }
+
+    /// Parses the identifier from the binary representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the identifier is not of version 1, uses an unknown content type or contains an invalid multihash, see [`DecodeError`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::cid::{self, Cid, Codec};
+    /// use chksum_hash::sha2_256;
+    ///
+    /// let cid = cid::hash::<sha2_256::Update>(Codec::DagCbor, "data");
+    /// assert_eq!(Cid::from_bytes(&cid.to_bytes()), Ok(cid));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (version, version_length) = multihash::read_varint(bytes)?;
+        if version != VERSION {
+            return Err(DecodeError::UnsupportedVersion { version });
+        }
+        let bytes = &bytes[version_length..];
+        let (code, code_length) = multihash::read_varint(bytes)?;
+        let codec = Codec::from_code(code).ok_or(DecodeError::UnknownCodec { code })?;
+        let multihash = Multihash::from_bytes(&bytes[code_length..])?;
+        Ok(Self::new(codec, multihash))
+    }
+
+    /// Returns the type of the content.
+    #[must_use]
+    pub const fn codec(&self) -> Codec {
+        self.codec
+    }
+
+    /// Returns the multihash of the content.
+    #[must_use]
+    pub const fn multihash(&self) -> &Multihash {
+        &self.multihash
+    }
+
+    /// Returns the binary representation, i.e. the version, the content type and the multihash.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        multihash::write_varint(&mut bytes, VERSION);
+        multihash::write_varint(&mut bytes, self.codec.code());
+        bytes.extend_from_slice(&self.multihash.to_bytes());
+        bytes
+    }
+
+    /// Returns the textual form in the given multibase encoding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::cid::{self, Base, Codec};
+    /// use chksum_hash::sha2_256;
+    ///
+    /// let cid = cid::hash::<sha2_256::Update>(Codec::Raw, "");
+    /// assert_eq!(
+    ///     cid.to_string_of_base(Base::Base16),
+    ///     "f01551220e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    /// );
+    /// assert_eq!(
+    ///     cid.to_string_of_base(Base::Base58Btc),
+    ///     "zb2rhmy65F3REf8SZp7De11gxtECBGgUKaLdiDj7MCGCHxbDW"
+    /// );
+    /// assert_eq!(cid.to_string_of_base(Base::Base32), cid.to_string());
+    /// ```
+    #[must_use]
+    pub fn to_string_of_base(&self, base: Base) -> String {
+        base.encode(&self.to_bytes())
+    }
+}
+
+impl Display for Cid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_of_base(Base::default()))
+    }
+}
+
+/// An error of decoding a content identifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// A version other than 1.
+    UnsupportedVersion {
+        /// The version.
+        version: u64,
+    },
+    /// A code that is not a content type of [`Codec`].
+    UnknownCodec {
+        /// The code from the multicodec table.
+        code: u64,
+    },
+    /// An invalid varint or multihash.
+    Multihash(multihash::DecodeError),
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion { version } => write!(f, "unsupported CID version {version}"),
+            Self::UnknownCodec { code } => write!(f, "unknown content type code {code:#x}"),
+            Self::Multihash(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl Error for DecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Multihash(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<multihash::DecodeError> for DecodeError {
+    fn from(error: multihash::DecodeError) -> Self {
+        Self::Multihash(error)
+    }
+}
+
+/// Encodes the data with the base58 alphabet of Bitcoin, leading zero bytes are encoded as `1`.
+fn encode_base58btc(data: &[u8]) -> String {
+    let zeros = data.iter().take_while(|&&byte| byte == 0).count();
+    // Digits of the number in base 58, the least significant first.
+    let mut digits = Vec::<u8>::with_capacity(data.len() * 138 / 100 + 1);
+    for &byte in &data[zeros..] {
+        let mut carry = u32::from(byte);
+        for digit in &mut digits {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let ones = std::iter::repeat('1').take(zeros);
+    let digits = digits
+        .iter()
+        .rev()
+        .map(|&digit| char::from(ALPHABET_BASE58BTC[usize::from(digit)]));
+    ones.chain(digits).collect()
+}
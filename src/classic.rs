@@ -0,0 +1,51 @@
+//! This module is optional and can be enabled using the `classic` Cargo feature.
+//!
+//! Implementations of classic 32-bit string hash functions.
+//!
+//! These simple hashes are still found in countless legacy formats, like symbol tables or old database layouts. The following hash functions are available:
+//!
+//! * [`djb2`] – Daniel J. Bernstein's multiply-by-33 hash,
+//! * [`sdbm`] – the hash of the sdbm database library,
+//! * [`elf`] – the ELF variant of the PJW hash used by ELF symbol hash tables.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the `hash` function of each module.
+//!
+//! ```rust
+//! use chksum_hash::classic::{djb2, elf, sdbm};
+//!
+//! let digest = djb2::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "118c0a8b");
+//!
+//! let digest = sdbm::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "a320e180");
+//!
+//! let digest = elf::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "04b82cd1");
+//! ```
+//!
+//! # Compile-time Evaluation
+//!
+//! All state functions are `const`, so hashes of constant data can be computed at compile time.
+//!
+//! ```rust
+//! use chksum_hash::classic::{djb2, elf, sdbm};
+//!
+//! const DJB2: u32 = djb2::state::new().update(b"example data").digest();
+//! assert_eq!(DJB2, 0x118C0A8B);
+//!
+//! const SDBM: u32 = sdbm::state::new().update(b"example data").digest();
+//! assert_eq!(SDBM, 0xA320E180);
+//!
+//! const ELF: u32 = elf::state::new().update(b"example data").digest();
+//! assert_eq!(ELF, 0x04B82CD1);
+//! ```
+//!
+//! # Disclaimer
+//!
+//! None of these hashes is a cryptographic hash function. They are designed for hash table lookup and must not be used where resistance to deliberate collisions is required.
+
+pub mod djb2;
+pub mod elf;
+pub mod sdbm;
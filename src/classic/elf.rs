@@ -0,0 +1,279 @@
+//! This module is optional and can be enabled using the `classic` Cargo feature.
+//!
+//! An implementation of the ELF hash function.
+//!
+//! The ELF hash is the 32-bit variant of Peter J. Weinberger's hash (PJW hash) specified by the System V ABI for the symbol hash tables of ELF object files. The four most significant bits of its result are always zero.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::classic::elf;
+//!
+//! let digest = elf::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "04b82cd1");
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::classic::elf;
+//!
+//! let digest = elf::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "04b82cd1");
+//! ```
+//!
+//! # Disclaimer
+//!
+//! The ELF hash is not a cryptographic hash function. It is designed for hash table lookup and must not be used where resistance to deliberate collisions is required.
+
+pub mod digest;
+pub mod state;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+use crate::verify::Expected;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::classic::elf;
+///
+/// let digest = elf::new().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "00000000");
+///
+/// let digest = elf::new().update("data").digest();
+/// assert_eq!(digest.to_hex_lowercase(), "0006a8a1");
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::classic::elf;
+///
+/// let digest = elf::default().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "00000000");
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::classic::elf;
+///
+/// let digest = elf::hash("abc");
+/// assert_eq!(digest.to_hex_lowercase(), "00006783");
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::classic::elf;
+///
+/// let digest = elf::hash("data");
+/// assert!(elf::verify("data", digest));
+/// assert!(elf::verify("data", digest.to_hex_uppercase()));
+/// assert!(!elf::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::classic::elf;
+///
+/// // Create a new hash instance
+/// let mut hash = elf::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "0006a8a1");
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(digest.to_hex_lowercase(), "00000000");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    state: State,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        let state = state::new();
+        Self { state }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// The data is processed immediately, no internal buffer is used.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.state = self.state.update(data.as_ref());
+        self
+    }
+
+    /// Produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let state = self.state;
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::classic::elf;
+///
+/// // Create a new hash instance
+/// let mut hash = elf::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "00006783");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        Digest::new(self.state.digest().to_be_bytes())
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        *out = self.state.digest().to_be_bytes();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::new()
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // There are no published test vectors, the expected values are computed by hand from the definition.
+    #[test]
+    fn definition() {
+        assert_eq!(hash("").to_hex_lowercase(), "00000000");
+        assert_eq!(hash("abc").to_hex_lowercase(), "00006783");
+    }
+}
@@ -0,0 +1,615 @@
+//! Module containing `const` functions computing MD5, SHA-1 and SHA-2 digests.
+//!
+//! The functions pad the message in this crate and compress it with the `const` states of the hash functions, so digests can be computed at compile time, e.g. to embed content identifiers or test vectors in constants. At runtime the [`hash`](crate::sha2_256::hash) functions of the hash modules are faster and should be preferred.
+//!
+//! The `_digest` and `_hex` macros, like [`sha2_256_digest!`](crate::sha2_256_digest) and [`sha2_256_hex!`](crate::sha2_256_hex), expand to the digest or its hexadecimal representation computed at compile time.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::{const_hash, sha2_256};
+//!
+//! const DIGEST: sha2_256::Digest = const_hash::sha2_256(b"abc");
+//!
+//! assert_eq!(
+//!     DIGEST.to_hex_lowercase(),
+//!     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+//! );
+//! assert_eq!(DIGEST, sha2_256::hash("abc"));
+//! ```
+
+/// Expands to the MD5 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{md5, md5_digest};
+///
+/// let digest = md5_digest!(b"abc");
+/// assert_eq!(digest, md5::hash("abc"));
+/// ```
+#[cfg(feature = "md5")]
+#[macro_export]
+macro_rules! md5_digest {
+    ($data:expr) => {{
+        const DIGEST: $crate::md5::Digest = $crate::const_hash::md5($crate::const_hash::Input($data).bytes());
+        DIGEST
+    }};
+}
+
+/// Expands to the lowercase hexadecimal representation of the MD5 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md5_hex;
+///
+/// let hex: &'static str = md5_hex!("abc");
+/// assert_eq!(hex, "900150983cd24fb0d6963f7d28e17f72");
+/// ```
+#[cfg(feature = "md5")]
+#[macro_export]
+macro_rules! md5_hex {
+    ($data:expr) => {{
+        const HEX: [u8; $crate::md5::DIGEST_LENGTH_BYTES * 2] =
+            $crate::const_hash::encode_hex($crate::md5_digest!($data).as_bytes());
+        match ::core::str::from_utf8(&HEX) {
+            Ok(hex) => hex,
+            Err(_) => unreachable!(),
+        }
+    }};
+}
+
+/// Expands to the SHA-1 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{sha1, sha1_digest};
+///
+/// let digest = sha1_digest!(b"abc");
+/// assert_eq!(digest, sha1::hash("abc"));
+/// ```
+#[cfg(feature = "sha1")]
+#[macro_export]
+macro_rules! sha1_digest {
+    ($data:expr) => {{
+        const DIGEST: $crate::sha1::Digest = $crate::const_hash::sha1($crate::const_hash::Input($data).bytes());
+        DIGEST
+    }};
+}
+
+/// Expands to the lowercase hexadecimal representation of the SHA-1 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha1_hex;
+///
+/// let hex: &'static str = sha1_hex!("abc");
+/// assert_eq!(hex, "a9993e364706816aba3e25717850c26c9cd0d89d");
+/// ```
+#[cfg(feature = "sha1")]
+#[macro_export]
+macro_rules! sha1_hex {
+    ($data:expr) => {{
+        const HEX: [u8; $crate::sha1::DIGEST_LENGTH_BYTES * 2] =
+            $crate::const_hash::encode_hex($crate::sha1_digest!($data).as_bytes());
+        match ::core::str::from_utf8(&HEX) {
+            Ok(hex) => hex,
+            Err(_) => unreachable!(),
+        }
+    }};
+}
+
+/// Expands to the SHA-2 224 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{sha2_224, sha2_224_digest};
+///
+/// let digest = sha2_224_digest!(b"abc");
+/// assert_eq!(digest, sha2_224::hash("abc"));
+/// ```
+#[cfg(feature = "sha2-224")]
+#[macro_export]
+macro_rules! sha2_224_digest {
+    ($data:expr) => {{
+        const DIGEST: $crate::sha2_224::Digest = $crate::const_hash::sha2_224($crate::const_hash::Input($data).bytes());
+        DIGEST
+    }};
+}
+
+/// Expands to the lowercase hexadecimal representation of the SHA-2 224 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha2_224_hex;
+///
+/// let hex: &'static str = sha2_224_hex!("abc");
+/// assert_eq!(
+///     hex,
+///     "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"
+/// );
+/// ```
+#[cfg(feature = "sha2-224")]
+#[macro_export]
+macro_rules! sha2_224_hex {
+    ($data:expr) => {{
+        const HEX: [u8; $crate::sha2_224::DIGEST_LENGTH_BYTES * 2] =
+            $crate::const_hash::encode_hex($crate::sha2_224_digest!($data).as_bytes());
+        match ::core::str::from_utf8(&HEX) {
+            Ok(hex) => hex,
+            Err(_) => unreachable!(),
+        }
+    }};
+}
+
+/// Expands to the SHA-2 256 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{sha2_256, sha2_256_digest};
+///
+/// let digest = sha2_256_digest!(b"abc");
+/// assert_eq!(digest, sha2_256::hash("abc"));
+/// ```
+#[cfg(feature = "sha2-256")]
+#[macro_export]
+macro_rules! sha2_256_digest {
+    ($data:expr) => {{
+        const DIGEST: $crate::sha2_256::Digest = $crate::const_hash::sha2_256($crate::const_hash::Input($data).bytes());
+        DIGEST
+    }};
+}
+
+/// Expands to the lowercase hexadecimal representation of the SHA-2 256 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha2_256_hex;
+///
+/// let hex: &'static str = sha2_256_hex!("abc");
+/// assert_eq!(
+///     hex,
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+#[cfg(feature = "sha2-256")]
+#[macro_export]
+macro_rules! sha2_256_hex {
+    ($data:expr) => {{
+        const HEX: [u8; $crate::sha2_256::DIGEST_LENGTH_BYTES * 2] =
+            $crate::const_hash::encode_hex($crate::sha2_256_digest!($data).as_bytes());
+        match ::core::str::from_utf8(&HEX) {
+            Ok(hex) => hex,
+            Err(_) => unreachable!(),
+        }
+    }};
+}
+
+/// Expands to the SHA-2 384 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{sha2_384, sha2_384_digest};
+///
+/// let digest = sha2_384_digest!(b"abc");
+/// assert_eq!(digest, sha2_384::hash("abc"));
+/// ```
+#[cfg(feature = "sha2-384")]
+#[macro_export]
+macro_rules! sha2_384_digest {
+    ($data:expr) => {{
+        const DIGEST: $crate::sha2_384::Digest = $crate::const_hash::sha2_384($crate::const_hash::Input($data).bytes());
+        DIGEST
+    }};
+}
+
+/// Expands to the lowercase hexadecimal representation of the SHA-2 384 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha2_384_hex;
+///
+/// let hex: &'static str = sha2_384_hex!("abc");
+/// assert_eq!(hex, "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7");
+/// ```
+#[cfg(feature = "sha2-384")]
+#[macro_export]
+macro_rules! sha2_384_hex {
+    ($data:expr) => {{
+        const HEX: [u8; $crate::sha2_384::DIGEST_LENGTH_BYTES * 2] =
+            $crate::const_hash::encode_hex($crate::sha2_384_digest!($data).as_bytes());
+        match ::core::str::from_utf8(&HEX) {
+            Ok(hex) => hex,
+            Err(_) => unreachable!(),
+        }
+    }};
+}
+
+/// Expands to the SHA-2 512 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{sha2_512, sha2_512_digest};
+///
+/// let digest = sha2_512_digest!(b"abc");
+/// assert_eq!(digest, sha2_512::hash("abc"));
+/// ```
+#[cfg(feature = "sha2-512")]
+#[macro_export]
+macro_rules! sha2_512_digest {
+    ($data:expr) => {{
+        const DIGEST: $crate::sha2_512::Digest = $crate::const_hash::sha2_512($crate::const_hash::Input($data).bytes());
+        DIGEST
+    }};
+}
+
+/// Expands to the lowercase hexadecimal representation of the SHA-2 512 digest of the given string, byte string or byte slice constant, computed at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha2_512_hex;
+///
+/// let hex: &'static str = sha2_512_hex!("abc");
+/// assert_eq!(hex, "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f");
+/// ```
+#[cfg(feature = "sha2-512")]
+#[macro_export]
+macro_rules! sha2_512_hex {
+    ($data:expr) => {{
+        const HEX: [u8; $crate::sha2_512::DIGEST_LENGTH_BYTES * 2] =
+            $crate::const_hash::encode_hex($crate::sha2_512_digest!($data).as_bytes());
+        match ::core::str::from_utf8(&HEX) {
+            Ok(hex) => hex,
+            Err(_) => unreachable!(),
+        }
+    }};
+}
+
+/// Computes the MD5 digest of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{const_hash, md5};
+///
+/// const DIGEST: md5::Digest = const_hash::md5(b"abc");
+///
+/// assert_eq!(
+///     DIGEST.to_hex_lowercase(),
+///     "900150983cd24fb0d6963f7d28e17f72"
+/// );
+/// ```
+#[cfg(feature = "md5")]
+#[must_use]
+pub const fn md5(data: &[u8]) -> crate::md5::Digest {
+    let length = ((data.len() as u64).wrapping_mul(8)).to_le_bytes();
+    let blocks = block_count(data.len(), 64, length.len());
+    let mut state = crate::md5::State::new();
+    let mut index = 0;
+    while index < blocks {
+        let block = padded_block::<64>(data, index, blocks, &length);
+        state = state.update(dwords_le(&block));
+        index += 1;
+    }
+    crate::md5::Digest::new(bytes_from_dwords_le(state.digest()))
+}
+
+/// Computes the SHA-1 digest of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{const_hash, sha1};
+///
+/// const DIGEST: sha1::Digest = const_hash::sha1(b"abc");
+///
+/// assert_eq!(
+///     DIGEST.to_hex_lowercase(),
+///     "a9993e364706816aba3e25717850c26c9cd0d89d"
+/// );
+/// ```
+#[cfg(feature = "sha1")]
+#[must_use]
+pub const fn sha1(data: &[u8]) -> crate::sha1::Digest {
+    let length = ((data.len() as u64).wrapping_mul(8)).to_be_bytes();
+    let blocks = block_count(data.len(), 64, length.len());
+    let mut state = crate::sha1::State::new();
+    let mut index = 0;
+    while index < blocks {
+        let block = padded_block::<64>(data, index, blocks, &length);
+        state = state.update(dwords_be(&block));
+        index += 1;
+    }
+    crate::sha1::Digest::new(bytes_from_dwords_be(state.digest()))
+}
+
+/// Computes the SHA-2 224 digest of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{const_hash, sha2_224};
+///
+/// const DIGEST: sha2_224::Digest = const_hash::sha2_224(b"abc");
+///
+/// assert_eq!(
+///     DIGEST.to_hex_lowercase(),
+///     "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"
+/// );
+/// ```
+#[cfg(feature = "sha2-224")]
+#[must_use]
+pub const fn sha2_224(data: &[u8]) -> crate::sha2_224::Digest {
+    let length = ((data.len() as u64).wrapping_mul(8)).to_be_bytes();
+    let blocks = block_count(data.len(), 64, length.len());
+    let mut state = crate::sha2_224::State::new();
+    let mut index = 0;
+    while index < blocks {
+        let block = padded_block::<64>(data, index, blocks, &length);
+        state = state.update(dwords_be(&block));
+        index += 1;
+    }
+    crate::sha2_224::Digest::new(bytes_from_dwords_be(state.digest()))
+}
+
+/// Computes the SHA-2 256 digest of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{const_hash, sha2_256};
+///
+/// const DIGEST: sha2_256::Digest = const_hash::sha2_256(b"abc");
+///
+/// assert_eq!(
+///     DIGEST.to_hex_lowercase(),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+#[cfg(feature = "sha2-256")]
+#[must_use]
+pub const fn sha2_256(data: &[u8]) -> crate::sha2_256::Digest {
+    let length = ((data.len() as u64).wrapping_mul(8)).to_be_bytes();
+    let blocks = block_count(data.len(), 64, length.len());
+    let mut state = crate::sha2_256::State::new();
+    let mut index = 0;
+    while index < blocks {
+        let block = padded_block::<64>(data, index, blocks, &length);
+        state = state.update(dwords_be(&block));
+        index += 1;
+    }
+    crate::sha2_256::Digest::new(bytes_from_dwords_be(state.digest()))
+}
+
+/// Computes the SHA-2 384 digest of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{const_hash, sha2_384};
+///
+/// const DIGEST: sha2_384::Digest = const_hash::sha2_384(b"abc");
+///
+/// assert_eq!(
+///     DIGEST.to_hex_lowercase(),
+///     "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+/// );
+/// ```
+#[cfg(feature = "sha2-384")]
+#[must_use]
+pub const fn sha2_384(data: &[u8]) -> crate::sha2_384::Digest {
+    let length = ((data.len() as u128).wrapping_mul(8)).to_be_bytes();
+    let blocks = block_count(data.len(), 128, length.len());
+    let mut state = crate::sha2_384::State::new();
+    let mut index = 0;
+    while index < blocks {
+        let block = padded_block::<128>(data, index, blocks, &length);
+        state = state.update(qwords_be(&block));
+        index += 1;
+    }
+    crate::sha2_384::Digest::new(bytes_from_qwords_be(state.digest()))
+}
+
+/// Computes the SHA-2 512 digest of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{const_hash, sha2_512};
+///
+/// const DIGEST: sha2_512::Digest = const_hash::sha2_512(b"abc");
+///
+/// assert_eq!(
+///     DIGEST.to_hex_lowercase(),
+///     "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+/// );
+/// ```
+#[cfg(feature = "sha2-512")]
+#[must_use]
+pub const fn sha2_512(data: &[u8]) -> crate::sha2_512::Digest {
+    let length = ((data.len() as u128).wrapping_mul(8)).to_be_bytes();
+    let blocks = block_count(data.len(), 128, length.len());
+    let mut state = crate::sha2_512::State::new();
+    let mut index = 0;
+    while index < blocks {
+        let block = padded_block::<128>(data, index, blocks, &length);
+        state = state.update(qwords_be(&block));
+        index += 1;
+    }
+    crate::sha2_512::Digest::new(bytes_from_qwords_be(state.digest()))
+}
+
+/// Encodes the bytes into lowercase hexadecimal digits, `N` being twice the number of bytes.
+///
+/// # Panics
+///
+/// Panics if `N` is not twice the number of bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::const_hash;
+///
+/// const HEX: [u8; 6] = const_hash::encode_hex(&[0x0A, 0xBC, 0xFF]);
+///
+/// assert_eq!(&HEX, b"0abcff");
+/// ```
+#[must_use]
+pub const fn encode_hex<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    assert!(
+        N == bytes.len() * 2,
+        "hexadecimal length must be twice the number of bytes"
+    );
+    let mut hex = [0u8; N];
+    let mut i = 0;
+    while i < bytes.len() {
+        hex[i * 2] = DIGITS[(bytes[i] >> 4) as usize];
+        hex[i * 2 + 1] = DIGITS[(bytes[i] & 0x0F) as usize];
+        i += 1;
+    }
+    hex
+}
+
+/// Input of the macros computing digests at compile time, accepting strings, byte strings and byte slices.
+#[doc(hidden)]
+pub struct Input<T>(pub T);
+
+impl<'a> Input<&'a str> {
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn bytes(self) -> &'a [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<'a, const N: usize> Input<&'a [u8; N]> {
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn bytes(self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl<'a> Input<&'a [u8]> {
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn bytes(self) -> &'a [u8] {
+        self.0
+    }
+}
+
+/// Returns the number of blocks of the padded message, which is followed by the `0x80` byte and the encoded message length.
+const fn block_count(data_length: usize, block_length: usize, length_length: usize) -> usize {
+    (data_length + 1 + length_length + block_length - 1) / block_length
+}
+
+/// Returns the block at the given index of the padded message.
+///
+/// The message is followed by the `0x80` byte, zeros and the encoded message length at the end of the last block.
+const fn padded_block<const N: usize>(data: &[u8], index: usize, blocks: usize, length: &[u8]) -> [u8; N] {
+    let mut block = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        let position = index * N + i;
+        if position < data.len() {
+            block[i] = data[position];
+        } else if position == data.len() {
+            block[i] = 0x80;
+        } else if index == blocks - 1 && i >= N - length.len() {
+            block[i] = length[i - (N - length.len())];
+        }
+        i += 1;
+    }
+    block
+}
+
+/// Converts the block into little-endian double words.
+#[cfg(feature = "md5")]
+const fn dwords_le(block: &[u8; 64]) -> [u32; 16] {
+    let mut words = [0u32; 16];
+    let mut i = 0;
+    while i < 16 {
+        words[i] = u32::from_le_bytes([block[i * 4], block[i * 4 + 1], block[i * 4 + 2], block[i * 4 + 3]]);
+        i += 1;
+    }
+    words
+}
+
+/// Converts the block into big-endian double words.
+#[cfg(any(feature = "sha1", feature = "sha2-224", feature = "sha2-256"))]
+const fn dwords_be(block: &[u8; 64]) -> [u32; 16] {
+    let mut words = [0u32; 16];
+    let mut i = 0;
+    while i < 16 {
+        words[i] = u32::from_be_bytes([block[i * 4], block[i * 4 + 1], block[i * 4 + 2], block[i * 4 + 3]]);
+        i += 1;
+    }
+    words
+}
+
+/// Converts the block into big-endian quadruple words.
+#[cfg(any(feature = "sha2-384", feature = "sha2-512"))]
+const fn qwords_be(block: &[u8; 128]) -> [u64; 16] {
+    let mut words = [0u64; 16];
+    let mut i = 0;
+    while i < 16 {
+        let mut bytes = [0u8; 8];
+        let mut j = 0;
+        while j < 8 {
+            bytes[j] = block[i * 8 + j];
+            j += 1;
+        }
+        words[i] = u64::from_be_bytes(bytes);
+        i += 1;
+    }
+    words
+}
+
+/// Converts the double words of a state into the little-endian bytes of a digest.
+#[cfg(feature = "md5")]
+const fn bytes_from_dwords_le<const W: usize, const B: usize>(words: [u32; W]) -> [u8; B] {
+    let mut bytes = [0u8; B];
+    let mut i = 0;
+    while i < B {
+        bytes[i] = words[i / 4].to_le_bytes()[i % 4];
+        i += 1;
+    }
+    bytes
+}
+
+/// Converts the double words of a state into the big-endian bytes of a digest.
+#[cfg(any(feature = "sha1", feature = "sha2-224", feature = "sha2-256"))]
+const fn bytes_from_dwords_be<const W: usize, const B: usize>(words: [u32; W]) -> [u8; B] {
+    let mut bytes = [0u8; B];
+    let mut i = 0;
+    while i < B {
+        bytes[i] = words[i / 4].to_be_bytes()[i % 4];
+        i += 1;
+    }
+    bytes
+}
+
+/// Converts the quadruple words of a state into the big-endian bytes of a digest.
+#[cfg(any(feature = "sha2-384", feature = "sha2-512"))]
+const fn bytes_from_qwords_be<const W: usize, const B: usize>(words: [u64; W]) -> [u8; B] {
+    let mut bytes = [0u8; B];
+    let mut i = 0;
+    while i < B {
+        bytes[i] = words[i / 8].to_be_bytes()[i % 8];
+        i += 1;
+    }
+    bytes
+}
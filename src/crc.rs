@@ -0,0 +1,370 @@
+//! This module is optional and can be enabled using the `crc` Cargo feature.
+//!
+//! A generic implementation of cyclic redundancy checks (CRCs).
+//!
+//! Any CRC algorithm of width up to 64 bits can be described with the parameters of the Rocksoft model, see [`Params`]. Common algorithms, such as CRC-8/SMBUS, CRC-16/MODBUS or CRC-16/IBM-3740 (CCITT-FALSE), are available as associated constants of [`Params`]. By default CRC-32/ISO-HDLC is used.
+//!
+//! The data is processed byte by byte with a lookup table computed when a hash is created. The dedicated `crc32`, `crc32c` and `crc64` modules are faster for their algorithms.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::crc;
+//!
+//! let digest = crc::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "e9c54def");
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::crc;
+//!
+//! let digest = crc::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "e9c54def");
+//! ```
+//!
+//! # Parameters
+//!
+//! Use [`Update::with_params`] to choose the algorithm.
+//!
+//! ```rust
+//! use chksum_hash::crc::{self, Params};
+//!
+//! let digest = crc::Update::with_params(Params::CRC_16_MODBUS)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "d041");
+//!
+//! // CRC-12/UMTS
+//! let params = Params::new(12, 0x80F, 0x000, false, true, 0x000);
+//! let digest = crc::Update::with_params(params)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(u64::from(digest), 0xB71);
+//! ```
+//!
+//! # Disclaimer
+//!
+//! A CRC is an error-detecting code, not a cryptographic hash function. It detects accidental corruption only; it must not be used to protect against intentional modification.
+
+pub mod digest;
+pub mod params;
+pub mod state;
+mod table;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::Digest;
+#[doc(inline)]
+pub use self::params::Params;
+#[doc(inline)]
+pub use self::state::State;
+use self::table::Table;
+use crate::error::ParameterError;
+use crate::verify::Expected;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc;
+///
+/// let digest = crc::new().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "00000000");
+///
+/// let digest = crc::new().update("data").digest();
+/// assert_eq!(digest.to_hex_lowercase(), "adf3f363");
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc;
+///
+/// let digest = crc::default().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "00000000");
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc;
+///
+/// let digest = crc::hash("abc");
+/// assert_eq!(digest.to_hex_lowercase(), "352441c2");
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc;
+///
+/// let digest = crc::hash("data");
+/// assert!(crc::verify("data", digest));
+/// assert!(crc::verify("data", digest.to_hex_uppercase()));
+/// assert!(!crc::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A checksum state that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc;
+///
+/// // Create a new hash instance
+/// let mut hash = crc::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "adf3f363");
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(digest.to_hex_lowercase(), "00000000");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    state: State,
+    table: Table,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_params(Params::default())
+    }
+
+    /// Creates a new hash for the given parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are invalid, check [`State::new`].
+    #[must_use]
+    pub fn with_params(params: Params) -> Self {
+        let state = state::new(params);
+        let table = Table::new(&params);
+        Self { state, table }
+    }
+
+    /// Creates a new hash for the given parameters, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameters are invalid, check [`State::new`].
+    pub fn try_with_params(params: Params) -> Result<Self, ParameterError> {
+        if state::is_valid(&params) {
+            Ok(Self::with_params(params))
+        } else {
+            Err(ParameterError {
+                parameter: "parameters",
+            })
+        }
+    }
+
+    /// Returns the parameters of the hash.
+    #[must_use]
+    pub const fn params(&self) -> &Params {
+        &self.state.params
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// The data is processed immediately, no internal buffer is used.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let register = self.table.update(self.state.register, data.as_ref());
+        self.state.register = register;
+        self
+    }
+
+    /// Produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let state = self.state;
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The parameters of the hash are kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // The lookup table is derived from the parameters of the state.
+        let state = u.arbitrary::<State>()?;
+        let table = Table::new(&state.params);
+        Ok(Self { state, table })
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized checksum state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc;
+///
+/// // Create a new hash instance
+/// let mut hash = crc::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "352441c2");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the checksum digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let bytes = self.state.digest().to_be_bytes();
+        let length = (self.state.params.width + 7) / 8;
+        Digest::new(&bytes[bytes.len() - length..])
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The parameters of the hash are kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_params(self.state.params)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Check values from the CRC RevEng catalogue.
+    #[test]
+    fn catalogue() {
+        for (params, expected) in [
+            (Params::CRC_8_AUTOSAR, "df"),
+            (Params::CRC_8_BLUETOOTH, "26"),
+            (Params::CRC_8_MAXIM_DOW, "a1"),
+            (Params::CRC_8_SMBUS, "f4"),
+            (Params::CRC_16_ARC, "bb3d"),
+            (Params::CRC_16_IBM_3740, "29b1"),
+            (Params::CRC_16_IBM_SDLC, "906e"),
+            (Params::CRC_16_KERMIT, "2189"),
+            (Params::CRC_16_MODBUS, "4b37"),
+            (Params::CRC_16_USB, "b4c8"),
+            (Params::CRC_16_XMODEM, "31c3"),
+            (Params::CRC_32_BZIP2, "fc891918"),
+            (Params::CRC_32_ISCSI, "e3069283"),
+            (Params::CRC_32_ISO_HDLC, "cbf43926"),
+            (Params::CRC_32_MPEG_2, "0376e6e7"),
+            (Params::CRC_64_ECMA_182, "6c40df5f0b497347"),
+            (Params::CRC_64_XZ, "995dc9bbdf1939fa"),
+        ] {
+            let digest = Update::with_params(params).update("123456789").digest();
+            assert_eq!(digest.to_hex_lowercase(), expected);
+        }
+    }
+}
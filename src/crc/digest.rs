@@ -0,0 +1,31 @@
+//! Module containing items related to the CRC digest.
+
+/// Maximum digest length in bits.
+pub const MAX_LENGTH_BITS: usize = 64;
+/// Maximum digest length in bytes.
+pub const MAX_LENGTH_BYTES: usize = MAX_LENGTH_BITS / 8;
+/// Maximum digest length in hexadecimal format.
+pub const MAX_LENGTH_HEX: usize = MAX_LENGTH_BYTES * 2;
+
+/// Marker type of the CRC digest.
+///
+/// It tags [`Digest`] so CRC digests are distinct from digests of other hash functions with the same length.
+pub enum Crc {}
+
+/// A checksum digest.
+///
+/// The digest holds the checksum in the big-endian byte order, as it is usually printed, using as many bytes as needed for the width of the algorithm, see [`Params`](super::Params).
+///
+/// Check [`crc`](super) for more information.
+///
+/// Digests are ordered lexicographically by their bytes, so a digest is ordered before the longer digests it is a prefix of.
+pub type Digest = crate::digest::VariableDigest<Crc, MAX_LENGTH_BYTES>;
+
+impl From<Digest> for u64 {
+    fn from(digest: Digest) -> Self {
+        digest
+            .as_ref()
+            .iter()
+            .fold(0, |value, &byte| (value << 8) | Self::from(byte))
+    }
+}
@@ -0,0 +1,130 @@
+//! Module containing items related to the CRC parameters.
+
+/// Parameters of a CRC algorithm in the Rocksoft model.
+///
+/// The parameters of most CRC algorithms in use can be found in the [Catalogue of parametrised CRC algorithms](https://reveng.sourceforge.io/crc-catalogue/). The common ones are available as associated constants.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc;
+///
+/// // CRC-16/GENIBUS
+/// let params = crc::Params {
+///     width: 16,
+///     poly: 0x1021,
+///     init: 0xFFFF,
+///     refin: false,
+///     refout: false,
+///     xorout: 0xFFFF,
+/// };
+/// let digest = crc::Update::with_params(params)
+///     .update("123456789")
+///     .digest();
+/// assert_eq!(u64::from(digest), 0xD64E);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Width of the checksum in bits, between 1 and 64.
+    pub width: usize,
+    /// Polynomial in the normal (most significant bit first) representation, without the leading term.
+    pub poly: u64,
+    /// Initial value of the register in the normal representation.
+    pub init: u64,
+    /// Whether the bits of the input bytes are processed starting with the least significant one.
+    pub refin: bool,
+    /// Whether the final value of the register is reflected before the final XOR.
+    pub refout: bool,
+    /// Value XORed with the register to produce the checksum.
+    pub xorout: u64,
+}
+
+#[allow(clippy::unreadable_literal)]
+impl Params {
+    /// CRC-16/ARC, also known as CRC-16/LHA.
+    pub const CRC_16_ARC: Self = Self::new(16, 0x8005, 0x0000, true, true, 0x0000);
+    /// CRC-16/IBM-3740, also known as CRC-16/CCITT-FALSE.
+    pub const CRC_16_IBM_3740: Self = Self::new(16, 0x1021, 0xFFFF, false, false, 0x0000);
+    /// CRC-16/IBM-SDLC, also known as CRC-16/X-25, used by HDLC.
+    pub const CRC_16_IBM_SDLC: Self = Self::new(16, 0x1021, 0xFFFF, true, true, 0xFFFF);
+    /// CRC-16/KERMIT, also known as CRC-16/CCITT.
+    pub const CRC_16_KERMIT: Self = Self::new(16, 0x1021, 0x0000, true, true, 0x0000);
+    /// CRC-16/MODBUS, used by the Modbus serial protocol.
+    pub const CRC_16_MODBUS: Self = Self::new(16, 0x8005, 0xFFFF, true, true, 0x0000);
+    /// CRC-16/USB, used by the USB data packets.
+    pub const CRC_16_USB: Self = Self::new(16, 0x8005, 0xFFFF, true, true, 0xFFFF);
+    /// CRC-16/XMODEM, used by the XMODEM protocol.
+    pub const CRC_16_XMODEM: Self = Self::new(16, 0x1021, 0x0000, false, false, 0x0000);
+    /// CRC-32/BZIP2, used by bzip2.
+    pub const CRC_32_BZIP2: Self = Self::new(32, 0x04C11DB7, 0xFFFFFFFF, false, false, 0xFFFFFFFF);
+    /// CRC-32/ISCSI, also known as CRC-32C.
+    pub const CRC_32_ISCSI: Self = Self::new(32, 0x1EDC6F41, 0xFFFFFFFF, true, true, 0xFFFFFFFF);
+    /// CRC-32/ISO-HDLC, the most common CRC-32.
+    pub const CRC_32_ISO_HDLC: Self = Self::new(32, 0x04C11DB7, 0xFFFFFFFF, true, true, 0xFFFFFFFF);
+    /// CRC-32/MPEG-2, used by the MPEG transport streams.
+    pub const CRC_32_MPEG_2: Self = Self::new(32, 0x04C11DB7, 0xFFFFFFFF, false, false, 0x00000000);
+    /// CRC-64/ECMA-182.
+    pub const CRC_64_ECMA_182: Self = Self::new(
+        64,
+        0x42F0E1EBA9EA3693,
+        0x0000000000000000,
+        false,
+        false,
+        0x0000000000000000,
+    );
+    /// CRC-64/XZ, used by the xz file format.
+    pub const CRC_64_XZ: Self = Self::new(
+        64,
+        0x42F0E1EBA9EA3693,
+        0xFFFFFFFFFFFFFFFF,
+        true,
+        true,
+        0xFFFFFFFFFFFFFFFF,
+    );
+    /// CRC-8/AUTOSAR, used by the AUTOSAR end-to-end protection.
+    pub const CRC_8_AUTOSAR: Self = Self::new(8, 0x2F, 0xFF, false, false, 0xFF);
+    /// CRC-8/BLUETOOTH, used by the Bluetooth header error check.
+    pub const CRC_8_BLUETOOTH: Self = Self::new(8, 0xA7, 0x00, true, true, 0x00);
+    /// CRC-8/MAXIM-DOW, used by the 1-Wire bus.
+    pub const CRC_8_MAXIM_DOW: Self = Self::new(8, 0x31, 0x00, true, true, 0x00);
+    /// CRC-8/SMBUS, used by the System Management Bus.
+    pub const CRC_8_SMBUS: Self = Self::new(8, 0x07, 0x00, false, false, 0x00);
+
+    /// Creates new parameters.
+    ///
+    /// The parameters are validated only when used by a [`State`](super::State).
+    #[must_use]
+    pub const fn new(width: usize, poly: u64, init: u64, refin: bool, refout: bool, xorout: u64) -> Self {
+        Self {
+            width,
+            poly,
+            init,
+            refin,
+            refout,
+            xorout,
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Params {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // The polynomial, the initial value and the final XOR value must fit the width.
+        let width = u.int_in_range(1..=64)?;
+        let mask = u64::MAX >> (64 - width);
+        Ok(Self {
+            width,
+            poly: u.arbitrary::<u64>()? & mask,
+            init: u.arbitrary::<u64>()? & mask,
+            refin: u.arbitrary()?,
+            refout: u.arbitrary()?,
+            xorout: u.arbitrary::<u64>()? & mask,
+        })
+    }
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::CRC_32_ISO_HDLC
+    }
+}
@@ -0,0 +1,236 @@
+//! Module contains items related to the [`State`] structure which allows to the direct CRC state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::crc::{self, Params};
+//!
+//! // Create new state for the CRC-16/MODBUS algorithm
+//! let mut state = crc::state::new(Params::CRC_16_MODBUS);
+//!
+//! // Update state with any amount of data and own new value
+//! state = state.update(b"123456789");
+//!
+//! // The check value of the algorithm
+//! assert_eq!(state.digest(), 0x4B37);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state, crc::state::new(Params::CRC_16_MODBUS));
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::params::Params;
+use crate::error::ParameterError;
+
+/// Create a new state for the given parameters.
+///
+/// # Panics
+///
+/// Panics if the parameters are invalid, check [`State::new`].
+#[must_use]
+pub const fn new(params: Params) -> State {
+    State::new(params)
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level checksum state.
+///
+/// The state processes data bit by bit, which is simple but slow; the [`Update`](super::Update) structure uses a lookup table instead.
+///
+/// The register of reflected algorithms is kept reflected, the register of other algorithms is kept aligned to the most significant bit, so that the same operations work for all widths.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct State {
+    pub(crate) params: Params,
+    pub(crate) register: u64,
+}
+
+impl State {
+    /// Creates a new state for the given parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is not between 1 and 64 bits, or if the polynomial, the initial value or the final XOR value do not fit the width.
+    #[must_use]
+    pub const fn new(params: Params) -> Self {
+        check(&params);
+
+        let register = if params.refin {
+            reflect(params.init, params.width)
+        } else {
+            params.init << (64 - params.width)
+        };
+        Self::from_raw(params, register)
+    }
+
+    /// Creates a new state for the given parameters, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the width is not between 1 and 64 bits, or if the polynomial, the initial value or the final XOR value do not fit the width.
+    pub const fn try_new(params: Params) -> Result<Self, ParameterError> {
+        if is_valid(&params) {
+            Ok(Self::new(params))
+        } else {
+            Err(ParameterError {
+                parameter: "parameters",
+            })
+        }
+    }
+
+    /// Creates a state with the given parameters from the raw register, e.g. a custom initial value.
+    ///
+    /// The register is in the internal representation of the state: reflected if the input is reflected, otherwise aligned to the most significant bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is not between 1 and 64 bits, or if the polynomial, the initial value or the final XOR value do not fit the width.
+    #[must_use]
+    pub const fn from_raw(params: Params, register: u64) -> Self {
+        check(&params);
+        Self { params, register }
+    }
+
+    /// Creates a state with the given parameters from the raw register, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the width is not between 1 and 64 bits, or if the polynomial, the initial value or the final XOR value do not fit the width.
+    pub const fn try_from_raw(params: Params, register: u64) -> Result<Self, ParameterError> {
+        if is_valid(&params) {
+            Ok(Self::from_raw(params, register))
+        } else {
+            Err(ParameterError {
+                parameter: "parameters",
+            })
+        }
+    }
+
+    /// Returns modified state with the passed data.
+    #[must_use]
+    pub const fn update(&self, data: &[u8]) -> Self {
+        let mut register = self.register;
+        let mut i = 0;
+        while i < data.len() {
+            register = update_byte(&self.params, register, data[i]);
+            i += 1;
+        }
+        Self {
+            params: self.params,
+            register,
+        }
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new(self.params)
+    }
+
+    /// Returns a checksum.
+    #[must_use]
+    pub const fn digest(&self) -> u64 {
+        let Params {
+            width,
+            refin,
+            refout,
+            xorout,
+            ..
+        } = self.params;
+        let crc = if refin {
+            reflect(self.register, width)
+        } else {
+            self.register >> (64 - width)
+        };
+        let crc = if refout { reflect(crc, width) } else { crc };
+        crc ^ xorout
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(Params::default())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for State {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let params = u.arbitrary::<Params>()?;
+        // The register keeps only the bits of the width in its internal representation, check [`State::from_raw`].
+        let register = u.arbitrary::<u64>()?;
+        let register = if params.refin {
+            register & (u64::MAX >> (64 - params.width))
+        } else {
+            register & (u64::MAX << (64 - params.width))
+        };
+        Ok(Self::from_raw(params, register))
+    }
+}
+
+/// Processes a single byte bit by bit.
+pub(crate) const fn update_byte(params: &Params, register: u64, byte: u8) -> u64 {
+    let mut register = register;
+    let mut bit = 0;
+    if params.refin {
+        let poly = reflect(params.poly, params.width);
+        register ^= byte as u64;
+        while bit < 8 {
+            register = if register & 1 == 0 {
+                register >> 1
+            } else {
+                (register >> 1) ^ poly
+            };
+            bit += 1;
+        }
+    } else {
+        let poly = params.poly << (64 - params.width);
+        register ^= (byte as u64) << 56;
+        while bit < 8 {
+            register = if register >> 63 == 0 {
+                register << 1
+            } else {
+                (register << 1) ^ poly
+            };
+            bit += 1;
+        }
+    }
+    register
+}
+
+/// Checks that the parameters are valid.
+const fn check(params: &Params) {
+    assert!(
+        params.width >= 1 && params.width <= 64,
+        "width must be between 1 and 64 bits"
+    );
+    let mask = u64::MAX >> (64 - params.width);
+    assert!(
+        params.poly & !mask == 0 && params.init & !mask == 0 && params.xorout & !mask == 0,
+        "polynomial, initial value and final XOR value must fit the width"
+    );
+}
+
+/// Returns `true` if the parameters pass the checks of [`check`].
+pub(crate) const fn is_valid(params: &Params) -> bool {
+    if params.width < 1 || params.width > 64 {
+        return false;
+    }
+    let mask = u64::MAX >> (64 - params.width);
+    params.poly & !mask == 0 && params.init & !mask == 0 && params.xorout & !mask == 0
+}
+
+/// Reverses the order of the lowest bits of the value.
+const fn reflect(value: u64, width: usize) -> u64 {
+    value.reverse_bits() >> (64 - width)
+}
@@ -0,0 +1,45 @@
+//! Module containing the lookup table of the byte-wise CRC algorithm.
+
+use std::fmt::{self, Debug, Formatter};
+
+use super::params::Params;
+use super::state::update_byte;
+
+/// A lookup table computed for the given parameters.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct Table {
+    entries: [u64; 256],
+    reflected: bool,
+}
+
+impl Table {
+    /// Computes the lookup table.
+    pub(crate) fn new(params: &Params) -> Self {
+        let mut entries = [0u64; 256];
+        for (byte, entry) in (0u8..=255).zip(entries.iter_mut()) {
+            *entry = update_byte(params, 0, byte);
+        }
+        let reflected = params.refin;
+        Self { entries, reflected }
+    }
+
+    /// Returns the register updated with the data.
+    pub(crate) fn update(&self, register: u64, data: &[u8]) -> u64 {
+        let Self { entries, reflected } = self;
+        if *reflected {
+            data.iter().fold(register, |register, &byte| {
+                (register >> 8) ^ entries[usize::from(register as u8 ^ byte)]
+            })
+        } else {
+            data.iter().fold(register, |register, &byte| {
+                (register << 8) ^ entries[usize::from((register >> 56) as u8 ^ byte)]
+            })
+        }
+    }
+}
+
+impl Debug for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Table").finish_non_exhaustive()
+    }
+}
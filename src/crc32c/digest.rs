@@ -0,0 +1,26 @@
+//! Module containing items related to the CRC-32C digest.
+
+/// Digest length in bits.
+pub const LENGTH_BITS: usize = 32;
+/// Digest length in bytes.
+pub const LENGTH_BYTES: usize = LENGTH_BITS / 8;
+/// Digest length in hexadecimal format.
+pub const LENGTH_HEX: usize = LENGTH_BYTES * 2;
+
+/// Marker type of the CRC-32C digest.
+///
+/// It tags [`Digest`] so CRC-32C digests are distinct from digests of other hash functions with the same length.
+pub enum Crc32c {}
+
+/// A checksum digest.
+///
+/// The bytes of the digest are the checksum in the big-endian byte order, as it is usually printed.
+///
+/// Check [`crc32c`](super) for more information.
+pub type Digest = crate::digest::Digest<Crc32c, LENGTH_BYTES>;
+
+impl From<Digest> for u32 {
+    fn from(digest: Digest) -> Self {
+        Self::from_be_bytes(digest.into_inner())
+    }
+}
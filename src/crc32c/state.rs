@@ -0,0 +1,157 @@
+//! Module contains items related to the [`State`] structure which allows to the direct CRC-32C state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::crc32c;
+//!
+//! // Create new state
+//! let mut state = crc32c::state::default();
+//!
+//! // By default it returns the checksum of empty input
+//! assert_eq!(state.digest(), 0x00000000);
+//!
+//! // Update state with any amount of data and own new value
+//! state = state.update(b"123456789");
+//!
+//! // The check value of the algorithm
+//! assert_eq!(state.digest(), 0xE3069283);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state.digest(), 0x00000000);
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+/// The reflected CRC-32C polynomial.
+#[allow(clippy::unreadable_literal)]
+const POLYNOMIAL: u32 = 0x82F63B78;
+
+/// Lookup tables of the slice-by-16 algorithm.
+const TABLES: [[u32; 256]; 16] = tables();
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level checksum state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) crc: u32,
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(u32::MAX)
+    }
+
+    /// Creates a state from the raw register, e.g. a custom initial value.
+    ///
+    /// The register holds the value before the final complement, the default value is `0xFFFFFFFF`.
+    #[must_use]
+    pub const fn from_raw(crc: u32) -> Self {
+        Self { crc }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// Unlike hash functions, the data does not need to be split into blocks.
+    #[must_use]
+    pub const fn update(&self, data: &[u8]) -> Self {
+        let mut crc = self.crc;
+        let mut i = 0;
+        while i + 16 <= data.len() {
+            let low = crc.to_le_bytes();
+            crc = TABLES[15][(data[i] ^ low[0]) as usize]
+                ^ TABLES[14][(data[i + 1] ^ low[1]) as usize]
+                ^ TABLES[13][(data[i + 2] ^ low[2]) as usize]
+                ^ TABLES[12][(data[i + 3] ^ low[3]) as usize]
+                ^ TABLES[11][data[i + 4] as usize]
+                ^ TABLES[10][data[i + 5] as usize]
+                ^ TABLES[9][data[i + 6] as usize]
+                ^ TABLES[8][data[i + 7] as usize]
+                ^ TABLES[7][data[i + 8] as usize]
+                ^ TABLES[6][data[i + 9] as usize]
+                ^ TABLES[5][data[i + 10] as usize]
+                ^ TABLES[4][data[i + 11] as usize]
+                ^ TABLES[3][data[i + 12] as usize]
+                ^ TABLES[2][data[i + 13] as usize]
+                ^ TABLES[1][data[i + 14] as usize]
+                ^ TABLES[0][data[i + 15] as usize];
+            i += 16;
+        }
+        while i < data.len() {
+            crc = (crc >> 8) ^ TABLES[0][((crc as u8) ^ data[i]) as usize];
+            i += 1;
+        }
+        Self::from_raw(crc)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns a checksum.
+    #[must_use]
+    pub const fn digest(&self) -> u32 {
+        !self.crc
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the lookup tables, the first one is the classic byte-wise table.
+const fn tables() -> [[u32; 256]; 16] {
+    let mut tables = [[0u32; 256]; 16];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ POLYNOMIAL
+            };
+            bit += 1;
+        }
+        tables[0][i] = crc;
+        i += 1;
+    }
+
+    // Each next table advances the checksum by one more zero byte.
+    let mut table = 1;
+    while table < 16 {
+        let mut i = 0;
+        while i < 256 {
+            let previous = tables[table - 1][i];
+            tables[table][i] = (previous >> 8) ^ tables[0][(previous & 0xFF) as usize];
+            i += 1;
+        }
+        table += 1;
+    }
+    tables
+}
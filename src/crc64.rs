@@ -0,0 +1,317 @@
+//! This module is optional and can be enabled using the `crc64` Cargo feature.
+//!
+//! An implementation of the CRC-64 checksum.
+//!
+//! There are several CRC-64 variants in use, see [`Variant`] for the supported ones. By default CRC-64/XZ is used, the checksum of the xz file format.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::crc64;
+//!
+//! let digest = crc64::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "09fb1b316ea07855");
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::crc64;
+//!
+//! let digest = crc64::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "09fb1b316ea07855");
+//! ```
+//!
+//! # Variants
+//!
+//! Use [`Update::with_variant`] to choose another variant.
+//!
+//! ```rust
+//! use chksum_hash::crc64::{self, Variant};
+//!
+//! let digest = crc64::Update::with_variant(Variant::Nvme)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "57d94bc430aa8ecf");
+//! ```
+//!
+//! # Disclaimer
+//!
+//! CRC-64 is an error-detecting code, not a cryptographic hash function. It detects accidental corruption only; it must not be used to protect against intentional modification.
+
+pub mod digest;
+pub mod state;
+pub mod variant;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+#[doc(inline)]
+pub use self::variant::Variant;
+use crate::verify::Expected;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc64;
+///
+/// let digest = crc64::new().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "0000000000000000");
+///
+/// let digest = crc64::new().update("data").digest();
+/// assert_eq!(digest.to_hex_lowercase(), "c3299f929d466f7f");
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc64;
+///
+/// let digest = crc64::default().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "0000000000000000");
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc64;
+///
+/// let digest = crc64::hash("abc");
+/// assert_eq!(digest.to_hex_lowercase(), "2cd8094a1a277627");
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc64;
+///
+/// let digest = crc64::hash("data");
+/// assert!(crc64::verify("data", digest));
+/// assert!(crc64::verify("data", digest.to_hex_uppercase()));
+/// assert!(!crc64::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A checksum state that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc64;
+///
+/// // Create a new hash instance
+/// let mut hash = crc64::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "c3299f929d466f7f");
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(digest.to_hex_lowercase(), "0000000000000000");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    state: State,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_variant(Variant::default())
+    }
+
+    /// Creates a new hash for the given variant.
+    #[must_use]
+    pub fn with_variant(variant: Variant) -> Self {
+        let state = state::new(variant);
+        Self { state }
+    }
+
+    /// Returns the variant of the hash.
+    #[must_use]
+    pub const fn variant(&self) -> Variant {
+        self.state.variant
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// The data is processed immediately, no internal buffer is used.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.state = self.state.update(data.as_ref());
+        self
+    }
+
+    /// Produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let state = self.state;
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The variant is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized checksum state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::crc64;
+///
+/// // Create a new hash instance
+/// let mut hash = crc64::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "2cd8094a1a277627");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the checksum digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        Digest::new(self.state.digest().to_be_bytes())
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        *out = self.state.digest().to_be_bytes();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The variant is kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_variant(self.state.variant)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Check values from the CRC RevEng catalogue.
+    #[test]
+    fn catalogue() {
+        for (variant, expected) in [
+            (Variant::Xz, "995dc9bbdf1939fa"),
+            (Variant::Nvme, "ae8b14860a799888"),
+            (Variant::GoIso, "b90956c775a41001"),
+        ] {
+            let digest = Update::with_variant(variant).update("123456789").digest();
+            assert_eq!(digest.to_hex_lowercase(), expected);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Module contains items related to the [`State`] structure which allows to the direct CRC-64 state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::crc64::{self, Variant};
+//!
+//! // Create new state for the CRC-64/NVME variant
+//! let mut state = crc64::state::new(Variant::Nvme);
+//!
+//! // By default it returns the checksum of empty input
+//! assert_eq!(state.digest(), 0x0000000000000000);
+//!
+//! // Update state with any amount of data and own new value
+//! state = state.update(b"123456789");
+//!
+//! // The check value of the algorithm
+//! assert_eq!(state.digest(), Variant::Nvme.check());
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state, crc64::state::new(Variant::Nvme));
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::variant::Variant;
+
+/// Lookup tables of the slice-by-16 algorithm for each of the variants.
+static TABLES: [[[u64; 256]; 16]; 3] = [tables(Variant::Xz), tables(Variant::Nvme), tables(Variant::GoIso)];
+
+/// Create a new state for the given variant.
+#[must_use]
+pub const fn new(variant: Variant) -> State {
+    State::new(variant)
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level checksum state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) crc: u64,
+    pub(crate) variant: Variant,
+}
+
+impl State {
+    /// Creates a new state for the given variant.
+    #[must_use]
+    pub const fn new(variant: Variant) -> Self {
+        Self::from_raw(u64::MAX, variant)
+    }
+
+    /// Creates a state of the given variant from the raw register, e.g. a custom initial value.
+    ///
+    /// The register holds the value before the final complement, the default value is `0xFFFFFFFFFFFFFFFF`.
+    #[must_use]
+    pub const fn from_raw(crc: u64, variant: Variant) -> Self {
+        Self { crc, variant }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// Unlike hash functions, the data does not need to be split into blocks.
+    #[must_use]
+    pub fn update(&self, data: &[u8]) -> Self {
+        let tables = &TABLES[self.variant as usize];
+        let mut crc = self.crc;
+        let mut i = 0;
+        while i + 16 <= data.len() {
+            let low = crc.to_le_bytes();
+            crc = tables[15][(data[i] ^ low[0]) as usize]
+                ^ tables[14][(data[i + 1] ^ low[1]) as usize]
+                ^ tables[13][(data[i + 2] ^ low[2]) as usize]
+                ^ tables[12][(data[i + 3] ^ low[3]) as usize]
+                ^ tables[11][(data[i + 4] ^ low[4]) as usize]
+                ^ tables[10][(data[i + 5] ^ low[5]) as usize]
+                ^ tables[9][(data[i + 6] ^ low[6]) as usize]
+                ^ tables[8][(data[i + 7] ^ low[7]) as usize]
+                ^ tables[7][data[i + 8] as usize]
+                ^ tables[6][data[i + 9] as usize]
+                ^ tables[5][data[i + 10] as usize]
+                ^ tables[4][data[i + 11] as usize]
+                ^ tables[3][data[i + 12] as usize]
+                ^ tables[2][data[i + 13] as usize]
+                ^ tables[1][data[i + 14] as usize]
+                ^ tables[0][data[i + 15] as usize];
+            i += 16;
+        }
+        while i < data.len() {
+            crc = (crc >> 8) ^ tables[0][((crc as u8) ^ data[i]) as usize];
+            i += 1;
+        }
+        Self::from_raw(crc, self.variant)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new(self.variant)
+    }
+
+    /// Returns a checksum.
+    #[must_use]
+    pub const fn digest(&self) -> u64 {
+        !self.crc
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(Variant::default())
+    }
+}
+
+/// Computes the lookup tables, the first one is the classic byte-wise table.
+const fn tables(variant: Variant) -> [[u64; 256]; 16] {
+    let polynomial = variant.polynomial().reverse_bits();
+    let mut tables = [[0u64; 256]; 16];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ polynomial
+            };
+            bit += 1;
+        }
+        tables[0][i] = crc;
+        i += 1;
+    }
+
+    // Each next table advances the checksum by one more zero byte.
+    let mut table = 1;
+    while table < 16 {
+        let mut i = 0;
+        while i < 256 {
+            let previous = tables[table - 1][i];
+            tables[table][i] = (previous >> 8) ^ tables[0][(previous & 0xFF) as usize];
+            i += 1;
+        }
+        table += 1;
+    }
+    tables
+}
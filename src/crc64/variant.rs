@@ -0,0 +1,60 @@
+//! Module containing the supported CRC-64 variants.
+
+/// A CRC-64 variant.
+///
+/// All variants process the data in the reflected bit order and use all-ones initial and final XOR values; they differ only in the polynomial.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variant {
+    /// CRC-64/XZ, also known as CRC-64/GO-ECMA, based on the ECMA-182 polynomial and used by the xz file format.
+    #[default]
+    Xz,
+    /// CRC-64/NVME, used by the NVMe end-to-end data protection.
+    Nvme,
+    /// CRC-64/GO-ISO, based on the ISO 3309 polynomial.
+    GoIso,
+}
+
+impl Variant {
+    /// Returns the polynomial in the normal (most significant bit first) representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::crc64::Variant;
+    ///
+    /// assert_eq!(Variant::Xz.polynomial(), 0x42F0E1EBA9EA3693);
+    /// ```
+    #[allow(clippy::unreadable_literal)]
+    #[must_use]
+    pub const fn polynomial(self) -> u64 {
+        match self {
+            Self::Xz => 0x42F0E1EBA9EA3693,
+            Self::Nvme => 0xAD93D23594C93659,
+            Self::GoIso => 0x000000000000001B,
+        }
+    }
+
+    /// Returns the check value, i.e. the checksum of the ASCII string `123456789`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::crc64::{self, Variant};
+    ///
+    /// let digest = crc64::Update::with_variant(Variant::Nvme)
+    ///     .update("123456789")
+    ///     .digest();
+    /// assert_eq!(u64::from(digest), Variant::Nvme.check());
+    /// ```
+    #[allow(clippy::unreadable_literal)]
+    #[must_use]
+    pub const fn check(self) -> u64 {
+        match self {
+            Self::Xz => 0x995DC9BBDF1939FA,
+            Self::Nvme => 0xAE8B14860A799888,
+            Self::GoIso => 0xB90956C775A41001,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Items shared by the crypt(3) password hashes.
+
+use crate::error::ParameterError;
+
+/// Alphabet of the base64 encoding used by crypt(3).
+const ALPHABET: &[u8; 64] = b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Checks that the salt contains no `$`, `:` or newline, which are not allowed in the modular crypt format.
+pub(crate) fn check_salt(salt: &str) -> Result<(), ParameterError> {
+    if salt.contains(['$', ':', '\n']) {
+        Err(ParameterError { parameter: "salt" })
+    } else {
+        Ok(())
+    }
+}
+
+/// Truncates the salt to the given number of bytes without splitting a character.
+///
+/// # Panics
+///
+/// Panics if the salt contains `$`, `:` or a newline, which are not allowed in the modular crypt format.
+pub(crate) fn truncate_salt(salt: &str, max_length: usize) -> &str {
+    assert!(check_salt(salt).is_ok(), "salt must not contain `$`, `:` or a newline");
+
+    let mut length = salt.len().min(max_length);
+    while !salt.is_char_boundary(length) {
+        length -= 1;
+    }
+    &salt[..length]
+}
+
+/// Encodes the bytes with the base64 encoding used by crypt(3), which takes groups of three bytes and emits the least significant bits first.
+pub(crate) fn encode_base64(bytes: impl Iterator<Item = u8>, output: &mut String) {
+    let bytes = bytes.collect::<Vec<_>>();
+    for chunk in bytes.chunks(3) {
+        let mut word = chunk.iter().fold(0u32, |word, &byte| word << 8 | u32::from(byte));
+        for _ in 0..=chunk.len() {
+            output.push(char::from(ALPHABET[(word & 0x3F) as usize]));
+            word >>= 6;
+        }
+    }
+}
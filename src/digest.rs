@@ -0,0 +1,1153 @@
+//! Module containing the digest types shared by the hash functions of this crate.
+//!
+//! A [`Digest`] holds a digest of a fixed length and a [`VariableDigest`] a digest whose length is chosen at runtime, up to a maximum. Both are tagged with a marker type of the hash function, so digests of different hash functions are distinct types even if they have the same length, e.g. MD4 and ed2k digests cannot be mixed up. The modules of the hash functions provide `Digest` aliases with the right marker and length, like [`md4::Digest`](crate::md4::Digest), so this module rarely has to be used directly.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::digest::Digest;
+//! use chksum_hash::md4;
+//!
+//! let digest: Digest<md4::digest::Md4, 16> = md4::hash("abc");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "a448017aaf21d8525fc10ae87aa6729d"
+//! );
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter, LowerHex, UpperHex};
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "borsh")]
+use std::io;
+use std::iter::Take;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{array, slice};
+
+use chksum_hash_core as core;
+
+use crate::error::LengthError;
+use crate::hex::{self, Case, FromHexError};
+#[cfg(feature = "serde")]
+use crate::serialization;
+use crate::truncated::{self, TruncatedDigest};
+use crate::verify::{self, Expected};
+use crate::{base32, base64};
+
+/// A digest of `N` bytes of the hash function `A`.
+///
+/// Check the modules of the hash functions, e.g. [`md4`](crate::md4), for more information.
+pub struct Digest<A, const N: usize> {
+    bytes: [u8; N],
+    algorithm: PhantomData<fn() -> A>,
+}
+
+impl<A, const N: usize> Digest<A, N> {
+    /// Creates a new digest.
+    #[must_use]
+    pub const fn new(digest: [u8; N]) -> Self {
+        Self {
+            bytes: digest,
+            algorithm: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the bytes of the digest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert_eq!(digest, *digest.as_bytes());
+    /// ```
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Returns the bytes of the digest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// let bytes = digest.into_inner();
+    /// assert_eq!(digest, bytes);
+    /// ```
+    #[must_use]
+    pub const fn into_inner(self) -> [u8; N] {
+        self.bytes
+    }
+
+    /// Returns an iterator over the bytes of the digest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert!(digest.iter().eq(digest.as_ref()));
+    /// assert!(digest.into_iter().eq(digest.iter().copied()));
+    /// ```
+    pub fn iter(&self) -> slice::Iter<'_, u8> {
+        self.bytes.iter()
+    }
+
+    /// Returns the digest truncated to its first `M` bytes.
+    ///
+    /// It does not compile if `M` is zero or greater than the length of the digest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// let truncated = digest.truncate::<2>();
+    /// assert_eq!(truncated.as_ref(), &digest.as_ref()[..2]);
+    /// ```
+    #[must_use]
+    pub fn truncate<const M: usize>(&self) -> TruncatedDigest<M> {
+        truncated::truncate::<M, N>(&self.bytes)
+    }
+
+    /// Parses a digest from the hexadecimal representation.
+    ///
+    /// Both lowercase and uppercase digits are accepted, optionally prefixed with `0x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string contains a non-hexadecimal character or its number of digits differs from twice the digest length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("0x31D6CFE0D16AE931B73C59D7E0C089C0").unwrap();
+    /// assert_eq!(
+    ///     digest.to_hex_lowercase(),
+    ///     "31d6cfe0d16ae931b73c59d7e0c089c0"
+    /// );
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, FromHexError> {
+        let mut digest = [0u8; N];
+        hex::decode_to_slice(hex, &mut digest)?;
+        Ok(Self::new(digest))
+    }
+
+    /// Returns a string in the lowercase hexadecimal representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// #[rustfmt::skip]
+    /// let digest = [
+    ///     0x31, 0xD6, 0xCF, 0xE0,
+    ///     0xD1, 0x6A, 0xE9, 0x31,
+    ///     0xB7, 0x3C, 0x59, 0xD7,
+    ///     0xE0, 0xC0, 0x89, 0xC0,
+    /// ];
+    /// let digest = md4::Digest::new(digest);
+    /// assert_eq!(
+    ///     digest.to_hex_lowercase(),
+    ///     "31d6cfe0d16ae931b73c59d7e0c089c0"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_hex_lowercase(&self) -> String {
+        format!("{self:x}")
+    }
+
+    /// Returns a string in the uppercase hexadecimal representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// #[rustfmt::skip]
+    /// let digest = [
+    ///     0x31, 0xD6, 0xCF, 0xE0,
+    ///     0xD1, 0x6A, 0xE9, 0x31,
+    ///     0xB7, 0x3C, 0x59, 0xD7,
+    ///     0xE0, 0xC0, 0x89, 0xC0,
+    /// ];
+    /// let digest = md4::Digest::new(digest);
+    /// assert_eq!(
+    ///     digest.to_hex_uppercase(),
+    ///     "31D6CFE0D16AE931B73C59D7E0C089C0"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_hex_uppercase(&self) -> String {
+        format!("{self:X}")
+    }
+
+    /// Writes the lowercase hexadecimal representation into the buffer and returns the written part of the buffer.
+    ///
+    /// Returns `None` if the buffer is shorter than the representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// let mut buffer = [0u8; 32];
+    /// assert_eq!(
+    ///     digest.to_hex_into(&mut buffer),
+    ///     Some("31d6cfe0d16ae931b73c59d7e0c089c0")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_hex_into<'a>(&self, buffer: &'a mut [u8]) -> Option<&'a str> {
+        hex::encode_to_slice(&self.bytes, buffer, Case::Lower)
+    }
+
+    /// Writes the hexadecimal representation in the given case to the writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::hex::Case;
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// let mut string = String::new();
+    /// digest.write_hex(&mut string, Case::Upper).unwrap();
+    /// assert_eq!(string, "31D6CFE0D16AE931B73C59D7E0C089C0");
+    /// ```
+    pub fn write_hex(&self, writer: &mut impl fmt::Write, case: Case) -> fmt::Result {
+        hex::write(writer, &self.bytes, case)
+    }
+
+    /// Returns a builder of the hexadecimal representation with grouped digits, e.g. `ab:cd:ef`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::hex::Case;
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert_eq!(
+    ///     digest.hex_grouped().to_string(),
+    ///     "31:d6:cf:e0:d1:6a:e9:31:b7:3c:59:d7:e0:c0:89:c0"
+    /// );
+    /// assert_eq!(
+    ///     digest
+    ///         .hex_grouped()
+    ///         .separator(' ')
+    ///         .group_length(2)
+    ///         .case(Case::Upper)
+    ///         .to_string(),
+    ///     "31D6 CFE0 D16A E931 B73C 59D7 E0C0 89C0"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn hex_grouped(&self) -> hex::Grouped<'_> {
+        hex::Grouped::new(&self.bytes)
+    }
+
+    /// Returns a string in the base64 representation with padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert_eq!(digest.to_base64(), "MdbP4NFq6TG3PFnX4MCJwA==");
+    /// ```
+    #[must_use]
+    pub fn to_base64(&self) -> String {
+        base64::encode(self)
+    }
+
+    /// Returns a string in the base64 representation without padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert_eq!(digest.to_base64_unpadded(), "MdbP4NFq6TG3PFnX4MCJwA");
+    /// ```
+    #[must_use]
+    pub fn to_base64_unpadded(&self) -> String {
+        base64::encode_unpadded(self)
+    }
+
+    /// Returns a string in the URL-safe base64 representation with padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert_eq!(digest.to_base64url(), "MdbP4NFq6TG3PFnX4MCJwA==");
+    /// ```
+    #[must_use]
+    pub fn to_base64url(&self) -> String {
+        base64::encode_url(self)
+    }
+
+    /// Returns a string in the URL-safe base64 representation without padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert_eq!(digest.to_base64url_unpadded(), "MdbP4NFq6TG3PFnX4MCJwA");
+    /// ```
+    #[must_use]
+    pub fn to_base64url_unpadded(&self) -> String {
+        base64::encode_url_unpadded(self)
+    }
+
+    /// Returns a string in the base32 representation of RFC 4648 without padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert_eq!(digest.to_base32(), "GHLM7YGRNLUTDNZ4LHL6BQEJYA");
+    /// ```
+    #[must_use]
+    pub fn to_base32(&self) -> String {
+        base32::encode(self)
+    }
+
+    /// Returns a string in the base32 representation of RFC 4648 with padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert_eq!(
+    ///     digest.to_base32_padded(),
+    ///     "GHLM7YGRNLUTDNZ4LHL6BQEJYA======"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_base32_padded(&self) -> String {
+        base32::encode_padded(self)
+    }
+
+    /// Returns a string in the base32 representation of Crockford.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// let digest = md4::Digest::from_hex("31d6cfe0d16ae931b73c59d7e0c089c0").unwrap();
+    /// assert_eq!(digest.to_base32_crockford(), "67BCZR6HDBMK3DSWB7BY1G49R0");
+    /// ```
+    #[must_use]
+    pub fn to_base32_crockford(&self) -> String {
+        base32::encode_crockford(self)
+    }
+}
+
+/// Implements the conversions into integers for the digest lengths that fill the integer.
+macro_rules! impl_to_integer {
+    ($type:ident, $function:ident, $integer:ty, $bytes:literal, [$($length:literal),+]) => {
+        $(
+            impl<A> $type<A, $length> {
+                #[doc = concat!("Returns the first ", $bytes, " bytes of the digest as a big-endian integer, e.g. for bucketing or sharding.")]
+                #[must_use]
+                pub fn $function(&self) -> $integer {
+                    <$integer>::from_be_bytes(self.truncate().into_inner())
+                }
+            }
+        )+
+    };
+}
+
+impl_to_integer!(Digest, to_u64, u64, 8, [8, 16, 20, 24, 28, 32, 48, 64]);
+impl_to_integer!(Digest, to_u128, u128, 16, [16, 20, 24, 28, 32, 48, 64]);
+
+impl<A, const N: usize> Clone for Digest<A, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A, const N: usize> Copy for Digest<A, N> {}
+
+impl<A, const N: usize> Debug for Digest<A, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Digest").field(&self.bytes).finish()
+    }
+}
+
+impl<A, const N: usize> PartialEq for Digest<A, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<A, const N: usize> Eq for Digest<A, N> {}
+
+impl<A, const N: usize> PartialOrd for Digest<A, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A, const N: usize> Ord for Digest<A, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes.cmp(&other.bytes)
+    }
+}
+
+impl<A, const N: usize> Hash for Digest<A, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state);
+    }
+}
+
+impl<A, const N: usize> core::Digest for Digest<A, N> {}
+
+impl<A, const N: usize> AsRef<[u8]> for Digest<A, N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<A, const N: usize> Expected for Digest<A, N> {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        verify::constant_time_eq(&self.bytes, digest)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A, const N: usize> serde::Serialize for Digest<A, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialization::serialize(&self.bytes, true, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A, const N: usize> serde::Deserialize<'de> for Digest<A, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serialization::deserialize(deserializer, N..=N).map(Self::new)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<A, const N: usize> borsh::BorshSerialize for Digest<A, N> {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.bytes)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<A, const N: usize> borsh::BorshDeserialize for Digest<A, N> {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        <[u8; N] as borsh::BorshDeserialize>::deserialize_reader(reader).map(Self::new)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, A, const N: usize> arbitrary::Arbitrary<'a> for Digest<A, N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary().map(Self::new)
+    }
+}
+
+impl<A, const N: usize> Display for Digest<A, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerHex::fmt(self, f)
+    }
+}
+
+impl<A, const N: usize> LowerHex for Digest<A, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_hex(&self.bytes, f, Case::Lower, "0x")
+    }
+}
+
+impl<A, const N: usize> UpperHex for Digest<A, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_hex(&self.bytes, f, Case::Upper, "0X")
+    }
+}
+
+impl<A, const N: usize> FromStr for Digest<A, N> {
+    type Err = FromHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl<A, const N: usize> PartialEq<str> for Digest<A, N> {
+    fn eq(&self, other: &str) -> bool {
+        hex::matches(&self.bytes, other)
+    }
+}
+
+impl<A, const N: usize> PartialEq<&str> for Digest<A, N> {
+    fn eq(&self, other: &&str) -> bool {
+        hex::matches(&self.bytes, other)
+    }
+}
+
+impl<A, const N: usize> PartialEq<[u8; N]> for Digest<A, N> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.bytes == *other
+    }
+}
+
+impl<A, const N: usize> From<[u8; N]> for Digest<A, N> {
+    fn from(digest: [u8; N]) -> Self {
+        Self::new(digest)
+    }
+}
+
+impl<A, const N: usize> From<Digest<A, N>> for [u8; N] {
+    fn from(digest: Digest<A, N>) -> Self {
+        digest.into_inner()
+    }
+}
+
+impl<A, const N: usize> TryFrom<&[u8]> for Digest<A, N> {
+    type Error = LengthError;
+
+    fn try_from(digest: &[u8]) -> Result<Self, Self::Error> {
+        digest
+            .try_into()
+            .map(Self::new)
+            .map_err(|_| LengthError { length: digest.len() })
+    }
+}
+
+impl<A, const N: usize> IntoIterator for Digest<A, N> {
+    type IntoIter = array::IntoIter<u8, N>;
+    type Item = u8;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bytes.into_iter()
+    }
+}
+
+impl<'a, A, const N: usize> IntoIterator for &'a Digest<A, N> {
+    type IntoIter = slice::Iter<'a, u8>;
+    type Item = &'a u8;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A digest of at most `N` bytes of the hash function `A`, whose length is chosen at runtime.
+///
+/// Check the modules of the hash functions, e.g. [`haval`](crate::haval), for more information.
+///
+/// Digests are ordered lexicographically by their bytes, so a digest is ordered before the longer digests it is a prefix of.
+pub struct VariableDigest<A, const N: usize> {
+    digest: [u8; N],
+    length: usize,
+    algorithm: PhantomData<fn() -> A>,
+}
+
+impl<A, const N: usize> VariableDigest<A, N> {
+    /// Creates a new digest.
+    ///
+    /// Use the [`TryFrom`] implementation for digests read from untrusted input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digest is empty or longer than `N` bytes.
+    #[must_use]
+    pub fn new(digest: &[u8]) -> Self {
+        assert!(
+            !digest.is_empty() && digest.len() <= N,
+            "digest length must be between 1 and {N} bytes"
+        );
+        let length = digest.len();
+        let digest = {
+            let mut buffer = [0u8; N];
+            buffer[..length].copy_from_slice(digest);
+            buffer
+        };
+        Self {
+            digest,
+            length,
+            algorithm: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the bytes of the digest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// assert!(digest.iter().eq(digest.as_ref()));
+    /// assert!(digest.into_iter().eq(digest.iter().copied()));
+    /// ```
+    pub fn iter(&self) -> slice::Iter<'_, u8> {
+        self.as_ref().iter()
+    }
+
+    /// Returns the digest truncated to its first `M` bytes.
+    ///
+    /// It does not compile if `M` is zero or greater than the maximum length of the digest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is greater than the length of the digest, use [`TruncatedDigest::try_from_prefix`] for digests of unknown length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// let truncated = digest.truncate::<2>();
+    /// assert_eq!(truncated.as_ref(), &digest.as_ref()[..2]);
+    /// ```
+    #[must_use]
+    pub fn truncate<const M: usize>(&self) -> TruncatedDigest<M> {
+        truncated::truncate::<M, N>(self.as_ref())
+    }
+
+    /// Parses a digest from the hexadecimal representation.
+    ///
+    /// Both lowercase and uppercase digits are accepted, optionally prefixed with `0x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string contains a non-hexadecimal character or its number of digits is odd, zero or greater than twice the maximum digest length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest = haval::Digest::from_hex(
+    ///     "0xBE417BB4DD5CFB76C7126F4F8EEB1553A449039307B1A3CD451DBFDC0FBBE330",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     digest.to_hex_lowercase(),
+    ///     "be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330"
+    /// );
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, FromHexError> {
+        let digest = hex::decode(hex)?;
+        if digest.is_empty() || digest.len() > N {
+            return Err(FromHexError::InvalidLength {
+                length: digest.len() * 2,
+            });
+        }
+        Ok(Self::new(&digest))
+    }
+
+    /// Returns the digest length in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the digest has no bytes, which is never the case.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns a string in the lowercase hexadecimal representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// #[rustfmt::skip]
+    /// let digest = [
+    ///     0xBE, 0x41, 0x7B, 0xB4, 0xDD, 0x5C, 0xFB, 0x76,
+    ///     0xC7, 0x12, 0x6F, 0x4F, 0x8E, 0xEB, 0x15, 0x53,
+    ///     0xA4, 0x49, 0x03, 0x93, 0x07, 0xB1, 0xA3, 0xCD,
+    ///     0x45, 0x1D, 0xBF, 0xDC, 0x0F, 0xBB, 0xE3, 0x30,
+    /// ];
+    /// let digest = haval::Digest::new(&digest);
+    /// assert_eq!(
+    ///     digest.to_hex_lowercase(),
+    ///     "be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_hex_lowercase(&self) -> String {
+        format!("{self:x}")
+    }
+
+    /// Returns a string in the uppercase hexadecimal representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// #[rustfmt::skip]
+    /// let digest = [
+    ///     0xBE, 0x41, 0x7B, 0xB4, 0xDD, 0x5C, 0xFB, 0x76,
+    ///     0xC7, 0x12, 0x6F, 0x4F, 0x8E, 0xEB, 0x15, 0x53,
+    ///     0xA4, 0x49, 0x03, 0x93, 0x07, 0xB1, 0xA3, 0xCD,
+    ///     0x45, 0x1D, 0xBF, 0xDC, 0x0F, 0xBB, 0xE3, 0x30,
+    /// ];
+    /// let digest = haval::Digest::new(&digest);
+    /// assert_eq!(
+    ///     digest.to_hex_uppercase(),
+    ///     "BE417BB4DD5CFB76C7126F4F8EEB1553A449039307B1A3CD451DBFDC0FBBE330"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_hex_uppercase(&self) -> String {
+        format!("{self:X}")
+    }
+
+    /// Writes the lowercase hexadecimal representation into the buffer and returns the written part of the buffer.
+    ///
+    /// Returns `None` if the buffer is shorter than the representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// let mut buffer = [0u8; 64];
+    /// assert_eq!(
+    ///     digest.to_hex_into(&mut buffer),
+    ///     Some("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_hex_into<'a>(&self, buffer: &'a mut [u8]) -> Option<&'a str> {
+        hex::encode_to_slice(self.as_ref(), buffer, Case::Lower)
+    }
+
+    /// Writes the hexadecimal representation in the given case to the writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    /// use chksum_hash::hex::Case;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// let mut string = String::new();
+    /// digest.write_hex(&mut string, Case::Upper).unwrap();
+    /// assert_eq!(
+    ///     string,
+    ///     "BE417BB4DD5CFB76C7126F4F8EEB1553A449039307B1A3CD451DBFDC0FBBE330"
+    /// );
+    /// ```
+    pub fn write_hex(&self, writer: &mut impl fmt::Write, case: Case) -> fmt::Result {
+        hex::write(writer, self.as_ref(), case)
+    }
+
+    /// Returns a builder of the hexadecimal representation with grouped digits, e.g. `ab:cd:ef`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    /// use chksum_hash::hex::Case;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     digest.hex_grouped().to_string(),
+    ///     "be:41:7b:b4:dd:5c:fb:76:c7:12:6f:4f:8e:eb:15:53:a4:49:03:93:07:b1:a3:cd:45:1d:bf:dc:0f:\
+    ///      bb:e3:30"
+    /// );
+    /// assert_eq!(
+    ///     digest
+    ///         .hex_grouped()
+    ///         .separator(' ')
+    ///         .group_length(2)
+    ///         .case(Case::Upper)
+    ///         .to_string(),
+    ///     "BE41 7BB4 DD5C FB76 C712 6F4F 8EEB 1553 A449 0393 07B1 A3CD 451D BFDC 0FBB E330"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn hex_grouped(&self) -> hex::Grouped<'_> {
+        hex::Grouped::new(self.as_ref())
+    }
+
+    /// Returns a string in the base64 representation with padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     digest.to_base64(),
+    ///     "vkF7tN1c+3bHEm9PjusVU6RJA5MHsaPNRR2/3A+74zA="
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_base64(&self) -> String {
+        base64::encode(self)
+    }
+
+    /// Returns a string in the base64 representation without padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     digest.to_base64_unpadded(),
+    ///     "vkF7tN1c+3bHEm9PjusVU6RJA5MHsaPNRR2/3A+74zA"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_base64_unpadded(&self) -> String {
+        base64::encode_unpadded(self)
+    }
+
+    /// Returns a string in the URL-safe base64 representation with padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     digest.to_base64url(),
+    ///     "vkF7tN1c-3bHEm9PjusVU6RJA5MHsaPNRR2_3A-74zA="
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_base64url(&self) -> String {
+        base64::encode_url(self)
+    }
+
+    /// Returns a string in the URL-safe base64 representation without padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     digest.to_base64url_unpadded(),
+    ///     "vkF7tN1c-3bHEm9PjusVU6RJA5MHsaPNRR2_3A-74zA"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_base64url_unpadded(&self) -> String {
+        base64::encode_url_unpadded(self)
+    }
+
+    /// Returns a string in the base32 representation of RFC 4648 without padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     digest.to_base32(),
+    ///     "XZAXXNG5LT5XNRYSN5HY52YVKOSESA4TA6Y2HTKFDW75YD534MYA"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_base32(&self) -> String {
+        base32::encode(self)
+    }
+
+    /// Returns a string in the base32 representation of RFC 4648 with padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     digest.to_base32_padded(),
+    ///     "XZAXXNG5LT5XNRYSN5HY52YVKOSESA4TA6Y2HTKFDW75YD534MYA===="
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_base32_padded(&self) -> String {
+        base32::encode_padded(self)
+    }
+
+    /// Returns a string in the base32 representation of Crockford.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::haval;
+    ///
+    /// let digest =
+    ///     haval::Digest::from_hex("be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     digest.to_base32_crockford(),
+    ///     "QS0QQD6XBKXQDHRJDX7RXTRNAEJ4J0WK0YRT7KA53PZXR3XVWCR0"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_base32_crockford(&self) -> String {
+        base32::encode_crockford(self)
+    }
+}
+
+impl_to_integer!(VariableDigest, to_u64, u64, 8, [32, 64, 128]);
+impl_to_integer!(VariableDigest, to_u128, u128, 16, [32, 64, 128]);
+
+impl<A, const N: usize> Clone for VariableDigest<A, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A, const N: usize> Copy for VariableDigest<A, N> {}
+
+impl<A, const N: usize> Debug for VariableDigest<A, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Digest").field(&self.as_ref()).finish()
+    }
+}
+
+impl<A, const N: usize> PartialEq for VariableDigest<A, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<A, const N: usize> Eq for VariableDigest<A, N> {}
+
+impl<A, const N: usize> PartialOrd for VariableDigest<A, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A, const N: usize> Ord for VariableDigest<A, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
+impl<A, const N: usize> Hash for VariableDigest<A, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state);
+    }
+}
+
+impl<A, const N: usize> core::Digest for VariableDigest<A, N> {}
+
+impl<A, const N: usize> AsRef<[u8]> for VariableDigest<A, N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.digest[..self.length]
+    }
+}
+
+impl<A, const N: usize> Expected for VariableDigest<A, N> {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        verify::constant_time_eq(self.as_ref(), digest)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A, const N: usize> serde::Serialize for VariableDigest<A, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialization::serialize(self.as_ref(), false, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A, const N: usize> serde::Deserialize<'de> for VariableDigest<A, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serialization::deserialize::<_, Vec<u8>>(deserializer, 1..=N).map(|digest| Self::new(&digest))
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<A, const N: usize> borsh::BorshSerialize for VariableDigest<A, N> {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        borsh::BorshSerialize::serialize(self.as_ref(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<A, const N: usize> borsh::BorshDeserialize for VariableDigest<A, N> {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let digest = <Vec<u8> as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+        if (1..=N).contains(&digest.len()) {
+            Ok(Self::new(&digest))
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "invalid digest length"))
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, A, const N: usize> arbitrary::Arbitrary<'a> for VariableDigest<A, N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let length = u.int_in_range(1..=N)?;
+        u.bytes(length).map(Self::new)
+    }
+}
+
+impl<A, const N: usize> Display for VariableDigest<A, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerHex::fmt(self, f)
+    }
+}
+
+impl<A, const N: usize> LowerHex for VariableDigest<A, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.as_ref(), f, Case::Lower, "0x")
+    }
+}
+
+impl<A, const N: usize> UpperHex for VariableDigest<A, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.as_ref(), f, Case::Upper, "0X")
+    }
+}
+
+impl<A, const N: usize> FromStr for VariableDigest<A, N> {
+    type Err = FromHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl<A, const N: usize> PartialEq<str> for VariableDigest<A, N> {
+    fn eq(&self, other: &str) -> bool {
+        hex::matches(self.as_ref(), other)
+    }
+}
+
+impl<A, const N: usize> PartialEq<&str> for VariableDigest<A, N> {
+    fn eq(&self, other: &&str) -> bool {
+        hex::matches(self.as_ref(), other)
+    }
+}
+
+impl<A, const N: usize, const M: usize> PartialEq<[u8; M]> for VariableDigest<A, N> {
+    fn eq(&self, other: &[u8; M]) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl<A, const N: usize> TryFrom<&[u8]> for VariableDigest<A, N> {
+    type Error = LengthError;
+
+    fn try_from(digest: &[u8]) -> Result<Self, Self::Error> {
+        if (1..=N).contains(&digest.len()) {
+            Ok(Self::new(digest))
+        } else {
+            Err(LengthError { length: digest.len() })
+        }
+    }
+}
+
+impl<A, const N: usize> IntoIterator for VariableDigest<A, N> {
+    type IntoIter = Take<array::IntoIter<u8, N>>;
+    type Item = u8;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.digest.into_iter().take(self.length)
+    }
+}
+
+impl<'a, A, const N: usize> IntoIterator for &'a VariableDigest<A, N> {
+    type IntoIter = slice::Iter<'a, u8>;
+    type Item = &'a u8;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Writes the hexadecimal representation of the digest in the given case to the formatter, padded and prefixed as requested.
+fn fmt_hex(digest: &[u8], f: &mut Formatter<'_>, case: Case, prefix: &str) -> fmt::Result {
+    let mut string = String::with_capacity(digest.len() * 2);
+    hex::write(&mut string, digest, case)?;
+    if f.alternate() {
+        f.pad_integral(true, prefix, &string)
+    } else {
+        f.pad(&string)
+    }
+}
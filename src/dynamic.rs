@@ -0,0 +1,1100 @@
+//! Module containing the object-safe [`DynHash`] trait for hash functions chosen at runtime.
+//!
+//! The [`Update`](core::Update) trait has generic functions and a `Default` bound, so it cannot be used as a trait object. Every hash of this crate implements [`DynHash`] instead, which produces the digest as boxed bytes, so hashes of different types can be stored behind a `Box<dyn DynHash>`.
+//!
+//! The [`HashAlgorithm`] enum lists the hash functions of this crate, so e.g. a command line argument can be mapped straight to a hash with [`new_with`] or [`hash_with`], and several hash functions can be computed in one pass with a [`MultiHasher`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::dynamic::{self, DynHash};
+//! use chksum_hash::{md4, sha2_256};
+//!
+//! let mut hashes: Vec<Box<dyn DynHash>> = vec![
+//!     dynamic::boxed::<md4::Update>(),
+//!     dynamic::boxed::<sha2_256::Update>(),
+//! ];
+//! for hash in &mut hashes {
+//!     hash.update(b"data");
+//! }
+//! assert_eq!(hashes[0].finalize_boxed()[..], *md4::hash("data").as_ref());
+//! assert_eq!(
+//!     hashes[1].finalize_boxed()[..],
+//!     *sha2_256::hash("data").as_ref()
+//! );
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::io;
+use std::str::FromStr;
+
+use chksum_hash_core as core;
+
+/// An object-safe hash that can handle an unknown amount of input data.
+///
+/// The trait is implemented for all [`Update`](core::Update) types with digests convertible to bytes.
+pub trait DynHash {
+    /// Updates the hash state with an input data.
+    fn update(&mut self, data: &[u8]);
+
+    /// Produces the hash digest as boxed bytes using internal finalization.
+    fn finalize_boxed(&self) -> Box<[u8]>;
+
+    /// Resets the hash state to its initial state.
+    fn reset(&mut self);
+}
+
+impl<U> DynHash for U
+where
+    U: core::Update,
+    U::Digest: AsRef<[u8]>,
+{
+    fn update(&mut self, data: &[u8]) {
+        core::Update::update(self, data);
+    }
+
+    fn finalize_boxed(&self) -> Box<[u8]> {
+        core::Update::digest(self).as_ref().into()
+    }
+
+    fn reset(&mut self) {
+        core::Update::reset(self);
+    }
+}
+
+/// Creates a new boxed hash of the given type.
+#[must_use]
+pub fn boxed<U>() -> Box<dyn DynHash>
+where
+    U: core::Update + 'static,
+    U::Digest: AsRef<[u8]>,
+{
+    Box::new(U::default())
+}
+
+/// A boxed hash created at runtime.
+pub type DynHasher = Box<dyn DynHash>;
+
+/// A hash function of this crate that can be chosen at runtime.
+///
+/// Only the variants of the enabled hash functions are available. HAVAL and MD6 are created with their default parameters; for other parameters, and for hash functions that need a key or parameters without defaults, like GHASH or the generic CRC, use [`boxed`] instead. Haraka is not listed, since it only hashes inputs of a fixed length and has no `Update` type.
+///
+/// The enum is not named `Algorithm` to avoid confusion with the [`Algorithm`](crate::algorithm::Algorithm) trait of the block-based hash functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HashAlgorithm {
+    /// BLAKE-256, accessible via the [`blake_256`](crate::blake_256) module.
+    #[cfg(feature = "blake-256")]
+    Blake256,
+    /// BLAKE-512, accessible via the [`blake_512`](crate::blake_512) module.
+    #[cfg(feature = "blake-512")]
+    Blake512,
+    /// BSD sum, accessible via the [`bsd_sum`](crate::bsd_sum) module.
+    #[cfg(feature = "bsd-sum")]
+    BsdSum,
+    /// CRC-32, accessible via the [`crc32`](crate::crc32) module.
+    #[cfg(feature = "crc32")]
+    Crc32,
+    /// CRC-32C, accessible via the [`crc32c`](crate::crc32c) module.
+    #[cfg(feature = "crc32c")]
+    Crc32c,
+    /// CRC-64, accessible via the [`crc64`](crate::crc64) module.
+    #[cfg(feature = "crc64")]
+    Crc64,
+    /// djb2, accessible via the [`classic::djb2`](crate::classic::djb2) module.
+    #[cfg(feature = "classic")]
+    Djb2,
+    /// ed2k, accessible via the [`ed2k`](crate::ed2k) module.
+    #[cfg(feature = "ed2k")]
+    Ed2k,
+    /// ELF hash, accessible via the [`classic::elf`](crate::classic::elf) module.
+    #[cfg(feature = "classic")]
+    Elf,
+    /// The Amazon S3 Glacier tree hash, accessible via the [`glacier`](crate::glacier) module.
+    #[cfg(feature = "glacier")]
+    Glacier,
+    /// HAVAL with its default parameters, 5 passes and a 256-bit digest, accessible via the [`haval`](crate::haval) module.
+    #[cfg(feature = "haval")]
+    Haval,
+    /// The Internet checksum, accessible via the [`internet_checksum`](crate::internet_checksum) module.
+    #[cfg(feature = "internet-checksum")]
+    InternetChecksum,
+    /// JH-224, accessible via the [`jh_224`](crate::jh_224) module.
+    #[cfg(feature = "jh-224")]
+    Jh224,
+    /// JH-256, accessible via the [`jh_256`](crate::jh_256) module.
+    #[cfg(feature = "jh-256")]
+    Jh256,
+    /// JH-384, accessible via the [`jh_384`](crate::jh_384) module.
+    #[cfg(feature = "jh-384")]
+    Jh384,
+    /// JH-512, accessible via the [`jh_512`](crate::jh_512) module.
+    #[cfg(feature = "jh-512")]
+    Jh512,
+    /// komihash, accessible via the [`komihash`](crate::komihash) module.
+    #[cfg(feature = "komihash")]
+    Komihash,
+    /// lookup3, accessible via the [`lookup3`](crate::lookup3) module.
+    #[cfg(feature = "lookup3")]
+    Lookup3,
+    /// LSH-256, accessible via the [`lsh_256`](crate::lsh_256) module.
+    #[cfg(feature = "lsh-256")]
+    Lsh256,
+    /// LSH-512, accessible via the [`lsh_512`](crate::lsh_512) module.
+    #[cfg(feature = "lsh-512")]
+    Lsh512,
+    /// MD4, accessible via the [`md4`](crate::md4) module.
+    #[cfg(feature = "md4")]
+    Md4,
+    /// MD5, accessible via the [`md5`](crate::md5) module.
+    #[cfg(feature = "md5")]
+    Md5,
+    /// MD6 with its default parameters, a 256-bit digest without a key, accessible via the [`md6`](crate::md6) module.
+    #[cfg(feature = "md6")]
+    Md6,
+    /// one-at-a-time, accessible via the [`one_at_a_time`](crate::one_at_a_time) module.
+    #[cfg(feature = "one-at-a-time")]
+    OneAtATime,
+    /// sdbm, accessible via the [`classic::sdbm`](crate::classic::sdbm) module.
+    #[cfg(feature = "classic")]
+    Sdbm,
+    /// SHA-0, accessible via the [`sha0`](crate::sha0) module.
+    #[cfg(feature = "sha0")]
+    Sha0,
+    /// SHA-1, accessible via the [`sha1`](crate::sha1) module.
+    #[cfg(feature = "sha1")]
+    Sha1,
+    /// SHA-224, accessible via the [`sha2_224`](crate::sha2_224) module.
+    #[cfg(feature = "sha2-224")]
+    Sha2_224,
+    /// SHA-256, accessible via the [`sha2_256`](crate::sha2_256) module.
+    #[cfg(feature = "sha2-256")]
+    Sha2_256,
+    /// SHA-384, accessible via the [`sha2_384`](crate::sha2_384) module.
+    #[cfg(feature = "sha2-384")]
+    Sha2_384,
+    /// SHA-512, accessible via the [`sha2_512`](crate::sha2_512) module.
+    #[cfg(feature = "sha2-512")]
+    Sha2_512,
+    /// Skein-256, accessible via the [`skein_256`](crate::skein_256) module.
+    #[cfg(feature = "skein-256")]
+    Skein256,
+    /// Skein-512, accessible via the [`skein_512`](crate::skein_512) module.
+    #[cfg(feature = "skein-512")]
+    Skein512,
+    /// Skein-1024, accessible via the [`skein_1024`](crate::skein_1024) module.
+    #[cfg(feature = "skein-1024")]
+    Skein1024,
+    /// System V sum, accessible via the [`sysv_sum`](crate::sysv_sum) module.
+    #[cfg(feature = "sysv-sum")]
+    SysvSum,
+    /// Tiger, accessible via the [`tiger`](crate::tiger) module.
+    #[cfg(feature = "tiger")]
+    Tiger,
+    /// Tiger2, accessible via the [`tiger2`](crate::tiger2) module.
+    #[cfg(feature = "tiger2")]
+    Tiger2,
+    /// The Tiger Tree Hash, accessible via the [`tth`](crate::tth) module.
+    #[cfg(feature = "tth")]
+    Tth,
+    /// XXH3-64, accessible via the [`xxh3_64`](crate::xxh3_64) module.
+    #[cfg(feature = "xxh3-64")]
+    Xxh3_64,
+    /// XXH3-128, accessible via the [`xxh3_128`](crate::xxh3_128) module.
+    #[cfg(feature = "xxh3-128")]
+    Xxh3_128,
+}
+
+impl HashAlgorithm {
+    /// All enabled hash functions.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "blake-256")]
+        Self::Blake256,
+        #[cfg(feature = "blake-512")]
+        Self::Blake512,
+        #[cfg(feature = "bsd-sum")]
+        Self::BsdSum,
+        #[cfg(feature = "crc32")]
+        Self::Crc32,
+        #[cfg(feature = "crc32c")]
+        Self::Crc32c,
+        #[cfg(feature = "crc64")]
+        Self::Crc64,
+        #[cfg(feature = "classic")]
+        Self::Djb2,
+        #[cfg(feature = "ed2k")]
+        Self::Ed2k,
+        #[cfg(feature = "classic")]
+        Self::Elf,
+        #[cfg(feature = "glacier")]
+        Self::Glacier,
+        #[cfg(feature = "haval")]
+        Self::Haval,
+        #[cfg(feature = "internet-checksum")]
+        Self::InternetChecksum,
+        #[cfg(feature = "jh-224")]
+        Self::Jh224,
+        #[cfg(feature = "jh-256")]
+        Self::Jh256,
+        #[cfg(feature = "jh-384")]
+        Self::Jh384,
+        #[cfg(feature = "jh-512")]
+        Self::Jh512,
+        #[cfg(feature = "komihash")]
+        Self::Komihash,
+        #[cfg(feature = "lookup3")]
+        Self::Lookup3,
+        #[cfg(feature = "lsh-256")]
+        Self::Lsh256,
+        #[cfg(feature = "lsh-512")]
+        Self::Lsh512,
+        #[cfg(feature = "md4")]
+        Self::Md4,
+        #[cfg(feature = "md5")]
+        Self::Md5,
+        #[cfg(feature = "md6")]
+        Self::Md6,
+        #[cfg(feature = "one-at-a-time")]
+        Self::OneAtATime,
+        #[cfg(feature = "classic")]
+        Self::Sdbm,
+        #[cfg(feature = "sha0")]
+        Self::Sha0,
+        #[cfg(feature = "sha1")]
+        Self::Sha1,
+        #[cfg(feature = "sha2-224")]
+        Self::Sha2_224,
+        #[cfg(feature = "sha2-256")]
+        Self::Sha2_256,
+        #[cfg(feature = "sha2-384")]
+        Self::Sha2_384,
+        #[cfg(feature = "sha2-512")]
+        Self::Sha2_512,
+        #[cfg(feature = "skein-256")]
+        Self::Skein256,
+        #[cfg(feature = "skein-512")]
+        Self::Skein512,
+        #[cfg(feature = "skein-1024")]
+        Self::Skein1024,
+        #[cfg(feature = "sysv-sum")]
+        Self::SysvSum,
+        #[cfg(feature = "tiger")]
+        Self::Tiger,
+        #[cfg(feature = "tiger2")]
+        Self::Tiger2,
+        #[cfg(feature = "tth")]
+        Self::Tth,
+        #[cfg(feature = "xxh3-64")]
+        Self::Xxh3_64,
+        #[cfg(feature = "xxh3-128")]
+        Self::Xxh3_128,
+    ];
+
+    /// Returns the canonical name of the hash function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::HashAlgorithm;
+    ///
+    /// assert_eq!(HashAlgorithm::Sha2_256.name(), "SHA-256");
+    /// ```
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.metadata().name
+    }
+
+    /// Returns the block length of the hash function in bytes, or `None` if the hash function does not process the input in blocks.
+    #[must_use]
+    pub const fn block_length_bytes(self) -> Option<usize> {
+        self.metadata().block_length
+    }
+
+    /// Returns the digest length of the hash function in bytes.
+    #[must_use]
+    pub const fn digest_length_bytes(self) -> usize {
+        self.metadata().digest_length
+    }
+
+    /// Returns the ASN.1 object identifier of the hash function in the dotted notation, or `None` if no identifier is registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::HashAlgorithm;
+    ///
+    /// assert_eq!(
+    ///     HashAlgorithm::Sha2_256.oid(),
+    ///     Some("2.16.840.1.101.3.4.2.1")
+    /// );
+    /// assert_eq!(HashAlgorithm::Crc32.oid(), None);
+    /// ```
+    #[must_use]
+    pub const fn oid(self) -> Option<&'static str> {
+        self.metadata().oid
+    }
+
+    /// Returns the metadata of the hash function.
+    const fn metadata(self) -> Metadata {
+        match self {
+            #[cfg(feature = "blake-256")]
+            Self::Blake256 => {
+                Metadata {
+                    name: "BLAKE-256",
+                    aliases: &["blake256"],
+                    block_length: Some(crate::blake_256::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::blake_256::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "blake-512")]
+            Self::Blake512 => {
+                Metadata {
+                    name: "BLAKE-512",
+                    aliases: &["blake512"],
+                    block_length: Some(crate::blake_512::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::blake_512::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "bsd-sum")]
+            Self::BsdSum => {
+                Metadata {
+                    name: "BSD sum",
+                    aliases: &["bsdsum"],
+                    block_length: None,
+                    digest_length: crate::bsd_sum::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "crc32")]
+            Self::Crc32 => {
+                Metadata {
+                    name: "CRC-32",
+                    aliases: &["crc32"],
+                    block_length: None,
+                    digest_length: crate::crc32::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "crc32c")]
+            Self::Crc32c => {
+                Metadata {
+                    name: "CRC-32C",
+                    aliases: &["crc32c"],
+                    block_length: None,
+                    digest_length: crate::crc32c::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "crc64")]
+            Self::Crc64 => {
+                Metadata {
+                    name: "CRC-64",
+                    aliases: &["crc64"],
+                    block_length: None,
+                    digest_length: crate::crc64::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "classic")]
+            Self::Djb2 => {
+                Metadata {
+                    name: "djb2",
+                    aliases: &["djb2"],
+                    block_length: None,
+                    digest_length: crate::classic::djb2::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "ed2k")]
+            Self::Ed2k => {
+                Metadata {
+                    name: "ed2k",
+                    aliases: &["ed2k"],
+                    block_length: None,
+                    digest_length: crate::ed2k::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "classic")]
+            Self::Elf => {
+                Metadata {
+                    name: "ELF hash",
+                    aliases: &["elf", "elfhash"],
+                    block_length: None,
+                    digest_length: crate::classic::elf::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "glacier")]
+            Self::Glacier => {
+                Metadata {
+                    name: "Glacier tree hash",
+                    aliases: &["glacier", "glaciertreehash"],
+                    block_length: None,
+                    digest_length: crate::glacier::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "haval")]
+            Self::Haval => {
+                Metadata {
+                    name: "HAVAL-256/5",
+                    aliases: &["haval", "haval2565", "haval256"],
+                    block_length: Some(crate::haval::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::haval::params::DEFAULT_DIGEST_LENGTH_BITS / 8,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "internet-checksum")]
+            Self::InternetChecksum => {
+                Metadata {
+                    name: "Internet checksum",
+                    aliases: &["internetchecksum"],
+                    block_length: None,
+                    digest_length: crate::internet_checksum::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "jh-224")]
+            Self::Jh224 => {
+                Metadata {
+                    name: "JH-224",
+                    aliases: &["jh224"],
+                    block_length: Some(crate::jh_224::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::jh_224::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "jh-256")]
+            Self::Jh256 => {
+                Metadata {
+                    name: "JH-256",
+                    aliases: &["jh256"],
+                    block_length: Some(crate::jh_256::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::jh_256::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "jh-384")]
+            Self::Jh384 => {
+                Metadata {
+                    name: "JH-384",
+                    aliases: &["jh384"],
+                    block_length: Some(crate::jh_384::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::jh_384::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "jh-512")]
+            Self::Jh512 => {
+                Metadata {
+                    name: "JH-512",
+                    aliases: &["jh512"],
+                    block_length: Some(crate::jh_512::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::jh_512::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "komihash")]
+            Self::Komihash => {
+                Metadata {
+                    name: "komihash",
+                    aliases: &["komihash"],
+                    block_length: Some(crate::komihash::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::komihash::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "lookup3")]
+            Self::Lookup3 => {
+                Metadata {
+                    name: "lookup3",
+                    aliases: &["lookup3"],
+                    block_length: None,
+                    digest_length: crate::lookup3::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "lsh-256")]
+            Self::Lsh256 => {
+                Metadata {
+                    name: "LSH-256",
+                    aliases: &["lsh256", "lsh256256"],
+                    block_length: Some(crate::lsh_256::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::lsh_256::DIGEST_LENGTH_BITS / 8,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "lsh-512")]
+            Self::Lsh512 => {
+                Metadata {
+                    name: "LSH-512",
+                    aliases: &["lsh512", "lsh512512"],
+                    block_length: Some(crate::lsh_512::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::lsh_512::DIGEST_LENGTH_BITS / 8,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "md4")]
+            Self::Md4 => {
+                Metadata {
+                    name: "MD4",
+                    aliases: &["md4"],
+                    block_length: Some(crate::md4::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::md4::DIGEST_LENGTH_BYTES,
+                    oid: Some("1.2.840.113549.2.4"),
+                }
+            },
+            #[cfg(feature = "md5")]
+            Self::Md5 => {
+                Metadata {
+                    name: "MD5",
+                    aliases: &["md5"],
+                    block_length: Some(crate::md5::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::md5::DIGEST_LENGTH_BYTES,
+                    oid: Some("1.2.840.113549.2.5"),
+                }
+            },
+            #[cfg(feature = "md6")]
+            Self::Md6 => {
+                Metadata {
+                    name: "MD6-256",
+                    aliases: &["md6", "md6256"],
+                    block_length: Some(crate::md6::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::md6::params::DEFAULT_DIGEST_LENGTH_BITS / 8,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "one-at-a-time")]
+            Self::OneAtATime => {
+                Metadata {
+                    name: "one-at-a-time",
+                    aliases: &["oneatatime", "joaat"],
+                    block_length: None,
+                    digest_length: crate::one_at_a_time::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "classic")]
+            Self::Sdbm => {
+                Metadata {
+                    name: "sdbm",
+                    aliases: &["sdbm"],
+                    block_length: None,
+                    digest_length: crate::classic::sdbm::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "sha0")]
+            Self::Sha0 => {
+                Metadata {
+                    name: "SHA-0",
+                    aliases: &["sha0"],
+                    block_length: Some(crate::sha0::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::sha0::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "sha1")]
+            Self::Sha1 => {
+                Metadata {
+                    name: "SHA-1",
+                    aliases: &["sha1"],
+                    block_length: Some(crate::sha1::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::sha1::DIGEST_LENGTH_BYTES,
+                    oid: Some("1.3.14.3.2.26"),
+                }
+            },
+            #[cfg(feature = "sha2-224")]
+            Self::Sha2_224 => {
+                Metadata {
+                    name: "SHA-224",
+                    aliases: &["sha224", "sha2224"],
+                    block_length: Some(crate::sha2_224::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::sha2_224::DIGEST_LENGTH_BYTES,
+                    oid: Some("2.16.840.1.101.3.4.2.4"),
+                }
+            },
+            #[cfg(feature = "sha2-256")]
+            Self::Sha2_256 => {
+                Metadata {
+                    name: "SHA-256",
+                    aliases: &["sha256", "sha2256"],
+                    block_length: Some(crate::sha2_256::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::sha2_256::DIGEST_LENGTH_BYTES,
+                    oid: Some("2.16.840.1.101.3.4.2.1"),
+                }
+            },
+            #[cfg(feature = "sha2-384")]
+            Self::Sha2_384 => {
+                Metadata {
+                    name: "SHA-384",
+                    aliases: &["sha384", "sha2384"],
+                    block_length: Some(crate::sha2_384::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::sha2_384::DIGEST_LENGTH_BYTES,
+                    oid: Some("2.16.840.1.101.3.4.2.2"),
+                }
+            },
+            #[cfg(feature = "sha2-512")]
+            Self::Sha2_512 => {
+                Metadata {
+                    name: "SHA-512",
+                    aliases: &["sha512", "sha2512"],
+                    block_length: Some(crate::sha2_512::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::sha2_512::DIGEST_LENGTH_BYTES,
+                    oid: Some("2.16.840.1.101.3.4.2.3"),
+                }
+            },
+            #[cfg(feature = "skein-256")]
+            Self::Skein256 => {
+                Metadata {
+                    name: "Skein-256",
+                    aliases: &["skein256", "skein256256"],
+                    block_length: Some(crate::skein_256::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::skein_256::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "skein-512")]
+            Self::Skein512 => {
+                Metadata {
+                    name: "Skein-512",
+                    aliases: &["skein512", "skein512512"],
+                    block_length: Some(crate::skein_512::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::skein_512::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "skein-1024")]
+            Self::Skein1024 => {
+                Metadata {
+                    name: "Skein-1024",
+                    aliases: &["skein1024", "skein10241024"],
+                    block_length: Some(crate::skein_1024::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::skein_1024::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "sysv-sum")]
+            Self::SysvSum => {
+                Metadata {
+                    name: "System V sum",
+                    aliases: &["sysvsum", "systemvsum"],
+                    block_length: None,
+                    digest_length: crate::sysv_sum::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "tiger")]
+            Self::Tiger => {
+                Metadata {
+                    name: "Tiger",
+                    aliases: &["tiger", "tiger192"],
+                    block_length: Some(crate::tiger::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::tiger::DIGEST_LENGTH_BYTES,
+                    oid: Some("1.3.6.1.4.1.11591.12.2"),
+                }
+            },
+            #[cfg(feature = "tiger2")]
+            Self::Tiger2 => {
+                Metadata {
+                    name: "Tiger2",
+                    aliases: &["tiger2"],
+                    block_length: Some(crate::tiger2::BLOCK_LENGTH_BYTES),
+                    digest_length: crate::tiger2::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "tth")]
+            Self::Tth => {
+                Metadata {
+                    name: "Tiger Tree Hash",
+                    aliases: &["tth", "tigertreehash"],
+                    block_length: None,
+                    digest_length: crate::tth::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "xxh3-64")]
+            Self::Xxh3_64 => {
+                Metadata {
+                    name: "XXH3-64",
+                    aliases: &["xxh3", "xxh364"],
+                    block_length: None,
+                    digest_length: crate::xxh3_64::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+            #[cfg(feature = "xxh3-128")]
+            Self::Xxh3_128 => {
+                Metadata {
+                    name: "XXH3-128",
+                    aliases: &["xxh3128"],
+                    block_length: None,
+                    digest_length: crate::xxh3_128::DIGEST_LENGTH_BYTES,
+                    oid: None,
+                }
+            },
+        }
+    }
+}
+
+impl Display for HashAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = ParseHashAlgorithmError;
+
+    /// Parses the name of a hash function.
+    ///
+    /// The names are case-insensitive and hyphens, underscores, slashes and spaces are ignored, so e.g. `sha-256`, `SHA256` and `sha2_256` are all accepted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::HashAlgorithm;
+    ///
+    /// assert_eq!("sha-256".parse(), Ok(HashAlgorithm::Sha2_256));
+    /// assert_eq!("SHA256".parse(), Ok(HashAlgorithm::Sha2_256));
+    /// assert_eq!("sha2-256".parse(), Ok(HashAlgorithm::Sha2_256));
+    /// assert!("sha-3".parse::<HashAlgorithm>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | '/' | ' '))
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<String>();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|algorithm| algorithm.metadata().aliases.contains(&normalized.as_str()))
+            .ok_or(ParseHashAlgorithmError)
+    }
+}
+
+/// The metadata of a hash function.
+struct Metadata {
+    /// Canonical name.
+    name: &'static str,
+    /// Lowercase names without separators accepted by the parser.
+    aliases: &'static [&'static str],
+    /// Block length in bytes.
+    block_length: Option<usize>,
+    /// Digest length in bytes.
+    digest_length: usize,
+    /// ASN.1 object identifier in the dotted notation.
+    oid: Option<&'static str>,
+}
+
+/// An error of parsing an unknown or disabled hash function name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseHashAlgorithmError;
+
+impl Display for ParseHashAlgorithmError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown hash function")
+    }
+}
+
+impl Error for ParseHashAlgorithmError {}
+
+/// Creates a new boxed hash of the given algorithm.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{new_with, sha2_256, HashAlgorithm};
+///
+/// let mut hash = new_with(HashAlgorithm::Sha2_256);
+/// hash.update(b"example ");
+/// hash.update(b"data");
+/// assert_eq!(
+///     hash.finalize_boxed()[..],
+///     *sha2_256::hash("example data").as_ref()
+/// );
+/// ```
+#[must_use]
+pub fn new_with(algorithm: HashAlgorithm) -> DynHasher {
+    match algorithm {
+        #[cfg(feature = "blake-256")]
+        HashAlgorithm::Blake256 => boxed::<crate::blake_256::Update>(),
+        #[cfg(feature = "blake-512")]
+        HashAlgorithm::Blake512 => boxed::<crate::blake_512::Update>(),
+        #[cfg(feature = "bsd-sum")]
+        HashAlgorithm::BsdSum => boxed::<crate::bsd_sum::Update>(),
+        #[cfg(feature = "crc32")]
+        HashAlgorithm::Crc32 => boxed::<crate::crc32::Update>(),
+        #[cfg(feature = "crc32c")]
+        HashAlgorithm::Crc32c => boxed::<crate::crc32c::Update>(),
+        #[cfg(feature = "crc64")]
+        HashAlgorithm::Crc64 => boxed::<crate::crc64::Update>(),
+        #[cfg(feature = "classic")]
+        HashAlgorithm::Djb2 => boxed::<crate::classic::djb2::Update>(),
+        #[cfg(feature = "ed2k")]
+        HashAlgorithm::Ed2k => boxed::<crate::ed2k::Update>(),
+        #[cfg(feature = "classic")]
+        HashAlgorithm::Elf => boxed::<crate::classic::elf::Update>(),
+        #[cfg(feature = "glacier")]
+        HashAlgorithm::Glacier => boxed::<crate::glacier::Update>(),
+        #[cfg(feature = "haval")]
+        HashAlgorithm::Haval => boxed::<crate::haval::Update>(),
+        #[cfg(feature = "internet-checksum")]
+        HashAlgorithm::InternetChecksum => boxed::<crate::internet_checksum::Update>(),
+        #[cfg(feature = "jh-224")]
+        HashAlgorithm::Jh224 => boxed::<crate::jh_224::Update>(),
+        #[cfg(feature = "jh-256")]
+        HashAlgorithm::Jh256 => boxed::<crate::jh_256::Update>(),
+        #[cfg(feature = "jh-384")]
+        HashAlgorithm::Jh384 => boxed::<crate::jh_384::Update>(),
+        #[cfg(feature = "jh-512")]
+        HashAlgorithm::Jh512 => boxed::<crate::jh_512::Update>(),
+        #[cfg(feature = "komihash")]
+        HashAlgorithm::Komihash => boxed::<crate::komihash::Update>(),
+        #[cfg(feature = "lookup3")]
+        HashAlgorithm::Lookup3 => boxed::<crate::lookup3::Update>(),
+        #[cfg(feature = "lsh-256")]
+        HashAlgorithm::Lsh256 => boxed::<crate::lsh_256::Update>(),
+        #[cfg(feature = "lsh-512")]
+        HashAlgorithm::Lsh512 => boxed::<crate::lsh_512::Update>(),
+        #[cfg(feature = "md4")]
+        HashAlgorithm::Md4 => boxed::<crate::md4::Update>(),
+        #[cfg(feature = "md5")]
+        HashAlgorithm::Md5 => boxed::<crate::md5::Update>(),
+        #[cfg(feature = "md6")]
+        HashAlgorithm::Md6 => boxed::<crate::md6::Update>(),
+        #[cfg(feature = "one-at-a-time")]
+        HashAlgorithm::OneAtATime => boxed::<crate::one_at_a_time::Update>(),
+        #[cfg(feature = "classic")]
+        HashAlgorithm::Sdbm => boxed::<crate::classic::sdbm::Update>(),
+        #[cfg(feature = "sha0")]
+        HashAlgorithm::Sha0 => boxed::<crate::sha0::Update>(),
+        #[cfg(feature = "sha1")]
+        HashAlgorithm::Sha1 => boxed::<crate::sha1::Update>(),
+        #[cfg(feature = "sha2-224")]
+        HashAlgorithm::Sha2_224 => boxed::<crate::sha2_224::Update>(),
+        #[cfg(feature = "sha2-256")]
+        HashAlgorithm::Sha2_256 => boxed::<crate::sha2_256::Update>(),
+        #[cfg(feature = "sha2-384")]
+        HashAlgorithm::Sha2_384 => boxed::<crate::sha2_384::Update>(),
+        #[cfg(feature = "sha2-512")]
+        HashAlgorithm::Sha2_512 => boxed::<crate::sha2_512::Update>(),
+        #[cfg(feature = "skein-256")]
+        HashAlgorithm::Skein256 => boxed::<crate::skein_256::Update>(),
+        #[cfg(feature = "skein-512")]
+        HashAlgorithm::Skein512 => boxed::<crate::skein_512::Update>(),
+        #[cfg(feature = "skein-1024")]
+        HashAlgorithm::Skein1024 => boxed::<crate::skein_1024::Update>(),
+        #[cfg(feature = "sysv-sum")]
+        HashAlgorithm::SysvSum => boxed::<crate::sysv_sum::Update>(),
+        #[cfg(feature = "tiger")]
+        HashAlgorithm::Tiger => boxed::<crate::tiger::Update>(),
+        #[cfg(feature = "tiger2")]
+        HashAlgorithm::Tiger2 => boxed::<crate::tiger2::Update>(),
+        #[cfg(feature = "tth")]
+        HashAlgorithm::Tth => boxed::<crate::tth::Update>(),
+        #[cfg(feature = "xxh3-64")]
+        HashAlgorithm::Xxh3_64 => boxed::<crate::xxh3_64::Update>(),
+        #[cfg(feature = "xxh3-128")]
+        HashAlgorithm::Xxh3_128 => boxed::<crate::xxh3_128::Update>(),
+    }
+}
+
+/// Computes the digest of the given input with the given algorithm.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{hash_with, HashAlgorithm};
+///
+/// let digest = hash_with(HashAlgorithm::Md4, "abc");
+/// assert_eq!(
+///     digest,
+///     [
+///         0xA4, 0x48, 0x01, 0x7A, 0xAF, 0x21, 0xD8, 0x52, 0x5F, 0xC1, 0x0A, 0xE8, 0x7A, 0xA6,
+///         0x72, 0x9D
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn hash_with(algorithm: HashAlgorithm, data: impl AsRef<[u8]>) -> Vec<u8> {
+    let mut hash = new_with(algorithm);
+    hash.update(data.as_ref());
+    hash.finalize_boxed().into_vec()
+}
+
+/// A hash of several hash functions at once, chosen at runtime.
+///
+/// Every input is passed to all hash functions, so e.g. release tooling that publishes the MD5, SHA-1 and SHA-256 digests of a file reads it only once. Duplicate hash functions are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io;
+///
+/// use chksum_hash::{md5, sha1, sha2_256, HashAlgorithm, MultiHasher};
+///
+/// let mut hash = MultiHasher::new([
+///     HashAlgorithm::Md5,
+///     HashAlgorithm::Sha1,
+///     HashAlgorithm::Sha2_256,
+/// ]);
+/// io::copy(&mut &b"example data"[..], &mut hash).unwrap();
+///
+/// let digests = hash.digests();
+/// assert_eq!(
+///     digests[0],
+///     (
+///         HashAlgorithm::Md5,
+///         md5::hash("example data").as_ref().to_vec()
+///     )
+/// );
+/// assert_eq!(
+///     digests[1],
+///     (
+///         HashAlgorithm::Sha1,
+///         sha1::hash("example data").as_ref().to_vec()
+///     )
+/// );
+/// assert_eq!(
+///     digests[2],
+///     (
+///         HashAlgorithm::Sha2_256,
+///         sha2_256::hash("example data").as_ref().to_vec()
+///     )
+/// );
+/// ```
+#[derive(Default)]
+pub struct MultiHasher {
+    hashes: Vec<(HashAlgorithm, DynHasher)>,
+}
+
+impl MultiHasher {
+    /// Creates a new hash of the given hash functions.
+    #[must_use]
+    pub fn new(algorithms: impl IntoIterator<Item = HashAlgorithm>) -> Self {
+        let mut hash = Self::default();
+        for algorithm in algorithms {
+            hash.push(algorithm);
+        }
+        hash
+    }
+
+    /// Adds a hash function, unless it is already present.
+    ///
+    /// The new hash function receives only the data of later updates.
+    pub fn push(&mut self, algorithm: HashAlgorithm) -> &mut Self {
+        if !self.hashes.iter().any(|(present, _)| *present == algorithm) {
+            self.hashes.push((algorithm, new_with(algorithm)));
+        }
+        self
+    }
+
+    /// Returns the hash functions in the order they were added.
+    pub fn algorithms(&self) -> impl Iterator<Item = HashAlgorithm> + '_ {
+        self.hashes.iter().map(|(algorithm, _)| *algorithm)
+    }
+
+    /// Updates the states of all hash functions with an input data.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+        for (_, hash) in &mut self.hashes {
+            hash.update(data);
+        }
+        self
+    }
+
+    /// Resets the states of all hash functions to their initial states.
+    pub fn reset(&mut self) -> &mut Self {
+        for (_, hash) in &mut self.hashes {
+            hash.reset();
+        }
+        self
+    }
+
+    /// Produces the digests of all hash functions in the order they were added.
+    #[must_use]
+    pub fn digests(&self) -> Vec<(HashAlgorithm, Vec<u8>)> {
+        self.hashes
+            .iter()
+            .map(|(algorithm, hash)| (*algorithm, hash.finalize_boxed().into_vec()))
+            .collect()
+    }
+}
+
+impl Debug for MultiHasher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiHasher")
+            .field("algorithms", &self.algorithms().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Extend<HashAlgorithm> for MultiHasher {
+    fn extend<T>(&mut self, algorithms: T)
+    where
+        T: IntoIterator<Item = HashAlgorithm>,
+    {
+        for algorithm in algorithms {
+            self.push(algorithm);
+        }
+    }
+}
+
+impl FromIterator<HashAlgorithm> for MultiHasher {
+    fn from_iter<T>(algorithms: T) -> Self
+    where
+        T: IntoIterator<Item = HashAlgorithm>,
+    {
+        Self::new(algorithms)
+    }
+}
+
+impl io::Write for MultiHasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_with, HashAlgorithm};
+
+    #[test]
+    fn metadata() {
+        for &algorithm in HashAlgorithm::ALL {
+            assert_eq!(
+                hash_with(algorithm, "abc").len(),
+                algorithm.digest_length_bytes(),
+                "{algorithm}"
+            );
+            assert_eq!(algorithm.name().parse(), Ok(algorithm));
+        }
+    }
+
+    #[cfg(all(feature = "haval", feature = "md6"))]
+    #[test]
+    fn default_parameters() {
+        assert_eq!(
+            hash_with(HashAlgorithm::Haval, "abc"),
+            crate::haval::hash("abc").as_ref()
+        );
+        assert_eq!(hash_with(HashAlgorithm::Md6, "abc"), crate::md6::hash("abc").as_ref());
+        assert_eq!("haval".parse(), Ok(HashAlgorithm::Haval));
+        assert_eq!("MD6-256".parse(), Ok(HashAlgorithm::Md6));
+    }
+}
@@ -0,0 +1,430 @@
+//! This module is optional and can be enabled using the `ed2k` Cargo feature.
+//!
+//! An implementation of the ed2k hash of the eDonkey network.
+//!
+//! The input is split into chunks of [`CHUNK_LENGTH_BYTES`] (9500 KiB) and each chunk is hashed with [`md4`](crate::md4). Inputs of a single partial chunk are identified by the MD4 digest of the chunk, longer inputs by the MD4 digest of the concatenated chunk digests. The hash is used by `ed2k://` links and it is still checked by file-sharing and deduplication tools.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::ed2k;
+//!
+//! let digest = ed2k::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "8c96deac45db5adc46f1e8d27d76f317"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::ed2k;
+//!
+//! let digest = ed2k::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "8c96deac45db5adc46f1e8d27d76f317"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! The data is passed to the MD4 hash of the current chunk, so only the buffer of MD4 is used and the memory usage does not depend on the input length.
+//!
+//! # Variants
+//!
+//! Inputs whose length is a multiple of the chunk length are hashed differently by different clients, see [`Variant`]. Use [`Update::with_variant`] to choose the variant.
+//!
+//! ```rust
+//! use chksum_hash::ed2k::{self, Variant};
+//!
+//! let data = vec![0x00; ed2k::CHUNK_LENGTH_BYTES];
+//!
+//! let digest = ed2k::Update::with_variant(Variant::Red)
+//!     .update(&data)
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "fc21d9af828f92a8df64beac3357425d"
+//! );
+//!
+//! let digest = ed2k::Update::with_variant(Variant::Blue)
+//!     .update(&data)
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "d7def262a127cd79096a108e7a9fc138"
+//! );
+//! ```
+//!
+//! # Disclaimer
+//!
+//! The ed2k hash inherits the weaknesses of MD4, which is broken. It must not be used for any security purpose.
+
+pub mod digest;
+pub mod variant;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::variant::Variant;
+use crate::md4;
+use crate::verify::Expected;
+
+/// Chunk length in bytes.
+pub const CHUNK_LENGTH_BYTES: usize = 9_728_000;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ed2k;
+///
+/// let digest = ed2k::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "31d6cfe0d16ae931b73c59d7e0c089c0"
+/// );
+///
+/// let digest = ed2k::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "539080ba278cf4cf4db2e4a32642ff30"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ed2k;
+///
+/// let digest = ed2k::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "31d6cfe0d16ae931b73c59d7e0c089c0"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ed2k;
+///
+/// let digest = ed2k::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "a448017aaf21d8525fc10ae87aa6729d"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ed2k;
+///
+/// let digest = ed2k::hash("data");
+/// assert!(ed2k::verify("data", digest));
+/// assert!(ed2k::verify("data", digest.to_hex_uppercase()));
+/// assert!(!ed2k::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ed2k;
+///
+/// // Create a new hash instance
+/// let mut hash = ed2k::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "539080ba278cf4cf4db2e4a32642ff30"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "31d6cfe0d16ae931b73c59d7e0c089c0"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    variant: Variant,
+    chunk: md4::Update,
+    chunk_length: usize,
+    chunks: md4::Update,
+    count: usize,
+    last: Option<md4::Digest>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_variant(Variant::default())
+    }
+
+    /// Creates a new hash for the given variant.
+    #[must_use]
+    pub fn with_variant(variant: Variant) -> Self {
+        Self {
+            variant,
+            chunk: md4::Update::new(),
+            chunk_length: 0,
+            chunks: md4::Update::new(),
+            count: 0,
+            last: None,
+        }
+    }
+
+    /// Returns the variant of the hash.
+    #[must_use]
+    pub const fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// The data is passed to the MD4 hash of the current chunk, see [`md4::Update::update`] for performance notes.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+        while !data.is_empty() {
+            let missing = CHUNK_LENGTH_BYTES - self.chunk_length;
+            let (fillment, remainder) = data.split_at(missing.min(data.len()));
+            self.chunk.update(fillment);
+            self.chunk_length += fillment.len();
+            data = remainder;
+
+            // Full chunks are processed immediately, so the current chunk is empty only at chunk boundaries.
+            if self.chunk_length == CHUNK_LENGTH_BYTES {
+                let digest = self.chunk.digest();
+                self.chunks.update(digest);
+                self.count += 1;
+                self.last = Some(digest);
+                self.chunk.reset();
+                self.chunk_length = 0;
+            }
+        }
+        self
+    }
+
+    /// Combines the chunk digests and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let digest = match (self.last, self.chunk_length, self.variant) {
+            // No full chunk, the input is identified by the digest of its only chunk.
+            (None, _, _) => self.chunk.digest(),
+            // The blue variant skips the empty chunk, a single full chunk is identified by its own digest.
+            (Some(last), 0, Variant::Blue) if self.count == 1 => last,
+            (Some(_), 0, Variant::Blue) => self.chunks.digest(),
+            // The current chunk is appended even if it is empty.
+            (Some(_), _, _) => {
+                let mut chunks = self.chunks.clone();
+                chunks.update(self.chunk.digest());
+                chunks.digest()
+            },
+        };
+        Finalize {
+            digest,
+            variant: self.variant,
+        }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The variant is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        *self = Self::with_variant(self.variant);
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let variant = u.arbitrary()?;
+        let digests = u.arbitrary::<Vec<md4::Digest>>()?;
+        let mut chunks = md4::Update::new();
+        for digest in &digests {
+            chunks.update(digest);
+        }
+        // Full chunks are processed immediately, so the current chunk is never full.
+        let chunk_length = u.int_in_range(0..=CHUNK_LENGTH_BYTES - 1)?;
+        let chunk = if chunk_length == 0 {
+            md4::Update::new()
+        } else {
+            md4::Update::arbitrary_with_length(u, chunk_length)?
+        };
+        Ok(Self {
+            variant,
+            chunk,
+            chunk_length,
+            chunks,
+            count: digests.len(),
+            last: digests.last().copied(),
+        })
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ed2k;
+///
+/// // Create a new hash instance
+/// let mut hash = ed2k::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "a448017aaf21d8525fc10ae87aa6729d"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    digest: md4::Digest,
+    variant: Variant,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+        digest.copy_from_slice(self.digest.as_ref());
+        Digest::new(digest)
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        out.copy_from_slice(self.digest.as_ref());
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The variant is kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_variant(self.variant)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Data shorter than a chunk is hashed with MD4 alone, so the test suite of RFC 1320 applies.
+    #[test]
+    fn single_chunk() {
+        assert_eq!(hash("").to_hex_lowercase(), "31d6cfe0d16ae931b73c59d7e0c089c0");
+        assert_eq!(hash("a").to_hex_lowercase(), "bde52cb31de33e46245e05fbdbd6fb24");
+        assert_eq!(hash("abc").to_hex_lowercase(), "a448017aaf21d8525fc10ae87aa6729d");
+    }
+}
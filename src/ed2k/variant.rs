@@ -0,0 +1,14 @@
+//! Module containing the supported ed2k variants.
+
+/// An ed2k variant.
+///
+/// The variants differ only for inputs whose length is a non-zero multiple of [`CHUNK_LENGTH_BYTES`](super::CHUNK_LENGTH_BYTES). The original eDonkey2000 client appended the digest of an empty chunk to the chunk digests of such inputs, which is why it is the default; some later clients do not.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Variant {
+    /// The "red" hash, with the digest of an empty chunk appended.
+    #[default]
+    Red,
+    /// The "blue" hash, without the digest of an empty chunk.
+    Blue,
+}
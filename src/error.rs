@@ -0,0 +1,248 @@
+//! Module containing the errors shared by the modules of this crate.
+//!
+//! Errors specific to a representation or a file format are defined next to it, e.g. [`FromHexError`](crate::hex::FromHexError) in the [`hex`](crate::hex) module.
+//!
+//! The [`Error`] enum unifies these errors, so a function combining fallible functions of different modules can return a single error type with the `?` operator.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::{md4, Error, HashAlgorithm};
+//!
+//! fn resume(name: &str, state: &[u8]) -> Result<md4::Update, Error> {
+//!     let algorithm = name.parse::<HashAlgorithm>()?;
+//!     assert_eq!(algorithm, HashAlgorithm::Md4);
+//!     let hash = md4::Update::import_state(state)?;
+//!     Ok(hash)
+//! }
+//!
+//! let state = md4::default().update("data").export_state();
+//! assert!(resume("md4", &state).is_ok());
+//! assert_eq!(
+//!     resume("md-four", &state).unwrap_err().to_string(),
+//!     "unknown hash function"
+//! );
+//! ```
+
+use std::error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::dynamic::ParseHashAlgorithmError;
+use crate::hex::FromHexError;
+
+/// An error of this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// An invalid hexadecimal representation of a digest.
+    Hex(FromHexError),
+    /// An unexpected length, e.g. of a byte slice converted into a digest or of a derived key.
+    Length(LengthError),
+    /// A message longer than the maximum message length of a hash function.
+    MessageLength(MessageLengthError),
+    /// An invalid parameter of a function.
+    Parameter(ParameterError),
+    /// An unknown or disabled hash function name.
+    ParseHashAlgorithm(ParseHashAlgorithmError),
+    /// An invalid exported hash state.
+    State(StateError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hex(error) => Display::fmt(error, f),
+            Self::Length(error) => Display::fmt(error, f),
+            Self::MessageLength(error) => Display::fmt(error, f),
+            Self::Parameter(error) => Display::fmt(error, f),
+            Self::ParseHashAlgorithm(error) => Display::fmt(error, f),
+            Self::State(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Hex(error) => Some(error),
+            Self::Length(error) => Some(error),
+            Self::MessageLength(error) => Some(error),
+            Self::Parameter(error) => Some(error),
+            Self::ParseHashAlgorithm(error) => Some(error),
+            Self::State(error) => Some(error),
+        }
+    }
+}
+
+impl From<FromHexError> for Error {
+    fn from(error: FromHexError) -> Self {
+        Self::Hex(error)
+    }
+}
+
+impl From<LengthError> for Error {
+    fn from(error: LengthError) -> Self {
+        Self::Length(error)
+    }
+}
+
+impl From<MessageLengthError> for Error {
+    fn from(error: MessageLengthError) -> Self {
+        Self::MessageLength(error)
+    }
+}
+
+impl From<ParameterError> for Error {
+    fn from(error: ParameterError) -> Self {
+        Self::Parameter(error)
+    }
+}
+
+impl From<ParseHashAlgorithmError> for Error {
+    fn from(error: ParseHashAlgorithmError) -> Self {
+        Self::ParseHashAlgorithm(error)
+    }
+}
+
+impl From<StateError> for Error {
+    fn from(error: StateError) -> Self {
+        Self::State(error)
+    }
+}
+
+/// An error of an unexpected length, e.g. of the output keying material of HKDF.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::error::LengthError;
+/// use chksum_hash::{hkdf, sha1};
+///
+/// let prk = hkdf::extract::<sha1::Update>("salt", "secret");
+/// assert_eq!(
+///     hkdf::try_expand::<sha1::Update>(&prk, "info", 255 * 20 + 1),
+///     Err(LengthError { length: 5101 })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthError {
+    /// The unexpected length.
+    pub length: usize,
+}
+
+impl Display for LengthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid length {}", self.length)
+    }
+}
+
+impl error::Error for LengthError {}
+
+/// An error of updating a hash with data exceeding the maximum message length of the hash function.
+///
+/// The `try_update` functions return it where the `update` functions silently wrap the message length.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::error::MessageLengthError;
+/// use chksum_hash::sha0;
+///
+/// // Resume from a state that has almost reached the maximum message length
+/// let mut state = sha0::default().export_state();
+/// let offset = state.len() - 9;
+/// let processed = sha0::MAX_MESSAGE_LENGTH_BYTES - sha0::MAX_MESSAGE_LENGTH_BYTES % 64;
+/// state[offset..offset + 8].copy_from_slice(&processed.to_be_bytes());
+/// let mut hash = sha0::Update::import_state(&state).unwrap();
+///
+/// assert!(hash.try_update([0u8; 63]).is_ok());
+/// assert_eq!(hash.try_update([0u8; 1]).unwrap_err(), MessageLengthError);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessageLengthError;
+
+impl Display for MessageLengthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("message length exceeds the maximum of the hash function")
+    }
+}
+
+impl error::Error for MessageLengthError {}
+
+/// An error of an invalid parameter, e.g. a zero number of iterations.
+///
+/// The fallible `try_` functions return it where the infallible ones panic, so parameters from untrusted input can be validated.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::error::{Error, ParameterError};
+/// use chksum_hash::{pbkdf2, sha1};
+///
+/// assert_eq!(
+///     pbkdf2::try_derive::<sha1::Update>("password", "salt", 0, 20),
+///     Err(Error::Parameter(ParameterError {
+///         parameter: "iterations"
+///     }))
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParameterError {
+    /// The name of the invalid parameter.
+    pub parameter: &'static str,
+}
+
+impl Display for ParameterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}", self.parameter)
+    }
+}
+
+impl error::Error for ParameterError {}
+
+/// An error of importing an exported hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::error::StateError;
+/// use chksum_hash::{md4, sha0};
+///
+/// let state = md4::default().update("data").export_state();
+/// assert_eq!(
+///     sha0::Update::import_state(&state),
+///     Err(StateError::Algorithm)
+/// );
+/// assert_eq!(
+///     md4::Update::import_state(&state[..8]),
+///     Err(StateError::Length)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StateError {
+    /// The bytes do not start with the magic bytes of an exported state.
+    Magic,
+    /// The format version is not supported by this release of the crate.
+    Version(u8),
+    /// The state was exported by a different hash function.
+    Algorithm,
+    /// The bytes are shorter or longer than the exported state.
+    Length,
+    /// The state contains values the hash function cannot reach, e.g. a full block of unprocessed data.
+    Invalid,
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Magic => write!(f, "not an exported hash state"),
+            Self::Version(version) => write!(f, "unsupported state format version {version}"),
+            Self::Algorithm => write!(f, "state of a different hash function"),
+            Self::Length => write!(f, "invalid state length"),
+            Self::Invalid => write!(f, "invalid state"),
+        }
+    }
+}
+
+impl error::Error for StateError {}
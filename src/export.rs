@@ -0,0 +1,124 @@
+//! Encoding and decoding of exported hash states, see the State Export section of the crate documentation for the format.
+
+use crate::error::StateError;
+
+/// Magic bytes at the beginning of every exported state.
+const MAGIC: [u8; 4] = *b"CHKS";
+
+/// Version of the format written by this release of the crate.
+const VERSION: u8 = 1;
+
+/// An encoder of a hash state.
+pub(crate) struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    /// Creates an encoder and writes the header with the identifier of the hash function.
+    pub(crate) fn new(name: &str) -> Self {
+        let mut bytes = Vec::with_capacity(128);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(VERSION);
+        bytes.push(u8::try_from(name.len()).expect("identifier length must fit into a byte"));
+        bytes.extend_from_slice(name.as_bytes());
+        Self { bytes }
+    }
+
+    /// Writes the words of the chaining state.
+    pub(crate) fn u32s(mut self, words: &[u32]) -> Self {
+        for word in words {
+            self.bytes.extend_from_slice(&word.to_be_bytes());
+        }
+        self
+    }
+
+    /// Writes the words of the chaining state.
+    pub(crate) fn u64s(mut self, words: &[u64]) -> Self {
+        for word in words {
+            self.bytes.extend_from_slice(&word.to_be_bytes());
+        }
+        self
+    }
+
+    /// Writes the number of processed bytes and the unprocessed data, and returns the encoded state.
+    pub(crate) fn finish(mut self, processed: u64, unprocessed: &[u8]) -> Vec<u8> {
+        self.bytes.extend_from_slice(&processed.to_be_bytes());
+        self.bytes
+            .push(u8::try_from(unprocessed.len()).expect("unprocessed data length must fit into a byte"));
+        self.bytes.extend_from_slice(unprocessed);
+        self.bytes
+    }
+}
+
+/// A decoder of a hash state.
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Creates a decoder and validates the header against the identifier of the hash function.
+    pub(crate) fn new(bytes: &'a [u8], name: &str) -> Result<Self, StateError> {
+        let mut reader = Self { bytes };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(StateError::Magic);
+        }
+        match reader.take(1)?[0] {
+            VERSION => {},
+            version => return Err(StateError::Version(version)),
+        }
+        let length = reader.take(1)?[0];
+        if reader.take(length.into())? != name.as_bytes() {
+            return Err(StateError::Algorithm);
+        }
+        Ok(reader)
+    }
+
+    /// Reads the words of the chaining state.
+    pub(crate) fn u32s<const N: usize>(&mut self) -> Result<[u32; N], StateError> {
+        let mut words = [0u32; N];
+        for word in &mut words {
+            let bytes = self.take(4)?;
+            *word = u32::from_be_bytes(bytes.try_into().expect("slice length must be exact size as word"));
+        }
+        Ok(words)
+    }
+
+    /// Reads the words of the chaining state.
+    pub(crate) fn u64s<const N: usize>(&mut self) -> Result<[u64; N], StateError> {
+        let mut words = [0u64; N];
+        for word in &mut words {
+            *word = self.u64()?;
+        }
+        Ok(words)
+    }
+
+    /// Reads the number of processed bytes and the unprocessed data, and validates them against the block length.
+    pub(crate) fn finish(mut self, block_length: usize) -> Result<(u64, &'a [u8]), StateError> {
+        let processed = self.u64()?;
+        let length = self.take(1)?[0];
+        let unprocessed = self.take(length.into())?;
+        if !self.bytes.is_empty() {
+            return Err(StateError::Length);
+        }
+        if processed % block_length as u64 != 0 || unprocessed.len() >= block_length {
+            return Err(StateError::Invalid);
+        }
+        Ok((processed, unprocessed))
+    }
+
+    fn u64(&mut self) -> Result<u64, StateError> {
+        let bytes = self.take(8)?;
+        Ok(u64::from_be_bytes(
+            bytes.try_into().expect("slice length must be exact size as word"),
+        ))
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], StateError> {
+        if self.bytes.len() < length {
+            return Err(StateError::Length);
+        }
+        let (bytes, remainder) = self.bytes.split_at(length);
+        self.bytes = remainder;
+        Ok(bytes)
+    }
+}
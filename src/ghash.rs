@@ -0,0 +1,339 @@
+//! This module is optional and can be enabled using the `ghash` Cargo feature.
+//!
+//! An implementation of the GHASH universal hash function.
+//!
+//! GHASH is the universal hash function of the Galois/Counter Mode (GCM) defined in [NIST SP 800-38D](https://csrc.nist.gov/pubs/sp/800/38/d/final). It evaluates a polynomial over GF(2^128) at the point given by a 128-bit key, so unlike most hash functions in this crate it always requires a key. It is computed with the field arithmetic of [`polyval`](crate::polyval), which does not branch on nor index memory with secret data.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::ghash;
+//!
+//! let key = *b"0123456789abcdef";
+//! let digest = ghash::hash(key, "example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "a4009c34f80df71c8a38216457606f98"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`new`] function.
+//!
+//! ```rust
+//! use chksum_hash::ghash;
+//!
+//! let key = *b"0123456789abcdef";
+//! let digest = ghash::new(key)
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "a4009c34f80df71c8a38216457606f98"
+//! );
+//! ```
+//!
+//! # Padding
+//!
+//! The input is processed in blocks of 16 bytes and the last incomplete block is padded with zeros. Constructions that hash several zero-padded fields, like the associated data and the ciphertext of GCM, can pad the buffered data with [`Update::pad`] between the fields.
+//!
+//! ```rust
+//! use chksum_hash::ghash;
+//!
+//! let key = *b"0123456789abcdef";
+//! let digest = ghash::new(key)
+//!     .update("example")
+//!     .pad()
+//!     .update("data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "064f6f09952d0d029420cca300e391b3"
+//! );
+//! ```
+//!
+//! # Disclaimer
+//!
+//! GHASH is not a cryptographic hash function. It is only secure as a part of a construction that keeps the key secret and masks its output, like GCM, and its output must not be used as a message authentication code on its own.
+
+pub mod block;
+pub mod digest;
+pub mod state;
+
+#[doc(inline)]
+pub use self::block::{Block, LENGTH_BYTES as BLOCK_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+use crate::buffer::Buffer;
+
+/// Key length in bytes.
+pub const KEY_LENGTH_BYTES: usize = 16;
+
+/// Creates a new hash with the given key.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ghash;
+///
+/// let key = *b"0123456789abcdef";
+///
+/// let digest = ghash::new(key).digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "00000000000000000000000000000000"
+/// );
+///
+/// let digest = ghash::new(key).update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "dfa153eda972599ab7b2b790fc497853"
+/// );
+/// ```
+#[must_use]
+pub fn new(key: [u8; KEY_LENGTH_BYTES]) -> Update {
+    Update::new(key)
+}
+
+/// Computes the hash of the given input with the given key.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ghash;
+///
+/// let digest = ghash::hash(*b"0123456789abcdef", "abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "65f367fc1fdd86571ace88a47e23e1ba"
+/// );
+/// ```
+pub fn hash(key: [u8; KEY_LENGTH_BYTES], data: impl AsRef<[u8]>) -> Digest {
+    new(key).update(data).digest()
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ghash;
+///
+/// // Create a new hash instance
+/// let mut hash = ghash::Update::new(*b"0123456789abcdef");
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "dfa153eda972599ab7b2b790fc497853"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "00000000000000000000000000000000"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    state: State,
+    unprocessed: Buffer<BLOCK_LENGTH_BYTES>,
+}
+
+impl Update {
+    /// Creates a new hash with the given key.
+    #[must_use]
+    pub fn new(key: [u8; KEY_LENGTH_BYTES]) -> Self {
+        let state = state::new(key);
+        let unprocessed = Buffer::new();
+        Self { state, unprocessed }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+
+        if !self.unprocessed.is_empty() {
+            let missing = BLOCK_LENGTH_BYTES - self.unprocessed.len();
+            if data.len() < missing {
+                // Not enough data even for one block.
+                self.unprocessed.extend_from_slice(data);
+                return self;
+            }
+
+            // Complete the buffered block with the beginning of incoming data.
+            let (fillment, remainder) = data.split_at(missing);
+            self.unprocessed.extend_from_slice(fillment);
+            let block = Block::try_from(&self.unprocessed[..])
+                .expect("unprocessed data length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.unprocessed.clear();
+            data = remainder;
+        }
+
+        // Internal buffer is empty, incoming data can be processed without buffering.
+        let mut chunks = data.chunks_exact(BLOCK_LENGTH_BYTES);
+        for chunk in chunks.by_ref() {
+            let block = Block::try_from(chunk)
+                .expect("chunk length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+        }
+        self.unprocessed.extend_from_slice(chunks.remainder());
+
+        self
+    }
+
+    /// Pads the buffered data with zeros to the full block and processes it.
+    ///
+    /// Does nothing when no data is buffered.
+    pub fn pad(&mut self) -> &mut Self {
+        if !self.unprocessed.is_empty() {
+            self.unprocessed.resize(BLOCK_LENGTH_BYTES, 0x00);
+            let block = Block::try_from(&self.unprocessed[..])
+                .expect("padded data length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.unprocessed.clear();
+        }
+        self
+    }
+
+    /// Processes the remaining data and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        assert!(
+            self.unprocessed.len() < BLOCK_LENGTH_BYTES,
+            "unprocessed data length should be less than block length"
+        );
+
+        let mut update = self.clone();
+        update.pad();
+        let state = update.state;
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The key is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self.unprocessed.clear();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let state = u.arbitrary()?;
+        // Full blocks are processed immediately, so less than a block is buffered.
+        let length = u.int_in_range(0..=BLOCK_LENGTH_BYTES - 1)?;
+        let unprocessed = Buffer::from_slice(u.bytes(length)?);
+        Ok(Self { state, unprocessed })
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ghash;
+///
+/// // Create a new hash instance
+/// let mut hash = ghash::Update::new(*b"0123456789abcdef");
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "65f367fc1fdd86571ace88a47e23e1ba"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+        self.digest_into(&mut digest);
+        Digest::new(digest)
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        let [high, low] = self.state.digest();
+        *out = (u128::from(high) << 64 | u128::from(low)).to_be_bytes();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The key is kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        let state = self.state.reset();
+        let unprocessed = Buffer::new();
+        Update { state, unprocessed }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test case 2 from the GCM specification, with the length block appended to the ciphertext.
+    #[test]
+    fn gcm_test_case_2() {
+        let key = 0x66E94BD4EF8A2C3B884CFA59CA342B2E_u128.to_be_bytes();
+        let mut data = 0x0388DACE60B6A392F328C2B971B2FE78_u128.to_be_bytes().to_vec();
+        data.extend_from_slice(&0x80_u128.to_be_bytes());
+        assert_eq!(hash(key, data).to_hex_lowercase(), "f38cbb1ad69223dcc3457ae5b6b0f885");
+    }
+}
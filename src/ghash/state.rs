@@ -0,0 +1,87 @@
+//! Module contains items related to the [`State`] structure which allows to the direct GHASH state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::ghash;
+//!
+//! // Create new state with the given key
+//! let mut state = ghash::state::new(*b"0123456789abcdef");
+//!
+//! // Create a block of data
+//! let block = std::array::from_fn(|index| index as u8);
+//! let data = ghash::Block::new(block).into();
+//!
+//! // Update state and own new value
+//! state = state.update(data);
+//!
+//! // Obtain the accumulator as big-endian words
+//! assert_eq!(state.digest(), [0xCD75248B2183A09A, 0x82B3ED5D9F225499]);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state.digest(), [0x0000000000000000, 0x0000000000000000]);
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_QWORDS as BLOCK_LENGTH_QWORDS;
+use super::KEY_LENGTH_BYTES;
+use crate::polyval;
+
+/// Create a new state with the given key.
+#[must_use]
+pub const fn new(key: [u8; KEY_LENGTH_BYTES]) -> State {
+    State::new(key)
+}
+
+/// A low-level hash state.
+///
+/// GHASH is computed with the POLYVAL field arithmetic on byte-reversed blocks, as described in the appendix A of RFC 8452.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct State {
+    inner: polyval::State,
+}
+
+impl State {
+    /// Creates a new state with the given key.
+    #[must_use]
+    pub const fn new(key: [u8; KEY_LENGTH_BYTES]) -> Self {
+        // The reversed key has to be multiplied by x to compensate the different bit order of the fields.
+        let key = u128::from_be_bytes(key);
+        let carry = key >> 127;
+        let key = (key << 1) ^ carry ^ (carry << 127) ^ (carry << 126) ^ (carry << 121);
+        let inner = polyval::State::new(key.to_le_bytes());
+        Self { inner }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// **Warning!** Input block must be in the big-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u64; BLOCK_LENGTH_QWORDS]) -> Self {
+        let inner = self.inner.update([block[1], block[0]]);
+        Self { inner }
+    }
+
+    /// Returns a new state with initial values.
+    ///
+    /// The key is kept.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        let inner = self.inner.reset();
+        Self { inner }
+    }
+
+    /// Returns a hash as big-endian words.
+    #[must_use]
+    pub const fn digest(&self) -> [u64; 2] {
+        let [low, high] = self.inner.digest();
+        [high, low]
+    }
+}
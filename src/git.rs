@@ -0,0 +1,353 @@
+//! This module is optional and can be enabled using the `git` Cargo feature.
+//!
+//! An implementation of the object identifiers of Git.
+//!
+//! Git identifies an object by the digest of its content prefixed with the header `"<type> <length>\0"`, where the type is one of [`ObjectType`] and the length is the decimal length of the content in bytes. Repositories use [`sha1`](crate::sha1) or, since the SHA-256 transition, [`sha2_256`](crate::sha2_256), see [`ObjectFormat`].
+//!
+//! # Batch Processing
+//!
+//! The identifier of known content can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::git::{self, ObjectFormat, ObjectType};
+//!
+//! let id = git::hash(ObjectType::Blob, "example data", ObjectFormat::Sha1);
+//! assert_eq!(
+//!     id.to_hex_lowercase(),
+//!     "3b8ae62d7ac7996e20826cbbe63fedfaa9918c26"
+//! );
+//!
+//! let id = git::hash(ObjectType::Blob, "example data", ObjectFormat::Sha256);
+//! assert_eq!(
+//!     id.to_hex_lowercase(),
+//!     "9e3645ee8c84ef5c8aeb708519e408036c88b9aa3f365fd8d454f7ef8d89e2e6"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The header contains the length of the content, so it has to be known in advance, e.g. from the metadata of a file. The content can then be passed chunk-by-chunk to a consumer created with [`Update::new`].
+//!
+//! ```rust
+//! use chksum_hash::git::{self, ObjectFormat, ObjectType};
+//!
+//! let id = git::Update::new(ObjectType::Blob, 12, ObjectFormat::Sha1)
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     id.to_hex_lowercase(),
+//!     "3b8ae62d7ac7996e20826cbbe63fedfaa9918c26"
+//! );
+//! ```
+
+use std::fmt::{self, Display, Formatter, LowerHex, UpperHex};
+
+use crate::error::LengthError;
+use crate::{sha1, sha2_256};
+
+/// A type of Git objects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectType {
+    /// File content.
+    Blob,
+    /// Directory listing.
+    Tree,
+    /// Commit.
+    Commit,
+    /// Annotated tag.
+    Tag,
+}
+
+impl ObjectType {
+    /// Returns the name of the type used in the object header, e.g. `"blob"`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Blob => "blob",
+            Self::Tree => "tree",
+            Self::Commit => "commit",
+            Self::Tag => "tag",
+        }
+    }
+}
+
+/// A hash function of Git repositories, set by the `extensions.objectFormat` option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ObjectFormat {
+    /// SHA-1, the default format.
+    #[default]
+    Sha1,
+    /// SHA-2 256.
+    Sha256,
+}
+
+impl ObjectFormat {
+    /// Returns the name of the format, e.g. `"sha1"`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Sha1 => "sha1",
+            Self::Sha256 => "sha256",
+        }
+    }
+
+    /// Returns the length of the object identifiers in bytes.
+    #[must_use]
+    pub const fn digest_length(&self) -> usize {
+        match self {
+            Self::Sha1 => sha1::DIGEST_LENGTH_BYTES,
+            Self::Sha256 => sha2_256::DIGEST_LENGTH_BYTES,
+        }
+    }
+}
+
+/// Computes the identifier of the object with the given type and content.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::git::{self, ObjectFormat, ObjectType};
+///
+/// // The empty tree
+/// let id = git::hash(ObjectType::Tree, "", ObjectFormat::Sha1);
+/// assert_eq!(
+///     id.to_hex_lowercase(),
+///     "4b825dc642cb6eb9a060e54bf8d69288fbee4904"
+/// );
+///
+/// let id = git::hash(ObjectType::Tree, "", ObjectFormat::Sha256);
+/// assert_eq!(
+///     id.to_hex_lowercase(),
+///     "6ef19b41225c5369f1c104d45d8d85efa9b057b53b14b4b9b939dd74decc5321"
+/// );
+/// ```
+#[must_use]
+pub fn hash(object_type: ObjectType, data: impl AsRef<[u8]>, format: ObjectFormat) -> ObjectId {
+    let data = data.as_ref();
+    Update::new(object_type, data.len() as u64, format)
+        .update(data)
+        .digest()
+}
+
+/// An object identifier, the digest of the object in the format of the repository.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectId {
+    /// SHA-1 identifier.
+    Sha1(sha1::Digest),
+    /// SHA-2 256 identifier.
+    Sha256(sha2_256::Digest),
+}
+
+impl ObjectId {
+    /// Returns the format of the identifier.
+    #[must_use]
+    pub const fn format(&self) -> ObjectFormat {
+        match self {
+            Self::Sha1(_) => ObjectFormat::Sha1,
+            Self::Sha256(_) => ObjectFormat::Sha256,
+        }
+    }
+
+    /// Returns a string in the lowercase hexadecimal representation, the form used by Git.
+    #[must_use]
+    pub fn to_hex_lowercase(&self) -> String {
+        format!("{self:x}")
+    }
+
+    /// Returns a string in the uppercase hexadecimal representation.
+    #[must_use]
+    pub fn to_hex_uppercase(&self) -> String {
+        format!("{self:X}")
+    }
+}
+
+impl AsRef<[u8]> for ObjectId {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Sha1(digest) => digest.as_ref(),
+            Self::Sha256(digest) => digest.as_ref(),
+        }
+    }
+}
+
+impl Display for ObjectId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerHex::fmt(self, f)
+    }
+}
+
+impl LowerHex for ObjectId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sha1(digest) => LowerHex::fmt(digest, f),
+            Self::Sha256(digest) => LowerHex::fmt(digest, f),
+        }
+    }
+}
+
+impl UpperHex for ObjectId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sha1(digest) => UpperHex::fmt(digest, f),
+            Self::Sha256(digest) => UpperHex::fmt(digest, f),
+        }
+    }
+}
+
+/// A hash state of a single object whose content is passed in chunks.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::git::{self, ObjectFormat, ObjectType};
+///
+/// let lines = [
+///     "tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\n",
+///     "author A <a@b> 0 +0000\n",
+///     "committer A <a@b> 0 +0000\n",
+///     "\n",
+///     "m\n",
+/// ];
+/// let length = lines.iter().map(|line| line.len() as u64).sum();
+///
+/// let mut hash = git::Update::new(ObjectType::Commit, length, ObjectFormat::Sha1);
+/// for line in lines {
+///     hash.update(line);
+/// }
+/// assert_eq!(
+///     hash.digest().to_hex_lowercase(),
+///     "09c9dfe00ddfa1db2b5ba1953e2536a5e84b21da"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Update {
+    object_type: ObjectType,
+    length: u64,
+    processed: u64,
+    state: State,
+}
+
+/// A hash state of the object format.
+#[derive(Clone, Debug)]
+enum State {
+    Sha1(sha1::Update),
+    Sha256(sha2_256::Update),
+}
+
+impl Update {
+    /// Creates a new hash of the object with the given type and content length in bytes.
+    #[must_use]
+    pub fn new(object_type: ObjectType, length: u64, format: ObjectFormat) -> Self {
+        let header = format!("{} {length}\0", object_type.name());
+        let state = match format {
+            ObjectFormat::Sha1 => {
+                let mut hash = sha1::Update::new();
+                hash.update(&header);
+                State::Sha1(hash)
+            },
+            ObjectFormat::Sha256 => {
+                let mut hash = sha2_256::Update::new();
+                hash.update(&header);
+                State::Sha256(hash)
+            },
+        };
+        Self {
+            object_type,
+            length,
+            processed: 0,
+            state,
+        }
+    }
+
+    /// Returns the type of the object.
+    #[must_use]
+    pub const fn object_type(&self) -> ObjectType {
+        self.object_type
+    }
+
+    /// Returns the format of the object identifier.
+    #[must_use]
+    pub const fn format(&self) -> ObjectFormat {
+        match self.state {
+            State::Sha1(_) => ObjectFormat::Sha1,
+            State::Sha256(_) => ObjectFormat::Sha256,
+        }
+    }
+
+    /// Returns the content length declared in the header.
+    #[must_use]
+    pub const fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Updates the internal state with a chunk of the content.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+        self.processed += data.len() as u64;
+        match &mut self.state {
+            State::Sha1(hash) => {
+                hash.update(data);
+            },
+            State::Sha256(hash) => {
+                hash.update(data);
+            },
+        }
+        self
+    }
+
+    /// Produces the object identifier.
+    ///
+    /// Use [`Update::try_digest`] if the content may be shorter or longer than declared, e.g. when it is read from a file that can change meanwhile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the processed content differs from the length declared in the header.
+    #[must_use]
+    pub fn digest(&self) -> ObjectId {
+        assert_eq!(
+            self.processed, self.length,
+            "content length must be equal to the length declared in the header"
+        );
+        match &self.state {
+            State::Sha1(hash) => ObjectId::Sha1(hash.digest()),
+            State::Sha256(hash) => ObjectId::Sha256(hash.digest()),
+        }
+    }
+
+    /// Produces the object identifier if the length of the processed content equals the length declared in the header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the length of the processed content if it differs from the length declared in the header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::error::LengthError;
+    /// use chksum_hash::git::{self, ObjectFormat, ObjectType};
+    ///
+    /// let mut hash = git::Update::new(ObjectType::Blob, 12, ObjectFormat::Sha1);
+    /// hash.update("example");
+    /// assert_eq!(hash.try_digest(), Err(LengthError { length: 7 }));
+    ///
+    /// hash.update(" data");
+    /// assert_eq!(
+    ///     hash.try_digest().unwrap().to_hex_lowercase(),
+    ///     "3b8ae62d7ac7996e20826cbbe63fedfaa9918c26"
+    /// );
+    /// ```
+    pub fn try_digest(&self) -> Result<ObjectId, LengthError> {
+        if self.processed == self.length {
+            Ok(self.digest())
+        } else {
+            Err(LengthError {
+                length: usize::try_from(self.processed).unwrap_or(usize::MAX),
+            })
+        }
+    }
+}
@@ -0,0 +1,427 @@
+//! This module is optional and can be enabled using the `glacier` Cargo feature.
+//!
+//! An implementation of the SHA-256 tree hash of Amazon S3 Glacier.
+//!
+//! The input is split into chunks of [`CHUNK_LENGTH_BYTES`] (1 MiB) and each chunk is hashed with [`sha2_256`](crate::sha2_256). The chunk digests are combined pairwise level by level and a digest without a pair is promoted to the upper level, which is the shape of the [`merkle`](crate::merkle) tree without prefixes. The tree hash is required by the Glacier API for archive uploads and it is returned in the archive inventories.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::glacier;
+//!
+//! let digest = glacier::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "44752f37272e944fd2c913a35342eaccdd1aaf189bae50676b301ab213fc5061"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::glacier;
+//!
+//! let digest = glacier::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "44752f37272e944fd2c913a35342eaccdd1aaf189bae50676b301ab213fc5061"
+//! );
+//! ```
+//!
+//! # Multipart Uploads
+//!
+//! The parts of a multipart upload are described by their own tree hashes. Use [`Update::with_part_size`] to collect them along with the tree hash of the whole archive.
+//!
+//! ```rust
+//! use chksum_hash::glacier;
+//!
+//! let data = vec![0x00; 3 * glacier::CHUNK_LENGTH_BYTES];
+//!
+//! let finalize = glacier::Update::with_part_size(2 * glacier::CHUNK_LENGTH_BYTES)
+//!     .update(&data)
+//!     .finalize();
+//! let parts = finalize.parts();
+//! assert_eq!(parts.len(), 2);
+//! assert_eq!(
+//!     parts[0],
+//!     glacier::hash(&data[..2 * glacier::CHUNK_LENGTH_BYTES])
+//! );
+//! assert_eq!(
+//!     parts[1],
+//!     glacier::hash(&data[2 * glacier::CHUNK_LENGTH_BYTES..])
+//! );
+//! assert_eq!(finalize.digest(), glacier::hash(&data));
+//! ```
+
+use chksum_hash_core as core;
+
+use crate::error::ParameterError;
+#[doc(inline)]
+pub use crate::sha2_256::{Digest, DIGEST_LENGTH_BYTES};
+use crate::verify::Expected;
+use crate::{merkle, sha2_256};
+
+/// Chunk length in bytes.
+pub const CHUNK_LENGTH_BYTES: usize = 1024 * 1024;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::glacier;
+///
+/// let digest = glacier::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+/// );
+///
+/// let digest = glacier::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "3a6eb0790f39ac87c94f3856b2dd2c5d110e6811602261a9a923d3bb23adc8b7"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::glacier;
+///
+/// let digest = glacier::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::glacier;
+///
+/// let digest = glacier::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::glacier;
+///
+/// let digest = glacier::hash("data");
+/// assert!(glacier::verify("data", digest));
+/// assert!(glacier::verify("data", digest.to_hex_uppercase()));
+/// assert!(!glacier::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::glacier;
+///
+/// // Create a new hash instance
+/// let mut hash = glacier::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "3a6eb0790f39ac87c94f3856b2dd2c5d110e6811602261a9a923d3bb23adc8b7"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Update {
+    chunk: sha2_256::Update,
+    chunk_length: usize,
+    tree: merkle::Tree<sha2_256::Update>,
+    part_size: Option<usize>,
+    part: merkle::Tree<sha2_256::Update>,
+    parts: Vec<Digest>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            chunk: sha2_256::Update::new(),
+            chunk_length: 0,
+            tree: merkle::Tree::with_prefixes([], []),
+            part_size: None,
+            part: merkle::Tree::with_prefixes([], []),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Creates a new hash that collects the tree hashes of the parts of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the part size is not a power of two multiple of [`CHUNK_LENGTH_BYTES`], as required by the multipart uploads.
+    #[must_use]
+    pub fn with_part_size(part_size: usize) -> Self {
+        assert!(
+            part_size % CHUNK_LENGTH_BYTES == 0 && (part_size / CHUNK_LENGTH_BYTES).is_power_of_two(),
+            "part size must be a power of two multiple of 1 MiB"
+        );
+        Self {
+            part_size: Some(part_size),
+            ..Self::new()
+        }
+    }
+
+    /// Creates a new hash that collects the tree hashes of the parts of the given size, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the part size is not a power of two multiple of [`CHUNK_LENGTH_BYTES`].
+    pub fn try_with_part_size(part_size: usize) -> Result<Self, ParameterError> {
+        if part_size % CHUNK_LENGTH_BYTES == 0 && (part_size / CHUNK_LENGTH_BYTES).is_power_of_two() {
+            Ok(Self::with_part_size(part_size))
+        } else {
+            Err(ParameterError { parameter: "part size" })
+        }
+    }
+
+    /// Returns the part size, if the tree hashes of the parts are collected.
+    #[must_use]
+    pub const fn part_size(&self) -> Option<usize> {
+        self.part_size
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// The data is passed to the SHA-2 256 hash of the current chunk, see its documentation for performance notes.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+        while !data.is_empty() {
+            let missing = CHUNK_LENGTH_BYTES - self.chunk_length;
+            let (fillment, remainder) = data.split_at(missing.min(data.len()));
+            self.chunk.update(fillment);
+            self.chunk_length += fillment.len();
+            data = remainder;
+
+            // Full chunks are processed immediately, so the current chunk is empty only at chunk boundaries.
+            if self.chunk_length == CHUNK_LENGTH_BYTES {
+                let digest = self.chunk.digest();
+                Self::push(self.part_size, &mut self.tree, &mut self.part, &mut self.parts, digest);
+                self.chunk.reset();
+                self.chunk_length = 0;
+            }
+        }
+        self
+    }
+
+    /// Combines the chunk digests and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let mut tree = self.tree.clone();
+        let mut part = self.part.clone();
+        let mut parts = self.parts.clone();
+        // The current chunk is partial, or the input is empty and it is hashed as a single empty chunk.
+        if self.chunk_length > 0 || tree.is_empty() {
+            Self::push(self.part_size, &mut tree, &mut part, &mut parts, self.chunk.digest());
+        }
+        if self.part_size.is_some() && !part.is_empty() {
+            parts.push(part.root());
+        }
+        Finalize {
+            digest: tree.root(),
+            part_size: self.part_size,
+            parts,
+        }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The part size is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.chunk.reset();
+        self.chunk_length = 0;
+        self.tree.reset();
+        self.part.reset();
+        self.parts.clear();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+
+    /// Appends the chunk digest to the tree and to the tree of the current part.
+    fn push(
+        part_size: Option<usize>,
+        tree: &mut merkle::Tree<sha2_256::Update>,
+        part: &mut merkle::Tree<sha2_256::Update>,
+        parts: &mut Vec<Digest>,
+        digest: Digest,
+    ) {
+        tree.push_digest(digest);
+        if let Some(part_size) = part_size {
+            part.push_digest(digest);
+            if part.len() == (part_size / CHUNK_LENGTH_BYTES) as u64 {
+                parts.push(part.root());
+                part.reset();
+            }
+        }
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::glacier;
+///
+/// // Create a new hash instance
+/// let mut hash = glacier::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    digest: Digest,
+    part_size: Option<usize>,
+    parts: Vec<Digest>,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.digest
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        out.copy_from_slice(self.digest.as_ref());
+    }
+
+    /// Returns the tree hashes of the parts, empty if the part size was not set.
+    #[must_use]
+    pub fn parts(&self) -> &[Digest] {
+        &self.parts
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The part size is kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        match self.part_size {
+            Some(part_size) => Update::with_part_size(part_size),
+            None => Update::new(),
+        }
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
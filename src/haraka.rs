@@ -0,0 +1,208 @@
+//! This module is optional and can be enabled using the `haraka` Cargo feature.
+//!
+//! An implementation of the Haraka v2 short-input hash functions.
+//!
+//! Unlike the other hash functions of this crate, Haraka does not process data of arbitrary length. Haraka-256 compresses exactly 256 bits and Haraka-512 exactly 512 bits of input into a 256-bit digest, without any padding. This makes them suitable as building blocks of hash-based signature schemes, such as SPHINCS+, and of Merkle trees; for data of any other length use a general-purpose hash function instead.
+//!
+//! Both functions are built on the AES round function and are evaluated with a portable implementation.
+//!
+//! # Haraka-256
+//!
+//! The digest of a 32-byte input can be calculated with the [`hash_256`] function.
+//!
+//! ```rust
+//! use chksum_hash::haraka;
+//!
+//! let input = std::array::from_fn(|i| i as u8);
+//! let digest = haraka::hash_256(&input);
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "8027ccb87949774b78d0545fb72bf70c695c2a0923cbd47bba1159efbf2b2c1c"
+//! );
+//! ```
+//!
+//! # Haraka-512
+//!
+//! The digest of a 64-byte input can be calculated with the [`hash_512`] function.
+//!
+//! ```rust
+//! use chksum_hash::haraka;
+//!
+//! let input = std::array::from_fn(|i| i as u8);
+//! let digest = haraka::hash_512(&input);
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "be7f723b4e80a99813b292287f306f625a6d57331cae5f34dd9277b0945be2aa"
+//! );
+//! ```
+//!
+//! # Disclaimer
+//!
+//! Haraka is designed for inputs of a fixed length only and has not received as much analysis as general-purpose hash functions. It should only be used where a protocol explicitly requires it.
+
+mod aes;
+mod constants;
+pub mod digest;
+
+use self::constants::ROUND_CONSTANTS;
+#[doc(inline)]
+pub use self::digest::Digest;
+
+/// Input length of Haraka-256 in bytes.
+pub const INPUT_256_LENGTH_BYTES: usize = 32;
+/// Input length of Haraka-512 in bytes.
+pub const INPUT_512_LENGTH_BYTES: usize = 64;
+
+/// Number of rounds, each made of two AES rounds followed by mixing.
+const ROUNDS: usize = 5;
+
+/// Computes the Haraka-256 digest of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::haraka;
+///
+/// let digest = haraka::hash_256(&[0; haraka::INPUT_256_LENGTH_BYTES]);
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "583066c7dd645eee22980f3c35971b702973d03a029eb246eb44eceb4a4f5863"
+/// );
+/// ```
+#[must_use]
+pub const fn hash_256(input: &[u8; INPUT_256_LENGTH_BYTES]) -> Digest {
+    let mut state = [[0u8; 16]; 2];
+    let mut i = 0;
+    while i < INPUT_256_LENGTH_BYTES {
+        state[i / 16][i % 16] = input[i];
+        i += 1;
+    }
+
+    let mut round = 0;
+    while round < ROUNDS {
+        let mut j = 0;
+        while j < 2 {
+            state[0] = aes::round(state[0], ROUND_CONSTANTS[round * 4 + j * 2]);
+            state[1] = aes::round(state[1], ROUND_CONSTANTS[round * 4 + j * 2 + 1]);
+            j += 1;
+        }
+        state = [unpack_low(state[0], state[1]), unpack_high(state[0], state[1])];
+        round += 1;
+    }
+
+    // The input is added to the permuted state, which is returned as a whole.
+    let mut digest = [0u8; digest::LENGTH_BYTES];
+    let mut i = 0;
+    while i < digest::LENGTH_BYTES {
+        digest[i] = state[i / 16][i % 16] ^ input[i];
+        i += 1;
+    }
+    Digest::new(digest)
+}
+
+/// Computes the Haraka-512 digest of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::haraka;
+///
+/// let digest = haraka::hash_512(&[0; haraka::INPUT_512_LENGTH_BYTES]);
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "6165454b61dae9b53d086b1a01d6764a911b2a4707cd23640ab148b3db65caf3"
+/// );
+/// ```
+#[must_use]
+pub const fn hash_512(input: &[u8; INPUT_512_LENGTH_BYTES]) -> Digest {
+    let mut state = [[0u8; 16]; 4];
+    let mut i = 0;
+    while i < INPUT_512_LENGTH_BYTES {
+        state[i / 16][i % 16] = input[i];
+        i += 1;
+    }
+
+    let mut round = 0;
+    while round < ROUNDS {
+        let mut j = 0;
+        while j < 2 {
+            let mut k = 0;
+            while k < 4 {
+                state[k] = aes::round(state[k], ROUND_CONSTANTS[round * 8 + j * 4 + k]);
+                k += 1;
+            }
+            j += 1;
+        }
+        let [s0, s1, s2, s3] = state;
+        let (low, high) = (unpack_low(s0, s1), unpack_high(s0, s1));
+        let (s1, s2) = (unpack_low(s2, s3), unpack_high(s2, s3));
+        state = [
+            unpack_high(high, s2),
+            unpack_low(s1, low),
+            unpack_high(s1, low),
+            unpack_low(high, s2),
+        ];
+        round += 1;
+    }
+
+    // The input is added to the permuted state, which is then truncated to the middle quarter-words.
+    let mut output = [0u8; INPUT_512_LENGTH_BYTES];
+    let mut i = 0;
+    while i < INPUT_512_LENGTH_BYTES {
+        output[i] = state[i / 16][i % 16] ^ input[i];
+        i += 1;
+    }
+    let mut digest = [0u8; digest::LENGTH_BYTES];
+    let mut i = 0;
+    while i < 8 {
+        digest[i] = output[8 + i];
+        digest[8 + i] = output[24 + i];
+        digest[16 + i] = output[32 + i];
+        digest[24 + i] = output[48 + i];
+        i += 1;
+    }
+    Digest::new(digest)
+}
+
+/// Interleaves the 32-bit words of the lower halves of both inputs.
+const fn unpack_low(a: [u8; 16], b: [u8; 16]) -> [u8; 16] {
+    interleave(a, b, 0)
+}
+
+/// Interleaves the 32-bit words of the upper halves of both inputs.
+const fn unpack_high(a: [u8; 16], b: [u8; 16]) -> [u8; 16] {
+    interleave(a, b, 8)
+}
+
+const fn interleave(a: [u8; 16], b: [u8; 16], offset: usize) -> [u8; 16] {
+    let mut output = [0u8; 16];
+    let mut i = 0;
+    while i < 4 {
+        output[i] = a[offset + i];
+        output[4 + i] = b[offset + i];
+        output[8 + i] = a[offset + 4 + i];
+        output[12 + i] = b[offset + 4 + i];
+        i += 1;
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the Haraka v2 reference implementation.
+    #[test]
+    fn reference() {
+        let digest = hash_256(&std::array::from_fn(|i| i as u8));
+        assert_eq!(
+            digest.to_hex_lowercase(),
+            "8027ccb87949774b78d0545fb72bf70c695c2a0923cbd47bba1159efbf2b2c1c"
+        );
+        let digest = hash_512(&std::array::from_fn(|i| i as u8));
+        assert_eq!(
+            digest.to_hex_lowercase(),
+            "be7f723b4e80a99813b292287f306f625a6d57331cae5f34dd9277b0945be2aa"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+//! Module containing items related to the Haraka digest.
+
+/// Digest length in bits.
+pub const LENGTH_BITS: usize = 256;
+/// Digest length in bytes.
+pub const LENGTH_BYTES: usize = LENGTH_BITS / 8;
+/// Digest length in words (double bytes).
+pub const LENGTH_WORDS: usize = LENGTH_BYTES / 2;
+/// Digest length in double words (quadruple bytes).
+pub const LENGTH_DWORDS: usize = LENGTH_WORDS / 2;
+/// Digest length in hexadecimal format.
+pub const LENGTH_HEX: usize = LENGTH_BYTES * 2;
+
+/// Marker type of the Haraka digest.
+///
+/// It tags [`Digest`] so Haraka digests are distinct from digests of other hash functions with the same length.
+pub enum Haraka {}
+
+/// A hash digest.
+///
+/// Both Haraka-256 and Haraka-512 produce 256-bit digests.
+///
+/// Check [`haraka`](super) for more information.
+pub type Digest = crate::digest::Digest<Haraka, LENGTH_BYTES>;
@@ -0,0 +1,186 @@
+//! Module containing adapters of the hash functions of this crate to the [`std::hash`] traits.
+//!
+//! A [`Hasher`] implements [`std::hash::Hasher`] over any hash of this crate and finishes with the first 8 bytes of the digest as a big-endian integer, consistently with the `to_u64` functions of digests. A [`BuildHasher`] creates such hashers, e.g. for a `HashMap`.
+//!
+//! Unlike the default hasher of the standard library, the results are stable across processes, platforms and releases of Rust, as long as the hashed types write the same bytes. Integers are written as little-endian bytes and sizes are widened to 64 bits, regardless of the platform. The hash functions of this crate are not designed to resist hash flooding unless keyed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! use chksum_hash::hasher::BuildHasher;
+//! use chksum_hash::xxh3_64;
+//!
+//! let mut map = HashMap::with_hasher(BuildHasher::<xxh3_64::Update>::new());
+//! map.insert("key", "value");
+//! assert_eq!(map.get("key"), Some(&"value"));
+//! ```
+
+use std::fmt::{self, Debug, Formatter};
+use std::hash;
+use std::marker::PhantomData;
+
+use chksum_hash_core as core;
+
+/// An adapter implementing [`std::hash::Hasher`] over a hash of this crate.
+///
+/// Digests shorter than 8 bytes are padded with zeros on the right.
+///
+/// # Example
+///
+/// ```rust
+/// use std::hash::Hasher as _;
+///
+/// use chksum_hash::hasher::Hasher;
+/// use chksum_hash::md4;
+///
+/// let mut hasher = Hasher::<md4::Update>::new();
+/// hasher.write(b"data");
+/// assert_eq!(hasher.finish(), md4::hash("data").to_u64());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Hasher<U> {
+    hash: U,
+}
+
+impl<U> Hasher<U>
+where
+    U: core::Update,
+{
+    /// Creates a new hasher with the default hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_hash(U::default())
+    }
+
+    /// Creates a new hasher with the given hash, e.g. a keyed or seeded one.
+    #[must_use]
+    pub const fn with_hash(hash: U) -> Self {
+        Self { hash }
+    }
+
+    /// Returns the inner hash.
+    #[must_use]
+    pub fn into_inner(self) -> U {
+        self.hash
+    }
+}
+
+impl<U> hash::Hasher for Hasher<U>
+where
+    U: core::Update,
+    U::Digest: AsRef<[u8]>,
+{
+    fn finish(&self) -> u64 {
+        let digest = self.hash.digest();
+        let digest = digest.as_ref();
+        let mut bytes = [0u8; 8];
+        let length = digest.len().min(bytes.len());
+        bytes[..length].copy_from_slice(&digest[..length]);
+        u64::from_be_bytes(bytes)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.hash.update(bytes);
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+
+    // Sizes are widened to 64 bits to hash the same bytes on 32-bit and 64-bit platforms.
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_isize(&mut self, value: isize) {
+        self.write_u64(value as i64 as u64);
+    }
+}
+
+/// A builder of [`Hasher`]s with the default hash, implementing [`std::hash::BuildHasher`].
+pub struct BuildHasher<U> {
+    hash: PhantomData<fn() -> U>,
+}
+
+impl<U> BuildHasher<U> {
+    /// Creates a new builder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { hash: PhantomData }
+    }
+}
+
+impl<U> hash::BuildHasher for BuildHasher<U>
+where
+    U: core::Update,
+    U::Digest: AsRef<[u8]>,
+{
+    type Hasher = Hasher<U>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        Hasher::new()
+    }
+}
+
+impl<U> Clone for BuildHasher<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U> Copy for BuildHasher<U> {}
+
+impl<U> Debug for BuildHasher<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BuildHasher").finish()
+    }
+}
+
+impl<U> Default for BuildHasher<U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "md4")]
+    #[test]
+    fn integers() {
+        use std::hash::Hasher as _;
+
+        use super::Hasher;
+        use crate::md4;
+
+        let mut hasher = Hasher::<md4::Update>::new();
+        hasher.write_u16(0x0102);
+        hasher.write_u32(0x0304_0506);
+        hasher.write_u64(0x0708_090A_0B0C_0D0E);
+        hasher.write_u128(0x0F);
+        hasher.write_usize(0x10);
+        hasher.write_isize(-1);
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&[0x02, 0x01]);
+        bytes.extend_from_slice(&[0x06, 0x05, 0x04, 0x03]);
+        bytes.extend_from_slice(&[0x0E, 0x0D, 0x0C, 0x0B, 0x0A, 0x09, 0x08, 0x07]);
+        bytes.extend_from_slice(&[0x0F, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0x10, 0, 0, 0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFF; 8]);
+        assert_eq!(hasher.finish(), md4::hash(bytes).to_u64());
+    }
+}
@@ -0,0 +1,599 @@
+//! This module is optional and can be enabled using the `haval` Cargo feature.
+//!
+//! An implementation of the HAVAL hash function.
+//!
+//! HAVAL has a configurable number of passes (3, 4 or 5) and digest length (128, 160, 192, 224 or 256 bits); both are encoded in the padding, so every combination produces unrelated digests. See [`Params`] for the options; by default HAVAL-256 with 5 passes is used.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::haval;
+//!
+//! let digest = haval::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "bb9532d378fbdf45414f7e55aaa2104e74bec53b62698ff534df2b52241dea04"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::haval;
+//!
+//! let digest = haval::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "bb9532d378fbdf45414f7e55aaa2104e74bec53b62698ff534df2b52241dea04"
+//! );
+//! ```
+//!
+//! # Parameters
+//!
+//! Use [`Update::with_params`] to choose the digest length and the number of passes.
+//!
+//! ```rust
+//! use chksum_hash::haval;
+//!
+//! let params = haval::Params::new(128).with_passes(3);
+//! let digest = haval::Update::with_params(params).digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "c68f39913f901f3ddf44c707357a7d70"
+//! );
+//!
+//! let params = haval::Params::new(160).with_passes(4);
+//! let digest = haval::Update::with_params(params)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "7fadf9ebf828fd1792ed3780f9191e2399b1eecf"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized due to the unknown size of data chunks.
+//!
+//! The size of this buffer is at least as large as one hash block of data processed at a time.
+//!
+//! To mitigate buffering, and consequently reduce memory overhead, data chunks should be aligned to multiples of the block size, or processed in block-sized chunks.
+//!
+//! The [`Update::is_block_aligned`] and [`Update::buffered_len`] functions report whether data is buffered, and [`Update::processed_bytes`] returns the number of bytes passed to the hash so far.
+//!
+//! # Disclaimer
+//!
+//! Collisions have been found for the 3-pass variant of HAVAL and the other variants have received little cryptanalysis. This module exists to verify digests stored by legacy systems, e.g. in forensic hash databases; it must not be used for any security purpose.
+
+pub mod block;
+pub mod digest;
+pub mod params;
+pub mod state;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::block::{Block, LENGTH_BYTES as BLOCK_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::digest::Digest;
+#[doc(inline)]
+pub use self::params::Params;
+#[doc(inline)]
+pub use self::state::State;
+use crate::buffer::Buffer;
+use crate::error::MessageLengthError;
+use crate::verify::Expected;
+
+/// Maximum message length in bytes, so that the length in bits fits into 64 bits.
+///
+/// The [`Update::update`] function does not check it and wraps the length of longer messages, the [`Update::try_update`] function rejects them.
+pub const MAX_MESSAGE_LENGTH_BYTES: u64 = u64::MAX / 8;
+
+/// Version of the HAVAL algorithm encoded in the padding.
+const VERSION: u8 = 1;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::haval;
+///
+/// let digest = haval::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330"
+/// );
+///
+/// let digest = haval::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e53efbac35bd8ba72d144768aeea53a19151fdd2fcd26673914101fe0a8fe483"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::haval;
+///
+/// let digest = haval::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::haval;
+///
+/// let digest = haval::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "976cd6254c337969e5913b158392a2921af16fca51f5601d486e0a9de01156e7"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::haval;
+///
+/// let digest = haval::hash("data");
+/// assert!(haval::verify("data", digest));
+/// assert!(haval::verify("data", digest.to_hex_uppercase()));
+/// assert!(!haval::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::haval;
+///
+/// // Create a new hash instance
+/// let mut hash = haval::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e53efbac35bd8ba72d144768aeea53a19151fdd2fcd26673914101fe0a8fe483"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    params: Params,
+    state: State,
+    unprocessed: Buffer<BLOCK_LENGTH_BYTES>,
+    processed: u64,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_params(Params::default())
+    }
+
+    /// Creates a new hash with the given parameters.
+    #[must_use]
+    pub fn with_params(params: Params) -> Self {
+        let state = state::new(params.passes());
+        let unprocessed = Buffer::new();
+        let processed = 0;
+        Self {
+            params,
+            state,
+            unprocessed,
+            processed,
+        }
+    }
+
+    /// Returns the parameters of the hash.
+    #[must_use]
+    pub const fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+
+        if !self.unprocessed.is_empty() {
+            let missing = BLOCK_LENGTH_BYTES - self.unprocessed.len();
+            if data.len() < missing {
+                // Not enough data even for one block.
+                self.unprocessed.extend_from_slice(data);
+                return self;
+            }
+
+            // Complete the buffered block with the beginning of incoming data.
+            let (fillment, remainder) = data.split_at(missing);
+            self.unprocessed.extend_from_slice(fillment);
+            let block = Block::try_from(&self.unprocessed[..])
+                .expect("unprocessed data length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u64);
+            self.unprocessed.clear();
+            data = remainder;
+        }
+
+        // Internal buffer is empty, incoming data can be processed without buffering.
+        let mut chunks = data.chunks_exact(BLOCK_LENGTH_BYTES);
+        for chunk in chunks.by_ref() {
+            let block = Block::try_from(chunk)
+                .expect("chunk length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u64);
+        }
+        self.unprocessed.extend_from_slice(chunks.remainder());
+
+        self
+    }
+
+    /// Updates the internal state with an input data, unless the message would exceed [`MAX_MESSAGE_LENGTH_BYTES`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the state unchanged if the total length of the message would exceed the maximum.
+    pub fn try_update<T>(&mut self, data: T) -> Result<&mut Self, MessageLengthError>
+    where
+        T: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+        match self.processed_bytes().checked_add(data.len() as u64) {
+            Some(length) if length <= MAX_MESSAGE_LENGTH_BYTES => Ok(self.update(data)),
+            _ => Err(MessageLengthError),
+        }
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        assert!(
+            self.unprocessed.len() < BLOCK_LENGTH_BYTES,
+            "unprocessed data length should be less than block length"
+        );
+
+        let length = {
+            let length = self.processed.wrapping_add(self.unprocessed.len() as u64);
+            let length = length.wrapping_mul(8); // convert byte-length into bits-length
+            length.to_le_bytes()
+        };
+
+        // The trailer holds the version, the number of passes and the digest length, followed by the data length.
+        #[allow(clippy::cast_possible_truncation)]
+        let trailer = {
+            let (passes, digest_length) = (self.params.passes() as u8, self.params.digest_length());
+            [
+                ((digest_length as u8 & 0x03) << 6) | ((passes & 0x07) << 3) | (VERSION & 0x07),
+                (digest_length >> 2) as u8,
+            ]
+        };
+
+        let mut padding = [0u8; BLOCK_LENGTH_BYTES * 2];
+        padding[..self.unprocessed.len()].copy_from_slice(&self.unprocessed);
+        padding[self.unprocessed.len()] = 0x01;
+        let padding = if (self.unprocessed.len() + 1 + trailer.len() + length.len()) <= BLOCK_LENGTH_BYTES {
+            &mut padding[..BLOCK_LENGTH_BYTES]
+        } else {
+            &mut padding[..]
+        };
+        let offset = padding.len() - length.len();
+        padding[offset..].copy_from_slice(&length);
+        padding[offset - trailer.len()..offset].copy_from_slice(&trailer);
+
+        let state = padding
+            .chunks_exact(BLOCK_LENGTH_BYTES)
+            .fold(self.state, |state, chunk| {
+                let block = Block::try_from(chunk)
+                    .expect("padding length must be multiple of block length")
+                    .into();
+                state.update(block)
+            });
+        let params = self.params;
+        Finalize { params, state }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The parameters of the hash are kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self.unprocessed.clear();
+        self.processed = 0;
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+
+    /// Returns the number of bytes passed to the hash so far, including the buffered ones.
+    #[must_use]
+    pub fn processed_bytes(&self) -> u64 {
+        self.processed.wrapping_add(self.unprocessed.len() as u64)
+    }
+
+    /// Returns the number of bytes buffered until a whole block is available.
+    ///
+    /// Updating with chunks that are multiples of the block length keeps the buffer empty.
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.unprocessed.len()
+    }
+
+    /// Returns `true` if no data is buffered, i.e. all bytes passed so far form whole blocks.
+    #[must_use]
+    pub fn is_block_aligned(&self) -> bool {
+        self.unprocessed.is_empty()
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let params = u.arbitrary::<Params>()?;
+        let state = State::from_raw(u.arbitrary()?, params.passes());
+        // Full blocks are processed immediately, so less than a block is buffered.
+        let length = u.int_in_range(0..=BLOCK_LENGTH_BYTES - 1)?;
+        let unprocessed = Buffer::from_slice(u.bytes(length)?);
+        let processed = u.arbitrary::<u64>()?;
+        let processed = processed - processed % BLOCK_LENGTH_BYTES as u64;
+        Ok(Self {
+            params,
+            state,
+            unprocessed,
+            processed,
+        })
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::haval;
+///
+/// // Create a new hash instance
+/// let mut hash = haval::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "976cd6254c337969e5913b158392a2921af16fca51f5601d486e0a9de01156e7"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    params: Params,
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    ///
+    /// For digests shorter than 256 bits, the last words of the state are folded into the first ones.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let words = fold(self.state.digest(), self.params.digest_length());
+        let mut digest = [0u8; state::LENGTH_DWORDS * 4];
+        for (bytes, word) in digest.chunks_exact_mut(4).zip(words) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+        Digest::new(&digest[..self.params.digest_length() / 8])
+    }
+
+    /// Writes the hash digest into the beginning of the buffer, e.g. a part of a packet, and returns the written part of the buffer.
+    ///
+    /// Returns `None` if the buffer is shorter than the digest, whose length is chosen at runtime.
+    #[must_use]
+    pub fn digest_into<'a>(&self, out: &'a mut [u8]) -> Option<&'a [u8]> {
+        let digest = self.digest();
+        let out = out.get_mut(..digest.len())?;
+        out.copy_from_slice(digest.as_ref());
+        Some(out)
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The parameters of the hash are kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_params(self.params)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+/// Folds the state into the given digest length in bits as specified by the reference implementation.
+#[allow(clippy::unreadable_literal)]
+fn fold(mut h: [u32; state::LENGTH_DWORDS], length: usize) -> [u32; state::LENGTH_DWORDS] {
+    match length {
+        128 => {
+            let temp = (h[7] & 0x000000FF) | (h[6] & 0xFF000000) | (h[5] & 0x00FF0000) | (h[4] & 0x0000FF00);
+            h[0] = h[0].wrapping_add(temp.rotate_right(8));
+            let temp = (h[7] & 0x0000FF00) | (h[6] & 0x000000FF) | (h[5] & 0xFF000000) | (h[4] & 0x00FF0000);
+            h[1] = h[1].wrapping_add(temp.rotate_right(16));
+            let temp = (h[7] & 0x00FF0000) | (h[6] & 0x0000FF00) | (h[5] & 0x000000FF) | (h[4] & 0xFF000000);
+            h[2] = h[2].wrapping_add(temp.rotate_right(24));
+            let temp = (h[7] & 0xFF000000) | (h[6] & 0x00FF0000) | (h[5] & 0x0000FF00) | (h[4] & 0x000000FF);
+            h[3] = h[3].wrapping_add(temp);
+        },
+        160 => {
+            let temp = (h[7] & 0x3F) | (h[6] & (0x7F << 25)) | (h[5] & (0x3F << 19));
+            h[0] = h[0].wrapping_add(temp.rotate_right(19));
+            let temp = (h[7] & (0x3F << 6)) | (h[6] & 0x3F) | (h[5] & (0x7F << 25));
+            h[1] = h[1].wrapping_add(temp.rotate_right(25));
+            let temp = (h[7] & (0x7F << 12)) | (h[6] & (0x3F << 6)) | (h[5] & 0x3F);
+            h[2] = h[2].wrapping_add(temp);
+            let temp = (h[7] & (0x3F << 19)) | (h[6] & (0x7F << 12)) | (h[5] & (0x3F << 6));
+            h[3] = h[3].wrapping_add(temp >> 6);
+            let temp = (h[7] & (0x7F << 25)) | (h[6] & (0x3F << 19)) | (h[5] & (0x7F << 12));
+            h[4] = h[4].wrapping_add(temp >> 12);
+        },
+        192 => {
+            let temp = (h[7] & 0x1F) | (h[6] & (0x3F << 26));
+            h[0] = h[0].wrapping_add(temp.rotate_right(26));
+            let temp = (h[7] & (0x1F << 5)) | (h[6] & 0x1F);
+            h[1] = h[1].wrapping_add(temp);
+            let temp = (h[7] & (0x3F << 10)) | (h[6] & (0x1F << 5));
+            h[2] = h[2].wrapping_add(temp >> 5);
+            let temp = (h[7] & (0x1F << 16)) | (h[6] & (0x3F << 10));
+            h[3] = h[3].wrapping_add(temp >> 10);
+            let temp = (h[7] & (0x1F << 21)) | (h[6] & (0x1F << 16));
+            h[4] = h[4].wrapping_add(temp >> 16);
+            let temp = (h[7] & (0x3F << 26)) | (h[6] & (0x1F << 21));
+            h[5] = h[5].wrapping_add(temp >> 21);
+        },
+        224 => {
+            h[0] = h[0].wrapping_add((h[7] >> 27) & 0x1F);
+            h[1] = h[1].wrapping_add((h[7] >> 22) & 0x1F);
+            h[2] = h[2].wrapping_add((h[7] >> 18) & 0x0F);
+            h[3] = h[3].wrapping_add((h[7] >> 13) & 0x1F);
+            h[4] = h[4].wrapping_add((h[7] >> 9) & 0x0F);
+            h[5] = h[5].wrapping_add((h[7] >> 4) & 0x1F);
+            h[6] = h[6].wrapping_add(h[7] & 0x0F);
+        },
+        _ => {},
+    }
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the HAVAL reference implementation.
+    #[test]
+    fn reference() {
+        for (digest_length, passes, data, expected) in [
+            (128, 3, "", "c68f39913f901f3ddf44c707357a7d70"),
+            (128, 3, "a", "0cd40739683e15f01ca5dbceef4059f1"),
+            (128, 4, "", "ee6bbf4d6a46a679b3a856c88538bb98"),
+            (128, 5, "", "184b8482a0c050dca54b59c7f05bf5dd"),
+            (160, 3, "", "d353c3ae22a25401d257643836d7231a9a95f953"),
+            (192, 3, "", "e9c48d7903eaf2a91c5b350151efcb175c0fc82de2289a4e"),
+            (224, 3, "", "c5aae9d47bffcaaf84a8c6e7ccacd60a0dd1932be7b1a192b9214b6d"),
+            (
+                256,
+                5,
+                "",
+                "be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330",
+            ),
+        ] {
+            let params = Params::new(digest_length).with_passes(passes);
+            let digest = Update::with_params(params).update(data).digest();
+            assert_eq!(digest.to_hex_lowercase(), expected);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Module containing items related to the HAVAL digest.
+
+/// Maximum digest length in bits.
+pub const MAX_LENGTH_BITS: usize = 256;
+/// Maximum digest length in bytes.
+pub const MAX_LENGTH_BYTES: usize = MAX_LENGTH_BITS / 8;
+/// Maximum digest length in hexadecimal format.
+pub const MAX_LENGTH_HEX: usize = MAX_LENGTH_BYTES * 2;
+
+/// Marker type of the HAVAL digest.
+///
+/// It tags [`Digest`] so HAVAL digests are distinct from digests of other hash functions with the same length.
+pub enum Haval {}
+
+/// A hash digest.
+///
+/// The length of a HAVAL digest (128, 160, 192, 224 or 256 bits) is chosen at runtime, see [`Params`](super::Params).
+///
+/// Check [`haval`](super) for more information.
+///
+/// Digests are ordered lexicographically by their bytes, so a digest is ordered before the longer digests it is a prefix of.
+pub type Digest = crate::digest::VariableDigest<Haval, MAX_LENGTH_BYTES>;
@@ -0,0 +1,117 @@
+//! Module containing items related to the HAVAL parameters.
+
+use crate::error::ParameterError;
+
+/// Default digest length in bits.
+pub const DEFAULT_DIGEST_LENGTH_BITS: usize = 256;
+/// Default number of passes.
+pub const DEFAULT_PASSES: usize = 5;
+
+/// Parameters of the HAVAL hash function.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::haval;
+///
+/// // HAVAL-128 with 3 passes
+/// let params = haval::Params::new(128).with_passes(3);
+/// assert_eq!(params.digest_length(), 128);
+/// assert_eq!(params.passes(), 3);
+///
+/// // HAVAL-256 with 5 passes is the default
+/// let params = haval::Params::default();
+/// assert_eq!(params, haval::Params::new(256).with_passes(5));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    digest_length: usize,
+    passes: usize,
+}
+
+impl Params {
+    /// Creates parameters for the given digest length in bits with the default number of passes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digest length is not one of 128, 160, 192, 224 or 256 bits.
+    #[must_use]
+    pub const fn new(digest_length: usize) -> Self {
+        assert!(
+            matches!(digest_length, 128 | 160 | 192 | 224 | 256),
+            "digest length must be one of 128, 160, 192, 224 or 256 bits"
+        );
+        Self {
+            digest_length,
+            passes: DEFAULT_PASSES,
+        }
+    }
+
+    /// Creates parameters for the given digest length in bits with the default number of passes, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the digest length is not one of 128, 160, 192, 224 or 256 bits.
+    pub const fn try_new(digest_length: usize) -> Result<Self, ParameterError> {
+        if matches!(digest_length, 128 | 160 | 192 | 224 | 256) {
+            Ok(Self::new(digest_length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Sets the number of passes of the compression function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of passes is not 3, 4 or 5.
+    #[must_use]
+    pub const fn with_passes(self, passes: usize) -> Self {
+        assert!(passes >= 3 && passes <= 5, "number of passes must be 3, 4 or 5");
+        Self { passes, ..self }
+    }
+
+    /// Sets the number of passes of the compression function, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of passes is not 3, 4 or 5.
+    pub const fn try_with_passes(self, passes: usize) -> Result<Self, ParameterError> {
+        if passes >= 3 && passes <= 5 {
+            Ok(self.with_passes(passes))
+        } else {
+            Err(ParameterError {
+                parameter: "number of passes",
+            })
+        }
+    }
+
+    /// Returns the digest length in bits.
+    #[must_use]
+    pub const fn digest_length(&self) -> usize {
+        self.digest_length
+    }
+
+    /// Returns the number of passes.
+    #[must_use]
+    pub const fn passes(&self) -> usize {
+        self.passes
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Params {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let digest_length = *u.choose(&[128, 160, 192, 224, 256])?;
+        let passes = u.int_in_range(3..=5)?;
+        Ok(Self::new(digest_length).with_passes(passes))
+    }
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::new(DEFAULT_DIGEST_LENGTH_BITS)
+    }
+}
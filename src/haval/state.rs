@@ -0,0 +1,293 @@
+//! Module contains items related to the [`State`] structure which allows to the direct HAVAL state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::haval;
+//!
+//! // Create new state with 5 passes
+//! let mut state = haval::state::new(5);
+//!
+//! // By default it returns initialization values
+//! assert_eq!(
+//!     state.digest(),
+//!     [
+//!         0x243F6A88, 0x85A308D3, 0x13198A2E, 0x03707344, 0xA4093822, 0x299F31D0, 0x082EFA98,
+//!         0xEC4E6C89
+//!     ]
+//! );
+//!
+//! // Create a block of data with proper padding for the 256-bit digest
+//! let mut block = [0u8; haval::BLOCK_LENGTH_BYTES];
+//! block[0] = 0x01;
+//! block[118] = 0x29;
+//! block[119] = 0x40;
+//! let data = haval::Block::new(block).into();
+//!
+//! // Update state and own new value
+//! state = state.update(data);
+//!
+//! // Proper digest of empty input
+//! assert_eq!(
+//!     state.digest(),
+//!     [
+//!         0xB47B41BE, 0x76FB5CDD, 0x4F6F12C7, 0x5315EB8E, 0x930349A4, 0xCDA3B107, 0xDCBF1D45,
+//!         0x30E3BB0F
+//!     ]
+//! );
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(
+//!     state.digest(),
+//!     [
+//!         0x243F6A88, 0x85A308D3, 0x13198A2E, 0x03707344, 0xA4093822, 0x299F31D0, 0x082EFA98,
+//!         0xEC4E6C89
+//!     ]
+//! );
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_DWORDS as BLOCK_LENGTH_DWORDS;
+use super::params::DEFAULT_PASSES;
+use crate::error::ParameterError;
+
+/// State length in double words (quadruple bytes).
+pub const LENGTH_DWORDS: usize = 8;
+
+#[allow(clippy::unreadable_literal)]
+const H: [u32; LENGTH_DWORDS] = [
+    0x243F6A88, 0x85A308D3, 0x13198A2E, 0x03707344, 0xA4093822, 0x299F31D0, 0x082EFA98, 0xEC4E6C89,
+];
+
+/// Constants of the passes 2 to 5, the first pass uses none.
+#[allow(clippy::unreadable_literal)]
+const K: [[u32; 32]; 4] = [
+    [
+        0x452821E6, 0x38D01377, 0xBE5466CF, 0x34E90C6C, 0xC0AC29B7, 0xC97C50DD, 0x3F84D5B5, 0xB5470917, 0x9216D5D9,
+        0x8979FB1B, 0xD1310BA6, 0x98DFB5AC, 0x2FFD72DB, 0xD01ADFB7, 0xB8E1AFED, 0x6A267E96, 0xBA7C9045, 0xF12C7F99,
+        0x24A19947, 0xB3916CF7, 0x0801F2E2, 0x858EFC16, 0x636920D8, 0x71574E69, 0xA458FEA3, 0xF4933D7E, 0x0D95748F,
+        0x728EB658, 0x718BCD58, 0x82154AEE, 0x7B54A41D, 0xC25A59B5,
+    ],
+    [
+        0x9C30D539, 0x2AF26013, 0xC5D1B023, 0x286085F0, 0xCA417918, 0xB8DB38EF, 0x8E79DCB0, 0x603A180E, 0x6C9E0E8B,
+        0xB01E8A3E, 0xD71577C1, 0xBD314B27, 0x78AF2FDA, 0x55605C60, 0xE65525F3, 0xAA55AB94, 0x57489862, 0x63E81440,
+        0x55CA396A, 0x2AAB10B6, 0xB4CC5C34, 0x1141E8CE, 0xA15486AF, 0x7C72E993, 0xB3EE1411, 0x636FBC2A, 0x2BA9C55D,
+        0x741831F6, 0xCE5C3E16, 0x9B87931E, 0xAFD6BA33, 0x6C24CF5C,
+    ],
+    [
+        0x7A325381, 0x28958677, 0x3B8F4898, 0x6B4BB9AF, 0xC4BFE81B, 0x66282193, 0x61D809CC, 0xFB21A991, 0x487CAC60,
+        0x5DEC8032, 0xEF845D5D, 0xE98575B1, 0xDC262302, 0xEB651B88, 0x23893E81, 0xD396ACC5, 0x0F6D6FF3, 0x83F44239,
+        0x2E0B4482, 0xA4842004, 0x69C8F04A, 0x9E1F9B5E, 0x21C66842, 0xF6E96C9A, 0x670C9C61, 0xABD388F0, 0x6A51A0D2,
+        0xD8542F68, 0x960FA728, 0xAB5133A3, 0x6EEF0B6C, 0x137A3BE4,
+    ],
+    [
+        0xBA3BF050, 0x7EFB2A98, 0xA1F1651D, 0x39AF0176, 0x66CA593E, 0x82430E88, 0x8CEE8619, 0x456F9FB4, 0x7D84A5C3,
+        0x3B8B5EBE, 0xE06F75D8, 0x85C12073, 0x401A449F, 0x56C16AA6, 0x4ED3AA62, 0x363F7706, 0x1BFEDF72, 0x429B023D,
+        0x37D0D724, 0xD00A1248, 0xDB0FEAD3, 0x49F1C09B, 0x075372C9, 0x80991B7B, 0x25D479D8, 0xF6E8DEF7, 0xE3FE501A,
+        0xB6794C3B, 0x976CE0BD, 0x04C006BA, 0xC1A94FB6, 0x409F60C4,
+    ],
+];
+
+/// Order in which the words of a block are used by each pass.
+#[rustfmt::skip]
+const ORDER: [[usize; 32]; 5] = [
+    [ 0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31],
+    [ 5, 14, 26, 18, 11, 28,  7, 16,  0, 23, 20, 22,  1, 10,  4,  8, 30,  3, 21,  9, 17, 24, 29,  6, 19, 12, 15, 13,  2, 25, 31, 27],
+    [19,  9,  4, 20, 28, 17,  8, 22, 29, 14, 25, 12, 24, 30, 16, 26, 31, 15,  7,  3,  1,  0, 18, 27, 13,  6, 21, 10, 23, 11,  5,  2],
+    [24,  4,  0, 14,  2,  7, 28, 23, 26,  6, 30, 20, 18, 25, 19,  3, 22, 11, 31, 21,  8, 27, 12,  9,  1, 29,  5, 15, 17, 10, 16, 13],
+    [27,  3, 21, 26, 17, 11, 20, 29, 19,  0, 12,  7, 13,  8, 31, 10,  5,  9, 14, 30, 18,  6, 28, 24,  2, 23, 16, 22,  4,  1, 25, 15],
+];
+
+/// Create a new state with the given number of passes.
+///
+/// # Panics
+///
+/// Panics if the number of passes is not 3, 4 or 5.
+#[must_use]
+pub const fn new(passes: usize) -> State {
+    State::new(passes)
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level hash state.
+///
+/// The state is shared by all digest lengths; the digest is folded to the requested length only at the end.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct State {
+    pub(crate) h: [u32; LENGTH_DWORDS],
+    pub(crate) passes: usize,
+}
+
+impl State {
+    /// Creates a new state with the given number of passes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of passes is not 3, 4 or 5.
+    #[must_use]
+    pub const fn new(passes: usize) -> Self {
+        Self::from_raw(H, passes)
+    }
+
+    /// Creates a new state with the given number of passes, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of passes is not 3, 4 or 5.
+    pub const fn try_new(passes: usize) -> Result<Self, ParameterError> {
+        if passes >= 3 && passes <= 5 {
+            Ok(Self::new(passes))
+        } else {
+            Err(ParameterError { parameter: "passes" })
+        }
+    }
+
+    /// Creates a state with the given number of passes from the raw chaining values, e.g. a custom initialization vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of passes is not 3, 4 or 5.
+    #[must_use]
+    pub const fn from_raw(h: [u32; LENGTH_DWORDS], passes: usize) -> Self {
+        assert!(passes >= 3 && passes <= 5, "number of passes must be 3, 4 or 5");
+        Self { h, passes }
+    }
+
+    /// Creates a state with the given number of passes from the raw chaining values, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of passes is not 3, 4 or 5.
+    pub const fn try_from_raw(h: [u32; LENGTH_DWORDS], passes: usize) -> Result<Self, ParameterError> {
+        if passes >= 3 && passes <= 5 {
+            Ok(Self::from_raw(h, passes))
+        } else {
+            Err(ParameterError { parameter: "passes" })
+        }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// **Warning!** Input block must be in the little-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u32; BLOCK_LENGTH_DWORDS]) -> Self {
+        let mut t = self.h;
+        let mut pass = 0;
+        while pass < self.passes {
+            let phi = phi(self.passes, pass);
+            let mut j = 0;
+            while j < 32 {
+                // The registers rotate by one position with every step.
+                let mut x = [0u32; 8];
+                let mut i = 0;
+                while i < 8 {
+                    x[i] = t[(i + 8 - j % 8) % 8];
+                    i += 1;
+                }
+                let [a, b, c, d, e, f, g] = phi;
+                let (x6, x5, x4, x3, x2, x1, x0) = (x[a], x[b], x[c], x[d], x[e], x[f], x[g]);
+                let temp = match pass {
+                    0 => f1(x6, x5, x4, x3, x2, x1, x0),
+                    1 => f2(x6, x5, x4, x3, x2, x1, x0),
+                    2 => f3(x6, x5, x4, x3, x2, x1, x0),
+                    3 => f4(x6, x5, x4, x3, x2, x1, x0),
+                    _ => f5(x6, x5, x4, x3, x2, x1, x0),
+                };
+                let k = if pass == 0 { 0 } else { K[pass - 1][j] };
+                t[7 - j % 8] = temp
+                    .rotate_right(7)
+                    .wrapping_add(x[7].rotate_right(11))
+                    .wrapping_add(block[ORDER[pass][j]])
+                    .wrapping_add(k);
+                j += 1;
+            }
+            pass += 1;
+        }
+
+        let mut h = self.h;
+        let mut i = 0;
+        while i < LENGTH_DWORDS {
+            h[i] = h[i].wrapping_add(t[i]);
+            i += 1;
+        }
+        Self::from_raw(h, self.passes)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new(self.passes)
+    }
+
+    /// Returns the whole state, before it is folded to the digest length.
+    #[must_use]
+    pub const fn digest(&self) -> [u32; LENGTH_DWORDS] {
+        self.h
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(DEFAULT_PASSES)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for State {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let h = u.arbitrary()?;
+        let passes = u.int_in_range(3..=5)?;
+        Ok(Self::from_raw(h, passes))
+    }
+}
+
+/// Returns the permutation of the Boolean function inputs for the given pass.
+///
+/// The permutations differ between the 3, 4 and 5 pass variants.
+const fn phi(passes: usize, pass: usize) -> [usize; 7] {
+    match (passes, pass) {
+        (3, 0) => [1, 0, 3, 5, 6, 2, 4],
+        (3, 1) => [4, 2, 1, 0, 5, 3, 6],
+        (3, _) => [6, 1, 2, 3, 4, 5, 0],
+        (4, 0) => [2, 6, 1, 4, 5, 3, 0],
+        (4, 1) => [3, 5, 2, 0, 1, 6, 4],
+        (4, 2) => [1, 4, 3, 6, 0, 2, 5],
+        (4, _) => [6, 4, 0, 5, 2, 1, 3],
+        (_, 0) => [3, 4, 1, 0, 5, 2, 6],
+        (_, 1) => [6, 2, 1, 0, 3, 4, 5],
+        (_, 2) => [2, 6, 0, 4, 3, 1, 5],
+        (_, 3) => [1, 5, 3, 2, 0, 4, 6],
+        _ => [2, 5, 0, 6, 4, 3, 1],
+    }
+}
+
+const fn f1(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x1 & (x0 ^ x4)) ^ (x2 & x5) ^ (x3 & x6) ^ x0
+}
+
+const fn f2(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x2 & ((x1 & !x3) ^ (x4 & x5) ^ x6 ^ x0)) ^ (x4 & (x1 ^ x5)) ^ (x3 & x5) ^ x0
+}
+
+const fn f3(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x3 & ((x1 & x2) ^ x6 ^ x0)) ^ (x1 & x4) ^ (x2 & x5) ^ x0
+}
+
+const fn f4(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x4 & ((x5 & !x2) ^ (x3 & !x6) ^ x1 ^ x6 ^ x0)) ^ (x3 & ((x1 & x2) ^ x5 ^ x6)) ^ (x2 & x6) ^ x0
+}
+
+const fn f5(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x0 & ((x1 & x2 & x3) ^ !x5)) ^ (x1 & x4) ^ (x2 & x5) ^ (x3 & x6)
+}
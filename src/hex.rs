@@ -0,0 +1,354 @@
+//! Module containing items related to the hexadecimal representation of digests.
+//!
+//! Digests of this crate can be parsed from the hexadecimal representation with the `from_hex` functions or the [`FromStr`](std::str::FromStr) trait, both of which use [`decode`] and [`decode_to_slice`].
+//!
+//! Digests are written in the hexadecimal representation without allocating with the `to_hex_into` and `write_hex` functions, which use [`encode_to_slice`] and [`write`].
+//!
+//! Layouts with grouped digits, e.g. of certificate and key fingerprints, are produced with the [`Grouped`] builder.
+//!
+//! Digests can be compared with the hexadecimal representation directly, regardless of the case of the digits.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::md4;
+//!
+//! let digest = md4::hash("data");
+//! let parsed = digest.to_hex_lowercase().parse::<md4::Digest>().unwrap();
+//! assert_eq!(parsed, digest);
+//!
+//! assert_eq!(digest, "539080BA278CF4CF4DB2E4A32642FF30");
+//! assert_ne!(digest, "0x539080ba278cf4cf4db2e4a32642ff30");
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::error::ParameterError;
+
+/// An error of parsing the hexadecimal representation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FromHexError {
+    /// A character that is not a hexadecimal digit, at the given byte index of the string.
+    InvalidCharacter {
+        /// The invalid character.
+        character: char,
+        /// The byte index of the character, including the prefix.
+        index: usize,
+    },
+    /// An odd or unexpected number of hexadecimal digits.
+    InvalidLength {
+        /// The number of hexadecimal digits, excluding the prefix.
+        length: usize,
+    },
+}
+
+impl Display for FromHexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter { character, index } => {
+                write!(f, "invalid hexadecimal character {character:?} at index {index}")
+            },
+            Self::InvalidLength { length } => write!(f, "invalid number of hexadecimal digits {length}"),
+        }
+    }
+}
+
+impl Error for FromHexError {}
+
+/// Decodes the hexadecimal representation into bytes.
+///
+/// Both lowercase and uppercase digits are accepted, optionally prefixed with `0x` or `0X`.
+///
+/// # Errors
+///
+/// Returns an error if the string contains a non-hexadecimal character or an odd number of digits.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::hex::{self, FromHexError};
+///
+/// assert_eq!(hex::decode("0xC0ffEE"), Ok(vec![0xC0, 0xFF, 0xEE]));
+/// assert_eq!(
+///     hex::decode("c0f"),
+///     Err(FromHexError::InvalidLength { length: 3 })
+/// );
+/// assert_eq!(
+///     hex::decode("0xc0fg"),
+///     Err(FromHexError::InvalidCharacter {
+///         character: 'g',
+///         index: 5,
+///     })
+/// );
+/// ```
+pub fn decode(hex: &str) -> Result<Vec<u8>, FromHexError> {
+    let (offset, digits) = strip_prefix(hex);
+    let mut bytes = vec![0u8; length(offset, digits)? / 2];
+    decode_digits(digits, &mut bytes);
+    Ok(bytes)
+}
+
+/// Decodes the hexadecimal representation into the given buffer, whose length has to match the number of bytes.
+///
+/// Both lowercase and uppercase digits are accepted, optionally prefixed with `0x` or `0X`.
+///
+/// # Errors
+///
+/// Returns an error if the string contains a non-hexadecimal character or the number of digits is not twice the length of the buffer.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::hex::{self, FromHexError};
+///
+/// let mut buffer = [0u8; 2];
+/// hex::decode_to_slice("BEEF", &mut buffer).unwrap();
+/// assert_eq!(buffer, [0xBE, 0xEF]);
+///
+/// assert_eq!(
+///     hex::decode_to_slice("0xBEEF00", &mut buffer),
+///     Err(FromHexError::InvalidLength { length: 6 })
+/// );
+/// ```
+pub fn decode_to_slice(hex: &str, buffer: &mut [u8]) -> Result<(), FromHexError> {
+    let (offset, digits) = strip_prefix(hex);
+    let length = length(offset, digits)?;
+    if length != buffer.len() * 2 {
+        return Err(FromHexError::InvalidLength { length });
+    }
+    decode_digits(digits, buffer);
+    Ok(())
+}
+
+/// Encodes the bytes into the hexadecimal representation in the given buffer and returns the written part of the buffer.
+///
+/// Returns `None` if the buffer is shorter than twice the number of bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::hex::{self, Case};
+///
+/// let mut buffer = [0u8; 8];
+/// assert_eq!(
+///     hex::encode_to_slice(&[0xBE, 0xEF], &mut buffer, Case::Lower),
+///     Some("beef")
+/// );
+/// assert_eq!(
+///     hex::encode_to_slice(&[0xBE, 0xEF], &mut buffer[..3], Case::Upper),
+///     None
+/// );
+/// ```
+#[must_use]
+pub fn encode_to_slice<'a>(data: &[u8], buffer: &'a mut [u8], case: Case) -> Option<&'a str> {
+    let buffer = buffer.get_mut(..data.len() * 2)?;
+    let digits = case.digits();
+    for (byte, pair) in data.iter().zip(buffer.chunks_exact_mut(2)) {
+        pair[0] = digits[usize::from(byte >> 4)];
+        pair[1] = digits[usize::from(byte & 0x0F)];
+    }
+    std::str::from_utf8(buffer).ok()
+}
+
+/// Writes the bytes in the hexadecimal representation to the writer.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::hex::{self, Case};
+///
+/// let mut string = String::from("0x");
+/// hex::write(&mut string, &[0xBE, 0xEF], Case::Upper).unwrap();
+/// assert_eq!(string, "0xBEEF");
+/// ```
+pub fn write(writer: &mut impl Write, data: &[u8], case: Case) -> fmt::Result {
+    let digits = case.digits();
+    for byte in data {
+        writer.write_char(char::from(digits[usize::from(byte >> 4)]))?;
+        writer.write_char(char::from(digits[usize::from(byte & 0x0F)]))?;
+    }
+    Ok(())
+}
+
+/// Returns `true` if the string is the hexadecimal representation of the bytes in any case, without the prefix.
+///
+/// This is the comparison used by the `PartialEq<str>` implementations of digests.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::hex;
+///
+/// assert!(hex::matches(&[0xBE, 0xEF], "BeEf"));
+/// assert!(!hex::matches(&[0xBE, 0xEF], "0xbeef"));
+/// ```
+#[must_use]
+pub fn matches(data: &[u8], hex: &str) -> bool {
+    hex.len() == data.len() * 2
+        && hex.as_bytes().chunks_exact(2).zip(data).all(|(pair, byte)| {
+            pair.iter().all(u8::is_ascii_hexdigit) && (value(pair[0]) << 4 | value(pair[1])) == *byte
+        })
+}
+
+/// Returns the byte offset and the digits of the string without the optional `0x` prefix.
+fn strip_prefix(hex: &str) -> (usize, &str) {
+    match hex.strip_prefix("0x").or_else(|| hex.strip_prefix("0X")) {
+        Some(digits) => (2, digits),
+        None => (0, hex),
+    }
+}
+
+/// Validates the digits and returns their number.
+fn length(offset: usize, digits: &str) -> Result<usize, FromHexError> {
+    if let Some((index, character)) = digits
+        .char_indices()
+        .find(|(_, character)| !character.is_ascii_hexdigit())
+    {
+        return Err(FromHexError::InvalidCharacter {
+            character,
+            index: offset + index,
+        });
+    }
+    // All characters are ASCII, so the number of bytes is the number of digits.
+    let length = digits.len();
+    if length % 2 == 0 {
+        Ok(length)
+    } else {
+        Err(FromHexError::InvalidLength { length })
+    }
+}
+
+/// Decodes the validated digits into the buffer of the matching length.
+fn decode_digits(digits: &str, buffer: &mut [u8]) {
+    for (byte, pair) in buffer.iter_mut().zip(digits.as_bytes().chunks_exact(2)) {
+        *byte = (value(pair[0]) << 4) | value(pair[1]);
+    }
+}
+
+/// Returns the value of the validated hexadecimal digit.
+const fn value(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        _ => digit - b'A' + 10,
+    }
+}
+
+/// A case of the hexadecimal digits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Case {
+    /// Lowercase digits, e.g. `ab`.
+    #[default]
+    Lower,
+    /// Uppercase digits, e.g. `AB`.
+    Upper,
+}
+
+impl Case {
+    /// Returns the digits of the case.
+    const fn digits(self) -> &'static [u8; 16] {
+        match self {
+            Self::Lower => b"0123456789abcdef",
+            Self::Upper => b"0123456789ABCDEF",
+        }
+    }
+}
+
+/// A builder of the hexadecimal representation with grouped digits.
+///
+/// By default every byte is a group and the groups are separated by colons with lowercase digits, e.g. `ab:cd:ef`. The representation is produced by the [`Display`] implementation.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::hex::{Case, Grouped};
+///
+/// let data = [0xAB, 0xCD, 0xEF, 0x01, 0x23];
+/// assert_eq!(Grouped::new(&data).to_string(), "ab:cd:ef:01:23");
+/// assert_eq!(
+///     Grouped::new(&data)
+///         .separator(' ')
+///         .group_length(2)
+///         .case(Case::Upper)
+///         .to_string(),
+///     "ABCD EF01 23"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Grouped<'a> {
+    data: &'a [u8],
+    separator: char,
+    group_length: usize,
+    case: Case,
+}
+
+impl<'a> Grouped<'a> {
+    /// Creates a new builder of the given data with the default layout.
+    #[must_use]
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            separator: ':',
+            group_length: 1,
+            case: Case::Lower,
+        }
+    }
+
+    /// Sets the separator of the groups.
+    #[must_use]
+    pub const fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the length of the groups in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is zero.
+    #[must_use]
+    pub const fn group_length(mut self, group_length: usize) -> Self {
+        assert!(group_length > 0, "group length must not be zero");
+        self.group_length = group_length;
+        self
+    }
+
+    /// Sets the length of the groups in bytes, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length is zero.
+    pub const fn try_group_length(self, group_length: usize) -> Result<Self, ParameterError> {
+        if group_length > 0 {
+            Ok(self.group_length(group_length))
+        } else {
+            Err(ParameterError {
+                parameter: "group length",
+            })
+        }
+    }
+
+    /// Sets the case of the digits.
+    #[must_use]
+    pub const fn case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+}
+
+impl Display for Grouped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, group) in self.data.chunks(self.group_length).enumerate() {
+            if index > 0 {
+                f.write_char(self.separator)?;
+            }
+            write(f, group, self.case)?;
+        }
+        Ok(())
+    }
+}
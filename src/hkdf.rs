@@ -0,0 +1,417 @@
+//! This module is optional and can be enabled using the `hkdf` Cargo feature.
+//!
+//! An implementation of the HMAC-based extract-and-expand key derivation function (HKDF).
+//!
+//! HKDF, defined in [RFC 5869](https://www.rfc-editor.org/rfc/rfc5869), derives keying material in two steps: [`extract`] concentrates the entropy of the input keying material into a pseudorandom key, and [`expand`] stretches the pseudorandom key into output keying material of the requested length. Both steps are built on [`hmac`](crate::hmac) and they are generic over the same [`Algorithm`] trait.
+//!
+//! # Key Derivation
+//!
+//! Both steps can be performed at once with the [`derive`] function.
+//!
+//! ```rust
+//! use chksum_hash::{hkdf, sha2_256};
+//!
+//! let okm = hkdf::derive::<sha2_256::Update>("salt", "example data", "info", 32);
+//! let okm = okm
+//!     .iter()
+//!     .map(|byte| format!("{byte:02x}"))
+//!     .collect::<String>();
+//! assert_eq!(
+//!     okm,
+//!     "25eb41d8560b395a7572cfe77534ef488dae285f8d876dca78fb62bc2360a7c9"
+//! );
+//! ```
+//!
+//! # Extract and Expand
+//!
+//! Protocols that derive several keys from one secret, like TLS 1.3 or Noise, call the steps separately and reuse the pseudorandom key.
+//!
+//! ```rust
+//! use chksum_hash::{hkdf, sha2_256};
+//!
+//! let prk = hkdf::extract::<sha2_256::Update>("salt", "example data");
+//! assert_eq!(
+//!     prk.to_hex_lowercase(),
+//!     "98182f19e60855899b4d28baf2168729757ed5db8300ef71d3738109cfafd714"
+//! );
+//!
+//! let okm = hkdf::expand::<sha2_256::Update>(prk, "info", 32);
+//! let okm = okm
+//!     .iter()
+//!     .map(|byte| format!("{byte:02x}"))
+//!     .collect::<String>();
+//! assert_eq!(
+//!     okm,
+//!     "25eb41d8560b395a7572cfe77534ef488dae285f8d876dca78fb62bc2360a7c9"
+//! );
+//! ```
+//!
+//! # TLS 1.3
+//!
+//! The key schedule of TLS 1.3 and QUIC expands secrets with encoded [`Label`]s, which is provided by the [`expand_label`] function.
+//!
+//! ```rust
+//! use chksum_hash::{hkdf, sha2_256};
+//!
+//! // The QUIC version 1 client initial secret from RFC 9001
+//! let secret = [
+//!     0xC0, 0x0C, 0xF1, 0x51, 0xCA, 0x5B, 0xE0, 0x75, 0xED, 0x0E, 0xBF, 0xB5, 0xC8, 0x03, 0x23,
+//!     0xC4, 0x2D, 0x6B, 0x7D, 0xB6, 0x78, 0x81, 0x28, 0x9A, 0xF4, 0x00, 0x8F, 0x1F, 0x6C, 0x35,
+//!     0x7A, 0xEA,
+//! ];
+//! let key = hkdf::expand_label::<sha2_256::Update>(secret, "quic key", "", 16);
+//! let key = key
+//!     .iter()
+//!     .map(|byte| format!("{byte:02x}"))
+//!     .collect::<String>();
+//! assert_eq!(key, "1f369613dd76d5467730efcbe3b1a22d");
+//! ```
+
+use crate::algorithm::Algorithm;
+use crate::error::{Error, LengthError, ParameterError};
+use crate::hmac;
+
+/// Extracts a pseudorandom key from the input keying material.
+///
+/// An empty salt is equivalent to the salt of zeros with the length of the digest, as specified by the RFC.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{hkdf, sha1};
+///
+/// let salt = [
+///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C,
+/// ];
+/// let ikm = [0x0B; 11];
+/// let prk = hkdf::extract::<sha1::Update>(salt, ikm);
+/// assert_eq!(
+///     prk.to_hex_lowercase(),
+///     "9b6c18c432a7bf8f0e71c8eb88f4b30baa2ba243"
+/// );
+/// ```
+pub fn extract<H>(salt: impl AsRef<[u8]>, ikm: impl AsRef<[u8]>) -> H::Digest
+where
+    H: Algorithm,
+{
+    hmac::hash::<H>(salt, ikm)
+}
+
+/// Expands the pseudorandom key into output keying material of the given length.
+///
+/// # Panics
+///
+/// Panics if the length exceeds 255 times the digest length of the hash function.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{hkdf, sha1};
+///
+/// let prk = [
+///     0x9B, 0x6C, 0x18, 0xC4, 0x32, 0xA7, 0xBF, 0x8F, 0x0E, 0x71, 0xC8, 0xEB, 0x88, 0xF4, 0xB3,
+///     0x0B, 0xAA, 0x2B, 0xA2, 0x43,
+/// ];
+/// let info = [0xF0, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8, 0xF9];
+/// let okm = hkdf::expand::<sha1::Update>(prk, info, 42);
+/// let okm = okm
+///     .iter()
+///     .map(|byte| format!("{byte:02x}"))
+///     .collect::<String>();
+/// assert_eq!(
+///     okm,
+///     "085a01ea1b10f36933068b56efa5ad81a4f14b822f5b091568a9cdd4f155fda2c22e422478d305f3f896"
+/// );
+/// ```
+#[must_use]
+pub fn expand<H>(prk: impl AsRef<[u8]>, info: impl AsRef<[u8]>, length: usize) -> Vec<u8>
+where
+    H: Algorithm,
+{
+    try_expand::<H>(prk, info, length).expect("output length should not exceed 255 digests")
+}
+
+/// Expands the pseudorandom key into output keying material of the given length, returning an error instead of panicking.
+///
+/// # Errors
+///
+/// Returns [`LengthError`] if the length exceeds 255 times the digest length of the hash function.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::error::LengthError;
+/// use chksum_hash::{hkdf, sha1};
+///
+/// let prk = hkdf::extract::<sha1::Update>("salt", "secret");
+/// assert_eq!(
+///     hkdf::try_expand::<sha1::Update>(&prk, "info", 255 * 20)
+///         .unwrap()
+///         .len(),
+///     5100
+/// );
+/// assert_eq!(
+///     hkdf::try_expand::<sha1::Update>(&prk, "info", 255 * 20 + 1),
+///     Err(LengthError { length: 5101 })
+/// );
+/// ```
+pub fn try_expand<H>(prk: impl AsRef<[u8]>, info: impl AsRef<[u8]>, length: usize) -> Result<Vec<u8>, LengthError>
+where
+    H: Algorithm,
+{
+    let info = info.as_ref();
+    let keyed = hmac::new::<H>(prk);
+
+    let mut okm = Vec::with_capacity(length);
+    let mut previous: Option<H::Digest> = None;
+    let mut counter = 0u8;
+    while okm.len() < length {
+        counter = counter.checked_add(1).ok_or(LengthError { length })?;
+        let mut hmac = keyed.clone();
+        if let Some(previous) = previous {
+            hmac.update(previous);
+        }
+        hmac.update(info).update([counter]);
+        let digest = hmac.digest();
+        let missing = length - okm.len();
+        let digest_bytes = digest.as_ref();
+        okm.extend_from_slice(&digest_bytes[..missing.min(digest_bytes.len())]);
+        previous = Some(digest);
+    }
+    Ok(okm)
+}
+
+/// Extracts a pseudorandom key from the input keying material and expands it into output keying material of the given length.
+///
+/// # Panics
+///
+/// Panics if the length exceeds 255 times the digest length of the hash function.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{hkdf, sha2_256};
+///
+/// let salt = [
+///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C,
+/// ];
+/// let ikm = [0x0B; 22];
+/// let info = [0xF0, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8, 0xF9];
+/// let okm = hkdf::derive::<sha2_256::Update>(salt, ikm, info, 42);
+/// let okm = okm
+///     .iter()
+///     .map(|byte| format!("{byte:02x}"))
+///     .collect::<String>();
+/// assert_eq!(
+///     okm,
+///     "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+/// );
+/// ```
+#[must_use]
+pub fn derive<H>(salt: impl AsRef<[u8]>, ikm: impl AsRef<[u8]>, info: impl AsRef<[u8]>, length: usize) -> Vec<u8>
+where
+    H: Algorithm,
+{
+    let prk = extract::<H>(salt, ikm);
+    expand::<H>(prk, info, length)
+}
+
+/// Extracts a pseudorandom key from the input keying material and expands it into output keying material of the given length, returning an error instead of panicking.
+///
+/// # Errors
+///
+/// Returns [`LengthError`] if the length exceeds 255 times the digest length of the hash function.
+pub fn try_derive<H>(
+    salt: impl AsRef<[u8]>,
+    ikm: impl AsRef<[u8]>,
+    info: impl AsRef<[u8]>,
+    length: usize,
+) -> Result<Vec<u8>, LengthError>
+where
+    H: Algorithm,
+{
+    let prk = extract::<H>(salt, ikm);
+    try_expand::<H>(prk, info, length)
+}
+
+/// Prefix of the labels used by TLS 1.3.
+pub const LABEL_PREFIX: &[u8] = b"tls13 ";
+
+/// The `HkdfLabel` structure used by TLS 1.3 and QUIC, defined in [RFC 8446](https://www.rfc-editor.org/rfc/rfc8446#section-7.1).
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::hkdf;
+///
+/// let label = hkdf::Label::new(b"quic key", b"", 16);
+/// assert_eq!(label.to_bytes(), b"\x00\x10\x0etls13 quic key\x00");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Label<'a> {
+    length: u16,
+    label: &'a [u8],
+    context: &'a [u8],
+}
+
+impl<'a> Label<'a> {
+    /// Creates a new label without the `"tls13 "` prefix, which is added during the encoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the label is longer than 249 bytes or the context is longer than 255 bytes.
+    #[must_use]
+    pub const fn new(label: &'a [u8], context: &'a [u8], length: u16) -> Self {
+        assert!(
+            label.len() <= 255 - LABEL_PREFIX.len(),
+            "label must not be longer than 249 bytes"
+        );
+        assert!(context.len() <= 255, "context must not be longer than 255 bytes");
+        Self { length, label, context }
+    }
+
+    /// Creates a new label without the `"tls13 "` prefix, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParameterError`] if the label is longer than 249 bytes or the context is longer than 255 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::error::ParameterError;
+    /// use chksum_hash::hkdf;
+    ///
+    /// assert!(hkdf::Label::try_new(b"quic key", b"", 16).is_ok());
+    /// assert_eq!(
+    ///     hkdf::Label::try_new(&[0u8; 250], b"", 16),
+    ///     Err(ParameterError { parameter: "label" })
+    /// );
+    /// ```
+    pub const fn try_new(label: &'a [u8], context: &'a [u8], length: u16) -> Result<Self, ParameterError> {
+        if label.len() > 255 - LABEL_PREFIX.len() {
+            Err(ParameterError { parameter: "label" })
+        } else if context.len() > 255 {
+            Err(ParameterError { parameter: "context" })
+        } else {
+            Ok(Self { length, label, context })
+        }
+    }
+
+    /// Returns the length of the output keying material.
+    #[must_use]
+    pub const fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Returns the label without the prefix.
+    #[must_use]
+    pub const fn label(&self) -> &'a [u8] {
+        self.label
+    }
+
+    /// Returns the context.
+    #[must_use]
+    pub const fn context(&self) -> &'a [u8] {
+        self.context
+    }
+
+    /// Encodes the label as used in the `info` parameter of [`expand`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let label_length = LABEL_PREFIX.len() + self.label.len();
+        let mut bytes = Vec::with_capacity(4 + label_length + self.context.len());
+        bytes.extend_from_slice(&self.length.to_be_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.push(label_length as u8);
+        bytes.extend_from_slice(LABEL_PREFIX);
+        bytes.extend_from_slice(self.label);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.push(self.context.len() as u8);
+        bytes.extend_from_slice(self.context);
+        bytes
+    }
+}
+
+/// Expands the secret with the encoded [`Label`], as specified by the `HKDF-Expand-Label` function of TLS 1.3.
+///
+/// # Panics
+///
+/// Panics if the label is longer than 249 bytes, the context is longer than 255 bytes or the length exceeds 255 times the digest length of the hash function.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{hkdf, sha2_256};
+///
+/// // The early secret and the derived secret of the TLS 1.3 key schedule without a pre-shared key
+/// let early = hkdf::extract::<sha2_256::Update>([0x00; 32], [0x00; 32]);
+/// assert_eq!(
+///     early.to_hex_lowercase(),
+///     "33ad0a1c607ec03b09e6cd9893680ce210adf300aa1f2660e1b22e10f170f92a"
+/// );
+///
+/// let transcript = sha2_256::hash("");
+/// let derived = hkdf::expand_label::<sha2_256::Update>(early, "derived", transcript, 32);
+/// let derived = derived
+///     .iter()
+///     .map(|byte| format!("{byte:02x}"))
+///     .collect::<String>();
+/// assert_eq!(
+///     derived,
+///     "6f2615a108c702c5678f54fc9dbab69716c076189c48250cebeac3576c3611ba"
+/// );
+/// ```
+#[must_use]
+pub fn expand_label<H>(
+    secret: impl AsRef<[u8]>,
+    label: impl AsRef<[u8]>,
+    context: impl AsRef<[u8]>,
+    length: u16,
+) -> Vec<u8>
+where
+    H: Algorithm,
+{
+    let info = Label::new(label.as_ref(), context.as_ref(), length).to_bytes();
+    expand::<H>(secret, info, usize::from(length))
+}
+
+/// Expands the secret with the encoded [`Label`], returning an error instead of panicking.
+///
+/// # Errors
+///
+/// Returns [`Error::Parameter`] if the label is longer than 249 bytes or the context is longer than 255 bytes, and [`Error::Length`] if the length exceeds 255 times the digest length of the hash function.
+pub fn try_expand_label<H>(
+    secret: impl AsRef<[u8]>,
+    label: impl AsRef<[u8]>,
+    context: impl AsRef<[u8]>,
+    length: u16,
+) -> Result<Vec<u8>, Error>
+where
+    H: Algorithm,
+{
+    let info = Label::try_new(label.as_ref(), context.as_ref(), length)?.to_bytes();
+    let okm = try_expand::<H>(secret, info, usize::from(length))?;
+    Ok(okm)
+}
+
+#[cfg(test)]
+mod tests {
+    // Test case 1 from RFC 5869, appendix A.
+    #[cfg(feature = "sha2-256")]
+    #[test]
+    fn rfc_5869() {
+        let salt = std::array::from_fn::<u8, 13, _>(|i| i as u8);
+        let info = std::array::from_fn::<u8, 10, _>(|i| 0xF0 + i as u8);
+        let prk = super::extract::<crate::sha2_256::Update>(salt, [0x0B; 22]);
+        assert_eq!(
+            prk.to_hex_lowercase(),
+            "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"
+        );
+        let okm = super::expand::<crate::sha2_256::Update>(prk, info, 42);
+        assert_eq!(
+            okm.iter().map(|byte| format!("{byte:02x}")).collect::<String>(),
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+        );
+    }
+}
@@ -0,0 +1,319 @@
+//! This module is optional and can be enabled using the `hmac` Cargo feature.
+//!
+//! An implementation of the keyed-hash message authentication code (HMAC).
+//!
+//! HMAC, defined in [RFC 2104](https://www.rfc-editor.org/rfc/rfc2104), turns any iterated hash function into a message authentication code. It is generic over the [`Algorithm`] trait, which is implemented by the `Update` types of the block-based hash functions of this crate, and it produces the digest type of the chosen hash function.
+//!
+//! # Batch Processing
+//!
+//! The authentication code of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::{hmac, sha2_256};
+//!
+//! let digest =
+//!     hmac::hash::<sha2_256::Update>("key", "The quick brown fox jumps over the lazy dog");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The authentication code of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`new`] function.
+//!
+//! ```rust
+//! use chksum_hash::{hmac, sha2_256};
+//!
+//! let digest = hmac::new::<sha2_256::Update>("key")
+//!     .update("The quick brown fox ")
+//!     .update(b"jumps over the lazy dog")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+//! );
+//! ```
+//!
+//! # Keys
+//!
+//! Keys of any length are accepted. Keys longer than the block length of the hash function are hashed first, shorter keys are padded with zeros.
+//!
+//! # Disclaimer
+//!
+//! Digests are compared with the `==` operator, which is not constant-time. Use a constant-time comparison, like [`verify::constant_time_eq`](crate::verify::constant_time_eq), when verifying authentication codes received from untrusted parties.
+
+use chksum_hash_core as core;
+
+use crate::algorithm::Algorithm;
+
+/// Creates a new HMAC with the given key.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{hmac, md5};
+///
+/// let digest = hmac::new::<md5::Update>("key")
+///     .update("The quick brown fox jumps over the lazy dog")
+///     .digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "80070713463e7749b90c2dc24911e275"
+/// );
+/// ```
+#[must_use]
+pub fn new<H>(key: impl AsRef<[u8]>) -> Update<H>
+where
+    H: Algorithm,
+{
+    Update::new(key)
+}
+
+/// Computes the HMAC of the given input with the given key.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{hmac, sha1};
+///
+/// let digest = hmac::hash::<sha1::Update>("key", "The quick brown fox jumps over the lazy dog");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9"
+/// );
+/// ```
+pub fn hash<H>(key: impl AsRef<[u8]>, data: impl AsRef<[u8]>) -> H::Digest
+where
+    H: Algorithm,
+{
+    let mut hmac = new::<H>(key);
+    hmac.update(data);
+    hmac.digest()
+}
+
+/// An HMAC state that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{hmac, sha2_512};
+///
+/// // Create a new HMAC instance
+/// let mut hmac = hmac::Update::<sha2_512::Update>::new("key");
+///
+/// // Fill with data
+/// hmac.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hmac.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "3c5953a18f7303ec653ba170ae334fafa08e3846f2efe317b87efce82376253cb52a8c31ddcde5a3a2eee183c2b34cb91f85e64ddbc325f7692b199473579c58"
+/// );
+///
+/// // Reset to the keyed state
+/// hmac.reset();
+///
+/// // Produce a digest using internal finalization
+/// let digest = hmac.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "84fa5aa0279bbc473267d05a53ea03310a987cecc4c1535ff29b6d76b8f1444a728df3aadb89d4a9a6709e1998f373566e8f824a8ca93b1821f0b69bc2a2f65e"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update<H>
+where
+    H: Algorithm,
+{
+    inner: H,
+    keyed_inner: H,
+    keyed_outer: H,
+}
+
+impl<H> Update<H>
+where
+    H: Algorithm,
+{
+    /// Creates a new HMAC with the given key.
+    #[must_use]
+    pub fn new(key: impl AsRef<[u8]>) -> Self {
+        let key = key.as_ref();
+
+        // Normalize the key to exactly one block.
+        let mut block = vec![0x00; H::BLOCK_LENGTH_BYTES];
+        if key.len() > H::BLOCK_LENGTH_BYTES {
+            let digest = core::hash::<H>(key);
+            let digest = digest.as_ref();
+            block[..digest.len()].copy_from_slice(digest);
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut keyed_inner = H::default();
+        keyed_inner.update(block.iter().map(|byte| byte ^ 0x36).collect::<Vec<_>>());
+        let mut keyed_outer = H::default();
+        keyed_outer.update(block.iter().map(|byte| byte ^ 0x5C).collect::<Vec<_>>());
+        let inner = keyed_inner.clone();
+        Self {
+            inner,
+            keyed_inner,
+            keyed_outer,
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// The data is passed to the inner hash, see its documentation for performance notes.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.update(data);
+        self
+    }
+
+    /// Processes the inner hash and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize<H> {
+        let digest = self.inner.digest();
+        let mut outer = self.keyed_outer.clone();
+        outer.update(digest);
+        Finalize {
+            outer,
+            keyed_inner: self.keyed_inner.clone(),
+            keyed_outer: self.keyed_outer.clone(),
+        }
+    }
+
+    /// Resets the internal state to the keyed state.
+    ///
+    /// The key is kept, so the next authentication code can be calculated without processing the key again.
+    pub fn reset(&mut self) -> &mut Self {
+        self.inner = self.keyed_inner.clone();
+        self
+    }
+
+    /// Produces the authentication code using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> H::Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the authentication code and resets the internal state to the keyed state.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> H::Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, H> arbitrary::Arbitrary<'a> for Update<H>
+where
+    H: Algorithm + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // The keyed states are derived from an arbitrary key, only the in-progress inner hash is arbitrary.
+        let mut update = Self::new(<&[u8]>::arbitrary(u)?);
+        update.inner = u.arbitrary()?;
+        Ok(update)
+    }
+}
+
+/// A finalized HMAC state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{hmac, sha2_512};
+///
+/// // Create a new HMAC instance
+/// let mut hmac = hmac::Update::<sha2_512::Update>::new("key");
+///
+/// // Fill with data
+/// hmac.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hmac.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "3926a207c8c42b0c41792cbd3e1a1aaaf5f7a25704f62dfc939c4987dd7ce060009c5bb1c2447355b3216f10b537e9afa7b64a4e5391b0d631172d07939e087a"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize<H>
+where
+    H: Algorithm,
+{
+    outer: H,
+    keyed_inner: H,
+    keyed_outer: H,
+}
+
+impl<H> Finalize<H>
+where
+    H: Algorithm,
+{
+    /// Creates and returns the authentication code.
+    #[must_use]
+    pub fn digest(&self) -> H::Digest {
+        self.outer.digest()
+    }
+
+    /// Resets the HMAC state to the keyed in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update<H> {
+        Update {
+            inner: self.keyed_inner.clone(),
+            keyed_inner: self.keyed_inner.clone(),
+            keyed_outer: self.keyed_outer.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Test case 2 from RFC 2202.
+    #[cfg(feature = "md5")]
+    #[test]
+    fn rfc_2202_md5() {
+        assert_eq!(
+            super::hash::<crate::md5::Update>("Jefe", "what do ya want for nothing?").to_hex_lowercase(),
+            "750c783e6ab0b503eaa86e310a5db738"
+        );
+    }
+
+    // Test case 2 from RFC 2202.
+    #[cfg(feature = "sha1")]
+    #[test]
+    fn rfc_2202_sha1() {
+        assert_eq!(
+            super::hash::<crate::sha1::Update>("Jefe", "what do ya want for nothing?").to_hex_lowercase(),
+            "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"
+        );
+    }
+
+    // Test case 2 from RFC 4231.
+    #[cfg(feature = "sha2-256")]
+    #[test]
+    fn rfc_4231_sha2_256() {
+        assert_eq!(
+            super::hash::<crate::sha2_256::Update>("Jefe", "what do ya want for nothing?").to_hex_lowercase(),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    // Test case 2 from RFC 4231.
+    #[cfg(feature = "sha2-512")]
+    #[test]
+    fn rfc_4231_sha2_512() {
+        assert_eq!(
+            super::hash::<crate::sha2_512::Update>("Jefe", "what do ya want for nothing?").to_hex_lowercase(),
+            "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"
+        );
+    }
+}
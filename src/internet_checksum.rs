@@ -0,0 +1,344 @@
+//! This module is optional and can be enabled using the `internet-checksum` Cargo feature.
+//!
+//! An implementation of the Internet checksum.
+//!
+//! The Internet checksum, specified in [RFC 1071](https://www.rfc-editor.org/rfc/rfc1071), is the 16-bit ones' complement of the ones' complement sum of the data taken as big-endian 16-bit words. It is used by the IPv4, ICMP, UDP and TCP headers; an odd-length input is padded with a zero byte.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::internet_checksum;
+//!
+//! let digest = internet_checksum::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "8aca");
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::internet_checksum;
+//!
+//! let digest = internet_checksum::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "8aca");
+//! ```
+//!
+//! # Verification
+//!
+//! The checksum of data that already contains its valid checksum is zero.
+//!
+//! ```rust
+//! use chksum_hash::internet_checksum;
+//!
+//! let mut header = [
+//!     0x45, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00, 0x7F, 0x00, 0x00,
+//!     0x01, 0x7F, 0x00, 0x00, 0x01,
+//! ];
+//! let checksum = internet_checksum::hash(header);
+//! header[10..12].copy_from_slice(checksum.as_ref());
+//! assert_eq!(u16::from(internet_checksum::hash(header)), 0x0000);
+//! ```
+//!
+//! # Incremental Update
+//!
+//! When a single 16-bit word of the data changes, e.g. the TTL field of an IPv4 header, the checksum can be adjusted with the [`checksum_update`] function instead of being calculated again.
+//!
+//! ```rust
+//! use chksum_hash::internet_checksum;
+//!
+//! let header = [
+//!     0x45, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00, 0x7F, 0x00, 0x00,
+//!     0x01, 0x7F, 0x00, 0x00, 0x01,
+//! ];
+//! let checksum = u16::from(internet_checksum::hash(header));
+//!
+//! // Decrement the TTL
+//! let mut updated = header;
+//! updated[8] -= 1;
+//! let old = u16::from_be_bytes([header[8], header[9]]);
+//! let new = u16::from_be_bytes([updated[8], updated[9]]);
+//! assert_eq!(
+//!     internet_checksum::checksum_update(checksum, old, new),
+//!     u16::from(internet_checksum::hash(updated))
+//! );
+//! ```
+//!
+//! # Disclaimer
+//!
+//! The Internet checksum is a weak error-detecting code, not a cryptographic hash function. It misses many kinds of accidental corruption, such as reordered 16-bit words, and must not be used to protect against intentional modification.
+
+pub mod digest;
+pub mod state;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+use crate::verify::Expected;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::internet_checksum;
+///
+/// let digest = internet_checksum::new().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "ffff");
+///
+/// let digest = internet_checksum::new().update("data").digest();
+/// assert_eq!(digest.to_hex_lowercase(), "273d");
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::internet_checksum;
+///
+/// let digest = internet_checksum::default().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "ffff");
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::internet_checksum;
+///
+/// let digest = internet_checksum::hash("abc");
+/// assert_eq!(digest.to_hex_lowercase(), "3b9d");
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::internet_checksum;
+///
+/// let digest = internet_checksum::hash("data");
+/// assert!(internet_checksum::verify("data", digest));
+/// assert!(internet_checksum::verify("data", digest.to_hex_uppercase()));
+/// assert!(!internet_checksum::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// Adjusts a checksum after a 16-bit word of the data has changed.
+///
+/// This is the incremental update specified in [RFC 1624](https://www.rfc-editor.org/rfc/rfc1624), which, unlike the one of RFC 1141, is correct for all values.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::internet_checksum;
+///
+/// let checksum = u16::from(internet_checksum::hash([0x00, 0x01, 0xF2, 0x03]));
+/// let checksum = internet_checksum::checksum_update(checksum, 0x0001, 0x0002);
+/// assert_eq!(
+///     checksum,
+///     u16::from(internet_checksum::hash([0x00, 0x02, 0xF2, 0x03]))
+/// );
+/// ```
+#[must_use]
+pub const fn checksum_update(checksum: u16, old: u16, new: u16) -> u16 {
+    // HC' = ~(~HC + ~m + m')
+    let sum = (!checksum) as u32 + (!old) as u32 + new as u32;
+    !state::fold(sum as u64)
+}
+
+/// A checksum state that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::internet_checksum;
+///
+/// // Create a new hash instance
+/// let mut hash = internet_checksum::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "273d");
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(digest.to_hex_lowercase(), "ffff");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    state: State,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        let state = state::new();
+        Self { state }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// The data is processed immediately, no internal buffer is used.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.state = self.state.update(data.as_ref());
+        self
+    }
+
+    /// Produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let state = self.state;
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized checksum state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::internet_checksum;
+///
+/// // Create a new hash instance
+/// let mut hash = internet_checksum::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "3b9d");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the checksum digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        Digest::new(self.state.digest().to_be_bytes())
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        *out = self.state.digest().to_be_bytes();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::new()
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example from RFC 1071, section 3.
+    #[test]
+    fn rfc_1071() {
+        assert_eq!(
+            hash([0x00u8, 0x01, 0xF2, 0x03, 0xF4, 0xF5, 0xF6, 0xF7]).to_hex_lowercase(),
+            "220d"
+        );
+    }
+}
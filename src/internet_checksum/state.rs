@@ -0,0 +1,105 @@
+//! Module contains items related to the [`State`] structure which allows to the direct Internet checksum state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::internet_checksum;
+//!
+//! // Create new state
+//! let mut state = internet_checksum::state::default();
+//!
+//! // By default it returns the checksum of empty input
+//! assert_eq!(state.digest(), 0xFFFF);
+//!
+//! // Update state with any amount of data and own new value
+//! state = state.update(b"example data");
+//! assert_eq!(state.digest(), 0x8ACA);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state.digest(), 0xFFFF);
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level checksum state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) sum: u64,
+    pub(crate) odd: bool,
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(0, false)
+    }
+
+    /// Creates a state from the raw sum and whether an odd number of bytes has been processed, e.g. a custom initial sum.
+    #[must_use]
+    pub const fn from_raw(sum: u64, odd: bool) -> Self {
+        Self { sum, odd }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// Unlike hash functions, the data does not need to be split into blocks, and it may even be split in the middle of a 16-bit word.
+    #[must_use]
+    pub const fn update(&self, data: &[u8]) -> Self {
+        let (mut sum, mut odd) = (self.sum, self.odd);
+        let mut i = 0;
+        while i < data.len() {
+            // Bytes at even offsets are the high bytes of the words.
+            sum += if odd { data[i] as u64 } else { (data[i] as u64) << 8 };
+            odd = !odd;
+            i += 1;
+        }
+        // The carries are added back to keep the sum small, which does not change its ones' complement value.
+        Self::from_raw((sum & 0xFFFF_FFFF) + (sum >> 32), odd)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns a checksum.
+    #[must_use]
+    pub const fn digest(&self) -> u16 {
+        !fold(self.sum)
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Folds the sum into 16 bits with the end-around carry.
+pub(crate) const fn fold(mut sum: u64) -> u16 {
+    while sum > 0xFFFF {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+    sum as u16
+}
@@ -0,0 +1,322 @@
+//! Module containing items related to hashing of readers and writers.
+//!
+//! The [`hash_reader`] and [`hash_path`] functions compute the digest of a whole reader or file with a block-aligned buffer, and their `_with_progress` variants report the number of hashed bytes to a callback, e.g. to drive a progress bar or to cancel the hashing. The [`hash_copy`] function additionally copies the data to a writer, e.g. when downloading or installing a file.
+//!
+//! A [`HashReader`] wraps a reader and hashes the bytes as they are read, so the digest of a stream is computed while it is consumed, without a manual read loop.
+//!
+//! With the `futures` Cargo feature, the `AsyncHashReader` and `AsyncHashWriter` adapters do the same for the asynchronous reader and writer traits of the futures crate, used e.g. by async-std and smol, and the `hash_stream` function computes the digest of a stream of byte chunks, e.g. an HTTP body.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::io::{self, Read};
+//!
+//! use chksum_hash::io::HashReader;
+//! use chksum_hash::sha2_256;
+//!
+//! let mut reader = HashReader::<_, sha2_256::Update>::new(&b"example data"[..]);
+//! let mut contents = String::new();
+//! reader.read_to_string(&mut contents).unwrap();
+//! assert_eq!(contents, "example data");
+//! assert_eq!(
+//!     reader.digest().to_hex_lowercase(),
+//!     "44752f37272e944fd2c913a35342eaccdd1aaf189bae50676b301ab213fc5061"
+//! );
+//! ```
+
+#[cfg(feature = "futures")]
+mod futures;
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use chksum_hash_core as core;
+
+#[cfg(feature = "futures")]
+pub use self::futures::{hash_stream, AsyncHashReader, AsyncHashWriter, HashStream};
+
+/// Length of the buffer of the reading functions, a multiple of the block lengths of all hash functions.
+const BUFFER_LENGTH_BYTES: usize = 64 * 1024;
+
+/// Computes the digest of the data read from the reader until the end.
+///
+/// Reads are retried when interrupted.
+///
+/// # Errors
+///
+/// Returns an error if reading fails.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{io, sha2_256};
+///
+/// let digest = io::hash_reader::<sha2_256::Update>(&b"example data"[..]).unwrap();
+/// assert_eq!(digest, sha2_256::hash("example data"));
+/// ```
+pub fn hash_reader<A>(reader: impl Read) -> io::Result<A::Digest>
+where
+    A: core::Update,
+{
+    hash_reader_with_progress::<A>(reader, |_| Ok(()))
+}
+
+/// Computes the digest of the data read from the reader until the end, calling the callback with the number of bytes hashed so far after every read.
+///
+/// Reads are retried when interrupted. The callback can drive a progress bar, and it can cancel the hashing by returning an error, which is then returned by this function.
+///
+/// # Errors
+///
+/// Returns an error if reading fails or the callback returns an error.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::{self, ErrorKind};
+///
+/// use chksum_hash::sha2_256;
+///
+/// let data = vec![0u8; 1024 * 1024];
+///
+/// let mut progress = Vec::new();
+/// let digest =
+///     chksum_hash::io::hash_reader_with_progress::<sha2_256::Update>(&data[..], |done| {
+///         progress.push(done);
+///         Ok(())
+///     })
+///     .unwrap();
+/// assert_eq!(digest, sha2_256::hash(&data));
+/// assert_eq!(progress.last(), Some(&(1024 * 1024)));
+///
+/// let result =
+///     chksum_hash::io::hash_reader_with_progress::<sha2_256::Update>(&data[..], |done| {
+///         if done >= 64 * 1024 {
+///             Err(io::Error::new(ErrorKind::Other, "cancelled"))
+///         } else {
+///             Ok(())
+///         }
+///     });
+/// assert_eq!(result.unwrap_err().to_string(), "cancelled");
+/// ```
+pub fn hash_reader_with_progress<A>(
+    mut reader: impl Read,
+    mut progress: impl FnMut(u64) -> io::Result<()>,
+) -> io::Result<A::Digest>
+where
+    A: core::Update,
+{
+    let mut hash = A::default();
+    let mut length = 0u64;
+    let mut buffer = vec![0u8; BUFFER_LENGTH_BYTES];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(count) => {
+                hash.update(&buffer[..count]);
+                length += count as u64;
+                progress(length)?;
+            },
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(hash.digest())
+}
+
+/// Computes the digest of the file at the given path.
+///
+/// # Errors
+///
+/// Returns an error if opening or reading the file fails.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::io;
+/// # use std::path::PathBuf;
+/// use chksum_hash::io::hash_path;
+/// use chksum_hash::sha2_256;
+///
+/// # fn wrapper(path: PathBuf) -> io::Result<()> {
+/// let digest = hash_path::<sha2_256::Update>(path)?;
+/// println!("{digest}");
+/// # Ok(())
+/// # }
+/// ```
+pub fn hash_path<A>(path: impl AsRef<Path>) -> io::Result<A::Digest>
+where
+    A: core::Update,
+{
+    File::open(path).and_then(hash_reader::<A>)
+}
+
+/// Computes the digest of the file at the given path, calling the callback with the number of bytes hashed so far after every read.
+///
+/// See [`hash_reader_with_progress`] for the behavior of the callback.
+///
+/// # Errors
+///
+/// Returns an error if opening or reading the file fails or the callback returns an error.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::io;
+/// # use std::path::PathBuf;
+/// use chksum_hash::io::hash_path_with_progress;
+/// use chksum_hash::sha2_256;
+///
+/// # fn wrapper(path: PathBuf) -> io::Result<()> {
+/// let length = path.metadata()?.len();
+/// let digest = hash_path_with_progress::<sha2_256::Update>(path, |done| {
+///     println!("{done}/{length} bytes");
+///     Ok(())
+/// })?;
+/// println!("{digest}");
+/// # Ok(())
+/// # }
+/// ```
+pub fn hash_path_with_progress<A>(
+    path: impl AsRef<Path>,
+    progress: impl FnMut(u64) -> io::Result<()>,
+) -> io::Result<A::Digest>
+where
+    A: core::Update,
+{
+    let file = File::open(path)?;
+    hash_reader_with_progress::<A>(file, progress)
+}
+
+/// Copies the data read from the reader until the end to the writer and computes its digest, returning the number of copied bytes and the digest.
+///
+/// Reads are retried when interrupted. The data is hashed only after it has been written.
+///
+/// # Errors
+///
+/// Returns an error if reading or writing fails.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{io, sha2_256};
+///
+/// let mut copy = Vec::new();
+/// let (length, digest) =
+///     io::hash_copy::<sha2_256::Update>(&b"example data"[..], &mut copy).unwrap();
+/// assert_eq!(length, 12);
+/// assert_eq!(copy, b"example data");
+/// assert_eq!(digest, sha2_256::hash("example data"));
+/// ```
+pub fn hash_copy<A>(mut reader: impl Read, mut writer: impl Write) -> io::Result<(u64, A::Digest)>
+where
+    A: core::Update,
+{
+    let mut hash = A::default();
+    let mut length = 0u64;
+    let mut buffer = vec![0u8; BUFFER_LENGTH_BYTES];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(count) => {
+                let data = &buffer[..count];
+                writer.write_all(data)?;
+                hash.update(data);
+                length += count as u64;
+            },
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    Ok((length, hash.digest()))
+}
+
+/// A reader that hashes the bytes read from the inner reader.
+///
+/// Only the bytes returned by the [`Read`] implementation are hashed, bytes read through [`get_mut`](Self::get_mut) bypass the hash.
+#[derive(Clone, Debug)]
+pub struct HashReader<R, A> {
+    reader: R,
+    hash: A,
+}
+
+impl<R, A> HashReader<R, A>
+where
+    A: core::Update,
+{
+    /// Creates a new reader with the default hash.
+    #[must_use]
+    pub fn new(reader: R) -> Self {
+        Self::with_hash(reader, A::default())
+    }
+
+    /// Creates a new reader with the given hash, e.g. one that has already consumed a prefix of the stream.
+    #[must_use]
+    pub const fn with_hash(reader: R, hash: A) -> Self {
+        Self { reader, hash }
+    }
+
+    /// Produces the digest of the bytes read so far.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::{self, Read};
+    ///
+    /// use chksum_hash::io::HashReader;
+    /// use chksum_hash::md4;
+    ///
+    /// let mut reader = HashReader::<_, md4::Update>::new(&b"data"[..]);
+    /// assert_eq!(reader.digest(), md4::hash(""));
+    ///
+    /// io::copy(&mut reader, &mut io::sink()).unwrap();
+    /// assert_eq!(reader.digest(), md4::hash("data"));
+    /// ```
+    #[must_use]
+    pub fn digest(&self) -> A::Digest {
+        self.hash.digest()
+    }
+
+    /// Returns a reference to the hash.
+    #[must_use]
+    pub const fn hash(&self) -> &A {
+        &self.hash
+    }
+
+    /// Returns a reference to the inner reader.
+    #[must_use]
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Bytes read directly from the inner reader are not hashed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the inner reader, discarding the hash.
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Returns the inner reader and the hash.
+    #[must_use]
+    pub fn into_parts(self) -> (R, A) {
+        (self.reader, self.hash)
+    }
+}
+
+impl<R, A> Read for HashReader<R, A>
+where
+    R: Read,
+    A: core::Update,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.reader.read(buf)?;
+        self.hash.update(&buf[..count]);
+        Ok(count)
+    }
+}
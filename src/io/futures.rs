@@ -0,0 +1,260 @@
+//! Adapters of the asynchronous reader, writer and stream traits of the futures crate.
+//!
+//! The adapters require the inner reader, writer or stream to be [`Unpin`]. Ones that are not can be pinned with [`Box::pin`] first.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use chksum_hash_core as core;
+use futures_core::Stream;
+use futures_io::{AsyncRead, AsyncWrite};
+
+/// An asynchronous reader that hashes the bytes read from the inner reader.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::io::AsyncHashReader;
+/// use chksum_hash::sha2_256;
+/// use futures::io::AsyncReadExt;
+///
+/// futures::executor::block_on(async {
+///     let mut reader = AsyncHashReader::<_, sha2_256::Update>::new(&b"example data"[..]);
+///     let mut contents = Vec::new();
+///     reader.read_to_end(&mut contents).await.unwrap();
+///     assert_eq!(reader.digest(), sha2_256::hash("example data"));
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct AsyncHashReader<R, A> {
+    reader: R,
+    hash: A,
+}
+
+impl<R, A> AsyncHashReader<R, A>
+where
+    A: core::Update,
+{
+    /// Creates a new reader with the default hash.
+    #[must_use]
+    pub fn new(reader: R) -> Self {
+        Self::with_hash(reader, A::default())
+    }
+
+    /// Creates a new reader with the given hash.
+    #[must_use]
+    pub const fn with_hash(reader: R, hash: A) -> Self {
+        Self { reader, hash }
+    }
+
+    /// Produces the digest of the bytes read so far.
+    #[must_use]
+    pub fn digest(&self) -> A::Digest {
+        self.hash.digest()
+    }
+
+    /// Returns a reference to the hash.
+    #[must_use]
+    pub const fn hash(&self) -> &A {
+        &self.hash
+    }
+
+    /// Returns a reference to the inner reader.
+    #[must_use]
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Bytes read directly from the inner reader are not hashed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the inner reader, discarding the hash.
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Returns the inner reader and the hash.
+    #[must_use]
+    pub fn into_parts(self) -> (R, A) {
+        (self.reader, self.hash)
+    }
+}
+
+impl<R, A> AsyncRead for AsyncHashReader<R, A>
+where
+    R: AsyncRead + Unpin,
+    A: core::Update + Unpin,
+{
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.reader).poll_read(cx, buf);
+        if let Poll::Ready(Ok(count)) = poll {
+            this.hash.update(&buf[..count]);
+        }
+        poll
+    }
+}
+
+/// An asynchronous writer that hashes the bytes written to the inner writer.
+///
+/// Only the bytes accepted by the inner writer are hashed.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::io::AsyncHashWriter;
+/// use chksum_hash::sha2_256;
+/// use futures::io::AsyncWriteExt;
+///
+/// futures::executor::block_on(async {
+///     let mut writer = AsyncHashWriter::<_, sha2_256::Update>::new(Vec::new());
+///     writer.write_all(b"example data").await.unwrap();
+///     assert_eq!(writer.digest(), sha2_256::hash("example data"));
+///     assert_eq!(writer.into_inner(), b"example data");
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct AsyncHashWriter<W, A> {
+    writer: W,
+    hash: A,
+}
+
+impl<W, A> AsyncHashWriter<W, A>
+where
+    A: core::Update,
+{
+    /// Creates a new writer with the default hash.
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self::with_hash(writer, A::default())
+    }
+
+    /// Creates a new writer with the given hash.
+    #[must_use]
+    pub const fn with_hash(writer: W, hash: A) -> Self {
+        Self { writer, hash }
+    }
+
+    /// Produces the digest of the bytes written so far.
+    #[must_use]
+    pub fn digest(&self) -> A::Digest {
+        self.hash.digest()
+    }
+
+    /// Returns a reference to the hash.
+    #[must_use]
+    pub const fn hash(&self) -> &A {
+        &self.hash
+    }
+
+    /// Returns a reference to the inner writer.
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    ///
+    /// Bytes written directly to the inner writer are not hashed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Returns the inner writer, discarding the hash.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Returns the inner writer and the hash.
+    #[must_use]
+    pub fn into_parts(self) -> (W, A) {
+        (self.writer, self.hash)
+    }
+}
+
+impl<W, A> AsyncWrite for AsyncHashWriter<W, A>
+where
+    W: AsyncWrite + Unpin,
+    A: core::Update + Unpin,
+{
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.writer).poll_write(cx, buf);
+        if let Poll::Ready(Ok(count)) = poll {
+            this.hash.update(&buf[..count]);
+        }
+        poll
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_close(cx)
+    }
+}
+
+/// Computes the digest of the chunks of bytes yielded by the stream until the end.
+///
+/// The stream is consumed chunk by chunk, so e.g. the body of an HTTP response is hashed without being collected into memory first. The returned future resolves to the first error yielded by the stream, if any.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io;
+///
+/// use chksum_hash::io::hash_stream;
+/// use chksum_hash::sha2_256;
+///
+/// let chunks = vec![Ok::<_, io::Error>(&b"example "[..]), Ok(&b"data"[..])];
+/// let stream = futures::stream::iter(chunks);
+/// let digest = futures::executor::block_on(hash_stream::<sha2_256::Update, _>(stream)).unwrap();
+/// assert_eq!(digest, sha2_256::hash("example data"));
+/// ```
+pub fn hash_stream<A, S>(stream: S) -> HashStream<S, A>
+where
+    A: core::Update,
+{
+    HashStream {
+        stream,
+        hash: A::default(),
+    }
+}
+
+/// A future that computes the digest of the chunks of bytes yielded by a stream, returned by [`hash_stream`].
+#[derive(Clone, Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct HashStream<S, A> {
+    stream: S,
+    hash: A,
+}
+
+impl<S, A, T, E> Future for HashStream<S, A>
+where
+    S: Stream<Item = Result<T, E>> + Unpin,
+    A: core::Update + Unpin,
+    T: AsRef<[u8]>,
+{
+    type Output = Result<A::Digest, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => this.hash.update(chunk),
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Err(error)),
+                Poll::Ready(None) => return Poll::Ready(Ok(this.hash.digest())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
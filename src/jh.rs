@@ -0,0 +1,168 @@
+//! This module is optional and can be enabled using any of the `jh-224`, `jh-256`, `jh-384` or `jh-512` Cargo features.
+//!
+//! Items shared by the JH hash functions.
+//!
+//! JH is a family of hash functions built on a single 1024-bit permutation. The members differ only in the initial state, which depends on the digest length, and in the number of state bytes returned as the digest. The implementations are available in the [`jh_224`](crate::jh_224), [`jh_256`](crate::jh_256), [`jh_384`](crate::jh_384) and [`jh_512`](crate::jh_512) modules.
+//!
+//! This is JH with 42 rounds, the final version submitted to the SHA-3 competition.
+//!
+//! # Disclaimer
+//!
+//! JH was a SHA-3 finalist but it is not standardized. It is provided for research and interoperability with existing systems; new designs should prefer SHA-2.
+
+pub mod block;
+mod constants;
+pub mod digest;
+pub mod state;
+
+#[doc(inline)]
+pub use self::block::{Block, LENGTH_BYTES as BLOCK_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::digest::Digest;
+#[doc(inline)]
+pub use self::state::State;
+use crate::buffer::Buffer;
+
+/// The message-processing part of JH with an internal buffer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Engine {
+    state: State,
+    unprocessed: Buffer<BLOCK_LENGTH_BYTES>,
+    processed: u128,
+}
+
+impl Engine {
+    /// Creates a new engine for the given digest length in bits.
+    pub(crate) fn new(length: usize) -> Self {
+        let state = state::new(length);
+        let unprocessed = Buffer::new();
+        let processed = 0;
+        Self {
+            state,
+            unprocessed,
+            processed,
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        let mut data = data;
+
+        if !self.unprocessed.is_empty() {
+            let missing = BLOCK_LENGTH_BYTES - self.unprocessed.len();
+            if data.len() < missing {
+                // Not enough data even for one block.
+                self.unprocessed.extend_from_slice(data);
+                return;
+            }
+
+            // Complete the buffered block with the beginning of incoming data.
+            let (fillment, remainder) = data.split_at(missing);
+            self.unprocessed.extend_from_slice(fillment);
+            let block = Block::try_from(&self.unprocessed[..])
+                .expect("unprocessed data length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u128);
+            self.unprocessed.clear();
+            data = remainder;
+        }
+
+        // Internal buffer is empty, incoming data can be processed without buffering.
+        let mut chunks = data.chunks_exact(BLOCK_LENGTH_BYTES);
+        for chunk in chunks.by_ref() {
+            let block = Block::try_from(chunk)
+                .expect("chunk length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u128);
+        }
+        self.unprocessed.extend_from_slice(chunks.remainder());
+    }
+
+    /// Applies padding and returns the final state.
+    pub(crate) fn finalize(&self) -> State {
+        assert!(
+            self.unprocessed.len() < BLOCK_LENGTH_BYTES,
+            "unprocessed data length should be less than block length"
+        );
+
+        let length = {
+            let length = self.processed.wrapping_add(self.unprocessed.len() as u128);
+            let length = length.wrapping_mul(8); // convert byte-length into bits-length
+            length.to_be_bytes()
+        };
+
+        // The padding is at least one full block, so the last block holds nothing but the padding unless the data is block-aligned.
+        let mut padding = [0u8; BLOCK_LENGTH_BYTES * 2];
+        padding[..self.unprocessed.len()].copy_from_slice(&self.unprocessed);
+        padding[self.unprocessed.len()] = 0x80;
+        let padding = if self.unprocessed.is_empty() {
+            &mut padding[..BLOCK_LENGTH_BYTES]
+        } else {
+            &mut padding[..]
+        };
+        let offset = padding.len() - length.len();
+        padding[offset..].copy_from_slice(&length);
+
+        padding
+            .chunks_exact(BLOCK_LENGTH_BYTES)
+            .fold(self.state, |state, chunk| {
+                let block = Block::try_from(chunk)
+                    .expect("padding length must be multiple of block length")
+                    .into();
+                state.update(block)
+            })
+    }
+
+    /// Generates an engine for the given digest length in bits with arbitrary chaining values and buffered data.
+    #[cfg(feature = "arbitrary")]
+    pub(crate) fn arbitrary(u: &mut arbitrary::Unstructured<'_>, length: usize) -> arbitrary::Result<Self> {
+        let state = State::from_raw(u.arbitrary()?, length);
+        // Full blocks are processed immediately, so less than a block is buffered.
+        let buffered = u.int_in_range(0..=BLOCK_LENGTH_BYTES - 1)?;
+        let unprocessed = Buffer::from_slice(u.bytes(buffered)?);
+        let processed = u.arbitrary::<u128>()?;
+        let processed = processed - processed % BLOCK_LENGTH_BYTES as u128;
+        Ok(Self {
+            state,
+            unprocessed,
+            processed,
+        })
+    }
+
+    /// Resets the internal state to default values.
+    pub(crate) fn reset(&mut self) {
+        self.state = self.state.reset();
+        self.unprocessed.clear();
+        self.processed = 0;
+    }
+
+    /// Returns the number of bytes passed to the engine so far, including the buffered ones.
+    #[allow(clippy::cast_possible_truncation)] // more than 2^64 bytes are not hashed in practice
+    pub(crate) fn processed_bytes(&self) -> u64 {
+        self.processed.wrapping_add(self.unprocessed.len() as u128) as u64
+    }
+
+    /// Returns the number of buffered bytes.
+    pub(crate) fn buffered_len(&self) -> usize {
+        self.unprocessed.len()
+    }
+
+    /// Returns `true` if no data is buffered.
+    pub(crate) fn is_block_aligned(&self) -> bool {
+        self.unprocessed.is_empty()
+    }
+}
+
+/// Returns the digest made of the last `N` bytes of the final state.
+pub(crate) fn output<const N: usize>(state: &State) -> Digest<N> {
+    let bytes = state
+        .digest()
+        .iter()
+        .flat_map(|word| word.to_be_bytes())
+        .collect::<Vec<_>>();
+    let mut digest = [0u8; N];
+    digest.copy_from_slice(&bytes[bytes.len() - N..]);
+    Digest::new(digest)
+}
@@ -0,0 +1,56 @@
+//! Module containing items related to the JH block.
+
+use std::array::TryFromSliceError;
+
+/// Block length in bits.
+pub const LENGTH_BITS: usize = 512;
+/// Block length in bytes.
+pub const LENGTH_BYTES: usize = LENGTH_BITS / 8;
+/// Block length in quadruple words (octuple bytes).
+pub const LENGTH_QWORDS: usize = LENGTH_BYTES / 8;
+
+/// A single block of data that can be processed by the [`State`](super::State).
+///
+/// The block converts into the array of words taken by the state, decoded with the byte order of the hash function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Block([u8; LENGTH_BYTES]);
+
+impl Block {
+    /// Creates a new block.
+    #[must_use]
+    pub const fn new(block: [u8; LENGTH_BYTES]) -> Self {
+        Self(block)
+    }
+
+    /// Returns the bytes of the block.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; LENGTH_BYTES] {
+        &self.0
+    }
+}
+
+impl From<[u8; LENGTH_BYTES]> for Block {
+    fn from(block: [u8; LENGTH_BYTES]) -> Self {
+        Self::new(block)
+    }
+}
+
+impl From<Block> for [u64; LENGTH_QWORDS] {
+    fn from(Block(block): Block) -> Self {
+        let mut words = [0u64; LENGTH_QWORDS];
+        for (word, bytes) in words.iter_mut().zip(block.chunks_exact(8)) {
+            *word = u64::from_be_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ]);
+        }
+        words
+    }
+}
+
+impl TryFrom<&[u8]> for Block {
+    type Error = TryFromSliceError;
+
+    fn try_from(block: &[u8]) -> Result<Self, Self::Error> {
+        block.try_into().map(Self)
+    }
+}
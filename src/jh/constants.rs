@@ -0,0 +1,50 @@
+//! Round constants of the JH compression function, derived from the fractional part of the square root of 2 as described in the specification.
+
+/// Round constants in the bitsliced form, one pair of 128-bit words per round.
+///
+/// The first word selects the S-boxes applied to the even state words and the second one the S-boxes applied to the odd state words.
+#[rustfmt::skip]
+pub(super) const ROUND_CONSTANTS: [[u128; 2]; 42] = [
+    [0x72D5DEA2DF15F867_7B84150AB7231557, 0x81ABD6904D5A87F6_4E9F4FC5C3D12B40],
+    [0xEA983AE05C45FA9C_03C5D29966B2999A, 0x660296B4F2BB538A_B556141A88DBA231],
+    [0x03A35A5C9A190EDB_403FB20A87C14410, 0x1C051980849E951D_6F33EBAD5EE7CDDC],
+    [0x10BA139202BF6B41_DC786515F7BB27D0, 0x0A2C813937AA7850_3F1ABFD2410091D3],
+    [0x422D5A0DF6CC7E90_DD629F9C92C097CE, 0x185CA70BC72B44AC_D1DF65D663C6FC23],
+    [0x976E6C039EE0B81A_2105457E446CECA8, 0xEEF103BB5D8E61FA_FD9697B294838197],
+    [0x4A8E8537DB03302F_2A678D2DFB9F6A95, 0x8AFE7381F8B8696C_8AC77246C07F4214],
+    [0xC5F4158FBDC75EC4_75446FA78F11BB80, 0x52DE75B7AEE488BC_82B8001E98A6A3F4],
+    [0x8EF48F33A9A36315_AA5F5624D5B7F989, 0xB6F1ED207C5AE0FD_36CAE95A06422C36],
+    [0xCE2935434EFE983D_533AF974739A4BA7, 0xD0F51F596F4E8186_0E9DAD81AFD85A9F],
+    [0xA7050667EE34626A_8B0B28BE6EB91727, 0x47740726C680103F_E0A07E6FC67E487B],
+    [0x0D550AA54AF8A4C0_91E3E79F978EF19E, 0x8676728150608DD4_7E9E5A41F3E5B062],
+    [0xFC9F1FEC4054207A_E3E41A00CEF4C984, 0x4FD794F59DFA95D8_552E7E1124C354A5],
+    [0x5BDF7228BDFE6E28_78F57FE20FA5C4B2, 0x05897CEFEE49D32E_447E9385EB28597F],
+    [0x705F6937B324314A_5E8628F11DD6E465, 0xC71B770451B920E7_74FE43E823D4878A],
+    [0x7D29E8A3927694F2_DDCB7A099B30D9C1, 0x1D1B30FB5BDC1BE0_DA24494FF29C82BF],
+    [0xA4E7BA31B470BFFF_0D324405DEF8BC48, 0x3BAEFC3253BBD339_459FC3C1E0298BA0],
+    [0xE5C905FDF7AE090F_947034124290F134, 0xA271B701E344ED95_E93B8E364F2F984A],
+    [0x88401D63A06CF615_47C1444B8752AFFF, 0x7EBB4AF1E20AC630_4670B6C5CC6E8CE6],
+    [0xA4D5A456BD4FCA00_DA9D844BC83E18AE, 0x7357CE453064D1AD_E8A6CE68145C2567],
+    [0xA3DA8CF2CB0EE116_33E906589A94999A, 0x1F60B220C26F847B_D1CEAC7FA0D18518],
+    [0x32595BA18DDD19D3_509A1CC0AAA5B446, 0x9F3D6367E4046BBA_F6CA19AB0B56EE7E],
+    [0x1FB179EAA9282174_E9BDF7353B3651EE, 0x1D57AC5A7550D376_3A46C2FEA37D7001],
+    [0xF735C1AF98A4D842_78EDEC209E6B6779, 0x41836315EA3ADBA8_FAC33B4D32832C83],
+    [0xA7403B1F1C2747F3_5940F034B72D769A, 0xE73E4E6CD2214FFD_B8FD8D39DC5759EF],
+    [0x8D9B0C492B49EBDA_5BA2D74968F3700D, 0x7D3BAED07A8D5584_F5A5E9F0E4F88E65],
+    [0xA0B8A2F436103B53_0CA8079E753EEC5A, 0x9168949256E8884F_5BB05C55F8BABC4C],
+    [0xE3BB3B99F387947B_75DAF4D6726B1C5D, 0x64AEAC28DC34B36D_6C34A550B828DB71],
+    [0xF861E2F2108D512A_E3DB643359DD75FC, 0x1CACBCF143CE3FA2_67BBD13C02E843B0],
+    [0x330A5BCA8829A175_7F34194DB416535C, 0x923B94C30E794D1E_797475D7B6EEAF3F],
+    [0xEAA8D4F7BE1A3921_5CF47E094C232751, 0x26A32453BA323CD2_44A3174A6DA6D5AD],
+    [0xB51D3EA6AFF2C908_83593D98916B3C56, 0x4CF87CA17286604D_46E23ECC086EC7F6],
+    [0x2F9833B3B1BC765E_2BD666A5EFC4E62A, 0x06F4B6E8BEC1D436_74EE8215BCEF2163],
+    [0xFDC14E0DF453C969_A77D5AC406585826, 0x7EC1141606E0FA16_7E90AF3D28639D3F],
+    [0xD2C9F2E3009BD20C_5FAACE30B7D40C30, 0x742A5116F2E03298_0DEB30D8E3CEF89A],
+    [0x4BC59E7BB5F17992_FF51E66E048668D3, 0x9B234D57E6966731_CCE6A6F3170A7505],
+    [0xB17681D913326CCE_3C175284F805A262, 0xF42BCBB378471547_FF46548223936A48],
+    [0x38DF58074E5E6565_F2FC7C89FC86508E, 0x31702E44D00BCA86_F04009A23078474E],
+    [0x65A0EE39D1F73883_F75EE937E42C3ABD, 0x2197B2260113F86F_A344EDD1EF9FDEE7],
+    [0x8BA0DF15762592D9_3C85F7F612DC42BE, 0xD8A7EC7CAB27B07E_538D7DDAAA3EA8DE],
+    [0xAA25CE93BD0269D8_5AF643FD1A7308F9, 0xC05FEFDA174A19A5_974D66334CFD216A],
+    [0x35B49831DB411570_EA1E0FBBEDCD549B, 0x9AD063A151974072_F6759DBF91476FE2],
+];
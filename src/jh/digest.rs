@@ -0,0 +1,11 @@
+//! Module containing items related to the JH digest.
+
+/// Marker type of the JH digest.
+///
+/// It tags [`Digest`] so JH digests are distinct from digests of other hash functions with the same length.
+pub enum Jh {}
+
+/// A hash digest.
+///
+/// JH produces digests of 224, 256, 384 or 512 bits, so the digest is generic over its length in bytes. Each of the [`jh_224`](crate::jh_224), [`jh_256`](crate::jh_256), [`jh_384`](crate::jh_384) and [`jh_512`](crate::jh_512) modules provides a `Digest` alias with the right length.
+pub type Digest<const N: usize> = crate::digest::Digest<Jh, N>;
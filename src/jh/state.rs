@@ -0,0 +1,255 @@
+//! Module contains items related to the [`State`] structure which allows to the direct JH state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::jh;
+//!
+//! // Create new state for 256-bit digests
+//! let mut state = jh::state::new(256);
+//!
+//! // Create a block of data with proper padding
+//! let mut block = [0u8; jh::BLOCK_LENGTH_BYTES];
+//! block[0] = 0x80;
+//! let data = jh::Block::new(block).into();
+//!
+//! // Update state and own new value
+//! state = state.update(data);
+//!
+//! // The digest of empty input is taken from the last words of the state
+//! assert_eq!(
+//!     state.digest()[12..],
+//!     [
+//!         0x46E64619C18BB0A9,
+//!         0x2A5E87185A47EEF8,
+//!         0x3CA747B8FCC8E141,
+//!         0x2921357E326DF434
+//!     ]
+//! );
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state, jh::state::new(256));
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_QWORDS as BLOCK_LENGTH_QWORDS;
+use super::constants::ROUND_CONSTANTS;
+use crate::error::ParameterError;
+
+/// State length in quadruple words (octuple bytes).
+pub const LENGTH_QWORDS: usize = 16;
+
+/// Masks of the bits moved by the swaps of the odd state words, one for each round modulo 7.
+///
+/// The last swap exchanges both halves of a word and is done with a rotation instead.
+#[allow(clippy::unreadable_literal)]
+const SWAP_MASKS: [u128; 6] = [
+    0xAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA,
+    0xCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCC,
+    0xF0F0F0F0F0F0F0F0F0F0F0F0F0F0F0F0,
+    0xFF00FF00FF00FF00FF00FF00FF00FF00,
+    0xFFFF0000FFFF0000FFFF0000FFFF0000,
+    0xFFFFFFFF00000000FFFFFFFF00000000,
+];
+
+/// Create a new state for the given digest length in bits.
+///
+/// # Panics
+///
+/// Panics if the length is not one of 224, 256, 384 or 512.
+#[must_use]
+pub const fn new(length: usize) -> State {
+    State::new(length)
+}
+
+/// A low-level hash state.
+///
+/// The state is 1024 bits wide, stored as eight 128-bit words in the bitsliced layout of the specification. Unlike other hash functions, the initial state depends on the digest length, which is why there is no default state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct State {
+    pub(crate) words: [u128; 8],
+    pub(crate) length: usize,
+}
+
+impl State {
+    /// Creates a new state for the given digest length in bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is not one of 224, 256, 384 or 512.
+    #[must_use]
+    pub const fn new(length: usize) -> Self {
+        // The initial state is the compression of an all-zero block with the digest length in the first two bytes.
+        let mut words = [0u128; 8];
+        words[0] = (length as u128) << 112;
+        Self::from_raw(words, length).update([0; BLOCK_LENGTH_QWORDS])
+    }
+
+    /// Creates a new state for the given digest length in bits, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length is not one of 224, 256, 384 or 512.
+    pub const fn try_new(length: usize) -> Result<Self, ParameterError> {
+        if matches!(length, 224 | 256 | 384 | 512) {
+            Ok(Self::new(length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Creates a state for the given digest length in bits from the raw chaining values, e.g. a custom initialization vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is not one of 224, 256, 384 or 512.
+    #[must_use]
+    pub const fn from_raw(words: [u128; 8], length: usize) -> Self {
+        assert!(
+            matches!(length, 224 | 256 | 384 | 512),
+            "digest length must be one of 224, 256, 384 or 512 bits"
+        );
+        Self { words, length }
+    }
+
+    /// Creates a state for the given digest length in bits from the raw chaining values, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length is not one of 224, 256, 384 or 512.
+    pub const fn try_from_raw(words: [u128; 8], length: usize) -> Result<Self, ParameterError> {
+        if matches!(length, 224 | 256 | 384 | 512) {
+            Ok(Self::from_raw(words, length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// **Warning!** Input block must be in the big-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u64; BLOCK_LENGTH_QWORDS]) -> Self {
+        let mut message = [0u128; 4];
+        let mut i = 0;
+        while i < 4 {
+            message[i] = ((block[i * 2] as u128) << 64) | (block[i * 2 + 1] as u128);
+            i += 1;
+        }
+
+        // The message is mixed into the first half of the state before the permutation and into the second half after it.
+        let mut words = self.words;
+        let mut i = 0;
+        while i < 4 {
+            words[i] ^= message[i];
+            i += 1;
+        }
+        let mut words = permute(words);
+        let mut i = 0;
+        while i < 4 {
+            words[i + 4] ^= message[i];
+            i += 1;
+        }
+
+        Self::from_raw(words, self.length)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new(self.length)
+    }
+
+    /// Returns the whole state, the digest is made of its last words.
+    #[must_use]
+    pub const fn digest(&self) -> [u64; LENGTH_QWORDS] {
+        let mut digest = [0u64; LENGTH_QWORDS];
+        let mut i = 0;
+        while i < 8 {
+            digest[i * 2] = (self.words[i] >> 64) as u64;
+            digest[i * 2 + 1] = self.words[i] as u64;
+            i += 1;
+        }
+        digest
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for State {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let words = u.arbitrary()?;
+        let length = *u.choose(&[224, 256, 384, 512])?;
+        Ok(Self::from_raw(words, length))
+    }
+}
+
+/// The E8 permutation made of 42 rounds.
+const fn permute(words: [u128; 8]) -> [u128; 8] {
+    let [mut x0, mut x1, mut x2, mut x3, mut x4, mut x5, mut x6, mut x7] = words;
+    let mut round = 0;
+    while round < 42 {
+        let [c0, c1] = ROUND_CONSTANTS[round];
+        [x0, x2, x4, x6] = sbox([x0, x2, x4, x6], c0);
+        [x1, x3, x5, x7] = sbox([x1, x3, x5, x7], c1);
+        [x0, x2, x4, x6, x1, x3, x5, x7] = mix([x0, x2, x4, x6], [x1, x3, x5, x7]);
+        x1 = swap(x1, round % 7);
+        x3 = swap(x3, round % 7);
+        x5 = swap(x5, round % 7);
+        x7 = swap(x7, round % 7);
+        round += 1;
+    }
+    [x0, x1, x2, x3, x4, x5, x6, x7]
+}
+
+/// Applies the S-box selected by the constant bits to each of the 4-bit elements.
+const fn sbox(words: [u128; 4], constant: u128) -> [u128; 4] {
+    let [mut m0, mut m1, mut m2, mut m3] = words;
+    m3 = !m3;
+    m0 ^= !m2 & constant;
+    let temp = constant ^ (m0 & m1);
+    m0 ^= m2 & m3;
+    m3 ^= !m1 & m2;
+    m1 ^= m0 & m2;
+    m2 ^= m0 & !m3;
+    m0 ^= m1 | m3;
+    m3 ^= m1 & m2;
+    m1 ^= temp & m0;
+    m2 ^= temp;
+    [m0, m1, m2, m3]
+}
+
+/// Applies the linear transformation to the pairs of elements.
+const fn mix(even: [u128; 4], odd: [u128; 4]) -> [u128; 8] {
+    let [mut m0, mut m1, mut m2, mut m3] = even;
+    let [mut m4, mut m5, mut m6, mut m7] = odd;
+    m4 ^= m1;
+    m5 ^= m2;
+    m6 ^= m0 ^ m3;
+    m7 ^= m0;
+    m0 ^= m5;
+    m1 ^= m6;
+    m2 ^= m4 ^ m7;
+    m3 ^= m4;
+    [m0, m1, m2, m3, m4, m5, m6, m7]
+}
+
+/// Swaps groups of bits of the given size, which replaces the permutation layer in the bitsliced form.
+const fn swap(word: u128, index: usize) -> u128 {
+    if index < SWAP_MASKS.len() {
+        let shift = 1 << index;
+        let mask = SWAP_MASKS[index];
+        ((word & mask) >> shift) | ((word & !mask) << shift)
+    } else {
+        word.rotate_left(64)
+    }
+}
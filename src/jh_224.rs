@@ -0,0 +1,350 @@
+//! This module is optional and can be enabled using the `jh-224` Cargo feature.
+//!
+//! An implementation of the JH-224 hash function.
+//!
+//! Check the [`jh`](crate::jh) module for information about the whole family.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::jh_224;
+//!
+//! let digest = jh_224::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "a9502bce5e9df65cb945af4f14d9ba675217bb64b143fad78abd68bc"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::jh_224;
+//!
+//! let digest = jh_224::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "a9502bce5e9df65cb945af4f14d9ba675217bb64b143fad78abd68bc"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized due to the unknown size of data chunks.
+//!
+//! The size of this buffer is at least as large as one hash block of data processed at a time.
+//!
+//! To mitigate buffering, and consequently reduce memory overhead, data chunks should be aligned to multiples of the block size, or processed in block-sized chunks.
+//!
+//! The [`Update::is_block_aligned`] and [`Update::buffered_len`] functions report whether data is buffered, and [`Update::processed_bytes`] returns the number of bytes passed to the hash so far.
+
+use chksum_hash_core as core;
+
+use crate::jh::{output, Engine};
+#[doc(inline)]
+pub use crate::jh::{Block, State, BLOCK_LENGTH_BYTES};
+use crate::verify::Expected;
+
+/// Digest length in bits.
+pub const DIGEST_LENGTH_BITS: usize = 224;
+/// Digest length in bytes.
+pub const DIGEST_LENGTH_BYTES: usize = DIGEST_LENGTH_BITS / 8;
+
+/// A hash digest.
+///
+/// Check [`jh_224`](self) for more information.
+pub type Digest = crate::jh::Digest<DIGEST_LENGTH_BYTES>;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::jh_224;
+///
+/// let digest = jh_224::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "2c99df889b019309051c60fecc2bd285a774940e43175b76b2626630"
+/// );
+///
+/// let digest = jh_224::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e71abf00a851bd10950853918f8465e13ccd45d592abbca03464812d"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::jh_224;
+///
+/// let digest = jh_224::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "2c99df889b019309051c60fecc2bd285a774940e43175b76b2626630"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::jh_224;
+///
+/// let digest = jh_224::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "21e88480ebb76dd51a984d52e97fa0da620f885b94a172320131ab54"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::jh_224;
+///
+/// let digest = jh_224::hash("data");
+/// assert!(jh_224::verify("data", digest));
+/// assert!(jh_224::verify("data", digest.to_hex_uppercase()));
+/// assert!(!jh_224::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::jh_224;
+///
+/// // Create a new hash instance
+/// let mut hash = jh_224::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e71abf00a851bd10950853918f8465e13ccd45d592abbca03464812d"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "2c99df889b019309051c60fecc2bd285a774940e43175b76b2626630"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    inner: Engine,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        let inner = Engine::new(DIGEST_LENGTH_BITS);
+        Self { inner }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.update(data.as_ref());
+        self
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let state = self.inner.finalize();
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        self.inner.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+
+    /// Returns the number of bytes passed to the hash so far, including the buffered ones.
+    #[must_use]
+    pub fn processed_bytes(&self) -> u64 {
+        self.inner.processed_bytes()
+    }
+
+    /// Returns the number of bytes buffered until a whole block is available.
+    ///
+    /// Updating with chunks that are multiples of the block length keeps the buffer empty.
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.inner.buffered_len()
+    }
+
+    /// Returns `true` if no data is buffered, i.e. all bytes passed so far form whole blocks.
+    #[must_use]
+    pub fn is_block_aligned(&self) -> bool {
+        self.inner.is_block_aligned()
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let inner = Engine::arbitrary(u, DIGEST_LENGTH_BITS)?;
+        Ok(Self { inner })
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::jh_224;
+///
+/// // Create a new hash instance
+/// let mut hash = jh_224::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "21e88480ebb76dd51a984d52e97fa0da620f885b94a172320131ab54"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        output(&self.state)
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        *out = output(&self.state).into_inner();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::new()
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vector from the SHA-3 submission.
+    #[test]
+    fn submission() {
+        assert_eq!(
+            hash("").to_hex_lowercase(),
+            "2c99df889b019309051c60fecc2bd285a774940e43175b76b2626630"
+        );
+    }
+}
@@ -0,0 +1,415 @@
+//! This module is optional and can be enabled using the `komihash` Cargo feature.
+//!
+//! An implementation of the komihash hash function.
+//!
+//! komihash is a fast non-cryptographic 64-bit hash function with a 64-bit seed. It is particularly fast on short inputs, which makes it a good fit for hash tables. This is komihash version 5, whose results are identical to the ones of its reference implementation for both batch and stream processing.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::komihash;
+//!
+//! let digest = komihash::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "3f83b768e1e2ed53");
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::komihash;
+//!
+//! let digest = komihash::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "3f83b768e1e2ed53");
+//! ```
+//!
+//! # Seeds
+//!
+//! Use [`Update::with_seed`] to choose another seed.
+//!
+//! ```rust
+//! use chksum_hash::komihash;
+//!
+//! let digest = komihash::Update::with_seed(42)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "21f60975a8f7e3ab");
+//! ```
+//!
+//! # Disclaimer
+//!
+//! komihash is not a cryptographic hash function. It is designed for hash tables and checksumming, and it must not be used where resistance to deliberate collisions is required.
+
+pub mod block;
+pub mod digest;
+pub mod state;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::block::{Block, LENGTH_BYTES as BLOCK_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+use self::state::{multiply, round};
+use crate::buffer::Buffer;
+use crate::verify::Expected;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::komihash;
+///
+/// let digest = komihash::new().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "b7683ea7430132b4");
+///
+/// let digest = komihash::new().update("data").digest();
+/// assert_eq!(digest.to_hex_lowercase(), "d5e53069db7ef41c");
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::komihash;
+///
+/// let digest = komihash::default().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "b7683ea7430132b4");
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::komihash;
+///
+/// let digest = komihash::hash("abc");
+/// assert_eq!(digest.to_hex_lowercase(), "03b74dc61a6b7f33");
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::komihash;
+///
+/// let digest = komihash::hash("data");
+/// assert!(komihash::verify("data", digest));
+/// assert!(komihash::verify("data", digest.to_hex_uppercase()));
+/// assert!(!komihash::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::komihash;
+///
+/// // Create a new hash instance
+/// let mut hash = komihash::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "d5e53069db7ef41c");
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(digest.to_hex_lowercase(), "b7683ea7430132b4");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    state: State,
+    unprocessed: Buffer<BLOCK_LENGTH_BYTES>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Creates a new hash with the given seed.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        let state = state::new(seed);
+        let unprocessed = Buffer::new();
+        Self { state, unprocessed }
+    }
+
+    /// Returns the seed of the hash.
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.state.seed
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+
+        if !self.unprocessed.is_empty() {
+            let missing = BLOCK_LENGTH_BYTES - self.unprocessed.len();
+            if data.len() < missing {
+                // Not enough data even for one block.
+                self.unprocessed.extend_from_slice(data);
+                return self;
+            }
+
+            // Complete the buffered block with the beginning of incoming data.
+            let (fillment, remainder) = data.split_at(missing);
+            self.unprocessed.extend_from_slice(fillment);
+            let block = Block::try_from(&self.unprocessed[..])
+                .expect("unprocessed data length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.unprocessed.clear();
+            data = remainder;
+        }
+
+        // Internal buffer is empty, incoming data can be processed without buffering.
+        let mut chunks = data.chunks_exact(BLOCK_LENGTH_BYTES);
+        for chunk in chunks.by_ref() {
+            let block = Block::try_from(chunk)
+                .expect("chunk length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+        }
+        self.unprocessed.extend_from_slice(chunks.remainder());
+
+        self
+    }
+
+    /// Processes the remaining data and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        assert!(
+            self.unprocessed.len() < BLOCK_LENGTH_BYTES,
+            "unprocessed data length should be less than block length"
+        );
+
+        let empty = !self.state.updated && self.unprocessed.is_empty();
+        let [mut first, mut fifth] = self.state.digest();
+        let mut data = &self.unprocessed[..];
+        // The remaining data is mixed in 16-byte parts, the last part of up to 15 bytes is padded with a single one bit.
+        while data.len() > 15 {
+            (first, fifth) = mix(first, fifth, read(data, 0), read(data, 8));
+            data = &data[16..];
+        }
+        let (low, high) = if data.len() > 7 {
+            (read(data, 0), pad(&data[8..]))
+        } else if empty {
+            // Empty input is not padded.
+            (0, 0)
+        } else {
+            (pad(data), 0)
+        };
+        let (first, fifth) = mix(first, fifth, low, high);
+        let (hash, _) = round(first, fifth);
+
+        Finalize {
+            hash,
+            seed: self.state.seed,
+        }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The seed is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self.unprocessed.clear();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let state = u.arbitrary()?;
+        // Full blocks are processed immediately, so less than a block is buffered.
+        let length = u.int_in_range(0..=BLOCK_LENGTH_BYTES - 1)?;
+        let unprocessed = Buffer::from_slice(u.bytes(length)?);
+        Ok(Self { state, unprocessed })
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::komihash;
+///
+/// // Create a new hash instance
+/// let mut hash = komihash::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "03b74dc61a6b7f33");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    hash: u64,
+    seed: u64,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        Digest::new(self.hash.to_be_bytes())
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        *out = self.hash.to_be_bytes();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The seed is kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_seed(self.seed)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+/// Mixes a pair of words into the first and the fifth lane.
+const fn mix(first: u64, fifth: u64, low: u64, high: u64) -> (u64, u64) {
+    let (first, high) = multiply(first ^ low, fifth ^ high);
+    let fifth = fifth.wrapping_add(high);
+    (first ^ fifth, fifth)
+}
+
+/// Reads a little-endian word at the given offset.
+fn read(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Reads up to 7 bytes as a little-endian word padded with a single one bit.
+fn pad(data: &[u8]) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes[..data.len()].copy_from_slice(data);
+    bytes[data.len()] = 0x01;
+    u64::from_le_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the komihash reference implementation.
+    #[test]
+    fn reference() {
+        assert_eq!(
+            hash("This is a 32-byte testing string").to_hex_lowercase(),
+            "05ad960802903a9d"
+        );
+        assert_eq!(hash("The cat is out of the bag").to_hex_lowercase(), "d15723521d3c37b1");
+        assert_eq!(hash("A 16-byte string").to_hex_lowercase(), "467caa28ea3da7a6");
+        assert_eq!(hash("The new string").to_hex_lowercase(), "f18e67bc90c43233");
+        assert_eq!(hash("7 chars").to_hex_lowercase(), "2c514f6e5dcb11cb");
+    }
+}
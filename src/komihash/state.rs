@@ -0,0 +1,163 @@
+//! Module contains items related to the [`State`] structure which allows to the direct komihash state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::komihash;
+//!
+//! // Create new state with the zero seed
+//! let mut state = komihash::state::new(0);
+//!
+//! // Create a block of data
+//! let block = std::array::from_fn(|index| index as u8);
+//! let data = komihash::Block::new(block).into();
+//!
+//! // Update state and own new value
+//! state = state.update(data);
+//!
+//! // The lanes folded into the pair of words used by the finalization
+//! assert_eq!(state.digest(), [0x19D1B369A4592238, 0x2CCBCD4DE6EEA38A]);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state, komihash::state::new(0));
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_QWORDS as BLOCK_LENGTH_QWORDS;
+
+/// State length in quadruple words (octuple bytes).
+pub const LENGTH_QWORDS: usize = 8;
+
+/// Initial values of the lanes, the first mantissa bits of pi.
+#[allow(clippy::unreadable_literal)]
+const PI: [u64; LENGTH_QWORDS] = [
+    0x243F6A8885A308D3,
+    0x13198A2E03707344,
+    0xA4093822299F31D0,
+    0x082EFA98EC4E6C89,
+    0x452821E638D01377,
+    0xBE5466CF34E90C6C,
+    0xC0AC29B7C97C50DD,
+    0x3F84D5B5B5470917,
+];
+
+/// Create a new state for the given seed.
+#[must_use]
+pub const fn new(seed: u64) -> State {
+    State::new(seed)
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level hash state.
+///
+/// The state is made of eight 64-bit lanes, `Seed1` to `Seed8` in the reference implementation. Only the first and the fifth lane are used for inputs shorter than a block, the remaining ones are folded into them once blocks have been processed.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) lanes: [u64; LENGTH_QWORDS],
+    pub(crate) seed: u64,
+    pub(crate) updated: bool,
+}
+
+impl State {
+    /// Creates a new state for the given seed.
+    #[allow(clippy::unreadable_literal)]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        let first = PI[0] ^ (seed & 0x5555555555555555);
+        let fifth = PI[4] ^ (seed & 0xAAAAAAAAAAAAAAAA);
+        let (first, fifth) = round(first, fifth);
+        let lanes = [
+            first,
+            PI[1] ^ first,
+            PI[2] ^ first,
+            PI[3] ^ first,
+            fifth,
+            PI[5] ^ fifth,
+            PI[6] ^ fifth,
+            PI[7] ^ fifth,
+        ];
+        Self {
+            lanes,
+            seed,
+            updated: false,
+        }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// **Warning!** Input block must be in the little-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u64; BLOCK_LENGTH_QWORDS]) -> Self {
+        let mut lanes = self.lanes;
+        let mut i = 0;
+        while i < 4 {
+            let (low, high) = multiply(lanes[i] ^ block[i], lanes[i + 4] ^ block[i + 4]);
+            lanes[i] = low;
+            lanes[i + 4] = lanes[i + 4].wrapping_add(high);
+            i += 1;
+        }
+        lanes[1] ^= lanes[4];
+        lanes[2] ^= lanes[5];
+        lanes[3] ^= lanes[6];
+        lanes[0] ^= lanes[7];
+        Self {
+            lanes,
+            seed: self.seed,
+            updated: true,
+        }
+    }
+
+    /// Returns a new state with initial values.
+    ///
+    /// The seed is kept.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new(self.seed)
+    }
+
+    /// Returns the pair of words the finalization starts from.
+    #[must_use]
+    pub const fn digest(&self) -> [u64; 2] {
+        let lanes = self.lanes;
+        if self.updated {
+            [
+                lanes[0] ^ lanes[1] ^ lanes[2] ^ lanes[3],
+                lanes[4] ^ lanes[5] ^ lanes[6] ^ lanes[7],
+            ]
+        } else {
+            [lanes[0], lanes[4]]
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Multiplies two words into a 128-bit product, returned as its low and high halves.
+pub(crate) const fn multiply(left: u64, right: u64) -> (u64, u64) {
+    let product = left as u128 * right as u128;
+    (product as u64, (product >> 64) as u64)
+}
+
+/// The constant-less PRNG round used by the initialization and the finalization.
+pub(crate) const fn round(first: u64, fifth: u64) -> (u64, u64) {
+    let (first, high) = multiply(first, fifth);
+    let fifth = fifth.wrapping_add(high);
+    (first ^ fifth, fifth)
+}
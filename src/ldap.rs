@@ -0,0 +1,275 @@
+//! This module is optional and can be enabled using the `ldap` Cargo feature.
+//!
+//! An implementation of the LDAP password schemes, like `{SHA}` and `{SSHA}`.
+//!
+//! The schemes are used in the `userPassword` attribute of LDAP directories, like OpenLDAP, and in the configuration of other servers, like Dovecot. The value is the name of the scheme in braces followed by the base64-encoded digest of the password. The salted schemes append the salt to the password before hashing and to the digest before encoding. The hash function is selected with [`Scheme`].
+//!
+//! # Encoding
+//!
+//! The unsalted and the salted passwords can be created with the [`encode`] and the [`encode_salted`] functions.
+//!
+//! ```rust
+//! use chksum_hash::ldap::{self, Scheme};
+//!
+//! let password = ldap::encode("password", Scheme::Sha1);
+//! assert_eq!(password, "{SHA}W6ph5Mm5Pz8GgiULbPgzG37mj9g=");
+//!
+//! let password = ldap::encode_salted("password", "salt", Scheme::Sha1);
+//! assert_eq!(password, "{SSHA}yI6cZwQadOA1e+/f+T+H3eCQQhRzYWx0");
+//! ```
+//!
+//! # Verification
+//!
+//! The password can be checked against an encoded one with the [`verify`] function. The scheme and the salt are read from the encoded password.
+//!
+//! ```rust
+//! use chksum_hash::ldap;
+//!
+//! let password = "{SSHA}yI6cZwQadOA1e+/f+T+H3eCQQhRzYWx0";
+//! assert!(ldap::verify("password", password));
+//! assert!(!ldap::verify("Password", password));
+//! ```
+//!
+//! # Disclaimer
+//!
+//! These schemes apply the hash function only once, which makes them cheap to attack. They are supported for compatibility with existing directories, prefer [`sha_crypt`](crate::sha_crypt) or memory-hard functions, like [`scrypt`](crate::scrypt), for new password storage.
+
+use chksum_hash_core as core;
+
+use crate::{md5, sha1, sha2_256, sha2_384, sha2_512, verify};
+
+/// Alphabet of the standard base64 encoding.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A hash function of the LDAP password schemes.
+///
+/// Each hash function has an unsalted and a salted scheme, e.g. `{SHA}` and `{SSHA}` for SHA-1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scheme {
+    /// MD5, `{MD5}` and `{SMD5}`.
+    Md5,
+    /// SHA-1, `{SHA}` and `{SSHA}`.
+    Sha1,
+    /// SHA-2 256, `{SHA256}` and `{SSHA256}`.
+    Sha2_256,
+    /// SHA-2 384, `{SHA384}` and `{SSHA384}`.
+    Sha2_384,
+    /// SHA-2 512, `{SHA512}` and `{SSHA512}`.
+    Sha2_512,
+}
+
+impl Scheme {
+    /// Returns the name of the unsalted scheme, e.g. `"SHA"`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Md5 => "MD5",
+            Self::Sha1 => "SHA",
+            Self::Sha2_256 => "SHA256",
+            Self::Sha2_384 => "SHA384",
+            Self::Sha2_512 => "SHA512",
+        }
+    }
+
+    /// Returns the name of the salted scheme, e.g. `"SSHA"`.
+    #[must_use]
+    pub const fn salted_name(&self) -> &'static str {
+        match self {
+            Self::Md5 => "SMD5",
+            Self::Sha1 => "SSHA",
+            Self::Sha2_256 => "SSHA256",
+            Self::Sha2_384 => "SSHA384",
+            Self::Sha2_512 => "SSHA512",
+        }
+    }
+
+    /// Returns the scheme with the given name and whether it is salted.
+    ///
+    /// The names are case-insensitive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::ldap::Scheme;
+    ///
+    /// assert_eq!(Scheme::from_name("SSHA256"), Some((Scheme::Sha2_256, true)));
+    /// assert_eq!(Scheme::from_name("md5"), Some((Scheme::Md5, false)));
+    /// assert_eq!(Scheme::from_name("CRYPT"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<(Self, bool)> {
+        [Self::Md5, Self::Sha1, Self::Sha2_256, Self::Sha2_384, Self::Sha2_512]
+            .into_iter()
+            .find_map(|scheme| {
+                if name.eq_ignore_ascii_case(scheme.name()) {
+                    Some((scheme, false))
+                } else if name.eq_ignore_ascii_case(scheme.salted_name()) {
+                    Some((scheme, true))
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Returns the length of the digest in bytes.
+    #[must_use]
+    pub const fn digest_length(&self) -> usize {
+        match self {
+            Self::Md5 => md5::DIGEST_LENGTH_BYTES,
+            Self::Sha1 => sha1::DIGEST_LENGTH_BYTES,
+            Self::Sha2_256 => sha2_256::DIGEST_LENGTH_BYTES,
+            Self::Sha2_384 => sha2_384::DIGEST_LENGTH_BYTES,
+            Self::Sha2_512 => sha2_512::DIGEST_LENGTH_BYTES,
+        }
+    }
+
+    /// Hashes the password followed by the salt.
+    fn hash(self, password: &[u8], salt: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Md5 => hash::<md5::Update>(password, salt),
+            Self::Sha1 => hash::<sha1::Update>(password, salt),
+            Self::Sha2_256 => hash::<sha2_256::Update>(password, salt),
+            Self::Sha2_384 => hash::<sha2_384::Update>(password, salt),
+            Self::Sha2_512 => hash::<sha2_512::Update>(password, salt),
+        }
+    }
+}
+
+/// Encodes the password with the unsalted scheme, e.g. `{SHA}`.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ldap::{self, Scheme};
+///
+/// let password = ldap::encode("password", Scheme::Md5);
+/// assert_eq!(password, "{MD5}X03MO1qnZdYdgyfeuILPmQ==");
+/// ```
+#[must_use]
+pub fn encode(password: impl AsRef<[u8]>, scheme: Scheme) -> String {
+    let digest = scheme.hash(password.as_ref(), &[]);
+    format!("{{{}}}{}", scheme.name(), encode_base64(&digest))
+}
+
+/// Encodes the password with the salted scheme, e.g. `{SSHA}`.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ldap::{self, Scheme};
+///
+/// let password = ldap::encode_salted("password", [0x01, 0x02, 0x03, 0x04], Scheme::Sha2_256);
+/// assert_eq!(
+///     password,
+///     "{SSHA256}HRrSnyMQoWgqgxQEQXacTDCOVmN0BYhOKi2fCgm+aykBAgME"
+/// );
+/// ```
+#[must_use]
+pub fn encode_salted(password: impl AsRef<[u8]>, salt: impl AsRef<[u8]>, scheme: Scheme) -> String {
+    let salt = salt.as_ref();
+    let mut digest = scheme.hash(password.as_ref(), salt);
+    digest.extend_from_slice(salt);
+    format!("{{{}}}{}", scheme.salted_name(), encode_base64(&digest))
+}
+
+/// Verifies the password against the encoded one.
+///
+/// Returns `false` if the encoded password is malformed or uses an unsupported scheme. The digests are compared in constant time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ldap;
+///
+/// assert!(ldap::verify(
+///     "password",
+///     "{SHA}W6ph5Mm5Pz8GgiULbPgzG37mj9g="
+/// ));
+/// assert!(!ldap::verify(
+///     "password",
+///     "{CRYPT}$1$saltstri$YMyguxXMBpd2TEZ.vS/3q1"
+/// ));
+/// ```
+#[must_use]
+pub fn verify(password: impl AsRef<[u8]>, encoded: &str) -> bool {
+    match parse(encoded) {
+        Some((scheme, decoded)) => {
+            let (digest, salt) = decoded.split_at(scheme.digest_length());
+            let expected = scheme.hash(password.as_ref(), salt);
+            verify::constant_time_eq(&expected, digest)
+        },
+        None => false,
+    }
+}
+
+/// Parses the scheme and the decoded digest followed by the salt of the encoded password.
+fn parse(encoded: &str) -> Option<(Scheme, Vec<u8>)> {
+    let encoded = encoded.strip_prefix('{')?;
+    let (name, encoded) = encoded.split_once('}')?;
+    let (scheme, salted) = Scheme::from_name(name)?;
+    let decoded = decode_base64(encoded)?;
+    let length = scheme.digest_length();
+    if decoded.len() < length || (!salted && decoded.len() != length) {
+        return None;
+    }
+    Some((scheme, decoded))
+}
+
+/// Hashes the password followed by the salt with the given hash function.
+fn hash<H>(password: &[u8], salt: &[u8]) -> Vec<u8>
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]>,
+{
+    let mut hash = H::default();
+    hash.update(password);
+    hash.update(salt);
+    hash.digest().as_ref().to_vec()
+}
+
+/// Encodes the bytes with the standard base64 encoding with padding.
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut output = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let word = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |word, (index, &byte)| word | u32::from(byte) << (16 - 8 * index));
+        for index in 0..4 {
+            if index <= chunk.len() {
+                output.push(char::from(ALPHABET[(word >> (18 - 6 * index) & 0x3F) as usize]));
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+/// Decodes the standard base64 encoding with padding.
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let encoded = encoded.as_bytes();
+    if encoded.len() % 4 != 0 {
+        return None;
+    }
+
+    let mut output = Vec::with_capacity(encoded.len() / 4 * 3);
+    let chunks = encoded.len() / 4;
+    for (index, chunk) in encoded.chunks_exact(4).enumerate() {
+        // Padding is allowed only at the end of the last chunk.
+        let padding = chunk.iter().rev().take_while(|&&byte| byte == b'=').count();
+        if padding > 2 || (padding > 0 && index + 1 != chunks) {
+            return None;
+        }
+        let mut word = 0u32;
+        for &byte in &chunk[..4 - padding] {
+            let value = ALPHABET.iter().position(|&symbol| symbol == byte)?;
+            #[allow(clippy::cast_possible_truncation)]
+            let value = value as u32;
+            word = word << 6 | value;
+        }
+        word <<= 6 * padding;
+        output.extend_from_slice(&word.to_be_bytes()[1..4 - padding]);
+    }
+    Some(output)
+}
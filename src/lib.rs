@@ -72,8 +72,282 @@
 //! # }
 //! ```
 //!
+//! Alternatively, a reader can be wrapped in a [`HashReader`](io::HashReader), which hashes the data as it is read.
+//!
+//! ```rust
+//! # use std::io;
+//! # use std::path::PathBuf;
+//! use std::fs::File;
+//!
+//! use chksum_hash::io::HashReader;
+//! use chksum_hash::sha2_384;
+//!
+//! # fn wrapper(path: PathBuf) -> io::Result<()> {
+//! let mut reader = HashReader::<_, sha2_384::Update>::new(File::open(path)?);
+//! io::copy(&mut reader, &mut io::sink())?;
+//! let digest = reader.digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "12ecdfd463a85a301b7c29a43bf4b19cdfc6e5e86a5f40396aa6ae3368a7e5b0ed31f3bef2eb3071577ba610b4ed1cb8"
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! To hash many items with one instance, the hashes of this crate provide the `digest_reset` function, which produces the digest and resets the state in one call.
+//!
+//! ```rust
+//! use chksum_hash::md4;
+//!
+//! let mut hash = md4::default();
+//! let digests: Vec<_> = ["first", "second"]
+//!     .iter()
+//!     .map(|item| hash.update(item).digest_reset())
+//!     .collect();
+//! assert_eq!(digests, [md4::hash("first"), md4::hash("second")]);
+//! ```
+//!
 //! # Algorithms
 //!
+//! ## BLAKE-256
+//!
+//! ```rust
+//! use chksum_hash::blake_256;
+//!
+//! let digest = blake_256::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "474c2d1c4c0174866859236131de3482e98e3c6d6c5327537cfe7f88e0b5b403"
+//! );
+//! ```
+//!
+//! ## BLAKE-512
+//!
+//! ```rust
+//! use chksum_hash::blake_512;
+//!
+//! let digest = blake_512::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "7a5f20325f5b5e38508327c655bc334dbe20463a29a361f9cb926e1786f9b09750a8a3afede46ad46a9430e232201437e81c9042c49d49d884f57c9d322cc3cc"
+//! );
+//! ```
+//!
+//! ## BSD sum
+//!
+//! ```rust
+//! use chksum_hash::bsd_sum;
+//!
+//! let digest = bsd_sum::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "40e8");
+//! ```
+//!
+//! ## Classic
+//!
+//! ```rust
+//! use chksum_hash::classic::djb2;
+//!
+//! let digest = djb2::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "118c0a8b");
+//! ```
+//!
+//! ## CRC
+//!
+//! ```rust
+//! use chksum_hash::crc::{self, Params};
+//!
+//! let digest = crc::Update::with_params(Params::CRC_16_MODBUS)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "d041");
+//! ```
+//!
+//! ## CRC-32
+//!
+//! ```rust
+//! use chksum_hash::crc32;
+//!
+//! let digest = crc32::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "e9c54def");
+//! ```
+//!
+//! ## CRC-32C
+//!
+//! ```rust
+//! use chksum_hash::crc32c;
+//!
+//! let digest = crc32c::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "e5ffa5d7");
+//! ```
+//!
+//! ## CRC-64
+//!
+//! ```rust
+//! use chksum_hash::crc64;
+//!
+//! let digest = crc64::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "09fb1b316ea07855");
+//! ```
+//!
+//! ## ed2k
+//!
+//! ```rust
+//! use chksum_hash::ed2k;
+//!
+//! let digest = ed2k::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "8c96deac45db5adc46f1e8d27d76f317"
+//! );
+//! ```
+//!
+//! ## GHASH
+//!
+//! ```rust
+//! use chksum_hash::ghash;
+//!
+//! let digest = ghash::hash(*b"0123456789abcdef", "example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "a4009c34f80df71c8a38216457606f98"
+//! );
+//! ```
+//!
+//! ## Haraka
+//!
+//! ```rust
+//! use chksum_hash::haraka;
+//!
+//! let digest = haraka::hash_256(&[0; 32]);
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "583066c7dd645eee22980f3c35971b702973d03a029eb246eb44eceb4a4f5863"
+//! );
+//! ```
+//!
+//! ## HAVAL
+//!
+//! ```rust
+//! use chksum_hash::haval;
+//!
+//! let digest = haval::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "bb9532d378fbdf45414f7e55aaa2104e74bec53b62698ff534df2b52241dea04"
+//! );
+//! ```
+//!
+//! ## Internet checksum
+//!
+//! ```rust
+//! use chksum_hash::internet_checksum;
+//!
+//! let digest = internet_checksum::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "8aca");
+//! ```
+//!
+//! ## JH-224
+//!
+//! ```rust
+//! use chksum_hash::jh_224;
+//!
+//! let digest = jh_224::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "a9502bce5e9df65cb945af4f14d9ba675217bb64b143fad78abd68bc"
+//! );
+//! ```
+//!
+//! ## JH-256
+//!
+//! ```rust
+//! use chksum_hash::jh_256;
+//!
+//! let digest = jh_256::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "a509970e4cb03e193a29c04b6fec2bdd46c671e9343b609997fad099d77fb0c1"
+//! );
+//! ```
+//!
+//! ## JH-384
+//!
+//! ```rust
+//! use chksum_hash::jh_384;
+//!
+//! let digest = jh_384::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "66ab1e1a7fd125cb1b5e021da63edd143bdfb4c0f312456872dc95f4983fa0ed6e0c40144e600b97e8d95bed41114f72"
+//! );
+//! ```
+//!
+//! ## JH-512
+//!
+//! ```rust
+//! use chksum_hash::jh_512;
+//!
+//! let digest = jh_512::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "76c70ae09372ab590c139e10d4a049e52850787b7294f46df06dc907a40a0b353db9157beb1fc72cbbd810f41900381f5487b1855a0f39404f19d4d151f9e071"
+//! );
+//! ```
+//!
+//! ## komihash
+//!
+//! ```rust
+//! use chksum_hash::komihash;
+//!
+//! let digest = komihash::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "3f83b768e1e2ed53");
+//! ```
+//!
+//! ## lookup3
+//!
+//! ```rust
+//! use chksum_hash::lookup3;
+//!
+//! let digest = lookup3::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "f4b9337f");
+//! ```
+//!
+//! ## LSH-256
+//!
+//! ```rust
+//! use chksum_hash::lsh_256;
+//!
+//! let digest = lsh_256::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "b89a43026126fa6de86229b3c3891dd940c0a7d4e277457a97cf56114facb8c6"
+//! );
+//! ```
+//!
+//! ## LSH-512
+//!
+//! ```rust
+//! use chksum_hash::lsh_512;
+//!
+//! let digest = lsh_512::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "43eeda6f32fa52461476aab68c0df7c6ffe8907de971e4a740724a185d69e8819436ac247cd982fd1d05bb8f4ff3831582d69bdc100b88fbdaa53681941aae01"
+//! );
+//! ```
+//!
+//! ## MD4
+//!
+//! ```rust
+//! use chksum_hash::md4;
+//!
+//! let digest = md4::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "8c96deac45db5adc46f1e8d27d76f317"
+//! );
+//! ```
+//!
 //! ## MD5
 //!
 //! ```rust
@@ -86,6 +360,51 @@
 //! );
 //! ```
 //!
+//! ## MD6
+//!
+//! ```rust
+//! use chksum_hash::md6;
+//!
+//! let digest = md6::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "8e9d78bf42c740ab84b9c4e5597e43efa0fc78f95bd5cb329878c50550ac2ec7"
+//! );
+//! ```
+//!
+//! ## One-at-a-time
+//!
+//! ```rust
+//! use chksum_hash::one_at_a_time;
+//!
+//! let digest = one_at_a_time::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "d0f467ca");
+//! ```
+//!
+//! ## POLYVAL
+//!
+//! ```rust
+//! use chksum_hash::polyval;
+//!
+//! let digest = polyval::hash(*b"0123456789abcdef", "example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "b355ef1e230a3059de3337546477c0ea"
+//! );
+//! ```
+//!
+//! ## SHA-0
+//!
+//! ```rust
+//! use chksum_hash::sha0;
+//!
+//! let digest = sha0::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "bcb013db91ec6a74ea58eaf6c129201b8fa0e176"
+//! );
+//! ```
+//!
 //! ## SHA-1
 //!
 //! ```rust
@@ -146,16 +465,437 @@
 //! );
 //! ```
 //!
+//! ## Skein-256
+//!
+//! ```rust
+//! use chksum_hash::skein_256;
+//!
+//! let digest = skein_256::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "94487605c133311744775c6cda70822c23f5e017c0ff157b2153a4b3a232fe41"
+//! );
+//! ```
+//!
+//! ## Skein-512
+//!
+//! ```rust
+//! use chksum_hash::skein_512;
+//!
+//! let digest = skein_512::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "61c8a1e2468ede23380c90db6b562b884f699221d25aab749088e904e45117bbfcf9b7b0325dbc563f862c9aed1d39a61f3a411288679b4b51f7750f4c3f3538"
+//! );
+//! ```
+//!
+//! ## Skein-1024
+//!
+//! ```rust
+//! use chksum_hash::skein_1024;
+//!
+//! let digest = skein_1024::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "7bfada07cf2a105598d73d3b610d9f7bfa416ab5d13ba1bcc6c3b626b63f129f808d6f8fcfd55e874ff67df38f3f7092d61719f4fa16dc338d019807eac0de7cb54ad1bf426d5c1064d319479b1c1f2ee72b2bb591bd92b54ee8e6cc7ca23ea0327eed2b9056f807bddc1d98c1f203766dd886d9f539561e3e8a45da0ede3628"
+//! );
+//! ```
+//!
+//! ## SysV sum
+//!
+//! ```rust
+//! use chksum_hash::sysv_sum;
+//!
+//! let digest = sysv_sum::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "04a6");
+//! ```
+//!
+//! ## Tiger
+//!
+//! ```rust
+//! use chksum_hash::tiger;
+//!
+//! let digest = tiger::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "91f20b3995fa62bae56644c0055dcab15481cc8089380c30"
+//! );
+//! ```
+//!
+//! ## Tiger2
+//!
+//! ```rust
+//! use chksum_hash::tiger2;
+//!
+//! let digest = tiger2::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "61b8036059e2c0136396a184ff3ddb1ae5704df2c33b69df"
+//! );
+//! ```
+//!
+//! ## TTH
+//!
+//! ```rust
+//! use chksum_hash::tth;
+//!
+//! let digest = tth::hash("example data");
+//! assert_eq!(
+//!     digest.to_base32(),
+//!     "VPDQROZSKJE5DTF2ID6ZZGSBRLJEULE5IXE7QRY"
+//! );
+//! ```
+//!
+//! ## XXH3-64
+//!
+//! ```rust
+//! use chksum_hash::xxh3_64;
+//!
+//! let digest = xxh3_64::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "713550694c4d35de");
+//! ```
+//!
+//! ## XXH3-128
+//!
+//! ```rust
+//! use chksum_hash::xxh3_128;
+//!
+//! let digest = xxh3_128::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "f3c20656bd27147872c71e4cece53b20"
+//! );
+//! ```
+//!
+//! # Message Authentication
+//!
+//! The [`hmac`] module provides HMAC over the block-based cryptographic hash functions.
+//!
+//! ```rust
+//! use chksum_hash::{hmac, sha2_256};
+//!
+//! let digest = hmac::hash::<sha2_256::Update>("key", "example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "b54622b45c60e7acdc4cb2cf8299bd14135c8d7e45e8afa49b4749292a2e1cf9"
+//! );
+//! ```
+//!
+//! # Salted Hashing
+//!
+//! The [`salt`] module computes the digests of data prefixed with a salt over the hash functions of this crate and generates random salts with the `salt-random` Cargo feature.
+//!
+//! ```rust
+//! use chksum_hash::{salt, sha2_256};
+//!
+//! let digest = salt::hash::<sha2_256::Update>("salt", "example data");
+//! assert_eq!(digest, sha2_256::hash("saltexample data"));
+//! ```
+//!
+//! # Key Derivation
+//!
+//! The [`hkdf`] module provides HKDF built on top of HMAC.
+//!
+//! ```rust
+//! use chksum_hash::{hkdf, sha2_256};
+//!
+//! let okm = hkdf::derive::<sha2_256::Update>("salt", "example data", "info", 32);
+//! assert_eq!(okm.len(), 32);
+//! ```
+//!
+//! The [`pbkdf2`] and [`scrypt`] modules provide password-based key derivation.
+//!
+//! ```rust
+//! use chksum_hash::scrypt;
+//!
+//! let params = scrypt::Params::new(10);
+//! let key = scrypt::derive("password", "salt", params, 32);
+//! assert_eq!(key.len(), 32);
+//! ```
+//!
+//! # Password Hashing
+//!
+//! The [`md5_crypt`] and [`sha_crypt`] modules provide the `$1$`, `$apr1$`, `$5$` and `$6$` password hashes of crypt(3) and `htpasswd`, the [`ldap`] module provides the `{SHA}` and `{SSHA}` family of LDAP password schemes.
+//!
+//! ```rust
+//! use chksum_hash::sha_crypt;
+//!
+//! let hash = "$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5";
+//! assert!(sha_crypt::verify("Hello world!", hash));
+//! ```
+//!
+//! # One-time Passwords
+//!
+//! The [`otp`] module provides HOTP and TOTP built on top of HMAC.
+//!
+//! ```rust
+//! use chksum_hash::{otp, sha1};
+//!
+//! let totp = otp::Totp::<sha1::Update>::new("12345678901234567890");
+//! assert_eq!(totp.generate(59), 287082);
+//! ```
+//!
+//! # Hash Chains
+//!
+//! The [`chain`] module applies a hash function repeatedly, either to get the digest after the given number of iterations or to iterate over the intermediate digests.
+//!
+//! ```rust
+//! use chksum_hash::{chain, sha2_256};
+//!
+//! let digest = chain::hash_iter::<sha2_256::Update>("example data", 2);
+//! assert_eq!(digest, sha2_256::hash(sha2_256::hash("example data")));
+//! ```
+//!
+//! # Merkle Trees
+//!
+//! The [`merkle`] module provides binary Merkle trees over the hash functions of this crate, the [`bitcoin`] module computes the Merkle roots of Bitcoin blocks, the [`bittorrent`] module computes the piece hashes of torrents and the [`glacier`] module computes the SHA-256 tree hash of Amazon S3 Glacier.
+//!
+//! ```rust
+//! use chksum_hash::{merkle, sha2_256};
+//!
+//! let root = merkle::root::<sha2_256::Update, _>(["a", "b", "c"]);
+//! assert_eq!(
+//!     root.to_hex_lowercase(),
+//!     "36642e73c2540ab121e3a6bf9545b0a24982cd830eb13d3cd19de3ce6c021ec1"
+//! );
+//! ```
+//!
+//! # Git Objects
+//!
+//! The [`git`] module computes the identifiers of Git objects in both the SHA-1 and the SHA-256 repository formats.
+//!
+//! ```rust
+//! use chksum_hash::git::{self, ObjectFormat, ObjectType};
+//!
+//! let id = git::hash(ObjectType::Blob, "", ObjectFormat::Sha1);
+//! assert_eq!(
+//!     id.to_hex_lowercase(),
+//!     "e69de29bb2d1d6434b8b29ae775ad8c2e48c5391"
+//! );
+//! ```
+//!
+//! # Container Images
+//!
+//! The [`oci`] module parses, emits and verifies the `sha256:<hex>` and `sha512:<hex>` digest strings of OCI and Docker registries.
+//!
+//! ```rust
+//! use chksum_hash::oci::{self, Algorithm};
+//!
+//! let digest = oci::hash(Algorithm::Sha256, "");
+//! assert_eq!(
+//!     digest.to_string(),
+//!     "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+//! );
+//! ```
+//!
+//! # SSH Fingerprints
+//!
+//! The [`ssh`] module computes, formats and parses the `SHA256:` and legacy `MD5:` public key fingerprints of OpenSSH.
+//!
+//! ```rust
+//! use chksum_hash::ssh::{self, Algorithm};
+//!
+//! let key = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIH3gl8QFeUw1wSpQ/uEPQplhzUyQ1+THzwXM14w1aHNE";
+//! let fingerprint = ssh::fingerprint_public_key(Algorithm::Sha256, key).unwrap();
+//! assert_eq!(
+//!     fingerprint.to_string(),
+//!     "SHA256:GyghVgujcZ/NJ6NcPG8hh+wRjy/xj6Au6ogAT6Jzji4"
+//! );
+//! ```
+//!
+//! # Multiformats
+//!
+//! The [`multihash`] module encodes digests as self-describing multihashes, which prefix the digest with the code of the hash function, and parses them back. The [`cid`] module builds the content identifiers of IPFS on top of them.
+//!
+//! ```rust
+//! use chksum_hash::cid::{self, Codec};
+//! use chksum_hash::{multihash, sha2_256};
+//!
+//! let multihash = multihash::encode(sha2_256::hash("example data"));
+//! assert_eq!(multihash.len(), 2 + sha2_256::DIGEST_LENGTH_BYTES);
+//!
+//! let cid = cid::hash::<sha2_256::Update>(Codec::Raw, "");
+//! assert_eq!(
+//!     cid.to_string(),
+//!     "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+//! );
+//! ```
+//!
+//! # Checksum Files
+//!
+//! The [`checksum_file`] module emits and parses the checksum files of `sha256sum` and the related tools of GNU coreutils, as well as the tagged checksum files of BSD, and verifies their entries. The [`sfv`] module does the same for the CRC-32 checksums of SFV files.
+//!
+//! ```rust
+//! use chksum_hash::checksum_file::{Entry, Mode};
+//! use chksum_hash::sha2_256;
+//!
+//! let entry = Entry::new(sha2_256::hash("example data"), "data.txt", Mode::Text);
+//! assert!(entry.to_string().ends_with("5061  data.txt"));
+//! assert!(entry.verify::<sha2_256::Update>("example data"));
+//! ```
+//!
+//! # Serialization
+//!
+//! The `serde` Cargo feature implements the `Serialize` and `Deserialize` traits of serde for the digests of this crate, except for the ones of the external MD5, SHA-1 and SHA-2 crates. Human-readable formats, like JSON, use the lowercase hexadecimal representation. Binary formats use the bytes of the digest, fixed-length digests as tuples without a length prefix.
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use chksum_hash::md4;
+//!
+//! let digest = md4::hash("data");
+//! let json = serde_json::to_string(&digest).unwrap();
+//! assert_eq!(json, r#""539080ba278cf4cf4db2e4a32642ff30""#);
+//! assert_eq!(serde_json::from_str::<md4::Digest>(&json).unwrap(), digest);
+//!
+//! let binary = bincode::serialize(&digest).unwrap();
+//! assert_eq!(binary, digest.as_ref());
+//! assert_eq!(
+//!     bincode::deserialize::<md4::Digest>(&binary).unwrap(),
+//!     digest
+//! );
+//! # }
+//! ```
+//!
+//! The same feature implements the traits for the in-progress `Update` states of the block-based hash functions with fixed-length digests, i.e. BLAKE, MD4, SHA-0 and Tiger, and of the checksums and non-cryptographic hash functions with simple states. The serialized state contains the state words, the unprocessed data and the number of processed bytes, so e.g. the hashing of a large upload can be checkpointed and resumed after a restart.
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use chksum_hash::md4;
+//!
+//! let mut hash = md4::default();
+//! hash.update("da");
+//! let checkpoint = serde_json::to_string(&hash).unwrap();
+//!
+//! let mut hash = serde_json::from_str::<md4::Update>(&checkpoint).unwrap();
+//! hash.update("ta");
+//! assert_eq!(hash.digest(), md4::hash("data"));
+//! # }
+//! ```
+//!
+//! The `borsh` Cargo feature implements the `BorshSerialize` and `BorshDeserialize` traits of borsh for the same digests. Fixed-length digests are written as their bytes, variable-length digests with the length prefix of borsh.
+//!
+//! ```rust
+//! # #[cfg(feature = "borsh")]
+//! # {
+//! use borsh::BorshDeserialize;
+//! use chksum_hash::{crc, md4};
+//!
+//! let digest = md4::hash("data");
+//! let bytes = borsh::to_vec(&digest).unwrap();
+//! assert_eq!(bytes, digest.as_ref());
+//! assert_eq!(md4::Digest::try_from_slice(&bytes).unwrap(), digest);
+//!
+//! let digest = crc::Digest::new(&[0xCB, 0xF4, 0x39, 0x26]);
+//! let bytes = borsh::to_vec(&digest).unwrap();
+//! assert_eq!(bytes, [4, 0, 0, 0, 0xCB, 0xF4, 0x39, 0x26]);
+//! assert_eq!(crc::Digest::try_from_slice(&bytes).unwrap(), digest);
+//! # }
+//! ```
+//!
+//! # State Export
+//!
+//! Independently of the serialization features, the `Update` states of BLAKE-256, BLAKE-512, MD4, SHA-0, Tiger and Tiger2 can be exported into bytes with `export_state` and imported back with `import_state`. The binary format is stable and versioned, so the states can be exchanged between processes, releases of this crate and implementations in other languages. All integers are big-endian.
+//!
+//! | Field | Length | Description |
+//! |-|-|-|
+//! | magic | 4 | the bytes `CHKS` |
+//! | version | 1 | the format version, currently 1 |
+//! | identifier length | 1 | the length of the identifier |
+//! | identifier | variable | the name of the Cargo feature of the hash function, e.g. `md4` or `blake-256` |
+//! | state | variable | the state words in the order of their definitions, 32-bit or 64-bit depending on the hash function |
+//! | processed | 8 | the number of processed bytes, a multiple of the block length |
+//! | unprocessed length | 1 | the length of the unprocessed data, less than the block length |
+//! | unprocessed | variable | the unprocessed data |
+//!
+//! The import fails with a [`StateError`](error::StateError) if the magic, the version, the identifier or the length does not match, or if the number of processed bytes or unprocessed data cannot occur.
+//!
+//! ```rust
+//! use chksum_hash::md4;
+//!
+//! let mut hash = md4::default();
+//! hash.update("da");
+//! let state = hash.export_state();
+//! assert_eq!(state[..9], *b"CHKS\x01\x03md4");
+//! assert_eq!(state.len(), 36);
+//!
+//! let mut hash = md4::Update::import_state(&state).unwrap();
+//! hash.update("ta");
+//! assert_eq!(hash.digest(), md4::hash("data"));
+//! ```
+//!
 //! # Features
 //!
 //! Cargo features are utilized to enable or disable specific hash algorithms.
 //!
+//! * `arbitrary` enables the generation of digests, states and hashes with arbitrary for fuzzing (except for the hashes built on MD5, SHA-1 and SHA-2), it is not enabled by default.
+//! * `bitcoin` enables Bitcoin Merkle roots, accessible via the [`bitcoin`] module.
+//! * `bittorrent` enables BitTorrent piece hashes, accessible via the [`bittorrent`] module.
+//! * `blake-256` enables BLAKE-256, accessible via the [`blake_256`] module.
+//! * `blake-512` enables BLAKE-512, accessible via the [`blake_512`] module.
+//! * `borsh` enables the serialization of digests with borsh, it is not enabled by default.
+//! * `bsd-sum` enables BSD sum, accessible via the [`bsd_sum`] module.
+//! * `chain` enables hash chains, accessible via the [`chain`] module.
+//! * `checksum-file` enables GNU and BSD checksum files, accessible via the [`checksum_file`] module.
+//! * `cid` enables IPFS content identifiers, accessible via the [`cid`] module.
+//! * `classic` enables djb2, sdbm and ELF hashes, accessible via the [`classic`] module.
+//! * `crc` enables CRCs with arbitrary parameters, accessible via the [`crc`] module.
+//! * `crc32` enables CRC-32, accessible via the [`crc32`] module.
+//! * `crc32c` enables CRC-32C, accessible via the [`crc32c`] module.
+//! * `crc64` enables CRC-64, accessible via the [`crc64`] module.
+//! * `ed2k` enables the ed2k hash, accessible via the [`ed2k`] module.
+//! * `futures` enables the asynchronous adapters and the stream hashing of the [`io`] module for the futures crate, it is not enabled by default.
+//! * `ghash` enables GHASH, accessible via the [`ghash`] module.
+//! * `git` enables Git object identifiers, accessible via the [`git`] module.
+//! * `glacier` enables the Amazon S3 Glacier tree hash, accessible via the [`glacier`] module.
+//! * `haraka` enables Haraka-256 and Haraka-512, accessible via the [`haraka`] module.
+//! * `haval` enables HAVAL, accessible via the [`haval`] module.
+//! * `hkdf` enables HKDF, accessible via the [`hkdf`] module.
+//! * `hmac` enables HMAC, accessible via the [`hmac`] module.
+//! * `internet-checksum` enables the Internet checksum, accessible via the [`internet_checksum`] module.
+//! * `jh-224` enables JH-224, accessible via the [`jh_224`] module.
+//! * `jh-256` enables JH-256, accessible via the [`jh_256`] module.
+//! * `jh-384` enables JH-384, accessible via the [`jh_384`] module.
+//! * `jh-512` enables JH-512, accessible via the [`jh_512`] module.
+//! * `komihash` enables komihash, accessible via the [`komihash`] module.
+//! * `ldap` enables the LDAP password schemes, accessible via the [`ldap`] module.
+//! * `lookup3` enables lookup3, accessible via the [`lookup3`] module.
+//! * `lsh-256` enables LSH-256, accessible via the [`lsh_256`] module.
+//! * `lsh-512` enables LSH-512, accessible via the [`lsh_512`] module.
+//! * `md4` enables MD4, accessible via the [`md4`] module.
 //! * `md5` enables MD5, accessible via the [`md5`] module.
+//! * `md5-crypt` enables MD5-crypt and APR1, accessible via the [`md5_crypt`] module.
+//! * `md6` enables MD6, accessible via the [`md6`] module.
+//! * `merkle` enables Merkle trees, accessible via the [`merkle`] module.
+//! * `multihash` enables multihash encoding, accessible via the [`multihash`] module.
+//! * `oci` enables OCI digest strings, accessible via the [`oci`] module.
+//! * `one-at-a-time` enables the one-at-a-time hash, accessible via the [`one_at_a_time`] module.
+//! * `polyval` enables POLYVAL, accessible via the [`polyval`] module.
+//! * `salt` enables salted hashing, accessible via the [`salt`] module.
+//! * `salt-random` enables the generation of random salts in the [`salt`] module, it is not enabled by default.
+//! * `otp` enables HOTP and TOTP, accessible via the [`otp`] module.
+//! * `pbkdf2` enables PBKDF2, accessible via the [`pbkdf2`] module.
+//! * `serde` enables the serialization of digests with serde, it is not enabled by default.
+//! * `sfv` enables SFV files, accessible via the [`sfv`] module.
+//! * `sha-crypt` enables SHA-crypt, accessible via the [`sha_crypt`] module.
+//! * `sha0` enables SHA-0, accessible via the [`sha0`] module.
 //! * `sha1` enables SHA-1, accessible via the [`sha1`] module.
 //! * `sha2-224` enables SHA-2 224, accessible via the [`sha2_224`] module.
 //! * `sha2-256` enables SHA-2 256, accessible via the [`sha2_256`] module.
 //! * `sha2-384` enables SHA-2 384, accessible via the [`sha2_384`] module.
 //! * `sha2-512` enables SHA-2 512, accessible via the [`sha2_512`] module.
+//! * `scrypt` enables scrypt, accessible via the [`scrypt`] module.
+//! * `skein-256` enables Skein-256, accessible via the [`skein_256`] module.
+//! * `skein-512` enables Skein-512, accessible via the [`skein_512`] module.
+//! * `skein-1024` enables Skein-1024, accessible via the [`skein_1024`] module.
+//! * `ssh` enables OpenSSH fingerprints, accessible via the [`ssh`] module.
+//! * `sysv-sum` enables SysV sum, accessible via the [`sysv_sum`] module.
+//! * `tiger` enables Tiger, accessible via the [`tiger`] module.
+//! * `tiger2` enables Tiger2, accessible via the [`tiger2`] module.
+//! * `tth` enables the Tiger Tree Hash, accessible via the [`tth`] module.
+//! * `xxh3-64` enables XXH3-64, accessible via the [`xxh3_64`] module.
+//! * `xxh3-128` enables XXH3-128, accessible via the [`xxh3_128`] module.
 //!
 //! By default, all of these features are enabled.
 //!
@@ -199,3 +939,183 @@ pub use chksum_hash_sha2::sha2_384;
 #[cfg(feature = "sha2-512")]
 #[doc(no_inline)]
 pub use chksum_hash_sha2::sha2_512;
+
+pub use crate::dynamic::{hash_with, new_with, HashAlgorithm, MultiHasher};
+pub use crate::error::Error;
+
+pub mod algorithm;
+pub mod base32;
+pub mod base64;
+#[cfg(feature = "bitcoin")]
+pub mod bitcoin;
+#[cfg(feature = "bittorrent")]
+pub mod bittorrent;
+#[cfg(feature = "blake-256")]
+pub mod blake_256;
+#[cfg(feature = "blake-512")]
+pub mod blake_512;
+#[cfg(feature = "bsd-sum")]
+pub mod bsd_sum;
+#[cfg(any(
+    feature = "blake-256",
+    feature = "blake-512",
+    feature = "haval",
+    feature = "jh-224",
+    feature = "jh-256",
+    feature = "jh-384",
+    feature = "jh-512",
+    feature = "komihash",
+    feature = "lsh-256",
+    feature = "lsh-512",
+    feature = "md4",
+    feature = "md6",
+    feature = "polyval",
+    feature = "sha0",
+    feature = "skein-256",
+    feature = "skein-512",
+    feature = "skein-1024",
+    feature = "tiger",
+    feature = "xxh3-64",
+    feature = "xxh3-128"
+))]
+#[allow(dead_code)] // partly unused depending on the enabled hash functions
+mod buffer;
+#[cfg(feature = "chain")]
+pub mod chain;
+#[cfg(feature = "checksum-file")]
+pub mod checksum_file;
+#[cfg(feature = "cid")]
+pub mod cid;
+#[cfg(feature = "classic")]
+pub mod classic;
+#[cfg(any(
+    feature = "md5",
+    feature = "sha1",
+    feature = "sha2-224",
+    feature = "sha2-256",
+    feature = "sha2-384",
+    feature = "sha2-512"
+))]
+pub mod const_hash;
+#[cfg(feature = "crc")]
+pub mod crc;
+#[cfg(feature = "crc32")]
+pub mod crc32;
+#[cfg(feature = "crc32c")]
+pub mod crc32c;
+#[cfg(feature = "crc64")]
+pub mod crc64;
+#[cfg(any(feature = "md5-crypt", feature = "sha-crypt"))]
+mod crypt;
+pub mod digest;
+pub mod dynamic;
+#[cfg(feature = "ed2k")]
+pub mod ed2k;
+pub mod error;
+#[cfg(any(
+    feature = "blake-256",
+    feature = "blake-512",
+    feature = "md4",
+    feature = "sha0",
+    feature = "tiger"
+))]
+#[allow(dead_code)] // partly unused depending on the enabled hash functions
+mod export;
+#[cfg(feature = "ghash")]
+pub mod ghash;
+#[cfg(feature = "git")]
+pub mod git;
+#[cfg(feature = "glacier")]
+pub mod glacier;
+#[cfg(feature = "haraka")]
+pub mod haraka;
+pub mod hasher;
+#[cfg(feature = "haval")]
+pub mod haval;
+pub mod hex;
+#[cfg(feature = "hkdf")]
+pub mod hkdf;
+#[cfg(feature = "hmac")]
+pub mod hmac;
+#[cfg(feature = "internet-checksum")]
+pub mod internet_checksum;
+pub mod io;
+#[cfg(any(feature = "jh-224", feature = "jh-256", feature = "jh-384", feature = "jh-512"))]
+pub mod jh;
+#[cfg(feature = "jh-224")]
+pub mod jh_224;
+#[cfg(feature = "jh-256")]
+pub mod jh_256;
+#[cfg(feature = "jh-384")]
+pub mod jh_384;
+#[cfg(feature = "jh-512")]
+pub mod jh_512;
+#[cfg(feature = "komihash")]
+pub mod komihash;
+#[cfg(feature = "ldap")]
+pub mod ldap;
+#[cfg(feature = "lookup3")]
+pub mod lookup3;
+#[cfg(feature = "lsh-256")]
+pub mod lsh_256;
+#[cfg(feature = "lsh-512")]
+pub mod lsh_512;
+#[cfg(feature = "md4")]
+pub mod md4;
+#[cfg(feature = "md5-crypt")]
+pub mod md5_crypt;
+#[cfg(feature = "md6")]
+pub mod md6;
+#[cfg(feature = "merkle")]
+pub mod merkle;
+#[cfg(feature = "multihash")]
+pub mod multihash;
+#[cfg(feature = "oci")]
+pub mod oci;
+#[cfg(feature = "one-at-a-time")]
+pub mod one_at_a_time;
+#[cfg(feature = "otp")]
+pub mod otp;
+#[cfg(feature = "pbkdf2")]
+pub mod pbkdf2;
+#[cfg(feature = "polyval")]
+pub mod polyval;
+#[cfg(feature = "salt")]
+pub mod salt;
+#[cfg(feature = "scrypt")]
+pub mod scrypt;
+#[cfg(feature = "serde")]
+#[allow(dead_code)] // unused if only the hash functions of the external crates are enabled
+mod serialization;
+#[cfg(feature = "sfv")]
+pub mod sfv;
+#[cfg(feature = "sha0")]
+pub mod sha0;
+#[cfg(feature = "sha-crypt")]
+pub mod sha_crypt;
+#[cfg(any(feature = "skein-256", feature = "skein-512", feature = "skein-1024"))]
+pub mod skein;
+#[cfg(feature = "skein-1024")]
+pub mod skein_1024;
+#[cfg(feature = "skein-256")]
+pub mod skein_256;
+#[cfg(feature = "skein-512")]
+pub mod skein_512;
+#[cfg(feature = "ssh")]
+pub mod ssh;
+#[cfg(feature = "sysv-sum")]
+pub mod sysv_sum;
+#[cfg(feature = "tiger")]
+pub mod tiger;
+#[cfg(feature = "tiger2")]
+pub mod tiger2;
+pub mod truncated;
+#[cfg(feature = "tth")]
+pub mod tth;
+pub mod verify;
+#[cfg(any(feature = "xxh3-64", feature = "xxh3-128"))]
+pub mod xxh3;
+#[cfg(feature = "xxh3-128")]
+pub mod xxh3_128;
+#[cfg(feature = "xxh3-64")]
+pub mod xxh3_64;
@@ -0,0 +1,469 @@
+//! This module is optional and can be enabled using the `lookup3` Cargo feature.
+//!
+//! An implementation of Bob Jenkins' lookup3 hash function.
+//!
+//! lookup3 produces a 32-bit hash of its input and accepts a 32-bit seed, called the initial value by the reference implementation. It comes in two variants, `hashlittle` and `hashbig`, see [`Variant`]. Since the input length is mixed into the initial state, the stream processing keeps all of the data in memory until the digest is requested; use the [`hash_little`], [`hash_little2`] and [`hash_big`] functions when the whole input is at hand. These are `const` functions, so they can be evaluated at compile time as well.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::lookup3;
+//!
+//! let digest = lookup3::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "f4b9337f");
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::lookup3;
+//!
+//! let digest = lookup3::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "f4b9337f");
+//! ```
+//!
+//! # Seeds and Variants
+//!
+//! Use [`Update::with_seed`] or [`Update::with_variant`] to choose another seed or variant.
+//!
+//! ```rust
+//! use chksum_hash::lookup3::{self, Variant};
+//!
+//! let digest = lookup3::Update::with_seed(42)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "4b0d1230");
+//!
+//! let digest = lookup3::Update::with_variant(Variant::Big, 42)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "14a0e722");
+//! ```
+//!
+//! # Disclaimer
+//!
+//! lookup3 is not a cryptographic hash function. It is designed for hash table lookup and must not be used where resistance to deliberate collisions is required.
+
+pub mod digest;
+pub mod variant;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::variant::Variant;
+use crate::verify::Expected;
+
+/// Block length in bytes.
+const BLOCK_LENGTH_BYTES: usize = 12;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lookup3;
+///
+/// let digest = lookup3::new().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "deadbeef");
+///
+/// let digest = lookup3::new().update("data").digest();
+/// assert_eq!(digest.to_hex_lowercase(), "eaa2c748");
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lookup3;
+///
+/// let digest = lookup3::default().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "deadbeef");
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lookup3;
+///
+/// let digest = lookup3::hash("abc");
+/// assert_eq!(digest.to_hex_lowercase(), "0e397631");
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lookup3;
+///
+/// let digest = lookup3::hash("data");
+/// assert!(lookup3::verify("data", digest));
+/// assert!(lookup3::verify("data", digest.to_hex_uppercase()));
+/// assert!(!lookup3::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// Computes `hashlittle` of the given input with the given seed.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lookup3;
+///
+/// const HASH: u32 = lookup3::hash_little(b"Four score and seven years ago", 0);
+/// assert_eq!(HASH, 0x17770551);
+///
+/// let hash = lookup3::hash_little(b"Four score and seven years ago", 1);
+/// assert_eq!(hash, 0xCD628161);
+/// ```
+#[must_use]
+pub const fn hash_little(data: &[u8], seed: u32) -> u32 {
+    compute(data, seed, 0, Variant::Little).0
+}
+
+/// Computes `hashlittle2` of the given input with the given pair of seeds.
+///
+/// Returns the primary hash, which is the same as the result of [`hash_little`] if the secondary seed is zero, and the secondary hash. Both can be combined into a 64-bit hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lookup3;
+///
+/// let (primary, secondary) = lookup3::hash_little2(b"Four score and seven years ago", 0, 1);
+/// assert_eq!(primary, 0xE3607CAE);
+/// assert_eq!(secondary, 0xBD371DE4);
+///
+/// let hash = u64::from(primary) | u64::from(secondary) << 32;
+/// assert_eq!(hash, 0xBD371DE4E3607CAE);
+/// ```
+#[must_use]
+pub const fn hash_little2(data: &[u8], primary: u32, secondary: u32) -> (u32, u32) {
+    compute(data, primary, secondary, Variant::Little)
+}
+
+/// Computes `hashbig` of the given input with the given seed.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lookup3;
+///
+/// let hash = lookup3::hash_big(b"Four score and seven years ago", 0);
+/// assert_eq!(hash, 0x65E759CB);
+/// ```
+#[must_use]
+pub const fn hash_big(data: &[u8], seed: u32) -> u32 {
+    compute(data, seed, 0, Variant::Big).0
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lookup3;
+///
+/// // Create a new hash instance
+/// let mut hash = lookup3::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "eaa2c748");
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(digest.to_hex_lowercase(), "deadbeef");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    variant: Variant,
+    seed: u32,
+    unprocessed: Vec<u8>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Creates a new `hashlittle` hash with the given seed.
+    #[must_use]
+    pub fn with_seed(seed: u32) -> Self {
+        Self::with_variant(Variant::default(), seed)
+    }
+
+    /// Creates a new hash for the given variant and seed.
+    #[must_use]
+    pub fn with_variant(variant: Variant, seed: u32) -> Self {
+        let unprocessed = Vec::new();
+        Self {
+            variant,
+            seed,
+            unprocessed,
+        }
+    }
+
+    /// Returns the variant of the hash.
+    #[must_use]
+    pub const fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// Returns the seed of the hash.
+    #[must_use]
+    pub const fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// The input length is a part of the initial state, so no data can be processed before the finalization and all of it is kept in the internal buffer.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.unprocessed.extend_from_slice(data.as_ref());
+        self
+    }
+
+    /// Processes the buffered data and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let (hash, _) = compute(&self.unprocessed, self.seed, 0, self.variant);
+        Finalize {
+            hash,
+            variant: self.variant,
+            seed: self.seed,
+        }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The variant and the seed are kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.unprocessed.clear();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lookup3;
+///
+/// // Create a new hash instance
+/// let mut hash = lookup3::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "0e397631");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    hash: u32,
+    variant: Variant,
+    seed: u32,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        Digest::new(self.hash.to_be_bytes())
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        *out = self.hash.to_be_bytes();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The variant and the seed are kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_variant(self.variant, self.seed)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+/// Computes the primary and the secondary hash of the given input.
+#[allow(clippy::cast_possible_truncation)]
+const fn compute(data: &[u8], primary: u32, secondary: u32, variant: Variant) -> (u32, u32) {
+    let initial = 0xDEAD_BEEF_u32.wrapping_add(data.len() as u32).wrapping_add(primary);
+    let (mut a, mut b, mut c) = (initial, initial, initial.wrapping_add(secondary));
+
+    // Empty input requires no mixing.
+    if data.is_empty() {
+        return (c, b);
+    }
+
+    // The last block, even if it is full, goes through the final mixing only.
+    let mut offset = 0;
+    while data.len() - offset > BLOCK_LENGTH_BYTES {
+        a = a.wrapping_add(word(data, offset, variant));
+        b = b.wrapping_add(word(data, offset + 4, variant));
+        c = c.wrapping_add(word(data, offset + 8, variant));
+        (a, b, c) = mix(a, b, c);
+        offset += BLOCK_LENGTH_BYTES;
+    }
+    a = a.wrapping_add(word(data, offset, variant));
+    b = b.wrapping_add(word(data, offset + 4, variant));
+    c = c.wrapping_add(word(data, offset + 8, variant));
+    (_, b, c) = finalize(a, b, c);
+
+    (c, b)
+}
+
+/// Reads a word at the given offset, the bytes past the end of the input are zeros.
+const fn word(data: &[u8], offset: usize, variant: Variant) -> u32 {
+    let mut bytes = [0u8; 4];
+    let mut i = 0;
+    while i < 4 && offset + i < data.len() {
+        bytes[i] = data[offset + i];
+        i += 1;
+    }
+    match variant {
+        Variant::Little => u32::from_le_bytes(bytes),
+        Variant::Big => u32::from_be_bytes(bytes),
+    }
+}
+
+/// Mixes three words reversibly.
+const fn mix(mut a: u32, mut b: u32, mut c: u32) -> (u32, u32, u32) {
+    a = a.wrapping_sub(c) ^ c.rotate_left(4);
+    c = c.wrapping_add(b);
+    b = b.wrapping_sub(a) ^ a.rotate_left(6);
+    a = a.wrapping_add(c);
+    c = c.wrapping_sub(b) ^ b.rotate_left(8);
+    b = b.wrapping_add(a);
+    a = a.wrapping_sub(c) ^ c.rotate_left(16);
+    c = c.wrapping_add(b);
+    b = b.wrapping_sub(a) ^ a.rotate_left(19);
+    a = a.wrapping_add(c);
+    c = c.wrapping_sub(b) ^ b.rotate_left(4);
+    b = b.wrapping_add(a);
+    (a, b, c)
+}
+
+/// Mixes three words into the final result.
+const fn finalize(mut a: u32, mut b: u32, mut c: u32) -> (u32, u32, u32) {
+    c = (c ^ b).wrapping_sub(b.rotate_left(14));
+    a = (a ^ c).wrapping_sub(c.rotate_left(11));
+    b = (b ^ a).wrapping_sub(a.rotate_left(25));
+    c = (c ^ b).wrapping_sub(b.rotate_left(16));
+    a = (a ^ c).wrapping_sub(c.rotate_left(4));
+    b = (b ^ a).wrapping_sub(a.rotate_left(14));
+    c = (c ^ b).wrapping_sub(b.rotate_left(24));
+    (a, b, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from `driver5` of the reference implementation.
+    #[test]
+    fn driver5() {
+        assert_eq!(hash_little(b"", 0), 0xDEADBEEF);
+        assert_eq!(hash_little(b"", 0xDEADBEEF), 0xBD5B7DDE);
+        assert_eq!(hash_little(b"Four score and seven years ago", 0), 0x17770551);
+        assert_eq!(hash_little(b"Four score and seven years ago", 1), 0xCD628161);
+    }
+}
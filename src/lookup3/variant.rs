@@ -0,0 +1,15 @@
+//! Module containing the supported lookup3 variants.
+
+/// A lookup3 variant.
+///
+/// Both variants split the input into 32-bit words and differ only in the byte order of the words. On a little-endian machine `hashlittle` is the natural one, which is why it is the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variant {
+    /// `hashlittle`, reading the input as little-endian words.
+    #[default]
+    Little,
+    /// `hashbig`, reading the input as big-endian words.
+    Big,
+}
@@ -0,0 +1,441 @@
+//! This module is optional and can be enabled using the `lsh-256` Cargo feature.
+//!
+//! An implementation of the LSH-256 hash function.
+//!
+//! LSH is the hash function family standardized in Korea as KS X 3262. LSH-256 works with 32-bit words and produces digests of 224 or 256 bits; by default the digest is 256 bits long. The initial state depends on the digest length, so the shorter digests are not truncations of the longer ones.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::lsh_256;
+//!
+//! let digest = lsh_256::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "b89a43026126fa6de86229b3c3891dd940c0a7d4e277457a97cf56114facb8c6"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::lsh_256;
+//!
+//! let digest = lsh_256::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "b89a43026126fa6de86229b3c3891dd940c0a7d4e277457a97cf56114facb8c6"
+//! );
+//! ```
+//!
+//! # Output Length
+//!
+//! Use [`Update::with_digest_length`] to select LSH-256-224 or any other supported digest length.
+//!
+//! ```rust
+//! use chksum_hash::lsh_256;
+//!
+//! let digest = lsh_256::Update::with_digest_length(224)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "7644174978a3f8d9a05629bc02dc6449bc3cb4f6a32e196c3ae8636d"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized due to the unknown size of data chunks.
+//!
+//! The size of this buffer is at least as large as one hash block of data processed at a time.
+//!
+//! To mitigate buffering, and consequently reduce memory overhead, data chunks should be aligned to multiples of the block size, or processed in block-sized chunks.
+
+pub mod block;
+pub mod digest;
+pub mod state;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::block::{Block, LENGTH_BYTES as BLOCK_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::digest::Digest;
+#[doc(inline)]
+pub use self::state::State;
+use crate::buffer::Buffer;
+use crate::error::ParameterError;
+use crate::verify::Expected;
+
+/// Default digest length in bits.
+pub const DIGEST_LENGTH_BITS: usize = 256;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lsh_256;
+///
+/// let digest = lsh_256::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "f3cd416a03818217726cb47f4e4d2881c9c29fd445c18b66fb19dea1a81007c1"
+/// );
+///
+/// let digest = lsh_256::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e41bd4026bfdb948716128bf716f6b546d8bbb428c49e2a419f3750f0058b27c"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lsh_256;
+///
+/// let digest = lsh_256::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "f3cd416a03818217726cb47f4e4d2881c9c29fd445c18b66fb19dea1a81007c1"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lsh_256;
+///
+/// let digest = lsh_256::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "5fbf365daea5446a7053c52b57404d77a07a5f48a1f7c1963a0898ba1b714741"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lsh_256;
+///
+/// let digest = lsh_256::hash("data");
+/// assert!(lsh_256::verify("data", digest));
+/// assert!(lsh_256::verify("data", digest.to_hex_uppercase()));
+/// assert!(!lsh_256::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lsh_256;
+///
+/// // Create a new hash instance
+/// let mut hash = lsh_256::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e41bd4026bfdb948716128bf716f6b546d8bbb428c49e2a419f3750f0058b27c"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "f3cd416a03818217726cb47f4e4d2881c9c29fd445c18b66fb19dea1a81007c1"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    state: State,
+    unprocessed: Buffer<BLOCK_LENGTH_BYTES>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_digest_length(DIGEST_LENGTH_BITS)
+    }
+
+    /// Creates a new hash producing a digest of the given length in bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is not one of 224 or 256.
+    #[must_use]
+    pub fn with_digest_length(length: usize) -> Self {
+        let state = state::new(length);
+        let unprocessed = Buffer::new();
+        Self { state, unprocessed }
+    }
+
+    /// Creates a new hash producing a digest of the given length in bits, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length is not one of 224 or 256.
+    pub fn try_with_digest_length(length: usize) -> Result<Self, ParameterError> {
+        if matches!(length, 224 | 256) {
+            Ok(Self::with_digest_length(length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+
+        if !self.unprocessed.is_empty() {
+            let missing = BLOCK_LENGTH_BYTES - self.unprocessed.len();
+            if data.len() < missing {
+                // Not enough data even for one block.
+                self.unprocessed.extend_from_slice(data);
+                return self;
+            }
+
+            // Complete the buffered block with the beginning of incoming data.
+            let (fillment, remainder) = data.split_at(missing);
+            self.unprocessed.extend_from_slice(fillment);
+            let block = Block::try_from(&self.unprocessed[..])
+                .expect("unprocessed data length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.unprocessed.clear();
+            data = remainder;
+        }
+
+        // Internal buffer is empty, incoming data can be processed without buffering.
+        let mut chunks = data.chunks_exact(BLOCK_LENGTH_BYTES);
+        for chunk in chunks.by_ref() {
+            let block = Block::try_from(chunk)
+                .expect("chunk length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+        }
+        self.unprocessed.extend_from_slice(chunks.remainder());
+
+        self
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        assert!(
+            self.unprocessed.len() < BLOCK_LENGTH_BYTES,
+            "unprocessed data length should be less than block length"
+        );
+
+        // The message is padded with a single one bit and zeros up to the end of the block, its length is not encoded.
+        let mut padding = [0u8; BLOCK_LENGTH_BYTES];
+        padding[..self.unprocessed.len()].copy_from_slice(&self.unprocessed);
+        padding[self.unprocessed.len()] = 0x80;
+
+        let block = Block::try_from(&padding[..])
+            .expect("padding length must be exact size as block")
+            .into();
+        let state = self.state.update(block);
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The digest length is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self.unprocessed.clear();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let state = u.arbitrary()?;
+        // Full blocks are processed immediately, so less than a block is buffered.
+        let length = u.int_in_range(0..=BLOCK_LENGTH_BYTES - 1)?;
+        let unprocessed = Buffer::from_slice(u.bytes(length)?);
+        Ok(Self { state, unprocessed })
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::lsh_256;
+///
+/// // Create a new hash instance
+/// let mut hash = lsh_256::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "5fbf365daea5446a7053c52b57404d77a07a5f48a1f7c1963a0898ba1b714741"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let bytes = self
+            .state
+            .digest()
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>();
+        Digest::new(&bytes[..self.state.length / 8])
+    }
+
+    /// Writes the hash digest into the beginning of the buffer, e.g. a part of a packet, and returns the written part of the buffer.
+    ///
+    /// Returns `None` if the buffer is shorter than the digest, whose length is chosen at runtime.
+    #[must_use]
+    pub fn digest_into<'a>(&self, out: &'a mut [u8]) -> Option<&'a [u8]> {
+        let digest = self.digest();
+        let out = out.get_mut(..digest.len())?;
+        out.copy_from_slice(digest.as_ref());
+        Some(out)
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The digest length is kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_digest_length(self.state.length)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the KISA specification.
+    #[test]
+    fn kisa() {
+        assert_eq!(
+            hash("abc").to_hex_lowercase(),
+            "5fbf365daea5446a7053c52b57404d77a07a5f48a1f7c1963a0898ba1b714741"
+        );
+        let digest = Update::with_digest_length(224).update("abc").digest();
+        assert_eq!(
+            digest.to_hex_lowercase(),
+            "f7c53ba4034e708e74fba42e55997ca5126bb7623688f85342f73732"
+        );
+    }
+}
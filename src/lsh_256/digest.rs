@@ -0,0 +1,22 @@
+//! Module containing items related to the LSH-256 digest.
+
+/// Maximum digest length in bits.
+pub const MAX_LENGTH_BITS: usize = 256;
+/// Maximum digest length in bytes.
+pub const MAX_LENGTH_BYTES: usize = MAX_LENGTH_BITS / 8;
+/// Maximum digest length in hexadecimal format.
+pub const MAX_LENGTH_HEX: usize = MAX_LENGTH_BYTES * 2;
+
+/// Marker type of the LSH-256 digest.
+///
+/// It tags [`Digest`] so LSH-256 digests are distinct from digests of other hash functions with the same length.
+pub enum Lsh256 {}
+
+/// A hash digest.
+///
+/// The length of an LSH-256 digest (224 or 256 bits) is chosen at runtime, see [`Update::with_digest_length`](super::Update::with_digest_length).
+///
+/// Check [`lsh_256`](super) for more information.
+///
+/// Digests are ordered lexicographically by their bytes, so a digest is ordered before the longer digests it is a prefix of.
+pub type Digest = crate::digest::VariableDigest<Lsh256, MAX_LENGTH_BYTES>;
@@ -0,0 +1,279 @@
+//! Module contains items related to the [`State`] structure which allows to the direct LSH-256 state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::lsh_256;
+//!
+//! // Create new state for 256-bit digests
+//! let mut state = lsh_256::state::new(256);
+//!
+//! // Create a block of data with proper padding
+//! let mut block = [0u8; lsh_256::BLOCK_LENGTH_BYTES];
+//! block[0] = 0x80;
+//! let data = lsh_256::Block::new(block).into();
+//!
+//! // Update state and own new value
+//! state = state.update(data);
+//!
+//! // Proper digest of empty input
+//! assert_eq!(
+//!     state.digest(),
+//!     [
+//!         0x6A41CDF3, 0x17828103, 0x7FB46C72, 0x81284D4E, 0xD49FC2C9, 0x668BC145, 0xA1DE19FB,
+//!         0xC10710A8,
+//!     ]
+//! );
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state, lsh_256::state::new(256));
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_DWORDS as BLOCK_LENGTH_DWORDS;
+use crate::error::ParameterError;
+
+/// State length in double words (quadruple bytes).
+pub const LENGTH_DWORDS: usize = 8;
+
+/// Number of steps of the compression function.
+const STEPS: usize = 26;
+
+/// Rotation amounts of the left words in the even and odd steps.
+const ALPHA: [u32; 2] = [29, 5];
+/// Rotation amounts of the right words in the even and odd steps.
+const BETA: [u32; 2] = [1, 17];
+/// Final rotation amounts of the right words, one for each word.
+const GAMMA: [u32; 8] = [0, 8, 16, 24, 24, 16, 8, 0];
+
+/// Permutation used by the message expansion.
+const TAU: [usize; 16] = [3, 2, 0, 1, 7, 4, 5, 6, 11, 10, 8, 9, 15, 12, 13, 14];
+
+/// Permutation of the chaining variable words applied after each step.
+const SIGMA: [usize; 16] = [6, 4, 5, 7, 12, 15, 14, 13, 2, 0, 1, 3, 8, 11, 10, 9];
+
+/// Constants of the first step, the following ones are derived from them.
+#[allow(clippy::unreadable_literal)]
+const SC: [u32; 8] = [
+    0x917CAF90, 0x6C1B10A2, 0x6F352943, 0xCF778243, 0x2CEB7472, 0x29E96FF2, 0x8A9BA428, 0x2EEB2642,
+];
+
+const STEP_CONSTANTS: [[u32; 8]; STEPS] = step_constants();
+
+/// Create a new state for the given digest length in bits.
+///
+/// # Panics
+///
+/// Panics if the length is not one of 224 or 256.
+#[must_use]
+pub const fn new(length: usize) -> State {
+    State::new(length)
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level hash state.
+///
+/// The chaining variable is 512 bits wide, twice the maximum digest length. The initial state depends on the digest length.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct State {
+    pub(crate) cv: [u32; 16],
+    pub(crate) length: usize,
+}
+
+impl State {
+    /// Creates a new state for the given digest length in bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is not one of 224 or 256.
+    #[must_use]
+    pub const fn new(length: usize) -> Self {
+        // The initial state is the compression of an all-zero block with the word size in bytes and the digest length in the first two words.
+        let mut cv = [0; 16];
+        cv[0] = 32;
+        cv[1] = length as u32;
+        Self::from_raw(cv, length).update([0; BLOCK_LENGTH_DWORDS])
+    }
+
+    /// Creates a new state for the given digest length in bits, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length is not one of 224 or 256.
+    pub const fn try_new(length: usize) -> Result<Self, ParameterError> {
+        if matches!(length, 224 | 256) {
+            Ok(Self::new(length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Creates a state for the given digest length in bits from the raw chaining values, e.g. a custom initialization vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is not one of 224 or 256.
+    #[must_use]
+    pub const fn from_raw(cv: [u32; 16], length: usize) -> Self {
+        assert!(
+            matches!(length, 224 | 256),
+            "digest length must be one of 224 or 256 bits"
+        );
+        Self { cv, length }
+    }
+
+    /// Creates a state for the given digest length in bits from the raw chaining values, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length is not one of 224 or 256.
+    pub const fn try_from_raw(cv: [u32; 16], length: usize) -> Result<Self, ParameterError> {
+        if matches!(length, 224 | 256) {
+            Ok(Self::from_raw(cv, length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// **Warning!** Input block must be in the little-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u32; BLOCK_LENGTH_DWORDS]) -> Self {
+        let mut messages = [[0; 16]; 2];
+        let mut i = 0;
+        while i < 16 {
+            messages[0][i] = block[i];
+            messages[1][i] = block[i + 16];
+            i += 1;
+        }
+
+        let mut cv = self.cv;
+        let mut step = 0;
+        while step < STEPS {
+            if step >= 2 {
+                messages[step % 2] = expand(messages[(step + 1) % 2], messages[step % 2]);
+            }
+            let mut i = 0;
+            while i < 16 {
+                cv[i] ^= messages[step % 2][i];
+                i += 1;
+            }
+            cv = permute(mix(cv, step));
+            step += 1;
+        }
+
+        // The chaining variable is masked with one more expanded message.
+        let message = expand(messages[(STEPS + 1) % 2], messages[STEPS % 2]);
+        let mut i = 0;
+        while i < 16 {
+            cv[i] ^= message[i];
+            i += 1;
+        }
+
+        Self::from_raw(cv, self.length)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new(self.length)
+    }
+
+    /// Returns the digest words, the digest is made of their first bytes.
+    #[must_use]
+    pub const fn digest(&self) -> [u32; LENGTH_DWORDS] {
+        let mut digest = [0; LENGTH_DWORDS];
+        let mut i = 0;
+        while i < LENGTH_DWORDS {
+            digest[i] = self.cv[i] ^ self.cv[i + 8];
+            i += 1;
+        }
+        digest
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for State {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let cv = u.arbitrary()?;
+        let length = *u.choose(&[224, 256])?;
+        Ok(Self::from_raw(cv, length))
+    }
+}
+
+/// Computes the step constants of all steps.
+const fn step_constants() -> [[u32; 8]; STEPS] {
+    let mut constants = [SC; STEPS];
+    let mut step = 1;
+    while step < STEPS {
+        let mut i = 0;
+        while i < 8 {
+            let previous = constants[step - 1][i];
+            constants[step][i] = previous.wrapping_add(previous.rotate_left(8));
+            i += 1;
+        }
+        step += 1;
+    }
+    constants
+}
+
+/// Expands the next message from the two preceding ones.
+const fn expand(previous: [u32; 16], older: [u32; 16]) -> [u32; 16] {
+    let mut message = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        message[i] = previous[i].wrapping_add(older[TAU[i]]);
+        i += 1;
+    }
+    message
+}
+
+/// Mixes the left and right halves of the chaining variable.
+const fn mix(mut cv: [u32; 16], step: usize) -> [u32; 16] {
+    let (alpha, beta) = (ALPHA[step % 2], BETA[step % 2]);
+    let mut i = 0;
+    while i < 8 {
+        let (mut x, mut y) = (cv[i], cv[i + 8]);
+        x = x.wrapping_add(y).rotate_left(alpha) ^ STEP_CONSTANTS[step][i];
+        y = y.wrapping_add(x).rotate_left(beta);
+        x = x.wrapping_add(y);
+        y = y.rotate_left(GAMMA[i]);
+        cv[i] = x;
+        cv[i + 8] = y;
+        i += 1;
+    }
+    cv
+}
+
+/// Permutes the words of the chaining variable.
+const fn permute(cv: [u32; 16]) -> [u32; 16] {
+    let mut permuted = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        permuted[i] = cv[SIGMA[i]];
+        i += 1;
+    }
+    permuted
+}
@@ -0,0 +1,192 @@
+//! Module contains items related to the [`State`] structure which allows to the direct MD4 state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::md4;
+//!
+//! // Create new state
+//! let mut state = md4::state::default();
+//!
+//! // By default it returns initialization values
+//! assert_eq!(
+//!     state.digest(),
+//!     [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476]
+//! );
+//!
+//! // Create a block of data with proper padding
+//! let mut block = [0u8; md4::BLOCK_LENGTH_BYTES];
+//! block[0] = 0x80;
+//! let data = md4::Block::new(block).into();
+//!
+//! // Update state and own new value
+//! state = state.update(data);
+//!
+//! // Proper digest of empty input
+//! assert_eq!(
+//!     state.digest(),
+//!     [0xE0CFD631, 0x31E96AD1, 0xD7593CB7, 0xC089C0E0]
+//! );
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(
+//!     state.digest(),
+//!     [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476]
+//! );
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_DWORDS as BLOCK_LENGTH_DWORDS;
+use super::digest::LENGTH_DWORDS as DIGEST_LENGTH_DWORDS;
+
+#[allow(clippy::unreadable_literal)]
+const A: u32 = 0x67452301;
+#[allow(clippy::unreadable_literal)]
+const B: u32 = 0xEFCDAB89;
+#[allow(clippy::unreadable_literal)]
+const C: u32 = 0x98BADCFE;
+#[allow(clippy::unreadable_literal)]
+const D: u32 = 0x10325476;
+
+#[allow(clippy::unreadable_literal)]
+const K: [u32; 3] = [0x00000000, 0x5A827999, 0x6ED9EBA1];
+
+/// Order of the message words in the second round.
+const INDEXES_2: [usize; 16] = [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15];
+/// Order of the message words in the third round.
+const INDEXES_3: [usize; 16] = [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15];
+
+/// Shift amounts of the rounds.
+const SHIFTS: [[u32; 4]; 3] = [[3, 7, 11, 19], [3, 5, 9, 13], [3, 9, 11, 15]];
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// Returns the chaining values A, B, C and D after processing a single block.
+///
+/// **Warning!** Input block must be in the little-endian byte order.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md4;
+///
+/// let state = md4::state::new().digest();
+/// let block = md4::Block::new([0u8; md4::BLOCK_LENGTH_BYTES]).into();
+/// assert_eq!(
+///     md4::state::compress(state, block),
+///     md4::state::new().update(block).digest()
+/// );
+/// ```
+#[must_use]
+pub const fn compress(
+    state: [u32; DIGEST_LENGTH_DWORDS],
+    block: [u32; BLOCK_LENGTH_DWORDS],
+) -> [u32; DIGEST_LENGTH_DWORDS] {
+    let [a, b, c, d] = state;
+    State::from_raw(a, b, c, d).update(block).digest()
+}
+
+/// A low-level hash state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) a: u32,
+    pub(crate) b: u32,
+    pub(crate) c: u32,
+    pub(crate) d: u32,
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(A, B, C, D)
+    }
+
+    /// Creates a state from the raw chaining values, e.g. a custom initialization vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::md4;
+    ///
+    /// // The default initialization vector
+    /// let state = md4::State::from_raw(0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476);
+    /// assert_eq!(state, md4::State::new());
+    /// ```
+    #[must_use]
+    pub const fn from_raw(a: u32, b: u32, c: u32, d: u32) -> Self {
+        Self { a, b, c, d }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// **Warning!** Input block must be in the little-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u32; BLOCK_LENGTH_DWORDS]) -> Self {
+        let mut x = [self.a, self.b, self.c, self.d];
+
+        let mut i = 0;
+        while i < 48 {
+            let round = i / 16;
+            let step = i % 16;
+            // The registers rotate, each step updates the one preceding the register updated by the previous step.
+            let a = (4 - step % 4) % 4;
+            let (b, c, d) = (x[(a + 1) % 4], x[(a + 2) % 4], x[(a + 3) % 4]);
+            let (f, index) = match round {
+                0 => ((b & c) | (!b & d), step),
+                1 => ((b & c) | (b & d) | (c & d), INDEXES_2[step]),
+                _ => (b ^ c ^ d, INDEXES_3[step]),
+            };
+            x[a] = x[a]
+                .wrapping_add(f)
+                .wrapping_add(block[index])
+                .wrapping_add(K[round])
+                .rotate_left(SHIFTS[round][step % 4]);
+            i += 1;
+        }
+
+        Self::from_raw(
+            self.a.wrapping_add(x[0]),
+            self.b.wrapping_add(x[1]),
+            self.c.wrapping_add(x[2]),
+            self.d.wrapping_add(x[3]),
+        )
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns a digest.
+    #[must_use]
+    pub const fn digest(&self) -> [u32; DIGEST_LENGTH_DWORDS] {
+        let Self { a, b, c, d } = *self;
+        [a, b, c, d]
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,238 @@
+//! This module is optional and can be enabled using the `md5-crypt` Cargo feature.
+//!
+//! An implementation of the MD5-crypt password hashing scheme, `md5crypt`, and its Apache variant, APR1.
+//!
+//! MD5-crypt, designed by Poul-Henning Kamp for FreeBSD, is the scheme behind the `$1$` password hashes of crypt(3). The Apache HTTP Server uses the same algorithm with the `$apr1$` identifier in its `htpasswd` files. Both are built on [`md5`](crate::md5) and they are selected with [`Variant`].
+//!
+//! # Encoding
+//!
+//! The password hash in the modular crypt format can be created with the [`encode`] function.
+//!
+//! ```rust
+//! use chksum_hash::md5_crypt::{self, Variant};
+//!
+//! let hash = md5_crypt::encode("Hello world!", "saltstri", Variant::Md5);
+//! assert_eq!(hash, "$1$saltstri$YMyguxXMBpd2TEZ.vS/3q1");
+//!
+//! let hash = md5_crypt::encode("Hello world!", "saltstri", Variant::Apr1);
+//! assert_eq!(hash, "$apr1$saltstri$aGfuB7Lcvs2TUeFTqUVfN0");
+//! ```
+//!
+//! # Verification
+//!
+//! The password can be checked against an encoded hash, like an `htpasswd` entry, with the [`verify`] function. The variant and the salt are read from the hash.
+//!
+//! ```rust
+//! use chksum_hash::md5_crypt;
+//!
+//! let hash = "$apr1$saltstri$aGfuB7Lcvs2TUeFTqUVfN0";
+//! assert!(md5_crypt::verify("Hello world!", hash));
+//! assert!(!md5_crypt::verify("Hello world?", hash));
+//! ```
+//!
+//! # Disclaimer
+//!
+//! MD5-crypt has a fixed cost and it is considered insecure by its own author. It is supported for compatibility with existing systems only, prefer [`sha_crypt`](crate::sha_crypt) or memory-hard functions, like [`scrypt`](crate::scrypt).
+
+use crate::crypt::{self, encode_base64};
+use crate::error::ParameterError;
+use crate::md5;
+use crate::verify::constant_time_eq;
+
+/// Maximum length of the salt in bytes.
+pub const MAX_SALT_LENGTH: usize = 8;
+
+/// Number of rounds, which is fixed.
+pub const ROUNDS: u32 = 1_000;
+
+/// Order of the digest bytes in the encoded hash.
+const ORDER: [usize; 16] = [0, 6, 12, 1, 7, 13, 2, 8, 14, 3, 9, 15, 4, 10, 5, 11];
+
+/// An MD5-crypt variant.
+///
+/// The variants differ only in the identifier of the encoded hash, which is also mixed into the digest.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Variant {
+    /// `md5crypt`, identified by `$1$`.
+    #[default]
+    Md5,
+    /// Apache's APR1, identified by `$apr1$`.
+    Apr1,
+}
+
+impl Variant {
+    /// Returns the identifier of the variant, e.g. `"1"` for `$1$`.
+    #[must_use]
+    pub const fn id(&self) -> &'static str {
+        match self {
+            Self::Md5 => "1",
+            Self::Apr1 => "apr1",
+        }
+    }
+
+    /// Returns the variant with the given identifier.
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "1" => Some(Self::Md5),
+            "apr1" => Some(Self::Apr1),
+            _ => None,
+        }
+    }
+}
+
+/// Encodes the password hash in the modular crypt format, i.e. `$<id>$<salt>$<checksum>`.
+///
+/// The salt is truncated to [`MAX_SALT_LENGTH`] bytes.
+///
+/// # Panics
+///
+/// Panics if the salt contains `$`, `:` or a newline, which are not allowed in the modular crypt format.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md5_crypt::{self, Variant};
+///
+/// // The salt is truncated to 8 bytes
+/// let hash = md5_crypt::encode("password", "saltsaltsalt", Variant::Apr1);
+/// assert_eq!(hash, "$apr1$saltsalt$yAAkm4libquA.ZWLHbSBq/");
+/// ```
+#[must_use]
+pub fn encode(password: impl AsRef<[u8]>, salt: &str, variant: Variant) -> String {
+    let salt = crypt::truncate_salt(salt, MAX_SALT_LENGTH);
+    let password = password.as_ref();
+    let prefix = format!("${}$", variant.id());
+
+    let mut alternate = md5::Update::new();
+    alternate.update(password).update(salt).update(password);
+    let alternate = alternate.digest();
+    let alternate = alternate.as_ref();
+
+    let mut intermediate = md5::Update::new();
+    intermediate.update(password).update(&prefix).update(salt);
+    let mut length = password.len();
+    while length > 0 {
+        let chunk = length.min(alternate.len());
+        intermediate.update(&alternate[..chunk]);
+        length -= chunk;
+    }
+    // The bits of the password length select between a zero byte and the first byte of the password.
+    let mut length = password.len();
+    while length > 0 {
+        if length & 1 == 1 {
+            intermediate.update([0x00]);
+        } else {
+            intermediate.update(&password[..1]);
+        }
+        length >>= 1;
+    }
+    let mut digest = intermediate.digest();
+
+    for round in 0..ROUNDS {
+        let mut hash = md5::Update::new();
+        if round % 2 == 1 {
+            hash.update(password);
+        } else {
+            hash.update(digest);
+        }
+        if round % 3 != 0 {
+            hash.update(salt);
+        }
+        if round % 7 != 0 {
+            hash.update(password);
+        }
+        if round % 2 == 1 {
+            hash.update(digest);
+        } else {
+            hash.update(password);
+        }
+        digest = hash.digest();
+    }
+
+    let mut hash = prefix;
+    hash.push_str(salt);
+    hash.push('$');
+    let digest = digest.as_ref();
+    encode_base64(ORDER.iter().map(|&index| digest[index]), &mut hash);
+    hash
+}
+
+/// Encodes the password hash in the modular crypt format, e.g. with a salt read from untrusted input.
+///
+/// The salt is truncated to [`MAX_SALT_LENGTH`] bytes.
+///
+/// # Errors
+///
+/// Returns an error if the salt contains `$`, `:` or a newline, which are not allowed in the modular crypt format.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md5_crypt::{self, Variant};
+///
+/// let hash = md5_crypt::try_encode("password", "saltsalt", Variant::Apr1).unwrap();
+/// assert_eq!(hash, "$apr1$saltsalt$yAAkm4libquA.ZWLHbSBq/");
+///
+/// assert!(md5_crypt::try_encode("password", "salt$salt", Variant::Apr1).is_err());
+/// ```
+pub fn try_encode(password: impl AsRef<[u8]>, salt: &str, variant: Variant) -> Result<String, ParameterError> {
+    crypt::check_salt(salt)?;
+    Ok(encode(password, salt, variant))
+}
+
+/// Verifies the password against the hash in the modular crypt format.
+///
+/// Returns `false` if the hash is malformed or uses a variant other than `$1$` and `$apr1$`. The hashes are compared in constant time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md5_crypt;
+///
+/// assert!(md5_crypt::verify(
+///     "Hello world!",
+///     "$1$saltstri$YMyguxXMBpd2TEZ.vS/3q1"
+/// ));
+/// assert!(!md5_crypt::verify(
+///     "Hello world!",
+///     "$1$saltstri$YMyguxXMBpd2TEZ.vS/3q2"
+/// ));
+/// ```
+#[must_use]
+pub fn verify(password: impl AsRef<[u8]>, hash: &str) -> bool {
+    match parse(hash) {
+        Some((variant, salt)) => {
+            let expected = encode(password, salt, variant);
+            constant_time_eq(expected.as_bytes(), hash.as_bytes())
+        },
+        None => false,
+    }
+}
+
+/// Parses the variant and the salt of the hash in the modular crypt format.
+fn parse(hash: &str) -> Option<(Variant, &str)> {
+    let hash = hash.strip_prefix('$')?;
+    let (id, hash) = hash.split_once('$')?;
+    let variant = Variant::from_id(id)?;
+    let salt = match hash.split_once('$') {
+        Some((salt, _)) => salt,
+        None => hash,
+    };
+    if salt.contains([':', '\n']) {
+        return None;
+    }
+    Some((variant, salt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example from the password encryption documentation of the Apache HTTP Server.
+    #[test]
+    fn apache() {
+        let hash = encode("myPassword", "r31.....", Variant::Apr1);
+        assert_eq!(hash, "$apr1$r31.....$HqJZimcKQFAMYayBlzkrA/");
+    }
+}
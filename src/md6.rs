@@ -0,0 +1,542 @@
+//! This module is optional and can be enabled using the `md6` Cargo feature.
+//!
+//! An implementation of the MD6 hash function.
+//!
+//! MD6 processes the input as a 4-ary Merkle tree of compression calls, with a configurable digest length of 1 to 512 bits, an optional key and a mode control that limits the height of the tree. See [`Params`] for all options; by default MD6-256 in the fully hierarchical mode is used.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::md6;
+//!
+//! let digest = md6::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "8e9d78bf42c740ab84b9c4e5597e43efa0fc78f95bd5cb329878c50550ac2ec7"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::md6;
+//!
+//! let digest = md6::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "8e9d78bf42c740ab84b9c4e5597e43efa0fc78f95bd5cb329878c50550ac2ec7"
+//! );
+//! ```
+//!
+//! # Parameters
+//!
+//! Use [`Update::with_params`] to choose the digest length, the mode, the key or the number of rounds.
+//!
+//! ```rust
+//! use chksum_hash::md6;
+//!
+//! let params = md6::Params::new(512);
+//! let digest = md6::Update::with_params(params)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "9a68cee021b7b94b9b4416af28582bc3f9c5f40b9bb215d29fc4508cecd4a7cbf2353f92a07c91748b1c3fa85c7dbc091d6c7e52a3b264e03ff56fcc5e242aac"
+//! );
+//!
+//! // Sequential mode produces a different digest for the same data
+//! let params = md6::Params::new(256).with_levels(0);
+//! let digest = md6::Update::with_params(params).update("abc").digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "93c70c8d38e1d0b583024a3f17c95fe23b3a19bfad96d567f1e522b89ec7b365"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized for every level of the tree that is currently being built.
+//!
+//! Memory usage grows logarithmically with the input length: each level holds at most one block of data.
+//!
+//! # Disclaimer
+//!
+//! MD6 was withdrawn from the SHA-3 competition by its authors and is not standardized. It is provided for research and interoperability; new designs should prefer SHA-2.
+
+mod compression;
+pub mod digest;
+pub mod params;
+
+use chksum_hash_core as core;
+
+use self::compression::{compress, Control, C as CHAINING_LENGTH_QWORDS};
+#[doc(inline)]
+pub use self::digest::Digest;
+#[doc(inline)]
+pub use self::params::Params;
+use crate::buffer::Buffer;
+use crate::verify::Expected;
+
+/// Block length in bits.
+pub const BLOCK_LENGTH_BITS: usize = 4096;
+/// Block length in bytes.
+pub const BLOCK_LENGTH_BYTES: usize = BLOCK_LENGTH_BITS / 8;
+/// Block length in quadruple words (octuple bytes).
+pub const BLOCK_LENGTH_QWORDS: usize = BLOCK_LENGTH_BYTES / 8;
+
+/// Chaining value length in bytes.
+const CHAINING_LENGTH_BYTES: usize = CHAINING_LENGTH_QWORDS * 8;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md6;
+///
+/// let digest = md6::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "bca38b24a804aa37d821d31af00f5598230122c5bbfc4c4ad5ed40e4258f04ca"
+/// );
+///
+/// let digest = md6::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "bdd596b4c5d4ac0bebf45010176a7a6942fc34a709bca215b69e52e0c488032e"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md6;
+///
+/// let digest = md6::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "bca38b24a804aa37d821d31af00f5598230122c5bbfc4c4ad5ed40e4258f04ca"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md6;
+///
+/// let digest = md6::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "230637d4e6845cf0d092b558e87625f03881dd53a7439da34cf3b94ed0d8b2c5"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md6;
+///
+/// let digest = md6::hash("data");
+/// assert!(md6::verify("data", digest));
+/// assert!(md6::verify("data", digest.to_hex_uppercase()));
+/// assert!(!md6::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A single level of the tree that is currently being built.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Level {
+    /// Data of the current node, either input bytes or chaining values of the children.
+    buffer: Buffer<BLOCK_LENGTH_BYTES>,
+    /// Index of the current node within the level.
+    index: u64,
+}
+
+impl Level {
+    fn new() -> Self {
+        let buffer = Buffer::new();
+        let index = 0;
+        Self { buffer, index }
+    }
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md6;
+///
+/// // Create a new hash instance
+/// let mut hash = md6::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "bdd596b4c5d4ac0bebf45010176a7a6942fc34a709bca215b69e52e0c488032e"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "bca38b24a804aa37d821d31af00f5598230122c5bbfc4c4ad5ed40e4258f04ca"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    params: Params,
+    key: [u64; 8],
+    levels: Vec<Level>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_params(Params::default())
+    }
+
+    /// Creates a new hash with the given parameters.
+    #[must_use]
+    pub fn with_params(params: Params) -> Self {
+        let key = params.key_words();
+        let levels = Vec::new();
+        let mut update = Self { params, key, levels };
+        update.level(1);
+        update
+    }
+
+    /// Returns the parameters of the hash.
+    #[must_use]
+    pub const fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+
+        while !data.is_empty() {
+            let leaf = &mut self.levels[0];
+            let missing = BLOCK_LENGTH_BYTES - leaf.buffer.len();
+            let (portion, remainder) = data.split_at(missing.min(data.len()));
+            leaf.buffer.extend_from_slice(portion);
+            data = remainder;
+
+            // A full leaf is compressed only once more data arrives, since the last node must be marked as final.
+            if leaf.buffer.len() == BLOCK_LENGTH_BYTES && !data.is_empty() {
+                self.process(1, false);
+            }
+        }
+
+        self
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let mut update = self.clone();
+        let level = update
+            .levels
+            .iter()
+            .position(|level| !level.buffer.is_empty())
+            .map_or(1, |position| position + 1);
+        let chaining = update
+            .process(level, true)
+            .expect("final compression must produce the chaining value");
+        let params = self.params;
+        Finalize { params, chaining }
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        *self = Self::with_params(self.params);
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+
+    /// Returns the given level, creating it if necessary.
+    ///
+    /// The first node of the sequential level starts with an all-zero chaining value.
+    fn level(&mut self, level: usize) -> &mut Level {
+        while self.levels.len() < level {
+            self.levels.push(Level::new());
+        }
+        let sequential = level == usize::from(self.params.levels()) + 1;
+        let level = &mut self.levels[level - 1];
+        if sequential && level.index == 0 && level.buffer.is_empty() {
+            level.buffer.resize(CHAINING_LENGTH_BYTES, 0);
+        }
+        level
+    }
+
+    /// Compresses nodes starting at the given level and moves their chaining values upwards.
+    ///
+    /// Returns the chaining value of the root if `last` is set, and `None` otherwise.
+    fn process(&mut self, mut level: usize, last: bool) -> Option<[u64; CHAINING_LENGTH_QWORDS]> {
+        let sequential = usize::from(self.params.levels()) + 1;
+        loop {
+            let top = self.levels.len();
+            let length = self.levels[level - 1].buffer.len();
+            if !last && length < BLOCK_LENGTH_BYTES {
+                return None;
+            }
+
+            let root = last && level == top;
+            let chaining = self.compress(level, root);
+            if root {
+                return Some(chaining);
+            }
+
+            let next = (level + 1).min(sequential);
+            let buffer = &mut self.level(next).buffer;
+            for word in chaining {
+                buffer.extend_from_slice(&word.to_be_bytes());
+            }
+            level = next;
+        }
+    }
+
+    /// Compresses the node of the given level and clears its buffer.
+    fn compress(&mut self, level: usize, last: bool) -> [u64; CHAINING_LENGTH_QWORDS] {
+        let Self {
+            params, key, levels, ..
+        } = self;
+        let node = &mut levels[level - 1];
+        let block = {
+            let mut block = [0u64; BLOCK_LENGTH_QWORDS];
+            for (word, bytes) in block.iter_mut().zip(node.buffer.chunks(8)) {
+                let mut buffer = [0u8; 8];
+                buffer[..bytes.len()].copy_from_slice(bytes);
+                *word = u64::from_be_bytes(buffer);
+            }
+            block
+        };
+        let control = Control {
+            rounds: params.rounds(),
+            levels: params.levels(),
+            last,
+            padding: (BLOCK_LENGTH_BYTES - node.buffer.len()) * 8,
+            key_length: params.key_length(),
+            digest_length: params.digest_length(),
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let chaining = compress(key, level as u8, node.index, control, &block);
+        node.buffer.clear();
+        node.index += 1;
+        chaining
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // The buffered nodes of the levels depend on each other, so the tree is built from arbitrary data.
+        let mut update = Self::with_params(u.arbitrary()?);
+        update.update(<&[u8]>::arbitrary(u)?);
+        Ok(update)
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md6;
+///
+/// // Create a new hash instance
+/// let mut hash = md6::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "230637d4e6845cf0d092b558e87625f03881dd53a7439da34cf3b94ed0d8b2c5"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    params: Params,
+    chaining: [u64; CHAINING_LENGTH_QWORDS],
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    ///
+    /// The digest consists of the last `d` bits of the root chaining value.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let mut chaining = [0u8; CHAINING_LENGTH_BYTES];
+        for (bytes, word) in chaining.chunks_exact_mut(8).zip(self.chaining) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+
+        let bits = self.params.digest_length();
+        let length = (bits + 7) / 8;
+        let mut digest = [0u8; CHAINING_LENGTH_BYTES];
+        digest[..length].copy_from_slice(&chaining[CHAINING_LENGTH_BYTES - length..]);
+
+        // Partial bytes are aligned to the most significant bit.
+        let shift = (8 - bits % 8) % 8;
+        if shift > 0 {
+            for i in 0..length {
+                digest[i] = (digest[i] << shift) | (digest[i + 1] >> (8 - shift));
+            }
+        }
+
+        Digest::new(&digest[..length])
+    }
+
+    /// Writes the hash digest into the beginning of the buffer, e.g. a part of a packet, and returns the written part of the buffer.
+    ///
+    /// Returns `None` if the buffer is shorter than the digest, whose length is chosen at runtime.
+    #[must_use]
+    pub fn digest_into<'a>(&self, out: &'a mut [u8]) -> Option<&'a [u8]> {
+        let digest = self.digest();
+        let out = out.get_mut(..digest.len())?;
+        out.copy_from_slice(digest.as_ref());
+        Some(out)
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The parameters of the hash are kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_params(self.params)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the SHA-3 submission.
+    #[test]
+    fn submission() {
+        for (digest_length, data, expected) in [
+            (256, "abc", "230637d4e6845cf0d092b558e87625f03881dd53a7439da34cf3b94ed0d8b2c5"),
+            (
+                512,
+                "",
+                "6b7f33821a2c060ecdd81aefddea2fd3c4720270e18654f4cb08ece49ccb469f8beeee7c831206bd577f9f2630d9177979203a9489e47e04df4e6deaa0f8e0c0",
+            ),
+            (
+                512,
+                "abc",
+                "00918245271e377a7ffb202b90f3bda5477d8feab12d8a3a8994ebc55fe6e74ca8341520032eeea3fdef892f2882378f636212af4b2683ccf80bf025b7d9b457",
+            ),
+        ] {
+            let digest = Update::with_params(Params::new(digest_length)).update(data).digest();
+            assert_eq!(digest.to_hex_lowercase(), expected);
+        }
+    }
+
+    #[test]
+    fn digest_into() {
+        let finalize = Update::with_params(Params::new(256)).update("abc").finalize();
+        let mut buffer = [0u8; 40];
+        assert_eq!(finalize.digest_into(&mut buffer), Some(finalize.digest().as_ref()));
+        assert_eq!(finalize.digest_into(&mut buffer[..31]), None);
+    }
+}
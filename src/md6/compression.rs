@@ -0,0 +1,97 @@
+//! Module containing the MD6 compression function.
+
+/// Number of words in the compression function input.
+const N: usize = 89;
+/// Number of words in the compression function output (chaining value).
+pub(crate) const C: usize = 16;
+
+/// Fractional part of the square root of 6, used as the constant `Q` vector.
+#[allow(clippy::unreadable_literal)]
+const Q: [u64; 15] = [
+    0x7311C2812425CFA0,
+    0x6432286434AAC8E7,
+    0xB60450E9EF68B7C1,
+    0xE8FB23908D9F06F1,
+    0xDD2E76CBA691E5BF,
+    0x0CD0D63B2C30BC41,
+    0x1F8CCF6823058F8A,
+    0x54E5ED5B88E3775D,
+    0x4AD12AAE0A6D6031,
+    0x3E7F16BB88222E0D,
+    0x8AF8671D3FB50C2C,
+    0x995AD1178BD25C31,
+    0xC878C1DD04C4B633,
+    0x3B72066C7A1552AC,
+    0x0D6F3522631EFFCB,
+];
+
+/// Tap positions of the feedback function.
+const T0: usize = 17;
+const T1: usize = 18;
+const T2: usize = 21;
+const T3: usize = 31;
+const T4: usize = 67;
+
+/// Right shift amounts, one per step of a round.
+const R: [u32; 16] = [10, 5, 13, 10, 11, 12, 2, 7, 14, 15, 7, 13, 11, 7, 6, 12];
+/// Left shift amounts, one per step of a round.
+const L: [u32; 16] = [11, 24, 9, 16, 15, 9, 27, 15, 6, 2, 29, 8, 15, 5, 31, 9];
+
+#[allow(clippy::unreadable_literal)]
+const S0: u64 = 0x0123456789ABCDEF;
+#[allow(clippy::unreadable_literal)]
+const S_MASK: u64 = 0x7311C2812425CFA0;
+
+/// The control word `V` of a single compression.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Control {
+    pub(crate) rounds: usize,
+    pub(crate) levels: u8,
+    pub(crate) last: bool,
+    pub(crate) padding: usize,
+    pub(crate) key_length: usize,
+    pub(crate) digest_length: usize,
+}
+
+impl Control {
+    const fn word(&self) -> u64 {
+        ((self.rounds as u64) << 48)
+            | ((self.levels as u64) << 40)
+            | ((self.last as u64) << 36)
+            | ((self.padding as u64) << 20)
+            | ((self.key_length as u64) << 12)
+            | (self.digest_length as u64)
+    }
+}
+
+/// Compresses a single 64-word block of the node at the given level and index.
+///
+/// The input `A[0..89]` is the concatenation of `Q`, the key, the node identifier `U`, the control word `V` and the block. Each of the `16 * rounds` steps computes one new word from the previous 89 words, so only a sliding window of 89 words is kept.
+pub(crate) fn compress(key: &[u64; 8], level: u8, index: u64, control: Control, block: &[u64; 64]) -> [u64; C] {
+    let mut a = [0u64; N];
+    a[..15].copy_from_slice(&Q);
+    a[15..23].copy_from_slice(key);
+    a[23] = (u64::from(level) << 56) | index;
+    a[24] = control.word();
+    a[25..].copy_from_slice(block);
+
+    let steps = control.rounds * C;
+    let mut s = S0;
+    for i in 0..steps {
+        let step = i % C;
+        let mut x = s ^ a[i % N] ^ a[(i + N - T0) % N];
+        x ^= a[(i + N - T1) % N] & a[(i + N - T2) % N];
+        x ^= a[(i + N - T3) % N] & a[(i + N - T4) % N];
+        x ^= x >> R[step];
+        a[i % N] = x ^ (x << L[step]);
+        if step == C - 1 {
+            s = s.rotate_left(1) ^ (s & S_MASK);
+        }
+    }
+
+    let mut chaining = [0u64; C];
+    for (k, word) in chaining.iter_mut().enumerate() {
+        *word = a[(steps + N - C + k) % N];
+    }
+    chaining
+}
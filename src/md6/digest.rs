@@ -0,0 +1,22 @@
+//! Module containing items related to the MD6 digest.
+
+/// Maximum digest length in bits.
+pub const MAX_LENGTH_BITS: usize = 512;
+/// Maximum digest length in bytes.
+pub const MAX_LENGTH_BYTES: usize = MAX_LENGTH_BITS / 8;
+/// Maximum digest length in hexadecimal format.
+pub const MAX_LENGTH_HEX: usize = MAX_LENGTH_BYTES * 2;
+
+/// Marker type of the MD6 digest.
+///
+/// It tags [`Digest`] so MD6 digests are distinct from digests of other hash functions with the same length.
+pub enum Md6 {}
+
+/// A hash digest.
+///
+/// The length of an MD6 digest is chosen at runtime, see [`Params`](super::Params). If the digest length in bits is not a multiple of 8, the last byte holds the remaining bits in its most significant positions, followed by zero bits.
+///
+/// Check [`md6`](super) for more information.
+///
+/// Digests are ordered lexicographically by their bytes, so a digest is ordered before the longer digests it is a prefix of.
+pub type Digest = crate::digest::VariableDigest<Md6, MAX_LENGTH_BYTES>;
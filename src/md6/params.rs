@@ -0,0 +1,212 @@
+//! Module containing items related to the MD6 parameters.
+
+use super::digest::MAX_LENGTH_BITS as DIGEST_MAX_LENGTH_BITS;
+use crate::error::ParameterError;
+
+/// Default digest length in bits.
+pub const DEFAULT_DIGEST_LENGTH_BITS: usize = 256;
+/// Default mode control, which selects the fully hierarchical (tree) mode.
+pub const DEFAULT_LEVELS: u8 = 64;
+/// Maximum key length in bytes.
+pub const MAX_KEY_LENGTH_BYTES: usize = 64;
+/// Maximum number of rounds.
+pub const MAX_ROUNDS: usize = 255;
+
+/// Parameters of the MD6 hash function.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::md6;
+///
+/// // MD6-512 in the fully sequential mode
+/// let params = md6::Params::new(512).with_levels(0);
+/// assert_eq!(params.digest_length(), 512);
+/// assert_eq!(params.levels(), 0);
+/// assert_eq!(params.rounds(), 168);
+///
+/// // Keyed MD6-128, which uses at least 80 rounds
+/// let params = md6::Params::new(128).with_key(b"key");
+/// assert_eq!(params.rounds(), 80);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    digest_length: usize,
+    levels: u8,
+    rounds: Option<usize>,
+    key: [u8; MAX_KEY_LENGTH_BYTES],
+    key_length: usize,
+}
+
+impl Params {
+    /// Creates parameters for the given digest length in bits with the default mode and no key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digest length is not between 1 and 512 bits.
+    #[must_use]
+    pub const fn new(digest_length: usize) -> Self {
+        assert!(
+            digest_length >= 1 && digest_length <= DIGEST_MAX_LENGTH_BITS,
+            "digest length must be between 1 and 512 bits"
+        );
+        Self {
+            digest_length,
+            levels: DEFAULT_LEVELS,
+            rounds: None,
+            key: [0u8; MAX_KEY_LENGTH_BYTES],
+            key_length: 0,
+        }
+    }
+
+    /// Creates parameters for the given digest length in bits with the default mode and no key, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the digest length is not between 1 and 512 bits.
+    pub const fn try_new(digest_length: usize) -> Result<Self, ParameterError> {
+        if digest_length >= 1 && digest_length <= DIGEST_MAX_LENGTH_BITS {
+            Ok(Self::new(digest_length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Sets the mode control `L`, i.e. the maximum height of the tree.
+    ///
+    /// With `0` the input is processed fully sequentially, with `64` (the default) as a 4-ary tree; values in between produce a tree of limited height on top of a sequential pass.
+    #[must_use]
+    pub const fn with_levels(self, levels: u8) -> Self {
+        Self { levels, ..self }
+    }
+
+    /// Overrides the number of rounds of the compression function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rounds is greater than [`MAX_ROUNDS`].
+    #[must_use]
+    pub const fn with_rounds(self, rounds: usize) -> Self {
+        assert!(rounds <= MAX_ROUNDS, "number of rounds must not exceed 255");
+        Self {
+            rounds: Some(rounds),
+            ..self
+        }
+    }
+
+    /// Overrides the number of rounds of the compression function, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of rounds is greater than [`MAX_ROUNDS`].
+    pub const fn try_with_rounds(self, rounds: usize) -> Result<Self, ParameterError> {
+        if rounds <= MAX_ROUNDS {
+            Ok(self.with_rounds(rounds))
+        } else {
+            Err(ParameterError {
+                parameter: "number of rounds",
+            })
+        }
+    }
+
+    /// Sets the key of the keyed hash mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is longer than [`MAX_KEY_LENGTH_BYTES`].
+    #[must_use]
+    pub fn with_key(self, key: &[u8]) -> Self {
+        assert!(key.len() <= MAX_KEY_LENGTH_BYTES, "key length must not exceed 64 bytes");
+        let key_length = key.len();
+        let key = {
+            let mut buffer = [0u8; MAX_KEY_LENGTH_BYTES];
+            buffer[..key_length].copy_from_slice(key);
+            buffer
+        };
+        Self {
+            key,
+            key_length,
+            ..self
+        }
+    }
+
+    /// Sets the key of the keyed hash mode, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is longer than [`MAX_KEY_LENGTH_BYTES`].
+    pub fn try_with_key(self, key: &[u8]) -> Result<Self, ParameterError> {
+        if key.len() <= MAX_KEY_LENGTH_BYTES {
+            Ok(self.with_key(key))
+        } else {
+            Err(ParameterError { parameter: "key" })
+        }
+    }
+
+    /// Returns the digest length in bits.
+    #[must_use]
+    pub const fn digest_length(&self) -> usize {
+        self.digest_length
+    }
+
+    /// Returns the mode control `L`.
+    #[must_use]
+    pub const fn levels(&self) -> u8 {
+        self.levels
+    }
+
+    /// Returns the number of rounds, either set explicitly or the default `40 + d / 4` (at least 80 when keyed).
+    #[must_use]
+    pub const fn rounds(&self) -> usize {
+        match self.rounds {
+            Some(rounds) => rounds,
+            None => {
+                let rounds = 40 + self.digest_length / 4;
+                if self.key_length > 0 && rounds < 80 {
+                    80
+                } else {
+                    rounds
+                }
+            },
+        }
+    }
+
+    /// Returns the key length in bytes.
+    #[must_use]
+    pub const fn key_length(&self) -> usize {
+        self.key_length
+    }
+
+    /// Returns the key as big-endian words.
+    pub(crate) fn key_words(&self) -> [u64; 8] {
+        let mut words = [0u64; 8];
+        for (word, bytes) in words.iter_mut().zip(self.key.chunks_exact(8)) {
+            *word = u64::from_be_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ]);
+        }
+        words
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Params {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let params = Self::new(u.int_in_range(1..=DIGEST_MAX_LENGTH_BITS)?).with_levels(u.arbitrary()?);
+        let params = if u.arbitrary()? {
+            params.with_rounds(u.int_in_range(0..=MAX_ROUNDS)?)
+        } else {
+            params
+        };
+        let key_length = u.int_in_range(0..=MAX_KEY_LENGTH_BYTES)?;
+        Ok(params.with_key(u.bytes(key_length)?))
+    }
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::new(DEFAULT_DIGEST_LENGTH_BITS)
+    }
+}
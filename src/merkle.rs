@@ -0,0 +1,297 @@
+//! This module is optional and can be enabled using the `merkle` Cargo feature.
+//!
+//! An implementation of binary Merkle trees.
+//!
+//! The tree is generic over the hash functions of this crate, i.e. any type implementing the [`Algorithm`] trait, and it produces the digest type of the chosen hash function. Its shape follows [RFC 6962](https://www.rfc-editor.org/rfc/rfc6962#section-2.1), used by Certificate Transparency: the left subtree of a node holds the largest power of two of the leaves and a node without a sibling is promoted to the upper level unchanged. Leaves and nodes are hashed with different prefixes, so a node cannot be passed off as a leaf.
+//!
+//! # Batch Processing
+//!
+//! The root of known leaves can be calculated with the [`root`] function.
+//!
+//! ```rust
+//! use chksum_hash::{merkle, sha2_256};
+//!
+//! let root = merkle::root::<sha2_256::Update, _>(["a", "b", "c"]);
+//! assert_eq!(
+//!     root.to_hex_lowercase(),
+//!     "36642e73c2540ab121e3a6bf9545b0a24982cd830eb13d3cd19de3ce6c021ec1"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The leaves can be pushed one by one into a tree created by calling the [`new`] function. The tree keeps at most one digest per level, i.e. the memory usage grows logarithmically with the number of leaves.
+//!
+//! ```rust
+//! use chksum_hash::{merkle, sha2_256};
+//!
+//! let root = merkle::new::<sha2_256::Update>()
+//!     .push("a")
+//!     .push("b")
+//!     .push("c")
+//!     .root();
+//! assert_eq!(
+//!     root.to_hex_lowercase(),
+//!     "36642e73c2540ab121e3a6bf9545b0a24982cd830eb13d3cd19de3ce6c021ec1"
+//! );
+//! ```
+//!
+//! # Domain Separation
+//!
+//! The leaves are prefixed with [`LEAF_PREFIX`] and the nodes with [`NODE_PREFIX`] by default. Use [`Tree::with_prefixes`] to choose other ones, or empty ones to build a tree without domain separation.
+//!
+//! ```rust
+//! use chksum_hash::{md5, merkle};
+//!
+//! let root = merkle::Tree::<md5::Update>::with_prefixes([], [])
+//!     .push("a")
+//!     .push("b")
+//!     .root();
+//! assert_eq!(root.to_hex_lowercase(), "96e024ba2074fe77e8e965ba43a704be");
+//! ```
+
+use crate::algorithm::Algorithm;
+
+/// Default prefix of the leaves.
+pub const LEAF_PREFIX: [u8; 1] = [0x00];
+/// Default prefix of the nodes.
+pub const NODE_PREFIX: [u8; 1] = [0x01];
+
+/// Creates a new tree with the default prefixes.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{merkle, sha2_256};
+///
+/// // The root of a tree without leaves is the digest of empty input
+/// let root = merkle::new::<sha2_256::Update>().root();
+/// assert_eq!(root, sha2_256::hash(""));
+/// ```
+#[must_use]
+pub fn new<H>() -> Tree<H>
+where
+    H: Algorithm,
+    H::Digest: Clone,
+{
+    Tree::new()
+}
+
+/// Computes the root of the tree with the given leaves and the default prefixes.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{merkle, sha2_256};
+///
+/// // Test vectors of Certificate Transparency
+/// let leaves: [&[u8]; 8] = [
+///     b"",
+///     b"\x00",
+///     b"\x10",
+///     b"\x20\x21",
+///     b"\x30\x31",
+///     b"\x40\x41\x42\x43",
+///     b"\x50\x51\x52\x53\x54\x55\x56\x57",
+///     b"\x60\x61\x62\x63\x64\x65\x66\x67\x68\x69\x6a\x6b\x6c\x6d\x6e\x6f",
+/// ];
+///
+/// let root = merkle::root::<sha2_256::Update, _>(&leaves[..1]);
+/// assert_eq!(
+///     root.to_hex_lowercase(),
+///     "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d"
+/// );
+///
+/// let root = merkle::root::<sha2_256::Update, _>(&leaves[..7]);
+/// assert_eq!(
+///     root.to_hex_lowercase(),
+///     "ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c"
+/// );
+///
+/// let root = merkle::root::<sha2_256::Update, _>(leaves);
+/// assert_eq!(
+///     root.to_hex_lowercase(),
+///     "5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328"
+/// );
+/// ```
+pub fn root<H, I>(leaves: I) -> H::Digest
+where
+    H: Algorithm,
+    H::Digest: Clone,
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut tree = Tree::<H>::new();
+    for leaf in leaves {
+        tree.push(leaf);
+    }
+    tree.root()
+}
+
+/// A Merkle tree that can handle an unknown number of leaves.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{merkle, sha2_256};
+///
+/// // Create a new tree
+/// let mut tree = merkle::Tree::<sha2_256::Update>::new();
+///
+/// // Push the leaves, either as data or as precomputed leaf digests
+/// tree.push("a").push("b");
+/// let leaf = merkle::Tree::<sha2_256::Update>::new().push("c").root();
+/// tree.push_digest(leaf);
+/// assert_eq!(tree.len(), 3);
+///
+/// // Compute the root
+/// assert_eq!(
+///     tree.root().to_hex_lowercase(),
+///     "36642e73c2540ab121e3a6bf9545b0a24982cd830eb13d3cd19de3ce6c021ec1"
+/// );
+///
+/// // Reset to an empty tree
+/// tree.reset();
+/// assert!(tree.is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tree<H>
+where
+    H: Algorithm,
+    H::Digest: Clone,
+{
+    leaf_prefix: Vec<u8>,
+    node_prefix: Vec<u8>,
+    // The roots of the complete subtrees with their heights, in decreasing order of height.
+    subtrees: Vec<(u32, H::Digest)>,
+    length: u64,
+}
+
+impl<H> Tree<H>
+where
+    H: Algorithm,
+    H::Digest: Clone,
+{
+    /// Creates a new tree with the default prefixes.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_prefixes(LEAF_PREFIX, NODE_PREFIX)
+    }
+
+    /// Creates a new tree with the given prefixes of the leaves and the nodes.
+    #[must_use]
+    pub fn with_prefixes(leaf_prefix: impl AsRef<[u8]>, node_prefix: impl AsRef<[u8]>) -> Self {
+        Self {
+            leaf_prefix: leaf_prefix.as_ref().to_vec(),
+            node_prefix: node_prefix.as_ref().to_vec(),
+            subtrees: Vec::new(),
+            length: 0,
+        }
+    }
+
+    /// Returns the number of leaves.
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        self.length
+    }
+
+    /// Returns `true` if there are no leaves.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Hashes the leaf data with the leaf prefix and appends it to the tree.
+    pub fn push(&mut self, leaf: impl AsRef<[u8]>) -> &mut Self {
+        let mut hash = H::default();
+        hash.update(&self.leaf_prefix);
+        hash.update(leaf);
+        self.push_digest(hash.digest())
+    }
+
+    /// Appends the precomputed leaf digest to the tree.
+    ///
+    /// The digest has to include the leaf prefix, if any.
+    pub fn push_digest(&mut self, digest: H::Digest) -> &mut Self {
+        let mut height = 0;
+        let mut digest = digest;
+        // Merge the complete subtrees of the same height, like carrying in a binary counter.
+        while let Some((last_height, last)) = self.subtrees.last() {
+            if *last_height != height {
+                break;
+            }
+            digest = self.node(last, &digest);
+            height += 1;
+            self.subtrees.pop();
+        }
+        self.subtrees.push((height, digest));
+        self.length += 1;
+        self
+    }
+
+    /// Computes the root of the tree.
+    ///
+    /// The root of a tree without leaves is the digest of empty input.
+    #[must_use]
+    pub fn root(&self) -> H::Digest {
+        // The remaining subtrees are merged from the smallest one, which promotes nodes without a sibling.
+        let mut subtrees = self.subtrees.iter().rev().map(|(_, digest)| digest);
+        match subtrees.next() {
+            Some(last) => subtrees.fold(last.clone(), |right, left| self.node(left, &right)),
+            None => H::default().digest(),
+        }
+    }
+
+    /// Resets the tree to no leaves.
+    ///
+    /// The prefixes are kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.subtrees.clear();
+        self.length = 0;
+        self
+    }
+
+    /// Hashes the children with the node prefix.
+    fn node(&self, left: &H::Digest, right: &H::Digest) -> H::Digest {
+        let mut hash = H::default();
+        hash.update(&self.node_prefix);
+        hash.update(left);
+        hash.update(right);
+        hash.digest()
+    }
+}
+
+#[cfg(all(feature = "arbitrary", feature = "tth"))]
+impl<H> Tree<H>
+where
+    H: Algorithm,
+    H::Digest: Clone,
+{
+    /// Replaces the leaves with an arbitrary number of them, represented by arbitrary roots of the complete subtrees.
+    pub(crate) fn arbitrary_leaves<'a>(&mut self, u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<()>
+    where
+        H::Digest: arbitrary::Arbitrary<'a>,
+    {
+        // Each set bit of the number of leaves stands for a complete subtree of the corresponding height.
+        let length = u64::from(u.arbitrary::<u32>()?);
+        let mut subtrees = Vec::new();
+        for height in (0..u64::BITS).rev() {
+            if length >> height & 1 == 1 {
+                subtrees.push((height, u.arbitrary()?));
+            }
+        }
+        self.subtrees = subtrees;
+        self.length = length;
+        Ok(())
+    }
+}
+
+impl<H> Default for Tree<H>
+where
+    H: Algorithm,
+    H::Digest: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,602 @@
+//! This module is optional and can be enabled using the `multihash` Cargo feature.
+//!
+//! An implementation of the [multihash](https://multiformats.io/multihash/) format of multiformats.
+//!
+//! A multihash is the digest prefixed with the code of the hash function from the [multicodec table](https://github.com/multiformats/multicodec/blob/master/table.csv) and the length of the digest, both encoded as unsigned varints. It is the hash part of the content identifiers of IPFS and of the peer identifiers of libp2p.
+//!
+//! # Encoding
+//!
+//! Digests of the hash functions with a multicodec code implement the [`Digest`] trait and can be encoded with the [`encode`] function.
+//!
+//! ```rust
+//! use chksum_hash::{multihash, sha2_256};
+//!
+//! let digest = sha2_256::hash("example data");
+//! let multihash = multihash::encode(digest);
+//! assert_eq!(multihash[..2], [0x12, 0x20]);
+//! assert_eq!(multihash[2..], *digest.as_ref());
+//! ```
+//!
+//! # Decoding
+//!
+//! A multihash can be parsed back into the hash function and the digest with the [`decode`] function.
+//!
+//! ```rust
+//! use chksum_hash::multihash::{self, Algorithm};
+//! use chksum_hash::sha1;
+//!
+//! let digest = sha1::hash("example data");
+//! let multihash = multihash::decode(&multihash::encode(digest)).unwrap();
+//! assert_eq!(multihash.algorithm(), Algorithm::Sha1);
+//! assert_eq!(multihash.digest(), digest.as_ref());
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use chksum_hash_core as core;
+
+#[cfg(feature = "crc32")]
+use crate::crc32;
+use crate::error::ParameterError;
+#[cfg(feature = "md4")]
+use crate::md4;
+#[cfg(feature = "md5")]
+use crate::md5;
+#[cfg(feature = "sha1")]
+use crate::sha1;
+#[cfg(feature = "sha2-224")]
+use crate::sha2_224;
+#[cfg(feature = "sha2-256")]
+use crate::sha2_256;
+#[cfg(feature = "sha2-384")]
+use crate::sha2_384;
+#[cfg(feature = "sha2-512")]
+use crate::sha2_512;
+#[cfg(feature = "skein-1024")]
+use crate::skein_1024;
+#[cfg(feature = "skein-256")]
+use crate::skein_256;
+#[cfg(feature = "skein-512")]
+use crate::skein_512;
+#[cfg(feature = "xxh3-128")]
+use crate::xxh3_128;
+#[cfg(feature = "xxh3-64")]
+use crate::xxh3_64;
+
+/// Maximum length of an unsigned varint in bytes, as limited by the multiformats specification.
+const VARINT_MAX_LENGTH_BYTES: usize = 9;
+
+/// A hash function of the multicodec table.
+///
+/// The variants cover the hash functions of this crate with an assigned code, as well as the identity function, whose digest is the data itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// Identity, `identity`.
+    Identity,
+    /// MD4, `md4`.
+    Md4,
+    /// MD5, `md5`.
+    Md5,
+    /// SHA-1, `sha1`.
+    Sha1,
+    /// SHA-2 224, `sha2-224`.
+    Sha2_224,
+    /// SHA-2 256, `sha2-256`.
+    Sha2_256,
+    /// SHA-2 384, `sha2-384`.
+    Sha2_384,
+    /// SHA-2 512, `sha2-512`.
+    Sha2_512,
+    /// SHA-2 256 applied twice, as in Bitcoin, `dbl-sha2-256`.
+    DoubleSha2_256,
+    /// Skein-256 with the given digest length in bytes, e.g. `skein256-256` for 32 bytes.
+    Skein256(usize),
+    /// Skein-512 with the given digest length in bytes, e.g. `skein512-512` for 64 bytes.
+    Skein512(usize),
+    /// Skein-1024 with the given digest length in bytes, e.g. `skein1024-1024` for 128 bytes.
+    Skein1024(usize),
+    /// CRC-32 of IEEE 802.3, `crc32`.
+    Crc32,
+    /// CRC-64 of ECMA-182, `crc64-ecma`.
+    Crc64Ecma,
+    /// XXH3-64, `xxh3-64`.
+    Xxh3_64,
+    /// XXH3-128, `xxh3-128`.
+    Xxh3_128,
+}
+
+impl Algorithm {
+    /// Returns the code of the hash function in the multicodec table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digest length of a Skein variant is zero or greater than the state size of the function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::multihash::Algorithm;
+    ///
+    /// assert_eq!(Algorithm::Sha2_256.code(), 0x12);
+    /// assert_eq!(Algorithm::Skein512(64).code(), 0xB360);
+    /// ```
+    #[must_use]
+    pub const fn code(&self) -> u64 {
+        match *self {
+            Self::Identity => 0x00,
+            Self::Md4 => 0xD4,
+            Self::Md5 => 0xD5,
+            Self::Sha1 => 0x11,
+            Self::Sha2_224 => 0x1013,
+            Self::Sha2_256 => 0x12,
+            Self::Sha2_384 => 0x20,
+            Self::Sha2_512 => 0x13,
+            Self::DoubleSha2_256 => 0x56,
+            Self::Skein256(length) => {
+                assert!(
+                    length > 0 && length <= 32,
+                    "digest length must be between 1 and 32 bytes"
+                );
+                0xB300 + length as u64
+            },
+            Self::Skein512(length) => {
+                assert!(
+                    length > 0 && length <= 64,
+                    "digest length must be between 1 and 64 bytes"
+                );
+                0xB320 + length as u64
+            },
+            Self::Skein1024(length) => {
+                assert!(
+                    length > 0 && length <= 128,
+                    "digest length must be between 1 and 128 bytes"
+                );
+                0xB360 + length as u64
+            },
+            Self::Crc32 => 0x0132,
+            Self::Crc64Ecma => 0x0164,
+            Self::Xxh3_64 => 0xB3E3,
+            Self::Xxh3_128 => 0xB3E4,
+        }
+    }
+
+    /// Returns the code of the hash function in the multicodec table, e.g. for an algorithm built from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the digest length of a Skein variant is zero or greater than the state size of the function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::multihash::Algorithm;
+    ///
+    /// assert_eq!(Algorithm::Skein512(64).try_code(), Ok(0xB360));
+    /// assert!(Algorithm::Skein512(65).try_code().is_err());
+    /// ```
+    pub const fn try_code(&self) -> Result<u64, ParameterError> {
+        let valid = match *self {
+            Self::Skein256(length) => length > 0 && length <= 32,
+            Self::Skein512(length) => length > 0 && length <= 64,
+            Self::Skein1024(length) => length > 0 && length <= 128,
+            _ => true,
+        };
+        if valid {
+            Ok(self.code())
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Returns the hash function with the given code of the multicodec table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::multihash::Algorithm;
+    ///
+    /// assert_eq!(Algorithm::from_code(0x11), Some(Algorithm::Sha1));
+    /// assert_eq!(Algorithm::from_code(0xB320), Some(Algorithm::Skein256(32)));
+    /// assert_eq!(Algorithm::from_code(0x1B), None);
+    /// ```
+    #[must_use]
+    pub const fn from_code(code: u64) -> Option<Self> {
+        let algorithm = match code {
+            0x00 => Self::Identity,
+            0xD4 => Self::Md4,
+            0xD5 => Self::Md5,
+            0x11 => Self::Sha1,
+            0x1013 => Self::Sha2_224,
+            0x12 => Self::Sha2_256,
+            0x20 => Self::Sha2_384,
+            0x13 => Self::Sha2_512,
+            0x56 => Self::DoubleSha2_256,
+            0xB301..=0xB320 => Self::Skein256((code - 0xB300) as usize),
+            0xB321..=0xB360 => Self::Skein512((code - 0xB320) as usize),
+            0xB361..=0xB3E0 => Self::Skein1024((code - 0xB360) as usize),
+            0x0132 => Self::Crc32,
+            0x0164 => Self::Crc64Ecma,
+            0xB3E3 => Self::Xxh3_64,
+            0xB3E4 => Self::Xxh3_128,
+            _ => return None,
+        };
+        Some(algorithm)
+    }
+
+    /// Returns the length of the digest in bytes, or `None` for the identity function.
+    ///
+    /// Multihashes may contain digests truncated to a shorter length.
+    #[must_use]
+    pub const fn digest_length(&self) -> Option<usize> {
+        let length = match *self {
+            Self::Identity => return None,
+            Self::Md4 | Self::Md5 | Self::Xxh3_128 => 16,
+            Self::Sha1 => 20,
+            Self::Sha2_224 => 28,
+            Self::Sha2_256 | Self::DoubleSha2_256 => 32,
+            Self::Sha2_384 => 48,
+            Self::Sha2_512 => 64,
+            Self::Skein256(length) | Self::Skein512(length) | Self::Skein1024(length) => length,
+            Self::Crc32 => 4,
+            Self::Crc64Ecma | Self::Xxh3_64 => 8,
+        };
+        Some(length)
+    }
+}
+
+impl Display for Algorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identity => f.write_str("identity"),
+            Self::Md4 => f.write_str("md4"),
+            Self::Md5 => f.write_str("md5"),
+            Self::Sha1 => f.write_str("sha1"),
+            Self::Sha2_224 => f.write_str("sha2-224"),
+            Self::Sha2_256 => f.write_str("sha2-256"),
+            Self::Sha2_384 => f.write_str("sha2-384"),
+            Self::Sha2_512 => f.write_str("sha2-512"),
+            Self::DoubleSha2_256 => f.write_str("dbl-sha2-256"),
+            Self::Skein256(length) => write!(f, "skein256-{}", length * 8),
+            Self::Skein512(length) => write!(f, "skein512-{}", length * 8),
+            Self::Skein1024(length) => write!(f, "skein1024-{}", length * 8),
+            Self::Crc32 => f.write_str("crc32"),
+            Self::Crc64Ecma => f.write_str("crc64-ecma"),
+            Self::Xxh3_64 => f.write_str("xxh3-64"),
+            Self::Xxh3_128 => f.write_str("xxh3-128"),
+        }
+    }
+}
+
+/// A digest of a hash function with a multicodec code.
+pub trait Digest: AsRef<[u8]> {
+    /// Returns the hash function of the digest.
+    fn algorithm(&self) -> Algorithm;
+}
+
+#[cfg(feature = "crc32")]
+impl Digest for crc32::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Crc32
+    }
+}
+
+#[cfg(feature = "md4")]
+impl Digest for md4::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Md4
+    }
+}
+
+#[cfg(feature = "md5")]
+impl Digest for md5::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Md5
+    }
+}
+
+#[cfg(feature = "sha1")]
+impl Digest for sha1::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Sha1
+    }
+}
+
+#[cfg(feature = "sha2-224")]
+impl Digest for sha2_224::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Sha2_224
+    }
+}
+
+#[cfg(feature = "sha2-256")]
+impl Digest for sha2_256::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Sha2_256
+    }
+}
+
+#[cfg(feature = "sha2-384")]
+impl Digest for sha2_384::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Sha2_384
+    }
+}
+
+#[cfg(feature = "sha2-512")]
+impl Digest for sha2_512::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Sha2_512
+    }
+}
+
+#[cfg(feature = "skein-256")]
+impl Digest for skein_256::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Skein256(self.len())
+    }
+}
+
+#[cfg(feature = "skein-512")]
+impl Digest for skein_512::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Skein512(self.len())
+    }
+}
+
+#[cfg(feature = "skein-1024")]
+impl Digest for skein_1024::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Skein1024(self.len())
+    }
+}
+
+#[cfg(feature = "xxh3-64")]
+impl Digest for xxh3_64::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Xxh3_64
+    }
+}
+
+#[cfg(feature = "xxh3-128")]
+impl Digest for xxh3_128::Digest {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::Xxh3_128
+    }
+}
+
+/// Encodes the digest as a multihash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{md5, multihash};
+///
+/// // Codes are varints, so the code of MD5 takes 2 bytes
+/// let multihash = multihash::encode(md5::hash("data"));
+/// assert_eq!(multihash.len(), 3 + md5::DIGEST_LENGTH_BYTES);
+/// assert_eq!(multihash[..3], [0xD5, 0x01, 0x10]);
+/// ```
+#[must_use]
+pub fn encode<D>(digest: D) -> Vec<u8>
+where
+    D: Digest,
+{
+    Multihash::wrap(&digest).to_bytes()
+}
+
+/// Decodes the multihash into the hash function and the digest.
+///
+/// # Errors
+///
+/// Returns an error if the multihash is malformed, has trailing bytes or uses an unknown hash function, see [`DecodeError`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::multihash::{self, Algorithm, DecodeError};
+///
+/// let multihash = multihash::decode(&[0x00, 0x04, b'd', b'a', b't', b'a']).unwrap();
+/// assert_eq!(multihash.algorithm(), Algorithm::Identity);
+/// assert_eq!(multihash.digest(), b"data");
+///
+/// assert_eq!(
+///     multihash::decode(&[0x12, 0x20, 0x00]),
+///     Err(DecodeError::UnexpectedEnd)
+/// );
+/// ```
+pub fn decode(bytes: &[u8]) -> Result<Multihash, DecodeError> {
+    Multihash::from_bytes(bytes)
+}
+
+/// Computes the digest of the given input and wraps it in a multihash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{multihash, sha2_256};
+///
+/// let multihash = multihash::hash::<sha2_256::Update>("data");
+/// assert_eq!(multihash.digest(), sha2_256::hash("data").as_ref());
+/// ```
+pub fn hash<H>(data: impl AsRef<[u8]>) -> Multihash
+where
+    H: core::Update,
+    H::Digest: Digest,
+{
+    Multihash::wrap(&core::hash::<H>(data))
+}
+
+/// A digest together with its hash function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Multihash {
+    algorithm: Algorithm,
+    digest: Vec<u8>,
+}
+
+impl Multihash {
+    /// Creates a new multihash of the digest of the given hash function.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecodeError::InvalidLength`] if the digest is longer than the digest of the hash function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::multihash::{Algorithm, Multihash};
+    /// use chksum_hash::sha2_256;
+    ///
+    /// // A digest truncated to 20 bytes
+    /// let digest = sha2_256::hash("data");
+    /// let multihash = Multihash::new(Algorithm::Sha2_256, &digest.as_ref()[..20]).unwrap();
+    /// assert_eq!(multihash.to_bytes()[..2], [0x12, 0x14]);
+    /// ```
+    pub fn new(algorithm: Algorithm, digest: impl AsRef<[u8]>) -> Result<Self, DecodeError> {
+        let digest = digest.as_ref();
+        match algorithm.digest_length() {
+            Some(length) if digest.len() > length => Err(DecodeError::InvalidLength { length: digest.len() }),
+            _ => {
+                Ok(Self {
+                    algorithm,
+                    digest: digest.to_vec(),
+                })
+            },
+        }
+    }
+
+    /// Creates a new multihash of the digest.
+    #[must_use]
+    pub fn wrap<D>(digest: &D) -> Self
+    where
+        D: Digest,
+    {
+        Self {
+            algorithm: digest.algorithm(),
+            digest: digest.as_ref().to_vec(),
+        }
+    }
+
+    /// Parses the multihash from the binary representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the multihash is malformed, has trailing bytes or uses an unknown hash function, see [`DecodeError`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (multihash, length) = Self::read(bytes)?;
+        if length == bytes.len() {
+            Ok(multihash)
+        } else {
+            Err(DecodeError::TrailingBytes)
+        }
+    }
+
+    /// Returns the hash function.
+    #[must_use]
+    pub const fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Returns the digest.
+    #[must_use]
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    /// Returns the binary representation, i.e. the code, the length and the digest.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 * VARINT_MAX_LENGTH_BYTES + self.digest.len());
+        write_varint(&mut bytes, self.algorithm.code());
+        write_varint(&mut bytes, self.digest.len() as u64);
+        bytes.extend_from_slice(&self.digest);
+        bytes
+    }
+
+    /// Parses the multihash at the beginning of the bytes and returns it with its length in bytes.
+    pub(crate) fn read(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (code, code_length) = read_varint(bytes)?;
+        let algorithm = Algorithm::from_code(code).ok_or(DecodeError::UnknownCode { code })?;
+        let (length, length_length) = read_varint(&bytes[code_length..])?;
+        let offset = code_length + length_length;
+        let length = usize::try_from(length).map_err(|_| DecodeError::UnexpectedEnd)?;
+        let digest = bytes
+            .get(offset..)
+            .and_then(|bytes| bytes.get(..length))
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        let multihash = Self::new(algorithm, digest)?;
+        Ok((multihash, offset + length))
+    }
+}
+
+impl AsRef<[u8]> for Multihash {
+    fn as_ref(&self) -> &[u8] {
+        &self.digest
+    }
+}
+
+/// An error of decoding a multihash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ends before the end of the multihash.
+    UnexpectedEnd,
+    /// A varint is longer than 9 bytes or not minimally encoded.
+    InvalidVarint,
+    /// A code that is not a hash function of [`Algorithm`].
+    UnknownCode {
+        /// The code from the multicodec table.
+        code: u64,
+    },
+    /// A digest longer than the digest of the hash function.
+    InvalidLength {
+        /// The digest length in bytes.
+        length: usize,
+    },
+    /// Bytes after the end of the multihash.
+    TrailingBytes,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of multihash"),
+            Self::InvalidVarint => f.write_str("invalid varint"),
+            Self::UnknownCode { code } => write!(f, "unknown hash function code {code:#x}"),
+            Self::InvalidLength { length } => write!(f, "invalid digest length {length}"),
+            Self::TrailingBytes => f.write_str("trailing bytes after multihash"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Appends the unsigned varint, 7 bits per byte starting with the least significant ones.
+pub(crate) fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Reads the unsigned varint at the beginning of the bytes and returns it with its length in bytes.
+pub(crate) fn read_varint(bytes: &[u8]) -> Result<(u64, usize), DecodeError> {
+    let mut value = 0u64;
+    for (index, &byte) in bytes.iter().take(VARINT_MAX_LENGTH_BYTES).enumerate() {
+        value |= u64::from(byte & 0x7F) << (7 * index);
+        if byte & 0x80 == 0 {
+            // The last byte of a minimal encoding is not zero, except for the value zero.
+            if byte == 0 && index > 0 {
+                return Err(DecodeError::InvalidVarint);
+            }
+            return Ok((value, index + 1));
+        }
+    }
+    if bytes.len() < VARINT_MAX_LENGTH_BYTES {
+        Err(DecodeError::UnexpectedEnd)
+    } else {
+        Err(DecodeError::InvalidVarint)
+    }
+}
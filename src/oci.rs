@@ -0,0 +1,234 @@
+//! This module is optional and can be enabled using the `oci` Cargo feature.
+//!
+//! An implementation of the digest strings of the [OCI image specification](https://github.com/opencontainers/image-spec/blob/main/descriptor.md#digests), used by container registries and tools like Docker.
+//!
+//! A digest string is the name of the algorithm and the lowercase hexadecimal digest separated by a colon, e.g. `sha256:<hex>`. The registered algorithms are [`sha2_256`](crate::sha2_256) and [`sha2_512`](crate::sha2_512), see [`Algorithm`].
+//!
+//! # Hashing
+//!
+//! The digest string of content, e.g. of a layer or a manifest, can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::oci::{self, Algorithm};
+//!
+//! let digest = oci::hash(Algorithm::Sha256, "example data");
+//! assert_eq!(
+//!     digest.to_string(),
+//!     "sha256:44752f37272e944fd2c913a35342eaccdd1aaf189bae50676b301ab213fc5061"
+//! );
+//! ```
+//!
+//! # Parsing
+//!
+//! Digest strings are parsed with the [`FromStr`] trait and can be compared with each other and with digests.
+//!
+//! ```rust
+//! use chksum_hash::oci::DigestString;
+//! use chksum_hash::sha2_256;
+//!
+//! let digest = "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+//!     .parse::<DigestString>()
+//!     .unwrap();
+//! assert_eq!(digest, sha2_256::hash(""));
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::{hex, sha2_256, sha2_512};
+
+/// A registered algorithm of digest strings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Algorithm {
+    /// SHA-2 256, the default algorithm.
+    #[default]
+    Sha256,
+    /// SHA-2 512.
+    Sha512,
+}
+
+impl Algorithm {
+    /// Returns the name of the algorithm used in digest strings, e.g. `"sha256"`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Sha512 => "sha512",
+        }
+    }
+
+    /// Returns the algorithm with the given name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::oci::Algorithm;
+    ///
+    /// assert_eq!(Algorithm::from_name("sha512"), Some(Algorithm::Sha512));
+    /// assert_eq!(Algorithm::from_name("SHA256"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::Sha256, Self::Sha512]
+            .into_iter()
+            .find(|algorithm| algorithm.name() == name)
+    }
+
+    /// Returns the length of the digest in bytes.
+    #[must_use]
+    pub const fn digest_length(&self) -> usize {
+        match self {
+            Self::Sha256 => sha2_256::DIGEST_LENGTH_BYTES,
+            Self::Sha512 => sha2_512::DIGEST_LENGTH_BYTES,
+        }
+    }
+}
+
+/// Computes the digest string of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::oci::{self, Algorithm};
+///
+/// let digest = oci::hash(Algorithm::Sha512, "");
+/// assert_eq!(digest.algorithm(), Algorithm::Sha512);
+/// assert!(digest.to_string().starts_with("sha512:cf83e135"));
+/// ```
+#[must_use]
+pub fn hash(algorithm: Algorithm, data: impl AsRef<[u8]>) -> DigestString {
+    match algorithm {
+        Algorithm::Sha256 => DigestString::Sha256(sha2_256::hash(data)),
+        Algorithm::Sha512 => DigestString::Sha512(sha2_512::hash(data)),
+    }
+}
+
+/// A digest together with its algorithm, in the textual form `<algorithm>:<hex>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DigestString {
+    /// SHA-2 256 digest, `sha256:<hex>`.
+    Sha256(sha2_256::Digest),
+    /// SHA-2 512 digest, `sha512:<hex>`.
+    Sha512(sha2_512::Digest),
+}
+
+impl DigestString {
+    /// Returns the algorithm of the digest.
+    #[must_use]
+    pub const fn algorithm(&self) -> Algorithm {
+        match self {
+            Self::Sha256(_) => Algorithm::Sha256,
+            Self::Sha512(_) => Algorithm::Sha512,
+        }
+    }
+
+    /// Returns `true` if the digest string is the digest of the given input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::oci::DigestString;
+    ///
+    /// let digest = "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    ///     .parse::<DigestString>()
+    ///     .unwrap();
+    /// assert!(digest.verify(""));
+    /// assert!(!digest.verify("data"));
+    /// ```
+    #[must_use]
+    pub fn verify(&self, data: impl AsRef<[u8]>) -> bool {
+        hash(self.algorithm(), data) == *self
+    }
+}
+
+impl AsRef<[u8]> for DigestString {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Sha256(digest) => digest.as_ref(),
+            Self::Sha512(digest) => digest.as_ref(),
+        }
+    }
+}
+
+impl Display for DigestString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sha256(digest) => write!(f, "{}:{digest:x}", self.algorithm().name()),
+            Self::Sha512(digest) => write!(f, "{}:{digest:x}", self.algorithm().name()),
+        }
+    }
+}
+
+impl FromStr for DigestString {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, encoded) = s.split_once(':').ok_or(ParseError::MissingSeparator)?;
+        let algorithm = Algorithm::from_name(name).ok_or(ParseError::UnsupportedAlgorithm)?;
+        // The registered algorithms use the lowercase hexadecimal encoding only.
+        if encoded.starts_with("0x") || encoded.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            return Err(ParseError::InvalidEncoded);
+        }
+        let digest = match algorithm {
+            Algorithm::Sha256 => {
+                let mut digest = [0u8; sha2_256::DIGEST_LENGTH_BYTES];
+                hex::decode_to_slice(encoded, &mut digest).map_err(|_| ParseError::InvalidEncoded)?;
+                Self::Sha256(sha2_256::Digest::new(digest))
+            },
+            Algorithm::Sha512 => {
+                let mut digest = [0u8; sha2_512::DIGEST_LENGTH_BYTES];
+                hex::decode_to_slice(encoded, &mut digest).map_err(|_| ParseError::InvalidEncoded)?;
+                Self::Sha512(sha2_512::Digest::new(digest))
+            },
+        };
+        Ok(digest)
+    }
+}
+
+impl From<sha2_256::Digest> for DigestString {
+    fn from(digest: sha2_256::Digest) -> Self {
+        Self::Sha256(digest)
+    }
+}
+
+impl From<sha2_512::Digest> for DigestString {
+    fn from(digest: sha2_512::Digest) -> Self {
+        Self::Sha512(digest)
+    }
+}
+
+impl PartialEq<sha2_256::Digest> for DigestString {
+    fn eq(&self, other: &sha2_256::Digest) -> bool {
+        matches!(self, Self::Sha256(digest) if digest == other)
+    }
+}
+
+impl PartialEq<sha2_512::Digest> for DigestString {
+    fn eq(&self, other: &sha2_512::Digest) -> bool {
+        matches!(self, Self::Sha512(digest) if digest == other)
+    }
+}
+
+/// An error of parsing a digest string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// No colon between the algorithm and the encoded digest.
+    MissingSeparator,
+    /// An algorithm other than the registered ones.
+    UnsupportedAlgorithm,
+    /// An encoded digest that is not lowercase hexadecimal of the digest length of the algorithm.
+    InvalidEncoded,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSeparator => f.write_str("missing separator between algorithm and digest"),
+            Self::UnsupportedAlgorithm => f.write_str("unsupported digest algorithm"),
+            Self::InvalidEncoded => f.write_str("invalid encoded digest"),
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,100 @@
+//! Module contains items related to the [`State`] structure which allows to the direct one-at-a-time state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::one_at_a_time;
+//!
+//! // Create new state
+//! let mut state = one_at_a_time::state::default();
+//!
+//! // By default it returns the hash of empty input
+//! assert_eq!(state.digest(), 0x00000000);
+//!
+//! // Update state with any amount of data and own new value
+//! state = state.update(b"a");
+//! assert_eq!(state.digest(), 0xCA2E9442);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state.digest(), 0x00000000);
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level hash state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) hash: u32,
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(0)
+    }
+
+    /// Creates a state from the raw hash value, e.g. a custom initial value.
+    #[must_use]
+    pub const fn from_raw(hash: u32) -> Self {
+        Self { hash }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// Unlike most hash functions, the data does not need to be split into blocks.
+    #[must_use]
+    pub const fn update(&self, data: &[u8]) -> Self {
+        let mut hash = self.hash;
+        let mut i = 0;
+        while i < data.len() {
+            hash = hash.wrapping_add(data[i] as u32);
+            hash = hash.wrapping_add(hash << 10);
+            hash ^= hash >> 6;
+            i += 1;
+        }
+        Self::from_raw(hash)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns a hash.
+    ///
+    /// The final mixing is applied to a copy of the state, so more data can be added afterwards.
+    #[must_use]
+    pub const fn digest(&self) -> u32 {
+        let mut hash = self.hash;
+        hash = hash.wrapping_add(hash << 3);
+        hash ^= hash >> 11;
+        hash.wrapping_add(hash << 15)
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,417 @@
+//! This module is optional and can be enabled using the `otp` Cargo feature.
+//!
+//! An implementation of the HMAC-based and the time-based one-time password algorithms (HOTP and TOTP).
+//!
+//! [`Hotp`] implements [RFC 4226](https://www.rfc-editor.org/rfc/rfc4226) and [`Totp`] implements [RFC 6238](https://www.rfc-editor.org/rfc/rfc6238). Both are built on [`hmac`](crate::hmac) and they are generic over the same [`Algorithm`] trait; authenticator applications use SHA-1 by default, SHA-256 and SHA-512 are the other standard choices.
+//!
+//! # Counter-based Passwords
+//!
+//! ```rust
+//! use chksum_hash::{otp, sha1};
+//!
+//! let hotp = otp::Hotp::<sha1::Update>::new("12345678901234567890");
+//! assert_eq!(hotp.generate(0), 755224);
+//! assert_eq!(hotp.generate(1), 287082);
+//!
+//! // Accept passwords up to 2 counter values ahead and obtain the matching counter
+//! assert_eq!(hotp.verify(969429, 1, 2), Some(3));
+//! assert_eq!(hotp.verify(338314, 1, 2), None);
+//! ```
+//!
+//! # Time-based Passwords
+//!
+//! ```rust
+//! use chksum_hash::{otp, sha2_256};
+//!
+//! let totp =
+//!     otp::Totp::<sha2_256::Update>::new("12345678901234567890123456789012").with_digits(8);
+//! assert_eq!(totp.generate(59), 46119246);
+//!
+//! // Accept passwords from one time step before or after and obtain the matching time step
+//! assert_eq!(totp.verify(46119246, 89, 1), Some(1));
+//! assert_eq!(totp.verify(46119246, 119, 1), None);
+//! ```
+//!
+//! Passwords are returned as numbers, they have to be padded with leading zeros to the number of digits for display.
+//!
+//! ```rust
+//! use chksum_hash::{otp, sha1};
+//!
+//! let totp = otp::Totp::<sha1::Update>::new("12345678901234567890").with_digits(8);
+//! let password = totp.generate(1_111_111_109);
+//! assert_eq!(format!("{password:08}"), "07081804");
+//! ```
+
+use std::time::{SystemTime, SystemTimeError, UNIX_EPOCH};
+
+use crate::algorithm::Algorithm;
+use crate::error::ParameterError;
+use crate::hmac;
+
+/// Default number of digits of a password.
+pub const DEFAULT_DIGITS: u32 = 6;
+/// Maximum number of digits of a password.
+pub const MAX_DIGITS: u32 = 9;
+/// Default time step in seconds.
+pub const DEFAULT_STEP: u64 = 30;
+
+/// Minimum digest length in bytes required by the dynamic truncation.
+pub const MIN_DIGEST_LENGTH_BYTES: usize = 20;
+
+/// A generator of HMAC-based one-time passwords.
+///
+/// The digest of the hash function has to be at least [`MIN_DIGEST_LENGTH_BYTES`] long, so generators over hash functions with shorter digests, like MD5, do not compile.
+///
+/// ```rust,compile_fail
+/// use chksum_hash::{md5, otp};
+///
+/// let hotp = otp::Hotp::<md5::Update>::new("12345678901234567890");
+/// ```
+///
+/// Check [`otp`](self) module for usage examples.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hotp<H>
+where
+    H: Algorithm,
+{
+    hmac: hmac::Update<H>,
+    digits: u32,
+}
+
+impl<H> Hotp<H>
+where
+    H: Algorithm,
+{
+    /// Fails the compilation of [`Hotp::new`] for hash functions with digests shorter than [`MIN_DIGEST_LENGTH_BYTES`].
+    const DIGEST_LENGTH_CHECK: () = assert!(
+        H::DIGEST_LENGTH_BYTES >= MIN_DIGEST_LENGTH_BYTES,
+        "digest must be at least 20 bytes long"
+    );
+
+    /// Creates a new generator with the given secret and the default number of digits.
+    #[must_use]
+    pub fn new(secret: impl AsRef<[u8]>) -> Self {
+        let () = Self::DIGEST_LENGTH_CHECK;
+        let hmac = hmac::new::<H>(secret);
+        Self {
+            hmac,
+            digits: DEFAULT_DIGITS,
+        }
+    }
+
+    /// Sets the number of digits of the passwords.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of digits is not between 1 and [`MAX_DIGITS`].
+    #[must_use]
+    pub fn with_digits(self, digits: u32) -> Self {
+        assert!(
+            (1..=MAX_DIGITS).contains(&digits),
+            "number of digits must be between 1 and 9"
+        );
+        Self { digits, ..self }
+    }
+
+    /// Sets the number of digits of the passwords, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParameterError`] if the number of digits is not between 1 and [`MAX_DIGITS`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::error::ParameterError;
+    /// use chksum_hash::{otp, sha1};
+    ///
+    /// let hotp = otp::Hotp::<sha1::Update>::new("12345678901234567890");
+    /// assert!(hotp.clone().try_with_digits(8).is_ok());
+    /// assert_eq!(
+    ///     hotp.try_with_digits(10),
+    ///     Err(ParameterError {
+    ///         parameter: "digits"
+    ///     })
+    /// );
+    /// ```
+    pub fn try_with_digits(self, digits: u32) -> Result<Self, ParameterError> {
+        if (1..=MAX_DIGITS).contains(&digits) {
+            Ok(Self { digits, ..self })
+        } else {
+            Err(ParameterError { parameter: "digits" })
+        }
+    }
+
+    /// Returns the number of digits of the passwords.
+    #[must_use]
+    pub const fn digits(&self) -> u32 {
+        self.digits
+    }
+
+    /// Generates the password for the given counter value.
+    #[must_use]
+    pub fn generate(&self, counter: u64) -> u32 {
+        let mut hmac = self.hmac.clone();
+        hmac.update(counter.to_be_bytes());
+        let digest = hmac.digest();
+        let digest = digest.as_ref();
+
+        // Dynamic truncation takes 31 bits at the offset given by the low nibble of the last byte.
+        let offset = usize::from(digest[digest.len() - 1] & 0x0F);
+        let code = u32::from_be_bytes([
+            digest[offset] & 0x7F,
+            digest[offset + 1],
+            digest[offset + 2],
+            digest[offset + 3],
+        ]);
+        code % 10u32.pow(self.digits)
+    }
+
+    /// Verifies the password against the counter values from `counter` to `counter + look_ahead`.
+    ///
+    /// Returns the matching counter value, the next expected counter value is one more.
+    #[must_use]
+    pub fn verify(&self, password: u32, counter: u64, look_ahead: u64) -> Option<u64> {
+        let last = counter.saturating_add(look_ahead);
+        (counter..=last).find(|&counter| self.generate(counter) == password)
+    }
+}
+
+/// A generator of time-based one-time passwords.
+///
+/// Check [`otp`](self) module for usage examples.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Totp<H>
+where
+    H: Algorithm,
+{
+    hotp: Hotp<H>,
+    step: u64,
+    start: u64,
+}
+
+impl<H> Totp<H>
+where
+    H: Algorithm,
+{
+    /// Creates a new generator with the given secret, the default number of digits and the default time step starting at the Unix epoch.
+    #[must_use]
+    pub fn new(secret: impl AsRef<[u8]>) -> Self {
+        let hotp = Hotp::new(secret);
+        Self {
+            hotp,
+            step: DEFAULT_STEP,
+            start: 0,
+        }
+    }
+
+    /// Sets the number of digits of the passwords.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of digits is not between 1 and [`MAX_DIGITS`].
+    #[must_use]
+    pub fn with_digits(self, digits: u32) -> Self {
+        let hotp = self.hotp.with_digits(digits);
+        Self { hotp, ..self }
+    }
+
+    /// Sets the number of digits of the passwords, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParameterError`] if the number of digits is not between 1 and [`MAX_DIGITS`].
+    pub fn try_with_digits(self, digits: u32) -> Result<Self, ParameterError> {
+        let hotp = self.hotp.try_with_digits(digits)?;
+        Ok(Self { hotp, ..self })
+    }
+
+    /// Sets the time step in seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time step is zero.
+    #[must_use]
+    pub fn with_step(self, step: u64) -> Self {
+        assert!(step > 0, "time step must be greater than zero");
+        Self { step, ..self }
+    }
+
+    /// Sets the time step in seconds, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParameterError`] if the time step is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::error::ParameterError;
+    /// use chksum_hash::{otp, sha1};
+    ///
+    /// let totp = otp::Totp::<sha1::Update>::new("12345678901234567890");
+    /// assert_eq!(
+    ///     totp.clone().try_with_step(60).map(|totp| totp.step()),
+    ///     Ok(60)
+    /// );
+    /// assert_eq!(
+    ///     totp.try_with_step(0),
+    ///     Err(ParameterError {
+    ///         parameter: "time step"
+    ///     })
+    /// );
+    /// ```
+    pub fn try_with_step(self, step: u64) -> Result<Self, ParameterError> {
+        if step > 0 {
+            Ok(Self { step, ..self })
+        } else {
+            Err(ParameterError { parameter: "time step" })
+        }
+    }
+
+    /// Sets the Unix time in seconds to start counting the time steps from.
+    #[must_use]
+    pub fn with_start(self, start: u64) -> Self {
+        Self { start, ..self }
+    }
+
+    /// Returns the number of digits of the passwords.
+    #[must_use]
+    pub const fn digits(&self) -> u32 {
+        self.hotp.digits()
+    }
+
+    /// Returns the time step in seconds.
+    #[must_use]
+    pub const fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// Returns the Unix time in seconds the time steps are counted from.
+    #[must_use]
+    pub const fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the time step of the given Unix time in seconds.
+    #[must_use]
+    pub const fn counter(&self, time: u64) -> u64 {
+        time.saturating_sub(self.start) / self.step
+    }
+
+    /// Generates the password for the given Unix time in seconds.
+    #[must_use]
+    pub fn generate(&self, time: u64) -> u32 {
+        self.hotp.generate(self.counter(time))
+    }
+
+    /// Generates the password for the current time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system clock is set before the Unix epoch.
+    #[must_use]
+    pub fn generate_now(&self) -> u32 {
+        self.generate(now())
+    }
+
+    /// Generates the password for the current time, e.g. on systems without a reliable clock.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system clock is set before the Unix epoch.
+    pub fn try_generate_now(&self) -> Result<u32, SystemTimeError> {
+        try_now().map(|time| self.generate(time))
+    }
+
+    /// Verifies the password against the time steps from `skew` steps before to `skew` steps after the given Unix time in seconds.
+    ///
+    /// Returns the matching time step, which should be stored to reject the reuse of the password.
+    #[must_use]
+    pub fn verify(&self, password: u32, time: u64, skew: u64) -> Option<u64> {
+        let counter = self.counter(time);
+        let first = counter.saturating_sub(skew);
+        let look_ahead = counter.saturating_add(skew) - first;
+        self.hotp.verify(password, first, look_ahead)
+    }
+
+    /// Verifies the password against the time steps around the current time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system clock is set before the Unix epoch.
+    #[must_use]
+    pub fn verify_now(&self, password: u32, skew: u64) -> Option<u64> {
+        self.verify(password, now(), skew)
+    }
+
+    /// Verifies the password against the time steps around the current time, e.g. on systems without a reliable clock.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system clock is set before the Unix epoch.
+    pub fn try_verify_now(&self, password: u32, skew: u64) -> Result<Option<u64>, SystemTimeError> {
+        try_now().map(|time| self.verify(password, time, skew))
+    }
+}
+
+/// Returns the current Unix time in seconds.
+fn now() -> u64 {
+    try_now().expect("system time should not be earlier than the Unix epoch")
+}
+
+/// Returns the current Unix time in seconds, or an error if the system clock is set before the Unix epoch.
+fn try_now() -> Result<u64, SystemTimeError> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    // Test values from RFC 4226, appendix D.
+    #[cfg(feature = "sha1")]
+    #[test]
+    fn rfc_4226() {
+        let hotp = super::Hotp::<crate::sha1::Update>::new("12345678901234567890");
+        let expected = [
+            755224, 287082, 359152, 969429, 338314, 254676, 287922, 162583, 399871, 520489,
+        ];
+        for (counter, expected) in (0..).zip(expected) {
+            assert_eq!(hotp.generate(counter), expected);
+        }
+    }
+
+    // Test vectors from RFC 6238, appendix B.
+    #[cfg(feature = "sha1")]
+    #[test]
+    fn rfc_6238() {
+        let totp = super::Totp::<crate::sha1::Update>::new("12345678901234567890").with_digits(8);
+        for (time, expected) in [
+            (59, 94287082),
+            (1111111109, 7081804),
+            (1111111111, 14050471),
+            (1234567890, 89005924),
+            (2000000000, 69279037),
+            (20000000000, 65353130),
+        ] {
+            assert_eq!(totp.generate(time), expected);
+        }
+    }
+
+    // Digests of the minimum length reach the last byte with the maximum offset of the truncation.
+    #[cfg(feature = "sha0")]
+    #[test]
+    fn min_digest_length() {
+        let hotp = super::Hotp::<crate::sha0::Update>::new("12345678901234567890");
+        let offsets = (0..1000)
+            .map(|counter| {
+                let mut hmac = crate::hmac::new::<crate::sha0::Update>("12345678901234567890");
+                hmac.update(u64::to_be_bytes(counter));
+                assert!(hotp.generate(counter) < 1_000_000);
+                hmac.digest().as_ref()[super::MIN_DIGEST_LENGTH_BYTES - 1] & 0x0F
+            })
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(offsets.len(), 16);
+    }
+}
@@ -0,0 +1,142 @@
+//! This module is optional and can be enabled using the `pbkdf2` Cargo feature.
+//!
+//! An implementation of the password-based key derivation function 2 (PBKDF2).
+//!
+//! PBKDF2, defined in [RFC 8018](https://www.rfc-editor.org/rfc/rfc8018), derives keys from passwords by iterating a pseudorandom function. This implementation uses [`hmac`](crate::hmac) as the pseudorandom function and it is generic over the same [`Algorithm`] trait.
+//!
+//! # Key Derivation
+//!
+//! The key of the given length can be derived with the [`derive`] function.
+//!
+//! ```rust
+//! use chksum_hash::{pbkdf2, sha2_256};
+//!
+//! let key = pbkdf2::derive::<sha2_256::Update>("password", "salt", 4096, 32);
+//! let key = key
+//!     .iter()
+//!     .map(|byte| format!("{byte:02x}"))
+//!     .collect::<String>();
+//! assert_eq!(
+//!     key,
+//!     "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+//! );
+//! ```
+//!
+//! # Disclaimer
+//!
+//! The number of iterations is the only cost of PBKDF2, which makes it cheap to attack with dedicated hardware. Prefer memory-hard functions, like [`scrypt`](crate::scrypt), for new password storage.
+
+use crate::algorithm::Algorithm;
+use crate::error::{Error, LengthError, ParameterError};
+use crate::hmac;
+
+/// Derives the key of the given length from the password and the salt.
+///
+/// # Panics
+///
+/// Panics if the number of iterations is zero or the length exceeds 2^32 - 1 times the digest length of the hash function.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{pbkdf2, sha1};
+///
+/// let key = pbkdf2::derive::<sha1::Update>("password", "salt", 1, 20);
+/// let key = key
+///     .iter()
+///     .map(|byte| format!("{byte:02x}"))
+///     .collect::<String>();
+/// assert_eq!(key, "0c60c80f961f0e71f3a9b524af6012062fe037a6");
+/// ```
+#[must_use]
+pub fn derive<H>(password: impl AsRef<[u8]>, salt: impl AsRef<[u8]>, iterations: u32, length: usize) -> Vec<u8>
+where
+    H: Algorithm,
+{
+    assert!(iterations > 0, "number of iterations must be greater than zero");
+    try_derive::<H>(password, salt, iterations, length).expect("key length should not exceed 2^32 - 1 digests")
+}
+
+/// Derives the key of the given length from the password and the salt, returning an error instead of panicking.
+///
+/// # Errors
+///
+/// Returns [`Error::Parameter`] if the number of iterations is zero and [`Error::Length`] if the length exceeds 2^32 - 1 times the digest length of the hash function.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::error::{Error, ParameterError};
+/// use chksum_hash::{pbkdf2, sha1};
+///
+/// assert!(pbkdf2::try_derive::<sha1::Update>("password", "salt", 1, 20).is_ok());
+/// assert_eq!(
+///     pbkdf2::try_derive::<sha1::Update>("password", "salt", 0, 20),
+///     Err(Error::Parameter(ParameterError {
+///         parameter: "iterations"
+///     }))
+/// );
+/// ```
+pub fn try_derive<H>(
+    password: impl AsRef<[u8]>,
+    salt: impl AsRef<[u8]>,
+    iterations: u32,
+    length: usize,
+) -> Result<Vec<u8>, Error>
+where
+    H: Algorithm,
+{
+    if iterations == 0 {
+        return Err(ParameterError {
+            parameter: "iterations",
+        }
+        .into());
+    }
+
+    let salt = salt.as_ref();
+    let keyed = hmac::new::<H>(password);
+
+    let mut key = Vec::with_capacity(length);
+    let mut index = 0u32;
+    while key.len() < length {
+        index = index.checked_add(1).ok_or(LengthError { length })?;
+
+        // The first block is keyed with the salt and the big-endian block index, the following ones with the previous block.
+        let mut hmac = keyed.clone();
+        hmac.update(salt).update(index.to_be_bytes());
+        let mut digest = hmac.digest();
+        let mut block = digest.as_ref().to_vec();
+        for _ in 1..iterations {
+            let mut hmac = keyed.clone();
+            hmac.update(digest);
+            digest = hmac.digest();
+            for (byte, xor) in block.iter_mut().zip(digest.as_ref()) {
+                *byte ^= xor;
+            }
+        }
+
+        let missing = length - key.len();
+        key.extend_from_slice(&block[..missing.min(block.len())]);
+    }
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    // Test vectors from RFC 6070.
+    #[cfg(feature = "sha1")]
+    #[test]
+    fn rfc_6070() {
+        for (iterations, expected) in [
+            (1, "0c60c80f961f0e71f3a9b524af6012062fe037a6"),
+            (2, "ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957"),
+            (4096, "4b007901b765489abead49d926f721d065a429c1"),
+        ] {
+            let key = super::derive::<crate::sha1::Update>("password", "salt", iterations, 20);
+            assert_eq!(
+                key.iter().map(|byte| format!("{byte:02x}")).collect::<String>(),
+                expected
+            );
+        }
+    }
+}
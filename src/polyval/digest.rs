@@ -0,0 +1,18 @@
+//! Module containing items related to the POLYVAL digest.
+
+/// Digest length in bits.
+pub const LENGTH_BITS: usize = 128;
+/// Digest length in bytes.
+pub const LENGTH_BYTES: usize = LENGTH_BITS / 8;
+/// Digest length in hexadecimal format.
+pub const LENGTH_HEX: usize = LENGTH_BYTES * 2;
+
+/// Marker type of the POLYVAL digest.
+///
+/// It tags [`Digest`] so POLYVAL digests are distinct from digests of other hash functions with the same length.
+pub enum Polyval {}
+
+/// A hash digest.
+///
+/// Check [`polyval`](super) for more information.
+pub type Digest = crate::digest::Digest<Polyval, LENGTH_BYTES>;
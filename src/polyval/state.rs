@@ -0,0 +1,139 @@
+//! Module contains items related to the [`State`] structure which allows to the direct POLYVAL state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::polyval;
+//!
+//! // Create new state with the given key
+//! let mut state = polyval::state::new(*b"0123456789abcdef");
+//!
+//! // Create a block of data
+//! let block = std::array::from_fn(|index| index as u8);
+//! let data = polyval::Block::new(block).into();
+//!
+//! // Update state and own new value
+//! state = state.update(data);
+//!
+//! // Obtain the accumulator as little-endian words
+//! assert_eq!(state.digest(), [0x49398A1DD95338BF, 0x62BA5ACF6C3A2820]);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state.digest(), [0x0000000000000000, 0x0000000000000000]);
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_QWORDS as BLOCK_LENGTH_QWORDS;
+use super::KEY_LENGTH_BYTES;
+
+/// Create a new state with the given key.
+#[must_use]
+pub const fn new(key: [u8; KEY_LENGTH_BYTES]) -> State {
+    State::new(key)
+}
+
+/// A low-level hash state.
+///
+/// Field elements are represented as pairs of little-endian words, the multiplication does not depend on secret data in its timing.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct State {
+    key: [u64; 2],
+    accumulator: [u64; 2],
+}
+
+impl State {
+    /// Creates a new state with the given key.
+    #[must_use]
+    pub const fn new(key: [u8; KEY_LENGTH_BYTES]) -> Self {
+        let key = u128::from_le_bytes(key);
+        #[allow(clippy::cast_possible_truncation)]
+        let key = [key as u64, (key >> 64) as u64];
+        Self::from_raw(key, [0; 2])
+    }
+
+    /// Creates a state with the given key from the raw accumulator, e.g. to resume a computation.
+    #[must_use]
+    pub const fn from_raw(key: [u64; 2], accumulator: [u64; 2]) -> Self {
+        Self { key, accumulator }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// **Warning!** Input block must be in the little-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u64; BLOCK_LENGTH_QWORDS]) -> Self {
+        let accumulator = [self.accumulator[0] ^ block[0], self.accumulator[1] ^ block[1]];
+        let accumulator = multiply(accumulator, self.key);
+        Self::from_raw(self.key, accumulator)
+    }
+
+    /// Returns a new state with initial values.
+    ///
+    /// The key is kept.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::from_raw(self.key, [0; 2])
+    }
+
+    /// Returns a hash as little-endian words.
+    #[must_use]
+    pub const fn digest(&self) -> [u64; 2] {
+        self.accumulator
+    }
+}
+
+/// Multiplies two field elements and the inverse of `x^128`, as defined by RFC 8452.
+const fn multiply(x: [u64; 2], y: [u64; 2]) -> [u64; 2] {
+    let [x0, x1] = x;
+    let [y0, y1] = y;
+    let (x0r, x1r) = (x0.reverse_bits(), x1.reverse_bits());
+    let (y0r, y1r) = (y0.reverse_bits(), y1.reverse_bits());
+
+    // Karatsuba multiplication, the reversed operands provide the upper halves of the products.
+    let z0 = clmul(x0, y0);
+    let z1 = clmul(x1, y1);
+    let z2 = clmul(x0 ^ x1, y0 ^ y1) ^ z0 ^ z1;
+    let z0h = clmul(x0r, y0r).reverse_bits() >> 1;
+    let z1h = clmul(x1r, y1r).reverse_bits() >> 1;
+    let z2h = (clmul(x0r ^ x1r, y0r ^ y1r).reverse_bits() >> 1) ^ z0h ^ z1h;
+
+    let v0 = z0;
+    let mut v1 = z0h ^ z2;
+    let mut v2 = z1 ^ z2h;
+    let mut v3 = z1h;
+
+    // Montgomery reduction by the polynomial x^128 + x^127 + x^126 + x^121 + 1.
+    v2 ^= v0 ^ (v0 >> 1) ^ (v0 >> 2) ^ (v0 >> 7);
+    v1 ^= (v0 << 63) ^ (v0 << 62) ^ (v0 << 57);
+    v3 ^= v1 ^ (v1 >> 1) ^ (v1 >> 2) ^ (v1 >> 7);
+    v2 ^= (v1 << 63) ^ (v1 << 62) ^ (v1 << 57);
+
+    [v2, v3]
+}
+
+/// Returns the lower half of the carry-less product of two words.
+///
+/// Integer multiplications of operands with holes every fourth bit keep the carries away from the result bits.
+const fn clmul(x: u64, y: u64) -> u64 {
+    const MASKS: [u64; 4] = [
+        0x1111_1111_1111_1111,
+        0x2222_2222_2222_2222,
+        0x4444_4444_4444_4444,
+        0x8888_8888_8888_8888,
+    ];
+
+    let [x0, x1, x2, x3] = [x & MASKS[0], x & MASKS[1], x & MASKS[2], x & MASKS[3]];
+    let [y0, y1, y2, y3] = [y & MASKS[0], y & MASKS[1], y & MASKS[2], y & MASKS[3]];
+    let z0 = x0.wrapping_mul(y0) ^ x1.wrapping_mul(y3) ^ x2.wrapping_mul(y2) ^ x3.wrapping_mul(y1);
+    let z1 = x0.wrapping_mul(y1) ^ x1.wrapping_mul(y0) ^ x2.wrapping_mul(y3) ^ x3.wrapping_mul(y2);
+    let z2 = x0.wrapping_mul(y2) ^ x1.wrapping_mul(y1) ^ x2.wrapping_mul(y0) ^ x3.wrapping_mul(y3);
+    let z3 = x0.wrapping_mul(y3) ^ x1.wrapping_mul(y2) ^ x2.wrapping_mul(y1) ^ x3.wrapping_mul(y0);
+    (z0 & MASKS[0]) | (z1 & MASKS[1]) | (z2 & MASKS[2]) | (z3 & MASKS[3])
+}
@@ -0,0 +1,113 @@
+//! This module is optional and can be enabled using the `salt` Cargo feature.
+//!
+//! An implementation of salted hashing.
+//!
+//! The salt is prepended to the data, i.e. the digest is the digest of the salt followed by the data. The helpers are generic over the hash functions of this crate, i.e. any type implementing the `Update` trait of the core crate, so all callers of this crate follow the same convention.
+//!
+//! # Batch Processing
+//!
+//! The salted digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::{salt, sha2_256};
+//!
+//! let digest = salt::hash::<sha2_256::Update>("salt", "example data");
+//! assert_eq!(digest, sha2_256::hash("saltexample data"));
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The salted digest of data streams can be calculated chunk-by-chunk with a hash created by calling the [`new`] function.
+//!
+//! ```rust
+//! use chksum_hash::{salt, sha2_256};
+//!
+//! let digest = salt::new::<sha2_256::Update>("salt")
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(digest, sha2_256::hash("saltexample data"));
+//! ```
+//!
+//! # Salt Generation
+//!
+//! Random salts can be generated with the `generate` function, which is enabled by the `salt-random` Cargo feature and uses the random number generator of the operating system.
+//!
+//! # Disclaimer
+//!
+//! A salted digest of a password can be computed quickly, which makes it cheap to attack. Prefer memory-hard functions, like [`scrypt`](crate::scrypt), for password storage.
+
+use chksum_hash_core as core;
+
+/// Recommended salt length in bytes.
+pub const SALT_LENGTH_BYTES: usize = 16;
+
+/// Creates a new hash with the given salt already processed.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{md5, salt};
+///
+/// let mut hash = salt::new::<md5::Update>([0x01, 0x02, 0x03, 0x04]);
+/// hash.update("data");
+/// assert_eq!(
+///     hash.digest().to_hex_lowercase(),
+///     "5ec8da66080fcda23d7b88facbfbb76a"
+/// );
+/// ```
+#[must_use]
+pub fn new<H>(salt: impl AsRef<[u8]>) -> H
+where
+    H: core::Update,
+{
+    let mut hash = H::default();
+    hash.update(salt);
+    hash
+}
+
+/// Computes the digest of the salt followed by the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{salt, sha1};
+///
+/// let digest = salt::hash::<sha1::Update>("salt", "data");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "60c2cbc880eb103a3bb8599b43769eb2d917e657"
+/// );
+/// ```
+#[must_use]
+pub fn hash<H>(salt: impl AsRef<[u8]>, data: impl AsRef<[u8]>) -> H::Digest
+where
+    H: core::Update,
+{
+    let mut hash = new::<H>(salt);
+    hash.update(data);
+    hash.digest()
+}
+
+/// Generates a random salt of the given length in bytes with the random number generator of the operating system.
+///
+/// # Errors
+///
+/// Returns an error if the random number generator is unavailable.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{salt, sha2_256};
+///
+/// let salt = salt::generate::<{ salt::SALT_LENGTH_BYTES }>().unwrap();
+/// let digest = salt::hash::<sha2_256::Update>(salt, "data");
+/// assert_ne!(digest, sha2_256::hash("data"));
+/// ```
+#[cfg(feature = "salt-random")]
+pub fn generate<const N: usize>() -> Result<[u8; N], getrandom::Error> {
+    let mut salt = [0u8; N];
+    getrandom::getrandom(&mut salt)?;
+    Ok(salt)
+}
@@ -0,0 +1,278 @@
+//! This module is optional and can be enabled using the `scrypt` Cargo feature.
+//!
+//! An implementation of the scrypt password-based key derivation function.
+//!
+//! scrypt, defined in [RFC 7914](https://www.rfc-editor.org/rfc/rfc7914), is a memory-hard key derivation function built on [`pbkdf2`](crate::pbkdf2) with HMAC-SHA-256 and the Salsa20/8 core. Its cost is configured with [`Params`].
+//!
+//! # Key Derivation
+//!
+//! The key of the given length can be derived with the [`derive`] function.
+//!
+//! ```rust
+//! use chksum_hash::scrypt;
+//!
+//! let params = scrypt::Params::new(10);
+//! let key = scrypt::derive("password", "salt", params, 32);
+//! let key = key
+//!     .iter()
+//!     .map(|byte| format!("{byte:02x}"))
+//!     .collect::<String>();
+//! assert_eq!(
+//!     key,
+//!     "16dbc8906763c7f048977a68f9d305f7710e068ca2cd95dab372125bb3f19608"
+//! );
+//! ```
+//!
+//! # Memory Budget
+//!
+//! The parallel lanes are processed one after another, so only a single table of [`Params::memory`] bytes is allocated regardless of the parallelization parameter. The lanes themselves take `128 * r * p` bytes. Parameters coming from untrusted sources, like stored password hashes, can be checked against a memory budget with the [`derive_with_budget`] function.
+//!
+//! ```rust
+//! use chksum_hash::scrypt;
+//!
+//! // N = 2, r = 1 and p = 2^30 - 1 need a small table, but 128 GiB of lanes
+//! let params = scrypt::Params::new(1)
+//!     .with_block_size(1)
+//!     .with_parallelization((1 << 30) - 1);
+//! let key = scrypt::derive_with_budget("password", "salt", params, 32, 64 * 1024 * 1024);
+//! assert_eq!(key, None);
+//! ```
+//!
+//! ```rust
+//! use chksum_hash::scrypt;
+//!
+//! // N = 2^20 and r = 8 need 1 GiB
+//! let params = scrypt::Params::new(20);
+//! let key = scrypt::derive_with_budget("password", "salt", params, 32, 64 * 1024 * 1024);
+//! assert_eq!(key, None);
+//! ```
+
+pub mod params;
+
+#[doc(inline)]
+pub use self::params::Params;
+use crate::{pbkdf2, sha2_256};
+
+/// Derives the key of the given length from the password and the salt.
+///
+/// # Panics
+///
+/// Panics if the memory of the parameters cannot be allocated, use [`derive_with_budget`] for parameters read from untrusted input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::scrypt;
+///
+/// let params = scrypt::Params::new(4).with_block_size(1);
+/// let key = scrypt::derive("", "", params, 64);
+/// let key = key
+///     .iter()
+///     .map(|byte| format!("{byte:02x}"))
+///     .collect::<String>();
+/// assert_eq!(
+///     key,
+///     "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+/// );
+/// ```
+#[must_use]
+pub fn derive(password: impl AsRef<[u8]>, salt: impl AsRef<[u8]>, params: Params, length: usize) -> Vec<u8> {
+    let password = password.as_ref();
+    let block_size = params.block_size() as usize;
+    let lane_length = 128 * block_size;
+    let cost = usize::try_from(params.cost()).expect("cost should fit in the address space");
+
+    let mut lanes =
+        pbkdf2::derive::<sha2_256::Update>(password, salt, 1, lane_length * params.parallelization() as usize);
+
+    // The table is shared by the lanes, which are mixed one after another.
+    let table_length = cost
+        .checked_mul(32 * block_size)
+        .expect("memory should fit in the address space");
+    let mut table = vec![0u32; table_length];
+    let mut words = vec![0u32; 32 * block_size];
+    let mut buffer = vec![0u32; 32 * block_size];
+    for lane in lanes.chunks_exact_mut(lane_length) {
+        for (word, bytes) in words.iter_mut().zip(lane.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        mix(&mut words, &mut table, &mut buffer, cost);
+        for (word, bytes) in words.iter().zip(lane.chunks_exact_mut(4)) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    pbkdf2::derive::<sha2_256::Update>(password, lanes, 1, length)
+}
+
+/// Derives the key like [`derive`] if the memory it allocates does not exceed the given number of bytes.
+///
+/// The memory consists of the table of [`Params::memory`] bytes, the lanes of `128 * r * p` bytes, two blocks of `128 * r` bytes for the mixing and the key. Returns `None` without allocating any of them otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::scrypt;
+///
+/// let params = scrypt::Params::new(10);
+/// assert_eq!(params.memory(), 1024 * 1024);
+///
+/// // The table, one lane, two blocks and the key
+/// let memory = 1024 * 1024 + 1024 + 2 * 1024 + 32;
+/// let key = scrypt::derive_with_budget("password", "salt", params, 32, memory);
+/// assert!(key.is_some());
+///
+/// let key = scrypt::derive_with_budget("password", "salt", params, 32, memory - 1);
+/// assert!(key.is_none());
+/// ```
+#[must_use]
+pub fn derive_with_budget(
+    password: impl AsRef<[u8]>,
+    salt: impl AsRef<[u8]>,
+    params: Params,
+    length: usize,
+    budget: u64,
+) -> Option<Vec<u8>> {
+    let block = 128 * u64::from(params.block_size());
+    let lanes = block * u64::from(params.parallelization());
+    let memory = params
+        .memory()
+        .saturating_add(lanes)
+        .saturating_add(2 * block)
+        .saturating_add(length as u64);
+    if memory > budget {
+        return None;
+    }
+    Some(derive(password, salt, params, length))
+}
+
+/// Applies the memory-hard mixing function `scryptROMix` to a lane.
+fn mix(words: &mut [u32], table: &mut [u32], buffer: &mut [u32], cost: usize) {
+    let length = words.len();
+
+    for row in table.chunks_exact_mut(length) {
+        row.copy_from_slice(words);
+        mix_blocks(words, buffer);
+    }
+
+    for _ in 0..cost {
+        // The first word of the last 64-byte block selects the row, the cost is a power of two.
+        let last = length - 16;
+        let index = u64::from(words[last]) | u64::from(words[last + 1]) << 32;
+        #[allow(clippy::cast_possible_truncation)]
+        let index = (index & (cost as u64 - 1)) as usize;
+        let row = &table[index * length..(index + 1) * length];
+        for (word, xor) in words.iter_mut().zip(row) {
+            *word ^= xor;
+        }
+        mix_blocks(words, buffer);
+    }
+}
+
+/// Applies the block mixing function `scryptBlockMix` to a lane.
+fn mix_blocks(words: &mut [u32], buffer: &mut [u32]) {
+    let blocks = words.len() / 16;
+
+    let mut block = [0u32; 16];
+    block.copy_from_slice(&words[words.len() - 16..]);
+    for i in 0..blocks {
+        for (word, xor) in block.iter_mut().zip(&words[i * 16..(i + 1) * 16]) {
+            *word ^= xor;
+        }
+        block = salsa20_8(block);
+        // Even blocks go to the first half of the output, odd blocks to the second one.
+        let position = (i / 2 + (i % 2) * (blocks / 2)) * 16;
+        buffer[position..position + 16].copy_from_slice(&block);
+    }
+    words.copy_from_slice(buffer);
+}
+
+/// Applies the Salsa20/8 core to a block.
+fn salsa20_8(input: [u32; 16]) -> [u32; 16] {
+    fn quarter(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+        x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+        x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+        x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+    }
+
+    let mut x = input;
+    for _ in 0..4 {
+        // Column round
+        quarter(&mut x, 0, 4, 8, 12);
+        quarter(&mut x, 5, 9, 13, 1);
+        quarter(&mut x, 10, 14, 2, 6);
+        quarter(&mut x, 15, 3, 7, 11);
+        // Row round
+        quarter(&mut x, 0, 1, 2, 3);
+        quarter(&mut x, 5, 6, 7, 4);
+        quarter(&mut x, 10, 11, 8, 9);
+        quarter(&mut x, 15, 12, 13, 14);
+    }
+
+    for (word, input) in x.iter_mut().zip(input) {
+        *word = word.wrapping_add(input);
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from RFC 7914, section 12.
+    #[test]
+    fn rfc_7914() {
+        for (password, salt, params, expected) in [
+            (
+                "",
+                "",
+                Params::new(4).with_block_size(1).with_parallelization(1),
+                "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906",
+            ),
+            (
+                "password",
+                "NaCl",
+                Params::new(10).with_block_size(8).with_parallelization(16),
+                "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640",
+            ),
+        ] {
+            let key = derive(password, salt, params, 64);
+            assert_eq!(key.iter().map(|byte| format!("{byte:02x}")).collect::<String>(), expected);
+        }
+    }
+
+    #[test]
+    fn budget() {
+        // The lanes of the maximum parallelization exceed any reasonable budget, even with a small table
+        let params = Params::new(1).with_block_size(1).with_parallelization((1 << 30) - 1);
+        assert_eq!(derive_with_budget("password", "salt", params, 32, 1 << 30), None);
+
+        // The key counts as well
+        let params = Params::new(4).with_block_size(1);
+        let memory = 16 * 128 + 128 + 2 * 128;
+        assert!(derive_with_budget("", "", params, 64, memory + 64).is_some());
+        assert_eq!(derive_with_budget("", "", params, 64, memory + 63), None);
+    }
+
+    // RFC 7914 requires N < 2^(128 * r / 8).
+    #[test]
+    fn cost_limit() {
+        assert_eq!(Params::new(15).with_block_size(1).cost(), 1 << 15);
+        assert_eq!(Params::new(31).with_block_size(2).cost(), 1 << 31);
+        assert_eq!(Params::new(63).with_block_size(4).cost(), 1 << 63);
+    }
+
+    #[test]
+    #[should_panic(expected = "cost must be less than 2^(16 * r)")]
+    fn cost_limit_exceeded() {
+        let _ = Params::new(16).with_block_size(1);
+    }
+
+    #[test]
+    fn try_cost_limit() {
+        assert!(Params::new(15).try_with_block_size(1).is_ok());
+        assert!(Params::new(16).try_with_block_size(1).is_err());
+        assert!(Params::new(32).try_with_block_size(2).is_err());
+    }
+}
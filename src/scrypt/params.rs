@@ -0,0 +1,189 @@
+//! Module containing items related to the scrypt parameters.
+
+use crate::error::ParameterError;
+
+/// Default base-2 logarithm of the cost parameter.
+pub const DEFAULT_LOG_N: u8 = 17;
+/// Default block size parameter.
+pub const DEFAULT_BLOCK_SIZE: u32 = 8;
+/// Default parallelization parameter.
+pub const DEFAULT_PARALLELIZATION: u32 = 1;
+
+/// Parameters of the scrypt key derivation function.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::scrypt;
+///
+/// // N = 1024, r = 8, p = 16
+/// let params = scrypt::Params::new(10).with_parallelization(16);
+/// assert_eq!(params.cost(), 1024);
+/// assert_eq!(params.block_size(), 8);
+/// assert_eq!(params.parallelization(), 16);
+///
+/// // The lanes are processed one after another, so only one of them is held in memory
+/// assert_eq!(params.memory(), 1024 * 1024);
+///
+/// // N = 2^17, r = 8 and p = 1 are the defaults
+/// let params = scrypt::Params::default();
+/// assert_eq!(
+///     params,
+///     scrypt::Params::new(17)
+///         .with_block_size(8)
+///         .with_parallelization(1)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    log_n: u8,
+    block_size: u32,
+    parallelization: u32,
+}
+
+impl Params {
+    /// Creates parameters for the cost `N = 2^log_n` with the default block size and parallelization.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the logarithm of the cost is not between 1 and 63.
+    #[must_use]
+    pub const fn new(log_n: u8) -> Self {
+        assert!(
+            log_n >= 1 && log_n <= 63,
+            "logarithm of the cost must be between 1 and 63"
+        );
+        Self {
+            log_n,
+            block_size: DEFAULT_BLOCK_SIZE,
+            parallelization: DEFAULT_PARALLELIZATION,
+        }
+    }
+
+    /// Creates parameters for the cost `N = 2^log_n` with the default block size and parallelization, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the logarithm of the cost is not between 1 and 63.
+    pub const fn try_new(log_n: u8) -> Result<Self, ParameterError> {
+        if log_n >= 1 && log_n <= 63 {
+            Ok(Self::new(log_n))
+        } else {
+            Err(ParameterError {
+                parameter: "logarithm of the cost",
+            })
+        }
+    }
+
+    /// Sets the block size parameter `r`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block size is zero, the cost is not less than `2^(16 * r)` or the product of the block size and the parallelization is not less than 2^30.
+    #[must_use]
+    pub const fn with_block_size(self, block_size: u32) -> Self {
+        assert!(block_size > 0, "block size must be greater than zero");
+        assert!(
+            (self.log_n as u64) < 16 * (block_size as u64),
+            "cost must be less than 2^(16 * r)"
+        );
+        assert!(
+            (block_size as u64) * (self.parallelization as u64) < 1 << 30,
+            "product of block size and parallelization must be less than 2^30"
+        );
+        Self { block_size, ..self }
+    }
+
+    /// Sets the block size parameter `r`, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the block size is zero, the cost is not less than `2^(16 * r)` or the product of the block size and the parallelization is not less than 2^30.
+    pub const fn try_with_block_size(self, block_size: u32) -> Result<Self, ParameterError> {
+        if block_size > 0
+            && (self.log_n as u64) < 16 * (block_size as u64)
+            && (block_size as u64) * (self.parallelization as u64) < 1 << 30
+        {
+            Ok(self.with_block_size(block_size))
+        } else {
+            Err(ParameterError {
+                parameter: "block size",
+            })
+        }
+    }
+
+    /// Sets the parallelization parameter `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parallelization is zero or the product of the block size and the parallelization is not less than 2^30.
+    #[must_use]
+    pub const fn with_parallelization(self, parallelization: u32) -> Self {
+        assert!(parallelization > 0, "parallelization must be greater than zero");
+        assert!(
+            (self.block_size as u64) * (parallelization as u64) < 1 << 30,
+            "product of block size and parallelization must be less than 2^30"
+        );
+        Self {
+            parallelization,
+            ..self
+        }
+    }
+
+    /// Sets the parallelization parameter `p`, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parallelization is zero or the product of the block size and the parallelization is not less than 2^30.
+    pub const fn try_with_parallelization(self, parallelization: u32) -> Result<Self, ParameterError> {
+        if parallelization > 0 && (self.block_size as u64) * (parallelization as u64) < 1 << 30 {
+            Ok(self.with_parallelization(parallelization))
+        } else {
+            Err(ParameterError {
+                parameter: "parallelization",
+            })
+        }
+    }
+
+    /// Returns the base-2 logarithm of the cost parameter.
+    #[must_use]
+    pub const fn log_n(&self) -> u8 {
+        self.log_n
+    }
+
+    /// Returns the cost parameter `N`.
+    #[must_use]
+    pub const fn cost(&self) -> u64 {
+        1 << self.log_n
+    }
+
+    /// Returns the block size parameter `r`.
+    #[must_use]
+    pub const fn block_size(&self) -> u32 {
+        self.block_size
+    }
+
+    /// Returns the parallelization parameter `p`.
+    #[must_use]
+    pub const fn parallelization(&self) -> u32 {
+        self.parallelization
+    }
+
+    /// Returns the number of bytes of the memory-hard table, i.e. `128 * r * N`.
+    ///
+    /// It saturates at [`u64::MAX`]. The key derivation allocates the lanes of `128 * r * p` bytes in addition, check [`derive_with_budget`](super::derive_with_budget).
+    #[must_use]
+    pub const fn memory(&self) -> u64 {
+        let lane = 128 * self.block_size as u64;
+        match lane.checked_mul(self.cost()) {
+            Some(memory) => memory,
+            None => u64::MAX,
+        }
+    }
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::new(DEFAULT_LOG_N)
+    }
+}
@@ -0,0 +1,133 @@
+//! Items shared by the serde implementations of digests and hash states.
+//!
+//! Human-readable formats use the lowercase hexadecimal representation. Binary formats use a tuple of bytes for fixed-length digests, which has no length prefix, and a byte sequence for variable-length digests.
+
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserializer, Serializer};
+
+use crate::hex::{self, Case};
+
+/// Serializes the digest, in binary formats as a tuple of bytes if the digest has a fixed length.
+pub(crate) fn serialize<S>(digest: &[u8], fixed_length: bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        return serializer.collect_str(&Hex(digest));
+    }
+    if !fixed_length {
+        return serializer.serialize_bytes(digest);
+    }
+    let mut tuple = serializer.serialize_tuple(digest.len())?;
+    for byte in digest {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+/// Deserializes the digest with the length in the given range, in binary formats from a tuple of bytes if the range is a single length.
+pub(crate) fn deserialize<'de, D, T>(deserializer: D, lengths: RangeInclusive<usize>) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<Vec<u8>>,
+{
+    let digest = deserialize_bytes(deserializer, "digest", lengths)?;
+    // The visitor has checked the length.
+    T::try_from(digest).map_err(|_| D::Error::custom("invalid digest length"))
+}
+
+/// Deserializes the bytes with the length in the given range, in binary formats from a tuple of bytes if the range is a single length.
+pub(crate) fn deserialize_bytes<'de, D>(
+    deserializer: D,
+    name: &'static str,
+    lengths: RangeInclusive<usize>,
+) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let fixed_length = lengths.start() == lengths.end();
+    let length = *lengths.end();
+    let visitor = BytesVisitor { name, lengths };
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else if fixed_length {
+        deserializer.deserialize_tuple(length, visitor)
+    } else {
+        deserializer.deserialize_bytes(visitor)
+    }
+}
+
+/// Lowercase hexadecimal representation of the bytes.
+struct Hex<'a>(&'a [u8]);
+
+impl Display for Hex<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        hex::write(f, self.0, Case::Lower)
+    }
+}
+
+/// A visitor of bytes with the length in the given range, accepting the hexadecimal representation, bytes and sequences of bytes.
+struct BytesVisitor {
+    name: &'static str,
+    lengths: RangeInclusive<usize>,
+}
+
+impl BytesVisitor {
+    /// Checks the length of the bytes.
+    fn check<E>(&self, digest: Vec<u8>) -> Result<Vec<u8>, E>
+    where
+        E: Error,
+    {
+        if self.lengths.contains(&digest.len()) {
+            Ok(digest)
+        } else {
+            Err(E::invalid_length(digest.len(), self))
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (min, max) = (self.lengths.start(), self.lengths.end());
+        if min == max {
+            write!(f, "a {} of {max} bytes", self.name)
+        } else {
+            write!(f, "a {} of {min} to {max} bytes", self.name)
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let digest = hex::decode(v).map_err(E::custom)?;
+        self.check(digest)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.check(v.to_vec())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut digest = Vec::with_capacity(*self.lengths.end());
+        while let Some(byte) = seq.next_element()? {
+            if digest.len() == *self.lengths.end() {
+                return Err(A::Error::invalid_length(digest.len() + 1, &self));
+            }
+            digest.push(byte);
+        }
+        self.check(digest)
+    }
+}
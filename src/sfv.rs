@@ -0,0 +1,215 @@
+//! This module is optional and can be enabled using the `sfv` Cargo feature.
+//!
+//! An implementation of the Simple File Verification (SFV) files, used to verify releases split into many files.
+//!
+//! Every line of an SFV file is either a [`Line::Comment`] starting with a semicolon or an [`Entry`] with the path of the file and its [`crc32`](crate::crc32) checksum in the uppercase hexadecimal representation separated by a space, e.g. `file.r00 CBF43926`. Tools like cksfv write the sizes and the modification timestamps of the files into the comments, which are preserved verbatim. Lines ending with `\r\n` are accepted when parsing.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::sfv::{self, Entry, Line};
+//!
+//! let lines = [
+//!     Line::Comment(" Generated by chksum-hash".to_owned()),
+//!     Line::Entry(Entry::hash("check.txt", "123456789")),
+//! ];
+//! let contents = sfv::format(&lines);
+//! assert_eq!(contents, "; Generated by chksum-hash\ncheck.txt CBF43926\n");
+//!
+//! for line in sfv::parse(&contents) {
+//!     if let Line::Entry(entry) = line.unwrap() {
+//!         assert!(entry.verify("123456789"));
+//!     }
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::str::FromStr;
+
+use crate::crc32;
+
+/// Returns the lines, every line terminated with a line feed.
+#[must_use]
+pub fn format<'a>(lines: impl IntoIterator<Item = &'a Line>) -> String {
+    lines.into_iter().map(|line| format!("{line}\n")).collect()
+}
+
+/// Parses the lines of the SFV file lazily, skipping empty lines.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sfv::{self, Line, ParseError};
+///
+/// let contents = ";   9  12:00.00 2024-01-31 check.txt\r\ncheck.txt cbf43926\r\n\r\ncheck.txt\r\n";
+/// let lines = sfv::parse(contents).collect::<Vec<_>>();
+/// assert_eq!(lines.len(), 3);
+/// assert_eq!(
+///     lines[0],
+///     Ok(Line::Comment("   9  12:00.00 2024-01-31 check.txt".to_owned()))
+/// );
+/// assert!(matches!(&lines[1], Ok(Line::Entry(entry)) if entry.path() == "check.txt"));
+/// assert_eq!(lines[2], Err(ParseError::MissingSeparator));
+/// ```
+pub fn parse(contents: &str) -> impl Iterator<Item = Result<Line, ParseError>> + '_ {
+    contents
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .map(str::parse)
+}
+
+/// A line of an SFV file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Line {
+    /// A comment, the text after the semicolon.
+    Comment(String),
+    /// A checksum of a file.
+    Entry(Entry),
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Comment(comment) => write!(f, ";{comment}"),
+            Self::Entry(entry) => Display::fmt(entry, f),
+        }
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix(';') {
+            Some(comment) => Ok(Self::Comment(comment.to_owned())),
+            None => s.parse().map(Self::Entry),
+        }
+    }
+}
+
+impl From<Entry> for Line {
+    fn from(entry: Entry) -> Self {
+        Self::Entry(entry)
+    }
+}
+
+/// A checksum of the file at the given path.
+///
+/// The [`Display`] and [`FromStr`] implementations use a single line without the line terminator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    path: String,
+    checksum: crc32::Digest,
+}
+
+impl Entry {
+    /// Creates a new entry of the checksum of the file at the given path.
+    #[must_use]
+    pub fn new(path: impl Into<String>, checksum: crc32::Digest) -> Self {
+        let path = path.into();
+        Self { path, checksum }
+    }
+
+    /// Computes the checksum of the given input and creates a new entry of the file at the given path.
+    #[must_use]
+    pub fn hash(path: impl Into<String>, data: impl AsRef<[u8]>) -> Self {
+        Self::new(path, crc32::hash(data))
+    }
+
+    /// Returns the path of the file.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the checksum.
+    #[must_use]
+    pub const fn checksum(&self) -> crc32::Digest {
+        self.checksum
+    }
+
+    /// Returns `true` if the checksum of the entry is the checksum of the given input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::sfv::Entry;
+    ///
+    /// let entry = "check.txt CBF43926".parse::<Entry>().unwrap();
+    /// assert!(entry.verify("123456789"));
+    /// assert!(!entry.verify("12345678"));
+    /// ```
+    #[must_use]
+    pub fn verify(&self, data: impl AsRef<[u8]>) -> bool {
+        crc32::hash(data) == self.checksum
+    }
+
+    /// Returns `true` if the checksum of the entry is the checksum of the data read from the reader until the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::sfv::Entry;
+    ///
+    /// let entry = "check.txt CBF43926".parse::<Entry>().unwrap();
+    /// assert!(entry.verify_reader(&b"123456789"[..]).unwrap());
+    /// ```
+    pub fn verify_reader(&self, reader: impl Read) -> io::Result<bool> {
+        Ok(crate::io::hash_reader::<crc32::Update>(reader)? == self.checksum)
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:X}", self.path, self.checksum)
+    }
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The path may contain spaces, so the checksum follows the last one.
+        let (path, encoded) = s.rsplit_once(' ').ok_or(ParseError::MissingSeparator)?;
+        if !encoded.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+            return Err(ParseError::InvalidChecksum);
+        }
+        let checksum = crc32::Digest::from_hex(encoded).map_err(|_| ParseError::InvalidChecksum)?;
+        // Some tools align the checksums with additional spaces.
+        let path = path.trim_end_matches(' ');
+        if path.is_empty() {
+            return Err(ParseError::EmptyPath);
+        }
+        Ok(Self::new(path, checksum))
+    }
+}
+
+/// An error of parsing a line of an SFV file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// No space between the path and the checksum.
+    MissingSeparator,
+    /// A checksum that is not eight hexadecimal digits.
+    InvalidChecksum,
+    /// No path before the checksum.
+    EmptyPath,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSeparator => f.write_str("missing separator between path and checksum"),
+            Self::InvalidChecksum => f.write_str("invalid checksum"),
+            Self::EmptyPath => f.write_str("empty path"),
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,503 @@
+//! This module is optional and can be enabled using the `sha0` Cargo feature.
+//!
+//! An implementation of the SHA-0 hash function.
+//!
+//! SHA-0 is the original 1993 version of the Secure Hash Standard (FIPS 180), withdrawn shortly after publication and replaced by SHA-1. The only difference between the two functions is the missing one-bit rotation in the SHA-0 message schedule.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::sha0;
+//!
+//! let digest = sha0::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "bcb013db91ec6a74ea58eaf6c129201b8fa0e176"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::sha0;
+//!
+//! let digest = sha0::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "bcb013db91ec6a74ea58eaf6c129201b8fa0e176"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized due to the unknown size of data chunks.
+//!
+//! The size of this buffer is at least as large as one hash block of data processed at a time.
+//!
+//! To mitigate buffering, and consequently reduce memory overhead, data chunks should be aligned to multiples of the block size, or processed in block-sized chunks.
+//!
+//! The [`Update::is_block_aligned`] and [`Update::buffered_len`] functions report whether data is buffered, and [`Update::processed_bytes`] returns the number of bytes passed to the hash so far.
+//!
+//! # Disclaimer
+//!
+//! The SHA-0 hash function is broken: full collisions were published in 2004, and they can now be found in about an hour on commodity hardware. It must not be used for any security purpose. This module exists only to reproduce digests produced by legacy tooling, e.g. during forensic analysis. Check [RFC 6194: Security Considerations for the SHA-0 and SHA-1 Message-Digest Algorithms](https://www.rfc-editor.org/rfc/rfc6194) for more details.
+
+pub mod block;
+pub mod digest;
+pub mod state;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::block::{Block, LENGTH_BYTES as BLOCK_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+use crate::buffer::Buffer;
+use crate::error::{MessageLengthError, StateError};
+use crate::export::{Reader, Writer};
+use crate::verify::Expected;
+
+/// Maximum message length in bytes, so that the length in bits fits into 64 bits.
+///
+/// The [`Update::update`] function does not check it and wraps the length of longer messages, the [`Update::try_update`] function rejects them.
+pub const MAX_MESSAGE_LENGTH_BYTES: u64 = u64::MAX / 8;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha0;
+///
+/// let digest = sha0::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "f96cea198ad1dd5617ac084a3d92c6107708c0ef"
+/// );
+///
+/// let digest = sha0::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "1892a77821a7d3e71b6aa6cee840f3e6634a6d2c"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha0;
+///
+/// let digest = sha0::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "f96cea198ad1dd5617ac084a3d92c6107708c0ef"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha0;
+///
+/// let digest = sha0::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "0164b8a914cd2a5e74c4f7ff082c4d97f1edf880"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha0;
+///
+/// let digest = sha0::hash("data");
+/// assert!(sha0::verify("data", digest));
+/// assert!(sha0::verify("data", digest.to_hex_uppercase()));
+/// assert!(!sha0::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha0;
+///
+/// // Create a new hash instance
+/// let mut hash = sha0::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "1892a77821a7d3e71b6aa6cee840f3e6634a6d2c"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "f96cea198ad1dd5617ac084a3d92c6107708c0ef"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    state: State,
+    unprocessed: Buffer<BLOCK_LENGTH_BYTES>,
+    processed: u64,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        let state = state::new();
+        let unprocessed = Buffer::new();
+        let processed = 0;
+        Self {
+            state,
+            unprocessed,
+            processed,
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+
+        if !self.unprocessed.is_empty() {
+            let missing = BLOCK_LENGTH_BYTES - self.unprocessed.len();
+            if data.len() < missing {
+                // Not enough data even for one block.
+                self.unprocessed.extend_from_slice(data);
+                return self;
+            }
+
+            // Complete the buffered block with the beginning of incoming data.
+            let (fillment, remainder) = data.split_at(missing);
+            self.unprocessed.extend_from_slice(fillment);
+            let block = Block::try_from(&self.unprocessed[..])
+                .expect("unprocessed data length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u64);
+            self.unprocessed.clear();
+            data = remainder;
+        }
+
+        // Internal buffer is empty, incoming data can be processed without buffering.
+        let mut chunks = data.chunks_exact(BLOCK_LENGTH_BYTES);
+        for chunk in chunks.by_ref() {
+            let block = Block::try_from(chunk)
+                .expect("chunk length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u64);
+        }
+        self.unprocessed.extend_from_slice(chunks.remainder());
+
+        self
+    }
+
+    /// Updates the internal state with an input data, unless the message would exceed [`MAX_MESSAGE_LENGTH_BYTES`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the state unchanged if the total length of the message would exceed the maximum.
+    pub fn try_update<T>(&mut self, data: T) -> Result<&mut Self, MessageLengthError>
+    where
+        T: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+        match self.processed_bytes().checked_add(data.len() as u64) {
+            Some(length) if length <= MAX_MESSAGE_LENGTH_BYTES => Ok(self.update(data)),
+            _ => Err(MessageLengthError),
+        }
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        assert!(
+            self.unprocessed.len() < BLOCK_LENGTH_BYTES,
+            "unprocessed data length should be less than block length"
+        );
+
+        let length = {
+            let length = self.processed.wrapping_add(self.unprocessed.len() as u64);
+            let length = length.wrapping_mul(8); // convert byte-length into bits-length
+            length.to_be_bytes()
+        };
+
+        let mut padding = [0u8; BLOCK_LENGTH_BYTES * 2];
+        padding[..self.unprocessed.len()].copy_from_slice(&self.unprocessed);
+        padding[self.unprocessed.len()] = 0x80;
+        let padding = if (self.unprocessed.len() + 1 + length.len()) <= BLOCK_LENGTH_BYTES {
+            &mut padding[..BLOCK_LENGTH_BYTES]
+        } else {
+            &mut padding[..]
+        };
+        let offset = padding.len() - length.len();
+        padding[offset..].copy_from_slice(&length);
+
+        let state = padding
+            .chunks_exact(BLOCK_LENGTH_BYTES)
+            .fold(self.state, |state, chunk| {
+                let block = Block::try_from(chunk)
+                    .expect("padding length must be multiple of block length")
+                    .into();
+                state.update(block)
+            });
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self.unprocessed.clear();
+        self.processed = 0;
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+
+    /// Returns the number of bytes passed to the hash so far, including the buffered ones.
+    #[must_use]
+    pub fn processed_bytes(&self) -> u64 {
+        self.processed.wrapping_add(self.unprocessed.len() as u64)
+    }
+
+    /// Returns the number of bytes buffered until a whole block is available.
+    ///
+    /// Updating with chunks that are multiples of the block length keeps the buffer empty.
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.unprocessed.len()
+    }
+
+    /// Returns `true` if no data is buffered, i.e. all bytes passed so far form whole blocks.
+    #[must_use]
+    pub fn is_block_aligned(&self) -> bool {
+        self.unprocessed.is_empty()
+    }
+
+    /// Exports the in-progress state in the versioned binary format described in the [crate documentation](crate#state-export).
+    #[must_use]
+    pub fn export_state(&self) -> Vec<u8> {
+        let State { a, b, c, d, e } = self.state;
+        Writer::new("sha0")
+            .u32s(&[a, b, c, d, e])
+            .finish(self.processed, &self.unprocessed)
+    }
+
+    /// Imports an in-progress state exported by [`export_state`](Self::export_state), e.g. in another process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid exported state of this hash function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::sha0;
+    ///
+    /// let state = sha0::default().update("example ").export_state();
+    /// let digest = sha0::Update::import_state(&state)
+    ///     .unwrap()
+    ///     .update("data")
+    ///     .digest();
+    /// assert_eq!(digest, sha0::hash("example data"));
+    /// ```
+    pub fn import_state(bytes: &[u8]) -> Result<Self, StateError> {
+        let mut reader = Reader::new(bytes, "sha0")?;
+        let [a, b, c, d, e] = reader.u32s()?;
+        let (processed, unprocessed) = reader.finish(BLOCK_LENGTH_BYTES)?;
+        Ok(Self {
+            state: State::from_raw(a, b, c, d, e),
+            unprocessed: Buffer::from_slice(unprocessed),
+            processed,
+        })
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let state = u.arbitrary()?;
+        // Full blocks are processed immediately, so less than a block is buffered.
+        let length = u.int_in_range(0..=BLOCK_LENGTH_BYTES - 1)?;
+        let unprocessed = Buffer::from_slice(u.bytes(length)?);
+        let processed = u.arbitrary::<u64>()?;
+        let processed = processed - processed % BLOCK_LENGTH_BYTES as u64;
+        Ok(Self {
+            state,
+            unprocessed,
+            processed,
+        })
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha0;
+///
+/// // Create a new hash instance
+/// let mut hash = sha0::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "0164b8a914cd2a5e74c4f7ff082c4d97f1edf880"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+        self.digest_into(&mut digest);
+        Digest::new(digest)
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        for (bytes, word) in out.chunks_exact_mut(4).zip(self.state.digest()) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+    }
+
+    /// Resets the hash state to the in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::new()
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors of the original FIPS 180 publication.
+    #[test]
+    fn fips_180() {
+        assert_eq!(hash("").to_hex_lowercase(), "f96cea198ad1dd5617ac084a3d92c6107708c0ef");
+        assert_eq!(
+            hash("abc").to_hex_lowercase(),
+            "0164b8a914cd2a5e74c4f7ff082c4d97f1edf880"
+        );
+        assert_eq!(
+            hash("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq").to_hex_lowercase(),
+            "d2516ee1acfa5baf33dfc1c471e438449ef134c8"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Module contains items related to the [`State`] structure which allows to the direct SHA-0 state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::sha0;
+//!
+//! // Create new state
+//! let mut state = sha0::state::default();
+//!
+//! // By default it returns initialization values
+//! assert_eq!(
+//!     state.digest(),
+//!     [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0]
+//! );
+//!
+//! // Create a block of data with proper padding
+//! let mut block = [0u8; sha0::BLOCK_LENGTH_BYTES];
+//! block[0] = 0x80;
+//! let data = sha0::Block::new(block).into();
+//!
+//! // Update state and own new value
+//! state = state.update(data);
+//!
+//! // Proper digest of empty input
+//! assert_eq!(
+//!     state.digest(),
+//!     [0xF96CEA19, 0x8AD1DD56, 0x17AC084A, 0x3D92C610, 0x7708C0EF]
+//! );
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(
+//!     state.digest(),
+//!     [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0]
+//! );
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_DWORDS as BLOCK_LENGTH_DWORDS;
+use super::digest::LENGTH_DWORDS as DIGEST_LENGTH_DWORDS;
+
+#[allow(clippy::unreadable_literal)]
+const A: u32 = 0x67452301;
+#[allow(clippy::unreadable_literal)]
+const B: u32 = 0xEFCDAB89;
+#[allow(clippy::unreadable_literal)]
+const C: u32 = 0x98BADCFE;
+#[allow(clippy::unreadable_literal)]
+const D: u32 = 0x10325476;
+#[allow(clippy::unreadable_literal)]
+const E: u32 = 0xC3D2E1F0;
+
+#[allow(clippy::unreadable_literal)]
+const K: [u32; 4] = [0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xCA62C1D6];
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// Returns the chaining values H0 to H4 after processing a single block.
+///
+/// **Warning!** Input block must be in the big-endian byte order.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha0;
+///
+/// let state = sha0::state::new().digest();
+/// let block = sha0::Block::new([0u8; sha0::BLOCK_LENGTH_BYTES]).into();
+/// assert_eq!(
+///     sha0::state::compress(state, block),
+///     sha0::state::new().update(block).digest()
+/// );
+/// ```
+#[must_use]
+pub const fn compress(
+    state: [u32; DIGEST_LENGTH_DWORDS],
+    block: [u32; BLOCK_LENGTH_DWORDS],
+) -> [u32; DIGEST_LENGTH_DWORDS] {
+    let [a, b, c, d, e] = state;
+    State::from_raw(a, b, c, d, e).update(block).digest()
+}
+
+/// A low-level hash state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) a: u32,
+    pub(crate) b: u32,
+    pub(crate) c: u32,
+    pub(crate) d: u32,
+    pub(crate) e: u32,
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(A, B, C, D, E)
+    }
+
+    /// Creates a state from the raw chaining values, e.g. a custom initialization vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::sha0;
+    ///
+    /// // The default initialization vector
+    /// let state = sha0::State::from_raw(0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0);
+    /// assert_eq!(state, sha0::State::new());
+    /// ```
+    #[must_use]
+    pub const fn from_raw(a: u32, b: u32, c: u32, d: u32, e: u32) -> Self {
+        Self { a, b, c, d, e }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// **Warning!** Input block must be in the big-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u32; BLOCK_LENGTH_DWORDS]) -> Self {
+        let Self { a, b, c, d, e } = *self;
+
+        // Unlike SHA-1, the message schedule of SHA-0 does not rotate the expanded words.
+        let mut w = [0u32; 80];
+        let mut i = 0;
+        while i < 80 {
+            w[i] = if i < BLOCK_LENGTH_DWORDS {
+                block[i]
+            } else {
+                w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]
+            };
+            i += 1;
+        }
+
+        let (mut a, mut b, mut c, mut d, mut e) = (a, b, c, d, e);
+        let mut i = 0;
+        while i < 80 {
+            let (f, k) = match i / 20 {
+                0 => ((b & c) | (!b & d), K[0]),
+                1 => (b ^ c ^ d, K[1]),
+                2 => ((b & c) | (b & d) | (c & d), K[2]),
+                _ => (b ^ c ^ d, K[3]),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(w[i]);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+            i += 1;
+        }
+
+        Self::from_raw(
+            self.a.wrapping_add(a),
+            self.b.wrapping_add(b),
+            self.c.wrapping_add(c),
+            self.d.wrapping_add(d),
+            self.e.wrapping_add(e),
+        )
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns a digest.
+    #[must_use]
+    pub const fn digest(&self) -> [u32; DIGEST_LENGTH_DWORDS] {
+        let Self { a, b, c, d, e } = *self;
+        [a, b, c, d, e]
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,294 @@
+//! This module is optional and can be enabled using the `sha-crypt` Cargo feature.
+//!
+//! An implementation of the SHA-crypt password hashing schemes, `sha256crypt` and `sha512crypt`.
+//!
+//! SHA-crypt, defined in the [Unix crypt using SHA-256 and SHA-512](https://www.akkadia.org/drepper/SHA-crypt.txt) specification, is the scheme behind the `$5$` and `$6$` password hashes of crypt(3) and `/etc/shadow`. It is built on [`sha2_256`](crate::sha2_256) and [`sha2_512`](crate::sha2_512), its cost is configured with [`Params`].
+//!
+//! # Encoding
+//!
+//! The password hash in the modular crypt format can be created with the [`encode`] function.
+//!
+//! ```rust
+//! use chksum_hash::sha_crypt::{self, Variant};
+//!
+//! let params = sha_crypt::Params::new(Variant::Sha512);
+//! let hash = sha_crypt::encode("Hello world!", "saltstring", params);
+//! assert_eq!(
+//!     hash,
+//!     "$6$saltstring$svn8UoSVapNtMuq1ukKS4tPQd8iKwSMHWjl/\
+//!      O817G3uBnIFNjnQJuesI68u4OTLiBFdcbYEdFCoEOfaS35inz1"
+//! );
+//! ```
+//!
+//! # Verification
+//!
+//! The password can be checked against an encoded hash, like an `/etc/shadow` entry, with the [`verify`] function. The variant, the number of rounds and the salt are read from the hash.
+//!
+//! ```rust
+//! use chksum_hash::sha_crypt;
+//!
+//! let hash = "$5$rounds=10000$saltstringsaltst$3xv.VbSHBb41AL9AvLeujZkZRBAwqFMz2.opqey6IcA";
+//! assert!(sha_crypt::verify("Hello world!", hash));
+//! assert!(!sha_crypt::verify("Hello world?", hash));
+//! ```
+//!
+//! # Disclaimer
+//!
+//! SHA-crypt is supported for compatibility with existing systems. Prefer memory-hard functions, like [`scrypt`](crate::scrypt), for new password storage.
+
+pub mod params;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::params::{Params, Variant};
+use crate::crypt::{self, encode_base64};
+use crate::error::ParameterError;
+use crate::verify::constant_time_eq;
+use crate::{sha2_256, sha2_512};
+
+/// Maximum length of the salt in bytes.
+pub const MAX_SALT_LENGTH: usize = 16;
+
+/// Order of the SHA-256 digest bytes in the encoded hash.
+const ORDER_SHA256: [usize; 32] = [
+    0, 10, 20, 21, 1, 11, 12, 22, 2, 3, 13, 23, 24, 4, 14, 15, 25, 5, 6, 16, 26, 27, 7, 17, 18, 28, 8, 9, 19, 29, 31,
+    30,
+];
+
+/// Order of the SHA-512 digest bytes in the encoded hash.
+const ORDER_SHA512: [usize; 64] = [
+    0, 21, 42, 22, 43, 1, 44, 2, 23, 3, 24, 45, 25, 46, 4, 47, 5, 26, 6, 27, 48, 28, 49, 7, 50, 8, 29, 9, 30, 51, 31,
+    52, 10, 53, 11, 32, 12, 33, 54, 34, 55, 13, 56, 14, 35, 15, 36, 57, 37, 58, 16, 59, 17, 38, 18, 39, 60, 40, 61, 19,
+    62, 20, 41, 63,
+];
+
+/// Encodes the password hash in the modular crypt format, i.e. `$<id>$[rounds=<rounds>$]<salt>$<checksum>`.
+///
+/// The salt is truncated to [`MAX_SALT_LENGTH`] bytes.
+///
+/// # Panics
+///
+/// Panics if the salt contains `$`, `:` or a newline, which are not allowed in the modular crypt format.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha_crypt::{self, Variant};
+///
+/// let params = sha_crypt::Params::new(Variant::Sha256).with_rounds(10);
+/// let hash = sha_crypt::encode(
+///     "the minimum number is still observed",
+///     "roundstoolow",
+///     params,
+/// );
+/// assert_eq!(
+///     hash,
+///     "$5$rounds=1000$roundstoolow$yfvwcWrQ8l/K0DAWyuPMDNHpIVlTQebY9l/gL972bIC"
+/// );
+/// ```
+#[must_use]
+pub fn encode(password: impl AsRef<[u8]>, salt: &str, params: Params) -> String {
+    let salt = crypt::truncate_salt(salt, MAX_SALT_LENGTH);
+    let password = password.as_ref();
+    let rounds = params.rounds();
+    let mut hash = format!("${}$", params.variant().id());
+    if params.has_explicit_rounds() {
+        hash.push_str(&format!("rounds={rounds}$"));
+    }
+    hash.push_str(salt);
+    hash.push('$');
+    match params.variant() {
+        Variant::Sha256 => {
+            let digest = compute::<sha2_256::Update>(password, salt.as_bytes(), rounds);
+            encode_base64(ORDER_SHA256.iter().map(|&index| digest[index]), &mut hash);
+        },
+        Variant::Sha512 => {
+            let digest = compute::<sha2_512::Update>(password, salt.as_bytes(), rounds);
+            encode_base64(ORDER_SHA512.iter().map(|&index| digest[index]), &mut hash);
+        },
+    }
+    hash
+}
+
+/// Encodes the password hash in the modular crypt format, e.g. with a salt read from untrusted input.
+///
+/// The salt is truncated to [`MAX_SALT_LENGTH`] bytes.
+///
+/// # Errors
+///
+/// Returns an error if the salt contains `$`, `:` or a newline, which are not allowed in the modular crypt format.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha_crypt::{self, Variant};
+///
+/// let params = sha_crypt::Params::new(Variant::Sha256);
+/// assert!(sha_crypt::try_encode("password", "salt", params).is_ok());
+/// assert!(sha_crypt::try_encode("password", "salt:salt", params).is_err());
+/// ```
+pub fn try_encode(password: impl AsRef<[u8]>, salt: &str, params: Params) -> Result<String, ParameterError> {
+    crypt::check_salt(salt)?;
+    Ok(encode(password, salt, params))
+}
+
+/// Verifies the password against the hash in the modular crypt format.
+///
+/// Returns `false` if the hash is malformed or uses a variant other than `$5$` and `$6$`. The hashes are compared in constant time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha_crypt;
+///
+/// let hash = "$6$rounds=5000$toolongsaltstrin$lQ8jolhgVRVhY4b5pZKaysCLi0QBxGoNeKQzQ3glMhwllF7oGDZxUhx1yxdYcz/e1JSbq3y6JMxxl8audkUEm0";
+/// assert!(sha_crypt::verify("This is just a test", hash));
+///
+/// assert!(!sha_crypt::verify("This is just a test", "$1$saltstri$YMyguxXMBpd2TEZ.vS/3q1"));
+/// ```
+#[must_use]
+pub fn verify(password: impl AsRef<[u8]>, hash: &str) -> bool {
+    match parse(hash) {
+        Some((params, salt)) => {
+            let expected = encode(password, salt, params);
+            constant_time_eq(expected.as_bytes(), hash.as_bytes())
+        },
+        None => false,
+    }
+}
+
+/// Parses the parameters and the salt of the hash in the modular crypt format.
+fn parse(hash: &str) -> Option<(Params, &str)> {
+    let hash = hash.strip_prefix('$')?;
+    let (id, hash) = hash.split_once('$')?;
+    let variant = Variant::from_id(id)?;
+    let mut params = Params::new(variant);
+
+    let hash = match hash.strip_prefix("rounds=") {
+        Some(hash) => {
+            let (rounds, hash) = hash.split_once('$')?;
+            if rounds.is_empty() || !rounds.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+            // Values out of range are clamped, including the ones that overflow.
+            let rounds = rounds.parse().unwrap_or(u32::MAX);
+            params = params.with_rounds(rounds);
+            hash
+        },
+        None => hash,
+    };
+
+    let salt = match hash.split_once('$') {
+        Some((salt, _)) => salt,
+        None => hash,
+    };
+    if salt.contains([':', '\n']) {
+        return None;
+    }
+    Some((params, salt))
+}
+
+/// Computes the final digest of the password and the salt.
+fn compute<H>(password: &[u8], salt: &[u8], rounds: u32) -> Vec<u8>
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]>,
+{
+    // Repeats the bytes until the given length is reached.
+    fn repeat(bytes: &[u8], length: usize) -> Vec<u8> {
+        bytes.iter().copied().cycle().take(length).collect()
+    }
+
+    let mut alternate = H::default();
+    alternate.update(password);
+    alternate.update(salt);
+    alternate.update(password);
+    let alternate = alternate.digest();
+    let alternate = alternate.as_ref();
+
+    let mut intermediate = H::default();
+    intermediate.update(password);
+    intermediate.update(salt);
+    intermediate.update(repeat(alternate, password.len()));
+    // The bits of the password length select between the alternate digest and the password.
+    let mut length = password.len();
+    while length > 0 {
+        if length & 1 == 1 {
+            intermediate.update(alternate);
+        } else {
+            intermediate.update(password);
+        }
+        length >>= 1;
+    }
+    let mut digest = intermediate.digest().as_ref().to_vec();
+
+    let mut sequence = H::default();
+    for _ in 0..password.len() {
+        sequence.update(password);
+    }
+    let password_sequence = repeat(sequence.digest().as_ref(), password.len());
+
+    let mut sequence = H::default();
+    for _ in 0..16 + usize::from(digest[0]) {
+        sequence.update(salt);
+    }
+    let salt_sequence = repeat(sequence.digest().as_ref(), salt.len());
+
+    for round in 0..rounds {
+        let mut hash = H::default();
+        if round % 2 == 1 {
+            hash.update(&password_sequence);
+        } else {
+            hash.update(&digest);
+        }
+        if round % 3 != 0 {
+            hash.update(&salt_sequence);
+        }
+        if round % 7 != 0 {
+            hash.update(&password_sequence);
+        }
+        if round % 2 == 1 {
+            hash.update(&digest);
+        } else {
+            hash.update(&password_sequence);
+        }
+        digest = hash.digest().as_ref().to_vec();
+    }
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the SHA-crypt specification.
+    #[test]
+    fn specification() {
+        for (salt, params, expected) in [
+            (
+                "saltstring",
+                Params::new(Variant::Sha256),
+                "$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5",
+            ),
+            (
+                "saltstringsaltstring",
+                Params::new(Variant::Sha256).with_rounds(10000),
+                "$5$rounds=10000$saltstringsaltst$3xv.VbSHBb41AL9AvLeujZkZRBAwqFMz2.opqey6IcA",
+            ),
+            (
+                "saltstring",
+                Params::new(Variant::Sha512),
+                "$6$saltstring$svn8UoSVapNtMuq1ukKS4tPQd8iKwSMHWjl/O817G3uBnIFNjnQJuesI68u4OTLiBFdcbYEdFCoEOfaS35inz1",
+            ),
+            (
+                "saltstringsaltstring",
+                Params::new(Variant::Sha512).with_rounds(10000),
+                "$6$rounds=10000$saltstringsaltst$OW1/O6BYHV6BcXZu8QVeXbDWra3Oeqh0sbHbbMCVNSnCM/UrjmM0Dp8vOuZeHBy/\
+                 YTBmSK6H9qs/y3RnOaw5v.",
+            ),
+        ] {
+            assert_eq!(encode("Hello world!", salt, params), expected);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Module containing items related to the SHA-crypt parameters.
+
+/// Default number of rounds.
+pub const DEFAULT_ROUNDS: u32 = 5_000;
+/// Minimum number of rounds.
+pub const MIN_ROUNDS: u32 = 1_000;
+/// Maximum number of rounds.
+pub const MAX_ROUNDS: u32 = 999_999_999;
+
+/// A SHA-crypt variant.
+///
+/// The variants differ in the hash function and in the identifier of the encoded hash. SHA-512 is the default of most Linux distributions, which is why it is the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Variant {
+    /// `sha256crypt`, identified by `$5$`.
+    Sha256,
+    /// `sha512crypt`, identified by `$6$`.
+    #[default]
+    Sha512,
+}
+
+impl Variant {
+    /// Returns the identifier of the variant, e.g. `"5"` for `$5$`.
+    #[must_use]
+    pub const fn id(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "5",
+            Self::Sha512 => "6",
+        }
+    }
+
+    /// Returns the variant with the given identifier.
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "5" => Some(Self::Sha256),
+            "6" => Some(Self::Sha512),
+            _ => None,
+        }
+    }
+}
+
+/// Parameters of the SHA-crypt password hashing scheme.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::sha_crypt::{self, Variant};
+///
+/// let params = sha_crypt::Params::new(Variant::Sha256).with_rounds(10_000);
+/// assert_eq!(params.variant(), Variant::Sha256);
+/// assert_eq!(params.rounds(), 10_000);
+///
+/// // The number of rounds is clamped like crypt(3) does
+/// let params = sha_crypt::Params::new(Variant::Sha256).with_rounds(10);
+/// assert_eq!(params.rounds(), 1_000);
+///
+/// // SHA-512 with 5000 rounds is the default
+/// let params = sha_crypt::Params::default();
+/// assert_eq!(params.variant(), Variant::Sha512);
+/// assert_eq!(params.rounds(), 5_000);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    variant: Variant,
+    rounds: Option<u32>,
+}
+
+impl Params {
+    /// Creates parameters for the given variant with the default number of rounds.
+    #[must_use]
+    pub const fn new(variant: Variant) -> Self {
+        Self { variant, rounds: None }
+    }
+
+    /// Sets the number of rounds, which is clamped between [`MIN_ROUNDS`] and [`MAX_ROUNDS`].
+    ///
+    /// An explicitly set number of rounds is stored in the encoded hash, even if it equals [`DEFAULT_ROUNDS`].
+    #[must_use]
+    pub const fn with_rounds(self, rounds: u32) -> Self {
+        let rounds = if rounds < MIN_ROUNDS {
+            MIN_ROUNDS
+        } else if rounds > MAX_ROUNDS {
+            MAX_ROUNDS
+        } else {
+            rounds
+        };
+        Self {
+            rounds: Some(rounds),
+            ..self
+        }
+    }
+
+    /// Returns the variant.
+    #[must_use]
+    pub const fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// Returns the number of rounds.
+    #[must_use]
+    pub const fn rounds(&self) -> u32 {
+        match self.rounds {
+            Some(rounds) => rounds,
+            None => DEFAULT_ROUNDS,
+        }
+    }
+
+    /// Returns `true` if the number of rounds was set explicitly.
+    #[must_use]
+    pub const fn has_explicit_rounds(&self) -> bool {
+        self.rounds.is_some()
+    }
+}
@@ -0,0 +1,180 @@
+//! This module is optional and can be enabled using any of the `skein-256`, `skein-512` or `skein-1024` Cargo features.
+//!
+//! Items shared by the Skein hash functions.
+//!
+//! Skein is a family of hash functions built on the Threefish tweakable block cipher, chained with the Unique Block Iteration (UBI) mode. The members differ in the internal state size (256, 512 or 1024 bits) and each of them can produce a digest of any length up to its state size. The implementations are available in the [`skein_256`](crate::skein_256), [`skein_512`](crate::skein_512) and [`skein_1024`](crate::skein_1024) modules.
+//!
+//! This is Skein version 1.3, the final version submitted to the SHA-3 competition.
+//!
+//! # Disclaimer
+//!
+//! Skein was a SHA-3 finalist but it is not standardized. It is provided for interoperability with existing systems; new designs should prefer SHA-2.
+
+pub mod digest;
+mod threefish;
+
+#[doc(inline)]
+pub use self::digest::Digest;
+use crate::buffer::Buffer;
+
+/// Type of the configuration block.
+const TYPE_CONFIG: u64 = 4;
+/// Type of the message blocks.
+const TYPE_MESSAGE: u64 = 48;
+/// Type of the output blocks.
+const TYPE_OUTPUT: u64 = 63;
+
+/// Tweak flag of the first block of a UBI call.
+const FLAG_FIRST: u64 = 1 << 62;
+/// Tweak flag of the last block of a UBI call.
+const FLAG_FINAL: u64 = 1 << 63;
+
+/// Capacity of the internal buffer, the block length of Skein-1024, the largest state size.
+const MAX_BLOCK_LENGTH_BYTES: usize = 128;
+
+/// The message-processing part of Skein with an internal buffer, generic over the number of state words.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Engine<const N: usize> {
+    chain: [u64; N],
+    initial: [u64; N],
+    unprocessed: Buffer<MAX_BLOCK_LENGTH_BYTES>,
+    processed: u128,
+    digest_length: usize,
+}
+
+impl<const N: usize> Engine<N> {
+    /// Creates a new engine for the given digest length in bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digest length is zero or greater than the state size.
+    pub(crate) fn new(digest_length: usize) -> Self {
+        assert!(
+            digest_length >= 1 && digest_length <= N * 64,
+            "digest length must be between 1 bit and the state size"
+        );
+
+        let config = {
+            let mut config = [0u8; 32];
+            config[..4].copy_from_slice(b"SHA3");
+            config[4..6].copy_from_slice(&1u16.to_le_bytes());
+            config[8..16].copy_from_slice(&(digest_length as u64).to_le_bytes());
+            config
+        };
+        let initial = ubi(&[0; N], &config, TYPE_CONFIG);
+
+        Self {
+            chain: initial,
+            initial,
+            unprocessed: Buffer::new(),
+            processed: 0,
+            digest_length,
+        }
+    }
+
+    /// Returns the digest length in bits.
+    pub(crate) const fn digest_length(&self) -> usize {
+        self.digest_length
+    }
+
+    /// Updates the chaining value with an input data.
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        let mut data = data;
+        while !data.is_empty() {
+            // A full buffer is processed only once more data arrives, since the last block must be flagged as final.
+            if self.unprocessed.len() == N * 8 {
+                self.process(0);
+            }
+            let missing = N * 8 - self.unprocessed.len();
+            let (portion, remainder) = data.split_at(missing.min(data.len()));
+            self.unprocessed.extend_from_slice(portion);
+            data = remainder;
+        }
+    }
+
+    /// Processes the last message block and returns the final chaining value.
+    pub(crate) fn finalize(&self) -> [u64; N] {
+        let mut engine = self.clone();
+        engine.process(FLAG_FINAL);
+        engine.chain
+    }
+
+    /// Resets the engine to the state right after the configuration block.
+    pub(crate) fn reset(&mut self) {
+        self.chain = self.initial;
+        self.unprocessed.clear();
+        self.processed = 0;
+    }
+
+    /// Generates an engine with an arbitrary digest length, chaining value and buffered data.
+    #[cfg(feature = "arbitrary")]
+    pub(crate) fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut engine = Self::new(u.int_in_range(1..=N * 64)?);
+        engine.chain = u.arbitrary()?;
+        let processed = u128::from(u.arbitrary::<u64>()?);
+        engine.processed = processed - processed % (N * 8) as u128;
+        // A full block stays buffered until more data arrives, so the buffer is empty only before the first block.
+        let buffered = if engine.processed == 0 {
+            u.int_in_range(0..=N * 8)?
+        } else {
+            u.int_in_range(1..=N * 8)?
+        };
+        engine.unprocessed = Buffer::from_slice(u.bytes(buffered)?);
+        Ok(engine)
+    }
+
+    /// Processes the buffered data as a single message block.
+    fn process(&mut self, flags: u64) {
+        let first = if self.processed == 0 { FLAG_FIRST } else { 0 };
+        self.processed += self.unprocessed.len() as u128;
+        self.chain = compress(
+            &self.chain,
+            &self.unprocessed,
+            self.processed,
+            (TYPE_MESSAGE << 56) | first | flags,
+        );
+        self.unprocessed.clear();
+    }
+}
+
+/// Produces the output of the given length in bits from the final chaining value.
+pub(crate) fn output<const N: usize>(chain: &[u64; N], digest_length: usize) -> Vec<u8> {
+    let output = ubi(chain, &0u64.to_le_bytes(), TYPE_OUTPUT);
+    let mut bytes = output.iter().flat_map(|word| word.to_le_bytes()).collect::<Vec<_>>();
+    bytes.truncate((digest_length + 7) / 8);
+    bytes
+}
+
+/// Processes a complete message with the UBI chaining mode.
+fn ubi<const N: usize>(chain: &[u64; N], message: &[u8], kind: u64) -> [u64; N] {
+    // An empty message is still processed as a single, zero-padded block.
+    let blocks = ((message.len() + N * 8 - 1) / (N * 8)).max(1);
+    let mut chain = *chain;
+    for index in 0..blocks {
+        let start = index * N * 8;
+        let end = message.len().min(start + N * 8);
+        let first = if index == 0 { FLAG_FIRST } else { 0 };
+        let last = if index + 1 == blocks { FLAG_FINAL } else { 0 };
+        chain = compress(&chain, &message[start..end], end as u128, (kind << 56) | first | last);
+    }
+    chain
+}
+
+/// Compresses a single, possibly partial, block with the given position and tweak flags.
+#[allow(clippy::cast_possible_truncation)]
+fn compress<const N: usize>(chain: &[u64; N], block: &[u8], position: u128, flags: u64) -> [u64; N] {
+    let mut words = [0u64; N];
+    for (word, bytes) in words.iter_mut().zip(block.chunks(8)) {
+        let mut buffer = [0u8; 8];
+        buffer[..bytes.len()].copy_from_slice(bytes);
+        *word = u64::from_le_bytes(buffer);
+    }
+
+    // The position occupies the lower 96 bits of the tweak.
+    let tweak = [position as u64, ((position >> 64) as u64) | flags];
+    let mut chain = threefish::encrypt(chain, tweak, &words);
+    for (word, input) in chain.iter_mut().zip(words) {
+        *word ^= input;
+    }
+    chain
+}
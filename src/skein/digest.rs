@@ -0,0 +1,13 @@
+//! Module containing items related to the Skein digest.
+
+/// Marker type of the Skein digest.
+///
+/// It tags [`Digest`] so Skein digests are distinct from digests of other hash functions with the same length.
+pub enum Skein {}
+
+/// A hash digest.
+///
+/// The output length of Skein is chosen at runtime and can be at most `N` bytes, the internal state size of the particular function. Each of the [`skein_256`](crate::skein_256), [`skein_512`](crate::skein_512) and [`skein_1024`](crate::skein_1024) modules provides a `Digest` alias with the right bound.
+///
+/// Digests are ordered lexicographically by their bytes, so a digest is ordered before the longer digests it is a prefix of.
+pub type Digest<const N: usize> = crate::digest::VariableDigest<Skein, N>;
@@ -0,0 +1,117 @@
+//! Module containing the Threefish block cipher underlying Skein.
+
+/// Key schedule parity constant.
+#[allow(clippy::unreadable_literal)]
+const C240: u64 = 0x1BD11BDAA9FC1A22;
+
+/// Rotation constants of Threefish-256.
+const R4: [[u32; 2]; 8] = [
+    [14, 16],
+    [52, 57],
+    [23, 40],
+    [5, 37],
+    [25, 33],
+    [46, 12],
+    [58, 22],
+    [32, 32],
+];
+
+/// Rotation constants of Threefish-512.
+const R8: [[u32; 4]; 8] = [
+    [46, 36, 19, 37],
+    [33, 27, 14, 42],
+    [17, 49, 36, 39],
+    [44, 9, 54, 56],
+    [39, 30, 34, 24],
+    [13, 50, 10, 17],
+    [25, 29, 39, 43],
+    [8, 35, 56, 22],
+];
+
+/// Rotation constants of Threefish-1024.
+const R16: [[u32; 8]; 8] = [
+    [24, 13, 8, 47, 8, 17, 22, 37],
+    [38, 19, 10, 55, 49, 18, 23, 52],
+    [33, 4, 51, 13, 34, 41, 59, 17],
+    [5, 20, 48, 41, 47, 28, 16, 25],
+    [41, 9, 37, 31, 12, 47, 44, 30],
+    [16, 34, 56, 51, 4, 53, 42, 41],
+    [31, 44, 47, 46, 19, 42, 44, 25],
+    [9, 48, 35, 52, 23, 31, 37, 20],
+];
+
+/// Word permutation of Threefish-256.
+const P4: [usize; 4] = [0, 3, 2, 1];
+/// Word permutation of Threefish-512.
+const P8: [usize; 8] = [2, 1, 4, 7, 6, 5, 0, 3];
+/// Word permutation of Threefish-1024.
+const P16: [usize; 16] = [0, 9, 2, 13, 6, 11, 4, 15, 10, 7, 12, 3, 14, 5, 8, 1];
+
+/// Returns the rotation constant for the given round and word pair.
+fn rotation<const N: usize>(round: usize, pair: usize) -> u32 {
+    match N {
+        4 => R4[round % 8][pair],
+        8 => R8[round % 8][pair],
+        16 => R16[round % 8][pair],
+        _ => unreachable!("Threefish is defined only for 4, 8 and 16 words"),
+    }
+}
+
+/// Returns the word permutation.
+fn permutation<const N: usize>() -> &'static [usize] {
+    match N {
+        4 => &P4,
+        8 => &P8,
+        16 => &P16,
+        _ => unreachable!("Threefish is defined only for 4, 8 and 16 words"),
+    }
+}
+
+/// Encrypts a single block with the given key and tweak.
+pub(crate) fn encrypt<const N: usize>(key: &[u64; N], tweak: [u64; 2], block: &[u64; N]) -> [u64; N] {
+    let rounds = if N == 16 { 80 } else { 72 };
+
+    // The extended key holds one extra parity word.
+    let mut extended = [0u64; 17];
+    extended[..N].copy_from_slice(key);
+    extended[N] = key.iter().fold(C240, |parity, word| parity ^ word);
+    let tweak = [tweak[0], tweak[1], tweak[0] ^ tweak[1]];
+
+    let subkey = |s: usize| {
+        let mut subkey = [0u64; N];
+        for (i, word) in subkey.iter_mut().enumerate() {
+            *word = extended[(s + i) % (N + 1)];
+        }
+        subkey[N - 3] = subkey[N - 3].wrapping_add(tweak[s % 3]);
+        subkey[N - 2] = subkey[N - 2].wrapping_add(tweak[(s + 1) % 3]);
+        subkey[N - 1] = subkey[N - 1].wrapping_add(s as u64);
+        subkey
+    };
+
+    let permutation = permutation::<N>();
+    let mut state = *block;
+    for round in 0..rounds {
+        if round % 4 == 0 {
+            for (word, key) in state.iter_mut().zip(subkey(round / 4)) {
+                *word = word.wrapping_add(key);
+            }
+        }
+
+        let mut mixed = [0u64; N];
+        for pair in 0..N / 2 {
+            let (x0, x1) = (state[2 * pair], state[2 * pair + 1]);
+            let y0 = x0.wrapping_add(x1);
+            let y1 = x1.rotate_left(rotation::<N>(round, pair)) ^ y0;
+            mixed[2 * pair] = y0;
+            mixed[2 * pair + 1] = y1;
+        }
+        for (word, &index) in state.iter_mut().zip(permutation) {
+            *word = mixed[index];
+        }
+    }
+
+    for (word, key) in state.iter_mut().zip(subkey(rounds / 4)) {
+        *word = word.wrapping_add(key);
+    }
+    state
+}
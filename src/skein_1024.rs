@@ -0,0 +1,394 @@
+//! This module is optional and can be enabled using the `skein-1024` Cargo feature.
+//!
+//! An implementation of the Skein-1024 hash function.
+//!
+//! By default the digest is as long as the internal state, i.e. 1024 bits; any shorter length can be selected with [`Update::with_digest_length`]. Check the [`skein`](crate::skein) module for information about the whole family.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::skein_1024;
+//!
+//! let digest = skein_1024::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "7bfada07cf2a105598d73d3b610d9f7bfa416ab5d13ba1bcc6c3b626b63f129f808d6f8fcfd55e874ff67df38f3f7092d61719f4fa16dc338d019807eac0de7cb54ad1bf426d5c1064d319479b1c1f2ee72b2bb591bd92b54ee8e6cc7ca23ea0327eed2b9056f807bddc1d98c1f203766dd886d9f539561e3e8a45da0ede3628"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::skein_1024;
+//!
+//! let digest = skein_1024::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "7bfada07cf2a105598d73d3b610d9f7bfa416ab5d13ba1bcc6c3b626b63f129f808d6f8fcfd55e874ff67df38f3f7092d61719f4fa16dc338d019807eac0de7cb54ad1bf426d5c1064d319479b1c1f2ee72b2bb591bd92b54ee8e6cc7ca23ea0327eed2b9056f807bddc1d98c1f203766dd886d9f539561e3e8a45da0ede3628"
+//! );
+//! ```
+//!
+//! # Output Length
+//!
+//! The digest length is a parameter of the hash function, not a truncation: Skein-1024 with a shorter output produces an unrelated digest.
+//!
+//! ```rust
+//! use chksum_hash::skein_1024;
+//!
+//! let digest = skein_1024::Update::with_digest_length(512)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "69581537d3b7d17a8d571d471ed3babbdca9d1621eaae8092189ec8129b981ffcce7c1b86976147867ca0a5928d6ab7ee5db410278d20376d75598c0194619d6"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized due to the unknown size of data chunks.
+//!
+//! The size of this buffer is at least as large as one hash block of data processed at a time.
+//!
+//! To mitigate buffering, and consequently reduce memory overhead, data chunks should be aligned to multiples of the block size, or processed in block-sized chunks.
+
+use chksum_hash_core as core;
+
+use crate::error::ParameterError;
+use crate::skein::{output, Engine};
+use crate::verify::Expected;
+
+/// Block length in bits.
+pub const BLOCK_LENGTH_BITS: usize = 1024;
+/// Block length in bytes.
+pub const BLOCK_LENGTH_BYTES: usize = BLOCK_LENGTH_BITS / 8;
+/// Block length in quadruple words (octuple bytes).
+pub const BLOCK_LENGTH_QWORDS: usize = BLOCK_LENGTH_BYTES / 8;
+
+/// Default (and maximum) digest length in bits.
+pub const DIGEST_LENGTH_BITS: usize = BLOCK_LENGTH_BITS;
+/// Default (and maximum) digest length in bytes.
+pub const DIGEST_LENGTH_BYTES: usize = DIGEST_LENGTH_BITS / 8;
+
+/// A hash digest.
+///
+/// Check [`skein_1024`](self) for more information.
+pub type Digest = crate::skein::Digest<DIGEST_LENGTH_BYTES>;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_1024;
+///
+/// let digest = skein_1024::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "0fff9563bb3279289227ac77d319b6fff8d7e9f09da1247b72a0a265cd6d2a62645ad547ed8193db48cff847c06494a03f55666d3b47eb4c20456c9373c86297d630d5578ebd34cb40991578f9f52b18003efa35d3da6553ff35db91b81ab890bec1b189b7f52cb2a783ebb7d823d725b0b4a71f6824e88f68f982eefc6d19c6"
+/// );
+///
+/// let digest = skein_1024::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "2cfa60b2bc07521545e542dfd9c28a2b46ed5438457d0ad67985279fa81b7423f4ebdb5175555b68a16de444ecaa306e90b6afcca1229c07c6f834108b0d741bab08ba113fd0543a2b864756005c4f0992b547b8d2e5df648004f1c5c2a7d61e00e0d9c4336ee2bc0c91548577cbba76fc3429c2e65cada65e6b1c68f34c96a5"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_1024;
+///
+/// let digest = skein_1024::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "0fff9563bb3279289227ac77d319b6fff8d7e9f09da1247b72a0a265cd6d2a62645ad547ed8193db48cff847c06494a03f55666d3b47eb4c20456c9373c86297d630d5578ebd34cb40991578f9f52b18003efa35d3da6553ff35db91b81ab890bec1b189b7f52cb2a783ebb7d823d725b0b4a71f6824e88f68f982eefc6d19c6"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_1024;
+///
+/// let digest = skein_1024::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "35a599a0f91abcdb4cb73c19b8cb8d947742d82c309137a7caed29e8e0a2ca7a9ff9a90c34c1908cc7e7fd99bb15032fb86e76df21b72628399b5f7c3cc209d7bb31c99cd4e19465622a049afbb87c03b5ce3888d17e6e667279ec0aa9b3e2712624c01b5f5bbe1a564220bdcf6990af0c2539019f313fdd7406cca3892a1f1f"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_1024;
+///
+/// let digest = skein_1024::hash("data");
+/// assert!(skein_1024::verify("data", digest));
+/// assert!(skein_1024::verify("data", digest.to_hex_uppercase()));
+/// assert!(!skein_1024::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_1024;
+///
+/// // Create a new hash instance
+/// let mut hash = skein_1024::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "2cfa60b2bc07521545e542dfd9c28a2b46ed5438457d0ad67985279fa81b7423f4ebdb5175555b68a16de444ecaa306e90b6afcca1229c07c6f834108b0d741bab08ba113fd0543a2b864756005c4f0992b547b8d2e5df648004f1c5c2a7d61e00e0d9c4336ee2bc0c91548577cbba76fc3429c2e65cada65e6b1c68f34c96a5"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "0fff9563bb3279289227ac77d319b6fff8d7e9f09da1247b72a0a265cd6d2a62645ad547ed8193db48cff847c06494a03f55666d3b47eb4c20456c9373c86297d630d5578ebd34cb40991578f9f52b18003efa35d3da6553ff35db91b81ab890bec1b189b7f52cb2a783ebb7d823d725b0b4a71f6824e88f68f982eefc6d19c6"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    inner: Engine<BLOCK_LENGTH_QWORDS>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_digest_length(DIGEST_LENGTH_BITS)
+    }
+
+    /// Creates a new hash producing a digest of the given length in bits.
+    ///
+    /// If the length is not a multiple of 8, the digest is rounded up to full bytes as in the reference implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is zero or greater than [`DIGEST_LENGTH_BITS`].
+    #[must_use]
+    pub fn with_digest_length(length: usize) -> Self {
+        let inner = Engine::new(length);
+        Self { inner }
+    }
+
+    /// Creates a new hash producing a digest of the given length in bits, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length is zero or greater than [`DIGEST_LENGTH_BITS`].
+    pub fn try_with_digest_length(length: usize) -> Result<Self, ParameterError> {
+        if (1..=DIGEST_LENGTH_BITS).contains(&length) {
+            Ok(Self::with_digest_length(length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.update(data.as_ref());
+        self
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let chain = self.inner.finalize();
+        let digest_length = self.inner.digest_length();
+        Finalize { chain, digest_length }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The digest length is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.inner.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let inner = Engine::arbitrary(u)?;
+        Ok(Self { inner })
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_1024;
+///
+/// // Create a new hash instance
+/// let mut hash = skein_1024::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "35a599a0f91abcdb4cb73c19b8cb8d947742d82c309137a7caed29e8e0a2ca7a9ff9a90c34c1908cc7e7fd99bb15032fb86e76df21b72628399b5f7c3cc209d7bb31c99cd4e19465622a049afbb87c03b5ce3888d17e6e667279ec0aa9b3e2712624c01b5f5bbe1a564220bdcf6990af0c2539019f313fdd7406cca3892a1f1f"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    chain: [u64; BLOCK_LENGTH_QWORDS],
+    digest_length: usize,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let digest = output(&self.chain, self.digest_length);
+        Digest::new(&digest)
+    }
+
+    /// Writes the hash digest into the beginning of the buffer, e.g. a part of a packet, and returns the written part of the buffer.
+    ///
+    /// Returns `None` if the buffer is shorter than the digest, whose length is chosen at runtime.
+    #[must_use]
+    pub fn digest_into<'a>(&self, out: &'a mut [u8]) -> Option<&'a [u8]> {
+        let digest = self.digest();
+        let out = out.get_mut(..digest.len())?;
+        out.copy_from_slice(digest.as_ref());
+        Some(out)
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The digest length is kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_digest_length(self.digest_length)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the Skein 1.3 specification, appendix C, and the SHA-3 submission.
+    #[test]
+    fn submission() {
+        assert_eq!(
+            hash("").to_hex_lowercase(),
+            "0fff9563bb3279289227ac77d319b6fff8d7e9f09da1247b72a0a265cd6d2a62645ad547ed8193db48cff847c06494a03f55666d3b47eb4c20456c9373c86297d630d5578ebd34cb40991578f9f52b18003efa35d3da6553ff35db91b81ab890bec1b189b7f52cb2a783ebb7d823d725b0b4a71f6824e88f68f982eefc6d19c6"
+        );
+        assert_eq!(
+            hash(std::array::from_fn::<u8, 128, _>(|i| 0xFF - i as u8)).to_hex_lowercase(),
+            "1f3e02c46fb80a3fcd2dfbbc7c173800b40c60c2354af551189ebf433c3d85f9ff1803e6d920493179ed7ae7fce69c3581a5a2f82d3e0c7a295574d0cd7d217c484d2f6313d59a7718ead07d0729c24851d7e7d2491b902d489194e6b7d369db0ab7aa106f0ee0a39a42efc54f18d93776080985f907574f995ec6a37153a578"
+        );
+    }
+}
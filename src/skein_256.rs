@@ -0,0 +1,398 @@
+//! This module is optional and can be enabled using the `skein-256` Cargo feature.
+//!
+//! An implementation of the Skein-256 hash function.
+//!
+//! By default the digest is as long as the internal state, i.e. 256 bits; any shorter length can be selected with [`Update::with_digest_length`]. Check the [`skein`](crate::skein) module for information about the whole family.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::skein_256;
+//!
+//! let digest = skein_256::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "94487605c133311744775c6cda70822c23f5e017c0ff157b2153a4b3a232fe41"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::skein_256;
+//!
+//! let digest = skein_256::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "94487605c133311744775c6cda70822c23f5e017c0ff157b2153a4b3a232fe41"
+//! );
+//! ```
+//!
+//! # Output Length
+//!
+//! The digest length is a parameter of the hash function, not a truncation: Skein-256 with a shorter output produces an unrelated digest.
+//!
+//! ```rust
+//! use chksum_hash::skein_256;
+//!
+//! let digest = skein_256::Update::with_digest_length(128)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "16eef6c399ba3ae75080d97ccf24858b"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized due to the unknown size of data chunks.
+//!
+//! The size of this buffer is at least as large as one hash block of data processed at a time.
+//!
+//! To mitigate buffering, and consequently reduce memory overhead, data chunks should be aligned to multiples of the block size, or processed in block-sized chunks.
+
+use chksum_hash_core as core;
+
+use crate::error::ParameterError;
+use crate::skein::{output, Engine};
+use crate::verify::Expected;
+
+/// Block length in bits.
+pub const BLOCK_LENGTH_BITS: usize = 256;
+/// Block length in bytes.
+pub const BLOCK_LENGTH_BYTES: usize = BLOCK_LENGTH_BITS / 8;
+/// Block length in quadruple words (octuple bytes).
+pub const BLOCK_LENGTH_QWORDS: usize = BLOCK_LENGTH_BYTES / 8;
+
+/// Default (and maximum) digest length in bits.
+pub const DIGEST_LENGTH_BITS: usize = BLOCK_LENGTH_BITS;
+/// Default (and maximum) digest length in bytes.
+pub const DIGEST_LENGTH_BYTES: usize = DIGEST_LENGTH_BITS / 8;
+
+/// A hash digest.
+///
+/// Check [`skein_256`](self) for more information.
+pub type Digest = crate::skein::Digest<DIGEST_LENGTH_BYTES>;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_256;
+///
+/// let digest = skein_256::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "c8877087da56e072870daa843f176e9453115929094c3a40c463a196c29bf7ba"
+/// );
+///
+/// let digest = skein_256::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "672439aa6aed71441bec134b52e5fb4ec3b8baf31b067bf79a382fd0498fae2c"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_256;
+///
+/// let digest = skein_256::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "c8877087da56e072870daa843f176e9453115929094c3a40c463a196c29bf7ba"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_256;
+///
+/// let digest = skein_256::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "258bdec343b9fde1639221a5ae0144a96e552e5288753c5fec76c05fc2fc1870"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_256;
+///
+/// let digest = skein_256::hash("data");
+/// assert!(skein_256::verify("data", digest));
+/// assert!(skein_256::verify("data", digest.to_hex_uppercase()));
+/// assert!(!skein_256::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_256;
+///
+/// // Create a new hash instance
+/// let mut hash = skein_256::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "672439aa6aed71441bec134b52e5fb4ec3b8baf31b067bf79a382fd0498fae2c"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "c8877087da56e072870daa843f176e9453115929094c3a40c463a196c29bf7ba"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    inner: Engine<BLOCK_LENGTH_QWORDS>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_digest_length(DIGEST_LENGTH_BITS)
+    }
+
+    /// Creates a new hash producing a digest of the given length in bits.
+    ///
+    /// If the length is not a multiple of 8, the digest is rounded up to full bytes as in the reference implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is zero or greater than [`DIGEST_LENGTH_BITS`].
+    #[must_use]
+    pub fn with_digest_length(length: usize) -> Self {
+        let inner = Engine::new(length);
+        Self { inner }
+    }
+
+    /// Creates a new hash producing a digest of the given length in bits, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length is zero or greater than [`DIGEST_LENGTH_BITS`].
+    pub fn try_with_digest_length(length: usize) -> Result<Self, ParameterError> {
+        if (1..=DIGEST_LENGTH_BITS).contains(&length) {
+            Ok(Self::with_digest_length(length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.update(data.as_ref());
+        self
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let chain = self.inner.finalize();
+        let digest_length = self.inner.digest_length();
+        Finalize { chain, digest_length }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The digest length is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.inner.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let inner = Engine::arbitrary(u)?;
+        Ok(Self { inner })
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_256;
+///
+/// // Create a new hash instance
+/// let mut hash = skein_256::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "258bdec343b9fde1639221a5ae0144a96e552e5288753c5fec76c05fc2fc1870"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    chain: [u64; BLOCK_LENGTH_QWORDS],
+    digest_length: usize,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let digest = output(&self.chain, self.digest_length);
+        Digest::new(&digest)
+    }
+
+    /// Writes the hash digest into the beginning of the buffer, e.g. a part of a packet, and returns the written part of the buffer.
+    ///
+    /// Returns `None` if the buffer is shorter than the digest, whose length is chosen at runtime.
+    #[must_use]
+    pub fn digest_into<'a>(&self, out: &'a mut [u8]) -> Option<&'a [u8]> {
+        let digest = self.digest();
+        let out = out.get_mut(..digest.len())?;
+        out.copy_from_slice(digest.as_ref());
+        Some(out)
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The digest length is kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_digest_length(self.digest_length)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the Skein 1.3 specification, appendix C.
+    #[test]
+    fn submission() {
+        assert_eq!(
+            hash("").to_hex_lowercase(),
+            "c8877087da56e072870daa843f176e9453115929094c3a40c463a196c29bf7ba"
+        );
+        assert_eq!(
+            hash([0xFFu8]).to_hex_lowercase(),
+            "0b98dcd198ea0e50a7a244c444e25c23da30c10fc9a1f270a6637f1f34e67ed2"
+        );
+        assert_eq!(
+            hash(std::array::from_fn::<u8, 32, _>(|i| 0xFF - i as u8)).to_hex_lowercase(),
+            "8d0fa4ef777fd759dfd4044e6f6a5ac3c774aec943dcfc07927b723b5dbf408b"
+        );
+    }
+}
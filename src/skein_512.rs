@@ -0,0 +1,403 @@
+//! This module is optional and can be enabled using the `skein-512` Cargo feature.
+//!
+//! An implementation of the Skein-512 hash function.
+//!
+//! By default the digest is as long as the internal state, i.e. 512 bits; any shorter length can be selected with [`Update::with_digest_length`]. Check the [`skein`](crate::skein) module for information about the whole family.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::skein_512;
+//!
+//! let digest = skein_512::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "61c8a1e2468ede23380c90db6b562b884f699221d25aab749088e904e45117bbfcf9b7b0325dbc563f862c9aed1d39a61f3a411288679b4b51f7750f4c3f3538"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::skein_512;
+//!
+//! let digest = skein_512::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "61c8a1e2468ede23380c90db6b562b884f699221d25aab749088e904e45117bbfcf9b7b0325dbc563f862c9aed1d39a61f3a411288679b4b51f7750f4c3f3538"
+//! );
+//! ```
+//!
+//! # Output Length
+//!
+//! The digest length is a parameter of the hash function, not a truncation: Skein-512 with a shorter output produces an unrelated digest.
+//!
+//! ```rust
+//! use chksum_hash::skein_512;
+//!
+//! let digest = skein_512::Update::with_digest_length(256)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "38e377e11f7859775d4f2b4fe699a53e3b6377c0256d063849f71aae79ef384a"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized due to the unknown size of data chunks.
+//!
+//! The size of this buffer is at least as large as one hash block of data processed at a time.
+//!
+//! To mitigate buffering, and consequently reduce memory overhead, data chunks should be aligned to multiples of the block size, or processed in block-sized chunks.
+
+use chksum_hash_core as core;
+
+use crate::error::ParameterError;
+use crate::skein::{output, Engine};
+use crate::verify::Expected;
+
+/// Block length in bits.
+pub const BLOCK_LENGTH_BITS: usize = 512;
+/// Block length in bytes.
+pub const BLOCK_LENGTH_BYTES: usize = BLOCK_LENGTH_BITS / 8;
+/// Block length in quadruple words (octuple bytes).
+pub const BLOCK_LENGTH_QWORDS: usize = BLOCK_LENGTH_BYTES / 8;
+
+/// Default (and maximum) digest length in bits.
+pub const DIGEST_LENGTH_BITS: usize = BLOCK_LENGTH_BITS;
+/// Default (and maximum) digest length in bytes.
+pub const DIGEST_LENGTH_BYTES: usize = DIGEST_LENGTH_BITS / 8;
+
+/// A hash digest.
+///
+/// Check [`skein_512`](self) for more information.
+pub type Digest = crate::skein::Digest<DIGEST_LENGTH_BYTES>;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_512;
+///
+/// let digest = skein_512::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "bc5b4c50925519c290cc634277ae3d6257212395cba733bbad37a4af0fa06af41fca7903d06564fea7a2d3730dbdb80c1f85562dfcc070334ea4d1d9e72cba7a"
+/// );
+///
+/// let digest = skein_512::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e9788131b5e4fdff0485548c0b6eeb24f0601de080947b40845b275b6950202ce31f5a439d31e4c968221a0871d086c79affd7511a7f4025581fd6b05bd8bede"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_512;
+///
+/// let digest = skein_512::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "bc5b4c50925519c290cc634277ae3d6257212395cba733bbad37a4af0fa06af41fca7903d06564fea7a2d3730dbdb80c1f85562dfcc070334ea4d1d9e72cba7a"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_512;
+///
+/// let digest = skein_512::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "8f5dd9ec798152668e35129496b029a960c9a9b88662f7f9482f110b31f9f93893ecfb25c009baad9e46737197d5630379816a886aa05526d3a70df272d96e75"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_512;
+///
+/// let digest = skein_512::hash("data");
+/// assert!(skein_512::verify("data", digest));
+/// assert!(skein_512::verify("data", digest.to_hex_uppercase()));
+/// assert!(!skein_512::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_512;
+///
+/// // Create a new hash instance
+/// let mut hash = skein_512::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "e9788131b5e4fdff0485548c0b6eeb24f0601de080947b40845b275b6950202ce31f5a439d31e4c968221a0871d086c79affd7511a7f4025581fd6b05bd8bede"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "bc5b4c50925519c290cc634277ae3d6257212395cba733bbad37a4af0fa06af41fca7903d06564fea7a2d3730dbdb80c1f85562dfcc070334ea4d1d9e72cba7a"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    inner: Engine<BLOCK_LENGTH_QWORDS>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_digest_length(DIGEST_LENGTH_BITS)
+    }
+
+    /// Creates a new hash producing a digest of the given length in bits.
+    ///
+    /// If the length is not a multiple of 8, the digest is rounded up to full bytes as in the reference implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length is zero or greater than [`DIGEST_LENGTH_BITS`].
+    #[must_use]
+    pub fn with_digest_length(length: usize) -> Self {
+        let inner = Engine::new(length);
+        Self { inner }
+    }
+
+    /// Creates a new hash producing a digest of the given length in bits, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length is zero or greater than [`DIGEST_LENGTH_BITS`].
+    pub fn try_with_digest_length(length: usize) -> Result<Self, ParameterError> {
+        if (1..=DIGEST_LENGTH_BITS).contains(&length) {
+            Ok(Self::with_digest_length(length))
+        } else {
+            Err(ParameterError {
+                parameter: "digest length",
+            })
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.update(data.as_ref());
+        self
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let chain = self.inner.finalize();
+        let digest_length = self.inner.digest_length();
+        Finalize { chain, digest_length }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The digest length is kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.inner.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let inner = Engine::arbitrary(u)?;
+        Ok(Self { inner })
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::skein_512;
+///
+/// // Create a new hash instance
+/// let mut hash = skein_512::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "8f5dd9ec798152668e35129496b029a960c9a9b88662f7f9482f110b31f9f93893ecfb25c009baad9e46737197d5630379816a886aa05526d3a70df272d96e75"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    chain: [u64; BLOCK_LENGTH_QWORDS],
+    digest_length: usize,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let digest = output(&self.chain, self.digest_length);
+        Digest::new(&digest)
+    }
+
+    /// Writes the hash digest into the beginning of the buffer, e.g. a part of a packet, and returns the written part of the buffer.
+    ///
+    /// Returns `None` if the buffer is shorter than the digest, whose length is chosen at runtime.
+    #[must_use]
+    pub fn digest_into<'a>(&self, out: &'a mut [u8]) -> Option<&'a [u8]> {
+        let digest = self.digest();
+        let out = out.get_mut(..digest.len())?;
+        out.copy_from_slice(digest.as_ref());
+        Some(out)
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The digest length is kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::with_digest_length(self.digest_length)
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the Skein 1.3 specification, appendix C, and the SHA-3 submission.
+    #[test]
+    fn submission() {
+        assert_eq!(
+            hash("").to_hex_lowercase(),
+            "bc5b4c50925519c290cc634277ae3d6257212395cba733bbad37a4af0fa06af41fca7903d06564fea7a2d3730dbdb80c1f85562dfcc070334ea4d1d9e72cba7a"
+        );
+        assert_eq!(
+            hash("The quick brown fox jumps over the lazy dog").to_hex_lowercase(),
+            "94c2ae036dba8783d0b3f7d6cc111ff810702f5c77707999be7e1c9486ff238a7044de734293147359b4ac7e1d09cd247c351d69826b78dcddd951f0ef912713"
+        );
+        assert_eq!(
+            hash(std::array::from_fn::<u8, 64, _>(|i| 0xFF - i as u8)).to_hex_lowercase(),
+            "45863ba3be0c4dfc27e75d358496f4ac9a736a505d9313b42b2f5eada79fc17f63861e947afb1d056aa199575ad3f8c9a3cc1780b5e5fa4cae050e989876625b"
+        );
+        let digest = Update::with_digest_length(256).update("").digest();
+        assert_eq!(
+            digest.to_hex_lowercase(),
+            "39ccc4554a8b31853b9de7a1fe638a24cce6b35a55f2431009e18780335d2621"
+        );
+    }
+}
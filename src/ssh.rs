@@ -0,0 +1,229 @@
+//! This module is optional and can be enabled using the `ssh` Cargo feature.
+//!
+//! An implementation of the public key fingerprints of OpenSSH.
+//!
+//! A fingerprint is the digest of the public key blob, i.e. the binary key in the SSH wire format, which is base64-encoded in `authorized_keys` and `.pub` files. OpenSSH writes [`sha2_256`](crate::sha2_256) fingerprints as `SHA256:` followed by the unpadded base64 digest and legacy [`md5`](crate::md5) fingerprints as `MD5:` followed by colon-separated hexadecimal bytes, see [`Fingerprint`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::ssh::{self, Algorithm};
+//!
+//! let key = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIH3gl8QFeUw1wSpQ/uEPQplhzUyQ1+THzwXM14w1aHNE";
+//!
+//! let fingerprint = ssh::fingerprint_public_key(Algorithm::Sha256, key).unwrap();
+//! assert_eq!(
+//!     fingerprint.to_string(),
+//!     "SHA256:GyghVgujcZ/NJ6NcPG8hh+wRjy/xj6Au6ogAT6Jzji4"
+//! );
+//!
+//! let fingerprint = ssh::fingerprint_public_key(Algorithm::Md5, key).unwrap();
+//! assert_eq!(
+//!     fingerprint.to_string(),
+//!     "MD5:5d:e5:2f:58:89:ea:be:23:0e:6b:7b:36:75:dd:6a:69"
+//! );
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::{base64, hex, md5, sha2_256};
+
+/// A hash function of fingerprints.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Algorithm {
+    /// MD5, the legacy algorithm.
+    Md5,
+    /// SHA-2 256, the default algorithm.
+    #[default]
+    Sha256,
+}
+
+impl Algorithm {
+    /// Returns the name of the algorithm used as the prefix of fingerprints, e.g. `"SHA256"`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Md5 => "MD5",
+            Self::Sha256 => "SHA256",
+        }
+    }
+}
+
+/// Computes the fingerprint of the given public key blob.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ssh::{self, Algorithm};
+/// use chksum_hash::{base64, sha2_256};
+///
+/// let blob =
+///     base64::decode("AAAAC3NzaC1lZDI1NTE5AAAAIH3gl8QFeUw1wSpQ/uEPQplhzUyQ1+THzwXM14w1aHNE")
+///         .unwrap();
+/// let fingerprint = ssh::fingerprint(Algorithm::Sha256, &blob);
+/// assert_eq!(fingerprint, sha2_256::hash(&blob));
+/// ```
+#[must_use]
+pub fn fingerprint(algorithm: Algorithm, blob: impl AsRef<[u8]>) -> Fingerprint {
+    match algorithm {
+        Algorithm::Md5 => Fingerprint::Md5(md5::hash(blob)),
+        Algorithm::Sha256 => Fingerprint::Sha256(sha2_256::hash(blob)),
+    }
+}
+
+/// Computes the fingerprint of the public key in the OpenSSH format, i.e. the key type, the base64-encoded blob and an optional comment separated by whitespace.
+///
+/// Returns `None` if the public key is malformed.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::ssh::{self, Algorithm};
+///
+/// let key = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIH3gl8QFeUw1wSpQ/uEPQplhzUyQ1+THzwXM14w1aHNE";
+/// let fingerprint =
+///     ssh::fingerprint_public_key(Algorithm::Md5, &format!("{key} user@host")).unwrap();
+/// assert_eq!(
+///     fingerprint,
+///     "5d:e5:2f:58:89:ea:be:23:0e:6b:7b:36:75:dd:6a:69"
+///         .parse::<ssh::Fingerprint>()
+///         .unwrap()
+/// );
+///
+/// assert_eq!(
+///     ssh::fingerprint_public_key(Algorithm::Sha256, "ssh-ed25519"),
+///     None
+/// );
+/// ```
+#[must_use]
+pub fn fingerprint_public_key(algorithm: Algorithm, public_key: &str) -> Option<Fingerprint> {
+    let mut fields = public_key.split_ascii_whitespace();
+    let _key_type = fields.next()?;
+    let blob = base64::decode(fields.next()?)?;
+    Some(fingerprint(algorithm, blob))
+}
+
+/// A fingerprint of a public key.
+///
+/// The [`Display`] and [`FromStr`] implementations use the textual forms of OpenSSH. The `MD5:` prefix is optional when parsing, since it was omitted by older versions of OpenSSH.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fingerprint {
+    /// MD5 fingerprint, `MD5:<colon-separated hex>`.
+    Md5(md5::Digest),
+    /// SHA-2 256 fingerprint, `SHA256:<unpadded base64>`.
+    Sha256(sha2_256::Digest),
+}
+
+impl Fingerprint {
+    /// Returns the algorithm of the fingerprint.
+    #[must_use]
+    pub const fn algorithm(&self) -> Algorithm {
+        match self {
+            Self::Md5(_) => Algorithm::Md5,
+            Self::Sha256(_) => Algorithm::Sha256,
+        }
+    }
+}
+
+impl AsRef<[u8]> for Fingerprint {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Md5(digest) => digest.as_ref(),
+            Self::Sha256(digest) => digest.as_ref(),
+        }
+    }
+}
+
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let encoded = match self {
+            Self::Md5(digest) => hex::Grouped::new(digest.as_ref()).to_string(),
+            Self::Sha256(digest) => base64::encode_unpadded(digest),
+        };
+        write!(f, "{}:{encoded}", self.algorithm().name())
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(encoded) = s.strip_prefix("SHA256:") {
+            let digest = base64::decode(encoded)
+                .and_then(|digest| digest.try_into().ok())
+                .ok_or(ParseError::InvalidEncoded)?;
+            return Ok(Self::Sha256(sha2_256::Digest::new(digest)));
+        }
+
+        let encoded = match s.strip_prefix("MD5:") {
+            Some(encoded) => encoded,
+            // Legacy fingerprints start with the first byte instead of the name of the algorithm.
+            None if s.split(':').next().map_or(false, is_hex_byte) => s,
+            None => return Err(ParseError::UnsupportedAlgorithm),
+        };
+        let mut digest = [0u8; md5::DIGEST_LENGTH_BYTES];
+        let mut bytes = encoded.split(':');
+        for byte in &mut digest {
+            let encoded = bytes
+                .next()
+                .filter(|byte| is_hex_byte(byte))
+                .ok_or(ParseError::InvalidEncoded)?;
+            hex::decode_to_slice(encoded, std::slice::from_mut(byte)).map_err(|_| ParseError::InvalidEncoded)?;
+        }
+        if bytes.next().is_some() {
+            return Err(ParseError::InvalidEncoded);
+        }
+        Ok(Self::Md5(md5::Digest::new(digest)))
+    }
+}
+
+impl From<md5::Digest> for Fingerprint {
+    fn from(digest: md5::Digest) -> Self {
+        Self::Md5(digest)
+    }
+}
+
+impl From<sha2_256::Digest> for Fingerprint {
+    fn from(digest: sha2_256::Digest) -> Self {
+        Self::Sha256(digest)
+    }
+}
+
+impl PartialEq<md5::Digest> for Fingerprint {
+    fn eq(&self, other: &md5::Digest) -> bool {
+        matches!(self, Self::Md5(digest) if digest == other)
+    }
+}
+
+impl PartialEq<sha2_256::Digest> for Fingerprint {
+    fn eq(&self, other: &sha2_256::Digest) -> bool {
+        matches!(self, Self::Sha256(digest) if digest == other)
+    }
+}
+
+/// An error of parsing a fingerprint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A prefix other than `SHA256:` and `MD5:`.
+    UnsupportedAlgorithm,
+    /// An encoded digest that is malformed or of a wrong length.
+    InvalidEncoded,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedAlgorithm => f.write_str("unsupported fingerprint algorithm"),
+            Self::InvalidEncoded => f.write_str("invalid encoded fingerprint"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returns `true` if the string is a byte of two hexadecimal digits.
+fn is_hex_byte(byte: &str) -> bool {
+    byte.len() == 2 && byte.bytes().all(|digit| digit.is_ascii_hexdigit())
+}
@@ -0,0 +1,94 @@
+//! Module contains items related to the [`State`] structure which allows to the direct SysV sum state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::sysv_sum;
+//!
+//! // Create new state
+//! let mut state = sysv_sum::state::default();
+//!
+//! // By default it returns the checksum of empty input
+//! assert_eq!(state.digest(), 0x0000);
+//!
+//! // Update state with any amount of data and own new value
+//! state = state.update(b"example data");
+//! assert_eq!(state.digest(), 0x04A6);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state.digest(), 0x0000);
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level checksum state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) sum: u32,
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(0)
+    }
+
+    /// Creates a state from the raw sum, e.g. a custom initial value.
+    #[must_use]
+    pub const fn from_raw(sum: u32) -> Self {
+        Self { sum }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// Unlike hash functions, the data does not need to be split into blocks.
+    #[must_use]
+    pub const fn update(&self, data: &[u8]) -> Self {
+        let mut sum = self.sum;
+        let mut i = 0;
+        while i < data.len() {
+            sum = sum.wrapping_add(data[i] as u32);
+            i += 1;
+        }
+        Self::from_raw(sum)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns a checksum.
+    #[must_use]
+    pub const fn digest(&self) -> u16 {
+        let sum = (self.sum & 0xFFFF) + (self.sum >> 16);
+        ((sum & 0xFFFF) + (sum >> 16)) as u16
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
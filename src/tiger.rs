@@ -0,0 +1,565 @@
+//! This module is optional and can be enabled using the `tiger` Cargo feature.
+//!
+//! An implementation of the Tiger hash function.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::tiger;
+//!
+//! let digest = tiger::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "91f20b3995fa62bae56644c0055dcab15481cc8089380c30"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! // Import all necessary items
+//! # use std::io;
+//! # use std::path::PathBuf;
+//! use std::fs::File;
+//! use std::io::Read;
+//!
+//! use chksum_hash::tiger;
+//!
+//! # fn wrapper(path: PathBuf) -> io::Result<()> {
+//! // Create a hash instance
+//! let mut hash = tiger::default();
+//!
+//! // Open a file and create a buffer for incoming data
+//! let mut file = File::open(path)?;
+//! let mut buffer = vec![0; 64];
+//!
+//! // Iterate chunk by chunk
+//! while let Ok(count) = file.read(&mut buffer) {
+//!     // EOF reached, exit loop
+//!     if count == 0 {
+//!         break;
+//!     }
+//!
+//!     // Update the hash with data
+//!     hash.update(&buffer[..count]);
+//! }
+//!
+//! // Calculate the digest
+//! let digest = hash.digest();
+//! // Cast the digest to hex and compare
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "91f20b3995fa62bae56644c0055dcab15481cc8089380c30"
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! An internal buffer is utilized due to the unknown size of data chunks.
+//!
+//! The size of this buffer is at least as large as one hash block of data processed at a time.
+//!
+//! To mitigate buffering, and consequently reduce memory overhead, data chunks should be aligned to multiples of the block size, or processed in block-sized chunks.
+//!
+//! The [`Update::is_block_aligned`] and [`Update::buffered_len`] functions report whether data is buffered, and [`Update::processed_bytes`] returns the number of bytes passed to the hash so far.
+//!
+//! # Byte Order
+//!
+//! Tiger operates on little-endian 64-bit words, and the digest is the concatenation of the three state words in little-endian byte order. This is the byte order used by the reference implementation and by the test vectors published alongside it; some older tools print the words in big-endian order instead, which yields a different hexadecimal string for the same hash.
+//!
+//! # Disclaimer
+//!
+//! Tiger has not been broken, but it is not standardized and has received considerably less cryptanalysis than the SHA-2 family. It is provided for compatibility with existing systems; new designs should prefer SHA-2.
+
+pub mod block;
+pub mod digest;
+mod sbox;
+pub mod state;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::block::{Block, LENGTH_BYTES as BLOCK_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+use crate::buffer::Buffer;
+use crate::error::{MessageLengthError, StateError};
+use crate::export::{Reader, Writer};
+use crate::verify::Expected;
+
+/// Maximum message length in bytes, so that the length in bits fits into 64 bits.
+///
+/// The [`Update::update`] function does not check it and wraps the length of longer messages, the [`Update::try_update`] function rejects them.
+pub const MAX_MESSAGE_LENGTH_BYTES: u64 = u64::MAX / 8;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger;
+///
+/// let digest = tiger::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3"
+/// );
+///
+/// let digest = tiger::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "fb0546a00df098a80a696e23ca5e8c84f220e17ffbabfc47"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger;
+///
+/// let digest = tiger::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger;
+///
+/// let digest = tiger::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger;
+///
+/// let digest = tiger::hash("data");
+/// assert!(tiger::verify("data", digest));
+/// assert!(tiger::verify("data", digest.to_hex_uppercase()));
+/// assert!(!tiger::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger;
+///
+/// // Create a new hash instance
+/// let mut hash = tiger::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "fb0546a00df098a80a696e23ca5e8c84f220e17ffbabfc47"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    state: State,
+    unprocessed: Buffer<BLOCK_LENGTH_BYTES>,
+    processed: u64,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        let state = state::new();
+        let unprocessed = Buffer::new();
+        let processed = 0;
+        Self {
+            state,
+            unprocessed,
+            processed,
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+
+        if !self.unprocessed.is_empty() {
+            let missing = BLOCK_LENGTH_BYTES - self.unprocessed.len();
+            if data.len() < missing {
+                // Not enough data even for one block.
+                self.unprocessed.extend_from_slice(data);
+                return self;
+            }
+
+            // Complete the buffered block with the beginning of incoming data.
+            let (fillment, remainder) = data.split_at(missing);
+            self.unprocessed.extend_from_slice(fillment);
+            let block = Block::try_from(&self.unprocessed[..])
+                .expect("unprocessed data length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u64);
+            self.unprocessed.clear();
+            data = remainder;
+        }
+
+        // Internal buffer is empty, incoming data can be processed without buffering.
+        let mut chunks = data.chunks_exact(BLOCK_LENGTH_BYTES);
+        for chunk in chunks.by_ref() {
+            let block = Block::try_from(chunk)
+                .expect("chunk length must be exact size as block")
+                .into();
+            self.state = self.state.update(block);
+            self.processed = self.processed.wrapping_add(BLOCK_LENGTH_BYTES as u64);
+        }
+        self.unprocessed.extend_from_slice(chunks.remainder());
+
+        self
+    }
+
+    /// Updates the internal state with an input data, unless the message would exceed [`MAX_MESSAGE_LENGTH_BYTES`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the state unchanged if the total length of the message would exceed the maximum.
+    pub fn try_update<T>(&mut self, data: T) -> Result<&mut Self, MessageLengthError>
+    where
+        T: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+        match self.processed_bytes().checked_add(data.len() as u64) {
+            Some(length) if length <= MAX_MESSAGE_LENGTH_BYTES => Ok(self.update(data)),
+            _ => Err(MessageLengthError),
+        }
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let state = self.finalize_with_marker(0x01);
+        Finalize { state }
+    }
+
+    /// Pads the unprocessed data with the given marker byte and returns the final state.
+    ///
+    /// Tiger and Tiger2 differ only in the first padding byte, so this is shared with the [`tiger2`](crate::tiger2) module.
+    pub(crate) fn finalize_with_marker(&self, marker: u8) -> State {
+        assert!(
+            self.unprocessed.len() < BLOCK_LENGTH_BYTES,
+            "unprocessed data length should be less than block length"
+        );
+
+        let length = {
+            let length = self.processed.wrapping_add(self.unprocessed.len() as u64);
+            let length = length.wrapping_mul(8); // convert byte-length into bits-length
+            length.to_le_bytes()
+        };
+
+        let mut padding = [0u8; BLOCK_LENGTH_BYTES * 2];
+        padding[..self.unprocessed.len()].copy_from_slice(&self.unprocessed);
+        padding[self.unprocessed.len()] = marker;
+        let padding = if (self.unprocessed.len() + 1 + length.len()) <= BLOCK_LENGTH_BYTES {
+            &mut padding[..BLOCK_LENGTH_BYTES]
+        } else {
+            &mut padding[..]
+        };
+        let offset = padding.len() - length.len();
+        padding[offset..].copy_from_slice(&length);
+
+        padding
+            .chunks_exact(BLOCK_LENGTH_BYTES)
+            .fold(self.state, |state, chunk| {
+                let block = Block::try_from(chunk)
+                    .expect("padding length must be multiple of block length")
+                    .into();
+                state.update(block)
+            })
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        self.state = self.state.reset();
+        self.unprocessed.clear();
+        self.processed = 0;
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+
+    /// Returns the number of bytes passed to the hash so far, including the buffered ones.
+    #[must_use]
+    pub fn processed_bytes(&self) -> u64 {
+        self.processed.wrapping_add(self.unprocessed.len() as u64)
+    }
+
+    /// Returns the number of bytes buffered until a whole block is available.
+    ///
+    /// Updating with chunks that are multiples of the block length keeps the buffer empty.
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.unprocessed.len()
+    }
+
+    /// Returns `true` if no data is buffered, i.e. all bytes passed so far form whole blocks.
+    #[must_use]
+    pub fn is_block_aligned(&self) -> bool {
+        self.unprocessed.is_empty()
+    }
+
+    /// Exports the in-progress state in the versioned binary format described in the [crate documentation](crate#state-export).
+    #[must_use]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.export_state_as("tiger")
+    }
+
+    /// Imports an in-progress state exported by [`export_state`](Self::export_state), e.g. in another process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid exported state of this hash function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::tiger;
+    ///
+    /// let state = tiger::default().update("example ").export_state();
+    /// let digest = tiger::Update::import_state(&state)
+    ///     .unwrap()
+    ///     .update("data")
+    ///     .digest();
+    /// assert_eq!(digest, tiger::hash("example data"));
+    /// ```
+    pub fn import_state(bytes: &[u8]) -> Result<Self, StateError> {
+        Self::import_state_as(bytes, "tiger")
+    }
+
+    /// Exports the in-progress state under the given identifier.
+    ///
+    /// Tiger and Tiger2 share the in-progress state, so this is shared with the [`tiger2`](crate::tiger2) module.
+    pub(crate) fn export_state_as(&self, name: &str) -> Vec<u8> {
+        let State { a, b, c } = self.state;
+        Writer::new(name)
+            .u64s(&[a, b, c])
+            .finish(self.processed, &self.unprocessed)
+    }
+
+    /// Imports an in-progress state exported under the given identifier.
+    pub(crate) fn import_state_as(bytes: &[u8], name: &str) -> Result<Self, StateError> {
+        let mut reader = Reader::new(bytes, name)?;
+        let [a, b, c] = reader.u64s()?;
+        let (processed, unprocessed) = reader.finish(BLOCK_LENGTH_BYTES)?;
+        Ok(Self {
+            state: State::from_raw(a, b, c),
+            unprocessed: Buffer::from_slice(unprocessed),
+            processed,
+        })
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let state = u.arbitrary()?;
+        // Full blocks are processed immediately, so less than a block is buffered.
+        let length = u.int_in_range(0..=BLOCK_LENGTH_BYTES - 1)?;
+        let unprocessed = Buffer::from_slice(u.bytes(length)?);
+        let processed = u.arbitrary::<u64>()?;
+        let processed = processed - processed % BLOCK_LENGTH_BYTES as u64;
+        Ok(Self {
+            state,
+            unprocessed,
+            processed,
+        })
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger;
+///
+/// // Create a new hash instance
+/// let mut hash = tiger::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+        self.digest_into(&mut digest);
+        Digest::new(digest)
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        for (bytes, word) in out.chunks_exact_mut(8).zip(self.state.digest()) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    /// Resets the hash state to the in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::new()
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the Tiger reference implementation.
+    #[test]
+    fn reference() {
+        assert_eq!(
+            hash("").to_hex_lowercase(),
+            "3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3"
+        );
+        assert_eq!(
+            hash("a").to_hex_lowercase(),
+            "77befbef2e7ef8ab2ec8f93bf587a7fc613e247f5f247809"
+        );
+        assert_eq!(
+            hash("abc").to_hex_lowercase(),
+            "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93"
+        );
+        assert_eq!(
+            hash("message digest").to_hex_lowercase(),
+            "d981f8cb78201a950dcf3048751e441c517fca1aa55a29f6"
+        );
+        assert_eq!(
+            hash("abcdefghijklmnopqrstuvwxyz").to_hex_lowercase(),
+            "1714a472eee57d30040412bfcc55032a0b11602ff37beee9"
+        );
+        assert_eq!(
+            hash("The quick brown fox jumps over the lazy dog").to_hex_lowercase(),
+            "6d12a41e72e644f017b6f0e2f7b44c6285f06dd5d2c5b075"
+        );
+    }
+}
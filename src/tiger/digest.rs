@@ -0,0 +1,24 @@
+//! Module containing items related to the Tiger digest.
+
+/// Digest length in bits.
+pub const LENGTH_BITS: usize = 192;
+/// Digest length in bytes.
+pub const LENGTH_BYTES: usize = LENGTH_BITS / 8;
+/// Digest length in words (double bytes).
+pub const LENGTH_WORDS: usize = LENGTH_BYTES / 2;
+/// Digest length in double words (quadruple bytes).
+pub const LENGTH_DWORDS: usize = LENGTH_WORDS / 2;
+/// Digest length in quadruple words (octuple bytes).
+pub const LENGTH_QWORDS: usize = LENGTH_DWORDS / 2;
+/// Digest length in hexadecimal format.
+pub const LENGTH_HEX: usize = LENGTH_BYTES * 2;
+
+/// Marker type of the Tiger digest.
+///
+/// It tags [`Digest`] so Tiger digests are distinct from digests of other hash functions with the same length.
+pub enum Tiger {}
+
+/// A hash digest.
+///
+/// Check [`tiger`](super) for more information.
+pub type Digest = crate::digest::Digest<Tiger, LENGTH_BYTES>;
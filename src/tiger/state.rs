@@ -0,0 +1,218 @@
+//! Module contains items related to the [`State`] structure which allows to the direct Tiger state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::tiger;
+//!
+//! // Create new state
+//! let mut state = tiger::state::default();
+//!
+//! // By default it returns initialization values
+//! assert_eq!(
+//!     state.digest(),
+//!     [0x0123456789ABCDEF, 0xFEDCBA9876543210, 0xF096A5B4C3B2E187]
+//! );
+//!
+//! // Create a block of data with proper padding
+//! let mut block = [0u8; tiger::BLOCK_LENGTH_BYTES];
+//! block[0] = 0x01;
+//! let data = tiger::Block::new(block).into();
+//!
+//! // Update state and own new value
+//! state = state.update(data);
+//!
+//! // Proper digest of empty input
+//! assert_eq!(
+//!     state.digest(),
+//!     [0x24F0130C63AC9332, 0x16166E76B1BB925F, 0xF373DE2D49584E7A]
+//! );
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(
+//!     state.digest(),
+//!     [0x0123456789ABCDEF, 0xFEDCBA9876543210, 0xF096A5B4C3B2E187]
+//! );
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::block::LENGTH_QWORDS as BLOCK_LENGTH_QWORDS;
+use super::digest::LENGTH_QWORDS as DIGEST_LENGTH_QWORDS;
+use super::sbox::{T1, T2, T3, T4};
+
+#[allow(clippy::unreadable_literal)]
+const A: u64 = 0x0123456789ABCDEF;
+#[allow(clippy::unreadable_literal)]
+const B: u64 = 0xFEDCBA9876543210;
+#[allow(clippy::unreadable_literal)]
+const C: u64 = 0xF096A5B4C3B2E187;
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// Returns the registers a, b and c after processing a single block, including the feedforward.
+///
+/// **Warning!** Input block must be in the little-endian byte order.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger;
+///
+/// let state = tiger::state::new().digest();
+/// let block = tiger::Block::new([0u8; tiger::BLOCK_LENGTH_BYTES]).into();
+/// assert_eq!(
+///     tiger::state::compress(state, block),
+///     tiger::state::new().update(block).digest()
+/// );
+/// ```
+#[must_use]
+pub const fn compress(
+    state: [u64; DIGEST_LENGTH_QWORDS],
+    block: [u64; BLOCK_LENGTH_QWORDS],
+) -> [u64; DIGEST_LENGTH_QWORDS] {
+    let [a, b, c] = state;
+    State::from_raw(a, b, c).update(block).digest()
+}
+
+/// A low-level hash state.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub(crate) a: u64,
+    pub(crate) b: u64,
+    pub(crate) c: u64,
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(A, B, C)
+    }
+
+    /// Creates a state from the raw chaining values, e.g. a custom initialization vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::tiger;
+    ///
+    /// // The default initialization vector
+    /// let state = tiger::State::from_raw(0x0123456789ABCDEF, 0xFEDCBA9876543210, 0xF096A5B4C3B2E187);
+    /// assert_eq!(state, tiger::State::new());
+    /// ```
+    #[must_use]
+    pub const fn from_raw(a: u64, b: u64, c: u64) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Returns modified state with the passed data.
+    ///
+    /// **Warning!** Input block must be in the little-endian byte order.
+    #[must_use]
+    pub const fn update(&self, block: [u64; BLOCK_LENGTH_QWORDS]) -> Self {
+        const fn round(a: u64, b: u64, c: u64, x: u64, mul: u64) -> (u64, u64, u64) {
+            let c = c ^ x;
+            let a = a.wrapping_sub(
+                T1[(c & 0xFF) as usize]
+                    ^ T2[((c >> 16) & 0xFF) as usize]
+                    ^ T3[((c >> 32) & 0xFF) as usize]
+                    ^ T4[((c >> 48) & 0xFF) as usize],
+            );
+            let b = b.wrapping_add(
+                T4[((c >> 8) & 0xFF) as usize]
+                    ^ T3[((c >> 24) & 0xFF) as usize]
+                    ^ T2[((c >> 40) & 0xFF) as usize]
+                    ^ T1[((c >> 56) & 0xFF) as usize],
+            );
+            let b = b.wrapping_mul(mul);
+            (a, b, c)
+        }
+
+        #[allow(clippy::many_single_char_names)]
+        const fn pass(a: u64, b: u64, c: u64, x: &[u64; BLOCK_LENGTH_QWORDS], mul: u64) -> (u64, u64, u64) {
+            let (a, b, c) = round(a, b, c, x[0], mul);
+            let (b, c, a) = round(b, c, a, x[1], mul);
+            let (c, a, b) = round(c, a, b, x[2], mul);
+            let (a, b, c) = round(a, b, c, x[3], mul);
+            let (b, c, a) = round(b, c, a, x[4], mul);
+            let (c, a, b) = round(c, a, b, x[5], mul);
+            let (a, b, c) = round(a, b, c, x[6], mul);
+            let (b, c, a) = round(b, c, a, x[7], mul);
+            (a, b, c)
+        }
+
+        #[allow(clippy::unreadable_literal)]
+        const fn schedule(mut x: [u64; BLOCK_LENGTH_QWORDS]) -> [u64; BLOCK_LENGTH_QWORDS] {
+            x[0] = x[0].wrapping_sub(x[7] ^ 0xA5A5A5A5A5A5A5A5);
+            x[1] ^= x[0];
+            x[2] = x[2].wrapping_add(x[1]);
+            x[3] = x[3].wrapping_sub(x[2] ^ (!x[1] << 19));
+            x[4] ^= x[3];
+            x[5] = x[5].wrapping_add(x[4]);
+            x[6] = x[6].wrapping_sub(x[5] ^ (!x[4] >> 23));
+            x[7] ^= x[6];
+            x[0] = x[0].wrapping_add(x[7]);
+            x[1] = x[1].wrapping_sub(x[0] ^ (!x[7] << 19));
+            x[2] ^= x[1];
+            x[3] = x[3].wrapping_add(x[2]);
+            x[4] = x[4].wrapping_sub(x[3] ^ (!x[2] >> 23));
+            x[5] ^= x[4];
+            x[6] = x[6].wrapping_add(x[5]);
+            x[7] = x[7].wrapping_sub(x[6] ^ 0x0123456789ABCDEF);
+            x
+        }
+
+        let Self { a, b, c } = *self;
+
+        let x = block;
+        let (a, b, c) = pass(a, b, c, &x, 5);
+        let x = schedule(x);
+        let (c, a, b) = pass(c, a, b, &x, 7);
+        let x = schedule(x);
+        let (b, c, a) = pass(b, c, a, &x, 9);
+
+        // Feedforward
+        let a = a ^ self.a;
+        let b = b.wrapping_sub(self.b);
+        let c = c.wrapping_add(self.c);
+
+        Self::from_raw(a, b, c)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns a digest.
+    #[must_use]
+    pub const fn digest(&self) -> [u64; DIGEST_LENGTH_QWORDS] {
+        let Self { a, b, c } = *self;
+        [a, b, c]
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
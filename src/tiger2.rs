@@ -0,0 +1,359 @@
+//! This module is optional and can be enabled using the `tiger2` Cargo feature.
+//!
+//! An implementation of the Tiger2 hash function.
+//!
+//! Tiger2 is identical to [Tiger](crate::tiger) except for the padding, which starts with the `0x80` byte as in MD5 and SHA-1 instead of `0x01`. Both functions share the compression function, so the [`State`] and [`Digest`] types are reused from the [`tiger`](crate::tiger) module.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::tiger2;
+//!
+//! let digest = tiger2::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "61b8036059e2c0136396a184ff3ddb1ae5704df2c33b69df"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::tiger2;
+//!
+//! let digest = tiger2::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "61b8036059e2c0136396a184ff3ddb1ae5704df2c33b69df"
+//! );
+//! ```
+//!
+//! # Disclaimer
+//!
+//! See the [`tiger`](crate::tiger) module for the byte order and security notes, which apply to Tiger2 as well.
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+use crate::error::StateError;
+pub use crate::tiger::{Block, Digest, State, BLOCK_LENGTH_BYTES, DIGEST_LENGTH_BYTES};
+use crate::verify::Expected;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger2;
+///
+/// let digest = tiger2::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "4441be75f6018773c206c22745374b924aa8313fef919f41"
+/// );
+///
+/// let digest = tiger2::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "d3897d7d2df91796bcbda3fb51eb449a56620c22e3d2ab26"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger2;
+///
+/// let digest = tiger2::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "4441be75f6018773c206c22745374b924aa8313fef919f41"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger2;
+///
+/// let digest = tiger2::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "f68d7bc5af4b43a06e048d7829560d4a9415658bb0b1f3bf"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger2;
+///
+/// let digest = tiger2::hash("data");
+/// assert!(tiger2::verify("data", digest));
+/// assert!(tiger2::verify("data", digest.to_hex_uppercase()));
+/// assert!(!tiger2::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger2;
+///
+/// // Create a new hash instance
+/// let mut hash = tiger2::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "d3897d7d2df91796bcbda3fb51eb449a56620c22e3d2ab26"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "4441be75f6018773c206c22745374b924aa8313fef919f41"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Update {
+    inner: crate::tiger::Update,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        let inner = crate::tiger::Update::new();
+        Self { inner }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// To achieve maximum performance, the length of incoming data parts should be a multiple of the block length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.update(data);
+        self
+    }
+
+    /// Applies padding and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let state = self.inner.finalize_with_marker(0x80);
+        Finalize { state }
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        self.inner.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+
+    /// Returns the number of bytes passed to the hash so far, including the buffered ones.
+    #[must_use]
+    pub fn processed_bytes(&self) -> u64 {
+        self.inner.processed_bytes()
+    }
+
+    /// Returns the number of bytes buffered until a whole block is available.
+    ///
+    /// Updating with chunks that are multiples of the block length keeps the buffer empty.
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.inner.buffered_len()
+    }
+
+    /// Returns `true` if no data is buffered, i.e. all bytes passed so far form whole blocks.
+    #[must_use]
+    pub fn is_block_aligned(&self) -> bool {
+        self.inner.is_block_aligned()
+    }
+
+    /// Exports the in-progress state in the versioned binary format described in the [crate documentation](crate#state-export).
+    #[must_use]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export_state_as("tiger2")
+    }
+
+    /// Imports an in-progress state exported by [`export_state`](Self::export_state), e.g. in another process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid exported state of this hash function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::tiger2;
+    ///
+    /// let state = tiger2::default().update("example ").export_state();
+    /// let digest = tiger2::Update::import_state(&state)
+    ///     .unwrap()
+    ///     .update("data")
+    ///     .digest();
+    /// assert_eq!(digest, tiger2::hash("example data"));
+    /// ```
+    pub fn import_state(bytes: &[u8]) -> Result<Self, StateError> {
+        let inner = crate::tiger::Update::import_state_as(bytes, "tiger2")?;
+        Ok(Self { inner })
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tiger2;
+///
+/// // Create a new hash instance
+/// let mut hash = tiger2::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "f68d7bc5af4b43a06e048d7829560d4a9415658bb0b1f3bf"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    state: State,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+        self.digest_into(&mut digest);
+        Digest::new(digest)
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        for (bytes, word) in out.chunks_exact_mut(8).zip(self.state.digest()) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    /// Resets the hash state to the in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::new()
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the Tiger reference implementation.
+    #[test]
+    fn reference() {
+        assert_eq!(
+            hash("").to_hex_lowercase(),
+            "4441be75f6018773c206c22745374b924aa8313fef919f41"
+        );
+        assert_eq!(
+            hash("The quick brown fox jumps over the lazy dog").to_hex_lowercase(),
+            "976abff8062a2e9dcea3a1ace966ed9c19cb85558b4976d8"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Module containing items related to truncated digests.
+//!
+//! Some protocols use only the leading bytes of a digest, e.g. the first 8 or 16 bytes of SHA-256. A [`TruncatedDigest`] keeps the number of bytes in its type, so the truncated values cannot be mixed up with each other or with full digests.
+//!
+//! Digests of this crate are truncated with their `truncate` functions, other digests, e.g. the ones of the external SHA-2 crate, with [`TruncatedDigest::from_prefix`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::sha2_256;
+//! use chksum_hash::truncated::TruncatedDigest;
+//!
+//! let digest = sha2_256::hash("data");
+//! let truncated = TruncatedDigest::<8>::from_prefix(digest.as_ref());
+//! assert_eq!(truncated.to_hex_lowercase(), "3a6eb0790f39ac87");
+//! ```
+//!
+//! Truncations of digests of this crate to zero bytes or to more bytes than the digest has do not compile.
+//!
+//! ```rust,compile_fail
+//! use chksum_hash::md4;
+//!
+//! let digest = md4::hash("data");
+//! let truncated = digest.truncate::<17>();
+//! ```
+
+use std::fmt::{self, Display, Formatter, LowerHex, UpperHex};
+use std::slice;
+
+use crate::error::LengthError;
+use crate::hex::{self, Case};
+
+/// A digest truncated to its first `M` bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TruncatedDigest<const M: usize>([u8; M]);
+
+impl<const M: usize> TruncatedDigest<M> {
+    /// Creates a new truncated digest.
+    #[must_use]
+    pub const fn new(digest: [u8; M]) -> Self {
+        Self(digest)
+    }
+
+    /// Creates a new truncated digest from the first `M` bytes of the digest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is zero or greater than the length of the digest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::truncated::TruncatedDigest;
+    ///
+    /// let truncated = TruncatedDigest::<2>::from_prefix(&[0xDE, 0xAD, 0xBE, 0xEF]);
+    /// assert_eq!(truncated, [0xDE, 0xAD]);
+    /// ```
+    #[must_use]
+    pub fn from_prefix(digest: &[u8]) -> Self {
+        assert!(
+            M > 0 && M <= digest.len(),
+            "truncated digest length must be between 1 and {} bytes",
+            digest.len()
+        );
+        let mut truncated = [0u8; M];
+        truncated.copy_from_slice(&digest[..M]);
+        Self(truncated)
+    }
+
+    /// Creates a new truncated digest from the first `M` bytes of the digest, e.g. of a digest read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `M` is zero or greater than the length of the digest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chksum_hash::truncated::TruncatedDigest;
+    ///
+    /// let truncated = TruncatedDigest::<2>::try_from_prefix(&[0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+    /// assert_eq!(truncated, [0xDE, 0xAD]);
+    ///
+    /// assert!(TruncatedDigest::<8>::try_from_prefix(&[0xDE, 0xAD, 0xBE, 0xEF]).is_err());
+    /// ```
+    pub fn try_from_prefix(digest: &[u8]) -> Result<Self, LengthError> {
+        if M > 0 && M <= digest.len() {
+            Ok(Self::from_prefix(digest))
+        } else {
+            Err(LengthError { length: digest.len() })
+        }
+    }
+
+    /// Returns a reference to the bytes of the truncated digest.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; M] {
+        &self.0
+    }
+
+    /// Returns the bytes of the truncated digest.
+    #[must_use]
+    pub const fn into_inner(self) -> [u8; M] {
+        self.0
+    }
+
+    /// Returns an iterator over the bytes of the truncated digest.
+    pub fn iter(&self) -> slice::Iter<'_, u8> {
+        self.0.iter()
+    }
+
+    /// Returns a string in the lowercase hexadecimal representation.
+    #[must_use]
+    pub fn to_hex_lowercase(&self) -> String {
+        format!("{self:x}")
+    }
+
+    /// Returns a string in the uppercase hexadecimal representation.
+    #[must_use]
+    pub fn to_hex_uppercase(&self) -> String {
+        format!("{self:X}")
+    }
+
+    /// Writes the hexadecimal representation in the given case to the formatter, padded and prefixed as requested.
+    fn fmt_hex(&self, f: &mut Formatter<'_>, case: Case, prefix: &str) -> fmt::Result {
+        let mut digest = String::with_capacity(M * 2);
+        hex::write(&mut digest, &self.0, case)?;
+        if f.alternate() {
+            f.pad_integral(true, prefix, &digest)
+        } else {
+            f.pad(&digest)
+        }
+    }
+}
+
+impl<const M: usize> AsRef<[u8]> for TruncatedDigest<M> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const M: usize> From<[u8; M]> for TruncatedDigest<M> {
+    fn from(digest: [u8; M]) -> Self {
+        Self::new(digest)
+    }
+}
+
+impl<const M: usize> From<TruncatedDigest<M>> for [u8; M] {
+    fn from(digest: TruncatedDigest<M>) -> Self {
+        digest.into_inner()
+    }
+}
+
+impl<const M: usize> Display for TruncatedDigest<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerHex::fmt(self, f)
+    }
+}
+
+impl<const M: usize> LowerHex for TruncatedDigest<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, Case::Lower, "0x")
+    }
+}
+
+impl<const M: usize> UpperHex for TruncatedDigest<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, Case::Upper, "0X")
+    }
+}
+
+impl<const M: usize> PartialEq<str> for TruncatedDigest<M> {
+    fn eq(&self, other: &str) -> bool {
+        hex::matches(&self.0, other)
+    }
+}
+
+impl<const M: usize> PartialEq<&str> for TruncatedDigest<M> {
+    fn eq(&self, other: &&str) -> bool {
+        hex::matches(&self.0, other)
+    }
+}
+
+impl<const M: usize> PartialEq<[u8; M]> for TruncatedDigest<M> {
+    fn eq(&self, other: &[u8; M]) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Fails the compilation of truncations to `M` bytes of digests of at most `N` bytes, unless `M` is between 1 and `N`.
+struct Bounds<const M: usize, const N: usize>;
+
+impl<const M: usize, const N: usize> Bounds<M, N> {
+    const CHECK: () = assert!(
+        M > 0 && M <= N,
+        "truncated digest length must be between 1 and the digest length"
+    );
+}
+
+/// Truncates a digest of at most `N` bytes to its first `M` bytes, failing the compilation unless `M` is between 1 and `N`.
+///
+/// It panics if `M` is greater than the length of the digest, which only happens for digests of variable length.
+pub(crate) fn truncate<const M: usize, const N: usize>(digest: &[u8]) -> TruncatedDigest<M> {
+    let () = Bounds::<M, N>::CHECK;
+    TruncatedDigest::from_prefix(digest)
+}
@@ -0,0 +1,364 @@
+//! This module is optional and can be enabled using the `tth` Cargo feature.
+//!
+//! An implementation of the Tiger Tree Hash (TTH).
+//!
+//! The hash follows the [THEX](https://adc.sourceforge.io/draft-jchapweske-thex-02.html) format: the input is split into leaves of [`LEAF_LENGTH_BYTES`] (1 KiB) and the leaves and the nodes are hashed with [`tiger`](crate::tiger) in the [`merkle`](crate::merkle) tree with the default prefixes. The hash identifies files in Direct Connect clients, like DC++, and in `magnet:` links, where it is written in base32, see [`Digest::to_base32`].
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::tth;
+//!
+//! let digest = tth::hash("example data");
+//! assert_eq!(
+//!     digest.to_base32(),
+//!     "VPDQROZSKJE5DTF2ID6ZZGSBRLJEULE5IXE7QRY"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::tth;
+//!
+//! let digest = tth::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_base32(),
+//!     "VPDQROZSKJE5DTF2ID6ZZGSBRLJEULE5IXE7QRY"
+//! );
+//! ```
+//!
+//! # Internal Buffering
+//!
+//! The data is passed to the Tiger hash of the current leaf and the tree keeps at most one digest per level, so the memory usage grows logarithmically with the input length.
+
+pub mod digest;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+use crate::verify::Expected;
+use crate::{merkle, tiger};
+
+/// Leaf length in bytes.
+pub const LEAF_LENGTH_BYTES: usize = 1024;
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tth;
+///
+/// let digest = tth::new().digest();
+/// assert_eq!(
+///     digest.to_base32(),
+///     "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ"
+/// );
+///
+/// let digest = tth::new().update([0x00]).digest();
+/// assert_eq!(
+///     digest.to_base32(),
+///     "VK54ZIEEVTWNAUI5D5RDFIL37LX2IQNSTAXFKSA"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tth;
+///
+/// let digest = tth::default().digest();
+/// assert_eq!(
+///     digest.to_base32(),
+///     "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tth;
+///
+/// // Test vectors of THEX
+/// let digest = tth::hash([b'A'; 1024]);
+/// assert_eq!(
+///     digest.to_base32(),
+///     "L66Q4YVNAFWVS23X2HJIRA5ZJ7WXR3F26RSASFA"
+/// );
+///
+/// let digest = tth::hash([b'A'; 1025]);
+/// assert_eq!(
+///     digest.to_base32(),
+///     "PZMRYHGY6LTBEH63ZWAHDORHSYTLO4LEFUIKHWY"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tth;
+///
+/// let digest = tth::hash("data");
+/// assert!(tth::verify("data", digest));
+/// assert!(tth::verify("data", digest.to_hex_uppercase()));
+/// assert!(!tth::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tth;
+///
+/// // Create a new hash instance
+/// let mut hash = tth::Update::new();
+///
+/// // Fill with data
+/// hash.update([0x00]);
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_base32(),
+///     "VK54ZIEEVTWNAUI5D5RDFIL37LX2IQNSTAXFKSA"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_base32(),
+///     "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Update {
+    leaf: tiger::Update,
+    leaf_length: usize,
+    tree: merkle::Tree<tiger::Update>,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut leaf = tiger::Update::new();
+        leaf.update(merkle::LEAF_PREFIX);
+        Self {
+            leaf,
+            leaf_length: 0,
+            tree: merkle::Tree::new(),
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// The data is passed to the Tiger hash of the current leaf, see [`tiger::Update::update`] for performance notes.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let mut data = data.as_ref();
+        while !data.is_empty() {
+            let missing = LEAF_LENGTH_BYTES - self.leaf_length;
+            let (fillment, remainder) = data.split_at(missing.min(data.len()));
+            self.leaf.update(fillment);
+            self.leaf_length += fillment.len();
+            data = remainder;
+
+            // Full leaves are processed immediately, so the current leaf is empty only at leaf boundaries.
+            if self.leaf_length == LEAF_LENGTH_BYTES {
+                self.tree.push_digest(self.leaf.digest());
+                self.leaf.reset().update(merkle::LEAF_PREFIX);
+                self.leaf_length = 0;
+            }
+        }
+        self
+    }
+
+    /// Combines the leaf digests and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        // The current leaf is partial, or the input is empty and it is hashed as a single empty leaf.
+        let digest = if self.leaf_length > 0 || self.tree.is_empty() {
+            let mut tree = self.tree.clone();
+            tree.push_digest(self.leaf.digest());
+            tree.root()
+        } else {
+            self.tree.root()
+        };
+        Finalize { digest }
+    }
+
+    /// Resets the internal state to default values.
+    pub fn reset(&mut self) -> &mut Self {
+        self.leaf.reset().update(merkle::LEAF_PREFIX);
+        self.leaf_length = 0;
+        self.tree.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Full leaves are processed immediately, so the current leaf is never full.
+        let leaf_length = u.int_in_range(0..=LEAF_LENGTH_BYTES - 1)?;
+        let mut leaf = tiger::Update::new();
+        leaf.update(merkle::LEAF_PREFIX).update(u.bytes(leaf_length)?);
+        let mut tree = merkle::Tree::new();
+        tree.arbitrary_leaves(u)?;
+        Ok(Self {
+            leaf,
+            leaf_length,
+            tree,
+        })
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::tth;
+///
+/// // Create a new hash instance
+/// let mut hash = tth::Update::new();
+///
+/// // Fill with data
+/// hash.update([b'A'; 1024]);
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_base32(),
+///     "L66Q4YVNAFWVS23X2HJIRA5ZJ7WXR3F26RSASFA"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    digest: tiger::Digest,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+        digest.copy_from_slice(self.digest.as_ref());
+        Digest::new(digest)
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        out.copy_from_slice(self.digest.as_ref());
+    }
+
+    /// Resets the hash state to the in-progress state.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        Update::new()
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the THEX specification.
+    #[test]
+    fn thex() {
+        assert_eq!(hash("").to_base32(), "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ");
+        assert_eq!(hash([0u8]).to_base32(), "VK54ZIEEVTWNAUI5D5RDFIL37LX2IQNSTAXFKSA");
+        assert_eq!(hash("abc").to_base32(), "ASD4UJSEH5M47PDYB46KBTSQTSGDKLBHYXOMUIA");
+    }
+}
@@ -0,0 +1,162 @@
+//! Module containing items related to the verification of digests.
+//!
+//! Every hash function module of this crate provides a `verify` function, which computes the digest of the input and compares it with the expected one in constant time, so the comparison does not leak how many leading bytes match. The expected digest can be given as a digest, a byte slice or array, or a hexadecimal string, see [`Expected`]. The [`with`] function does the same for any hash function, e.g. for the ones of the external crates.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::{md4, sha1, verify};
+//!
+//! assert!(md4::verify("data", "539080ba278cf4cf4db2e4a32642ff30"));
+//! assert!(verify::with::<sha1::Update>("data", sha1::hash("data")));
+//! ```
+
+use chksum_hash_core as core;
+
+use crate::hex;
+#[cfg(feature = "md5")]
+use crate::md5;
+#[cfg(feature = "sha1")]
+use crate::sha1;
+#[cfg(feature = "sha2-224")]
+use crate::sha2_224;
+#[cfg(feature = "sha2-256")]
+use crate::sha2_256;
+#[cfg(feature = "sha2-384")]
+use crate::sha2_384;
+#[cfg(feature = "sha2-512")]
+use crate::sha2_512;
+
+/// An expected digest that can be compared with a computed one in constant time.
+///
+/// Digests, byte slices, arrays and vectors are compared as bytes. Strings are decoded from the hexadecimal representation first, see [`hex::decode`], and never match if they are malformed.
+pub trait Expected {
+    /// Returns `true` if the computed digest is the expected one.
+    ///
+    /// The time of the comparison depends only on the lengths of the digests, not on their content.
+    fn matches_digest(&self, digest: &[u8]) -> bool;
+}
+
+impl<T> Expected for &T
+where
+    T: Expected + ?Sized,
+{
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        (**self).matches_digest(digest)
+    }
+}
+
+impl Expected for [u8] {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        constant_time_eq(self, digest)
+    }
+}
+
+impl<const N: usize> Expected for [u8; N] {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        constant_time_eq(self, digest)
+    }
+}
+
+impl Expected for Vec<u8> {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        constant_time_eq(self, digest)
+    }
+}
+
+impl Expected for str {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        hex::decode(self).map_or(false, |expected| constant_time_eq(&expected, digest))
+    }
+}
+
+impl Expected for String {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        self.as_str().matches_digest(digest)
+    }
+}
+
+#[cfg(feature = "md5")]
+impl Expected for md5::Digest {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        constant_time_eq(self.as_ref(), digest)
+    }
+}
+
+#[cfg(feature = "sha1")]
+impl Expected for sha1::Digest {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        constant_time_eq(self.as_ref(), digest)
+    }
+}
+
+#[cfg(feature = "sha2-224")]
+impl Expected for sha2_224::Digest {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        constant_time_eq(self.as_ref(), digest)
+    }
+}
+
+#[cfg(feature = "sha2-256")]
+impl Expected for sha2_256::Digest {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        constant_time_eq(self.as_ref(), digest)
+    }
+}
+
+#[cfg(feature = "sha2-384")]
+impl Expected for sha2_384::Digest {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        constant_time_eq(self.as_ref(), digest)
+    }
+}
+
+#[cfg(feature = "sha2-512")]
+impl Expected for sha2_512::Digest {
+    fn matches_digest(&self, digest: &[u8]) -> bool {
+        constant_time_eq(self.as_ref(), digest)
+    }
+}
+
+/// Computes the digest of the given input with the hash function and compares it with the expected digest in constant time.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::{sha2_256, verify};
+///
+/// let expected = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+/// assert!(verify::with::<sha2_256::Update>("", expected));
+/// assert!(!verify::with::<sha2_256::Update>("data", expected));
+/// ```
+#[must_use]
+pub fn with<H>(data: impl AsRef<[u8]>, expected: impl Expected) -> bool
+where
+    H: core::Update,
+    H::Digest: AsRef<[u8]>,
+{
+    expected.matches_digest(core::hash::<H>(data).as_ref())
+}
+
+/// Compares the byte slices in constant time with respect to their content.
+///
+/// Slices of different lengths are never equal, the lengths are not considered secret.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::verify;
+///
+/// assert!(verify::constant_time_eq(b"digest", b"digest"));
+/// assert!(!verify::constant_time_eq(b"digest", b"Digest"));
+/// assert!(!verify::constant_time_eq(b"digest", b"digest\0"));
+/// ```
+#[must_use]
+pub fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .fold(0, |difference, (left, right)| difference | (left ^ right))
+            == 0
+}
@@ -0,0 +1,332 @@
+//! This module is optional and can be enabled using any of the `xxh3-64` or `xxh3-128` Cargo features.
+//!
+//! Items shared by the XXH3 hash functions.
+//!
+//! XXH3 is a fast non-cryptographic hash function of the xxHash family. Inputs of up to 240 bytes are hashed in one go by dedicated routines, longer inputs are split into 64-byte stripes which are mixed into eight 64-bit accumulators. Both variants share the long-input processing and differ only in the way the result is extracted, see the [`xxh3_64`](crate::xxh3_64) and [`xxh3_128`](crate::xxh3_128) modules.
+//!
+//! # Customization
+//!
+//! The hash can be customized with either a 64-bit seed or a secret of at least [`SECRET_MIN_LENGTH_BYTES`] bytes. Seeding is cheap, a secret allows for more entropy. For long inputs, a seeded hash is the same as a hash with the secret derived by [`secret::from_seed`].
+//!
+//! # Disclaimer
+//!
+//! XXH3 is not a cryptographic hash function. It is designed for hash tables, deduplication and checksumming, and it must not be used where resistance to deliberate collisions is required.
+
+pub mod secret;
+pub mod state;
+
+use std::borrow::Cow;
+use std::mem;
+
+#[doc(inline)]
+pub use self::secret::{DEFAULT_SECRET, MIN_LENGTH_BYTES as SECRET_MIN_LENGTH_BYTES};
+#[doc(inline)]
+pub use self::state::State;
+use crate::buffer::Buffer;
+
+/// Stripe length in bytes.
+pub const STRIPE_LENGTH_BYTES: usize = 64;
+
+/// Maximum length of inputs hashed without the stripe processing.
+pub(crate) const MIDSIZE_MAX_LENGTH_BYTES: usize = 240;
+
+/// Capacity of the internal buffer, a midsize input completed to whole stripes.
+const BUFFER_LENGTH_BYTES: usize =
+    (MIDSIZE_MAX_LENGTH_BYTES + STRIPE_LENGTH_BYTES - 1) / STRIPE_LENGTH_BYTES * STRIPE_LENGTH_BYTES;
+
+/// Number of secret bytes consumed by each following stripe.
+const SECRET_CONSUME_RATE: usize = 8;
+/// Offset from the end of the secret to the key of the last stripe.
+const SECRET_LAST_STRIPE_OFFSET: usize = STRIPE_LENGTH_BYTES + 7;
+/// Offset of the key used to merge the accumulators.
+pub(crate) const SECRET_MERGE_OFFSET: usize = 11;
+
+pub(crate) const PRIME32_1: u64 = 0x9E37_79B1;
+pub(crate) const PRIME32_2: u64 = 0x85EB_CA77;
+pub(crate) const PRIME32_3: u64 = 0xC2B2_AE3D;
+pub(crate) const PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+pub(crate) const PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+pub(crate) const PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+pub(crate) const PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+pub(crate) const PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+/// The message-processing part of XXH3 with an internal buffer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Engine {
+    state: State,
+    secret: Cow<'static, [u8]>,
+    seed: u64,
+    unprocessed: Buffer<BUFFER_LENGTH_BYTES>,
+    processed: u64,
+    stripes: usize,
+    previous: [u8; STRIPE_LENGTH_BYTES],
+}
+
+/// The data needed to produce the result once all input is known.
+pub(crate) enum Output<'a> {
+    /// The whole input, which is hashed in one go.
+    Short {
+        data: &'a [u8],
+        secret: &'a [u8],
+        seed: u64,
+    },
+    /// The accumulators after processing of all stripes.
+    Long {
+        state: State,
+        secret: &'a [u8],
+        length: u64,
+    },
+}
+
+impl Engine {
+    /// Creates a new engine with the given seed.
+    pub(crate) fn with_seed(seed: u64) -> Self {
+        // Long inputs use a secret derived from the seed, short inputs combine the seed with the default secret on the fly.
+        let secret = if seed == 0 {
+            Cow::Borrowed(&DEFAULT_SECRET[..])
+        } else {
+            Cow::Owned(secret::from_seed(seed).to_vec())
+        };
+        Self::from_parts(secret, seed)
+    }
+
+    /// Creates a new engine with the given secret.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the secret is shorter than [`SECRET_MIN_LENGTH_BYTES`].
+    pub(crate) fn with_secret(secret: &[u8]) -> Self {
+        assert!(
+            secret.len() >= SECRET_MIN_LENGTH_BYTES,
+            "secret length must be at least {SECRET_MIN_LENGTH_BYTES} bytes"
+        );
+        Self::from_parts(Cow::Owned(secret.to_vec()), 0)
+    }
+
+    fn from_parts(secret: Cow<'static, [u8]>, seed: u64) -> Self {
+        Self {
+            state: State::new(),
+            secret,
+            seed,
+            unprocessed: Buffer::new(),
+            processed: 0,
+            stripes: 0,
+            previous: [0u8; STRIPE_LENGTH_BYTES],
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        let mut data = data;
+
+        if data.is_empty() {
+            return;
+        }
+
+        if self.processed == 0 && self.unprocessed.len() + data.len() <= MIDSIZE_MAX_LENGTH_BYTES {
+            // Short inputs are hashed as a whole, nothing can be processed until the input is known to be long.
+            self.unprocessed.extend_from_slice(data);
+            return;
+        }
+
+        if !self.unprocessed.is_empty() {
+            let missing = (STRIPE_LENGTH_BYTES - self.unprocessed.len() % STRIPE_LENGTH_BYTES) % STRIPE_LENGTH_BYTES;
+            let (fillment, remainder) = data.split_at(missing.min(data.len()));
+            self.unprocessed.extend_from_slice(fillment);
+            if remainder.is_empty() {
+                return;
+            }
+
+            // More data follows, so none of the buffered stripes is the last one.
+            let unprocessed = mem::take(&mut self.unprocessed);
+            for stripe in unprocessed.chunks_exact(STRIPE_LENGTH_BYTES) {
+                (self.state, self.stripes) = self.consume(self.state, self.stripes, stripe);
+            }
+            self.processed = self.processed.wrapping_add(unprocessed.len() as u64);
+            self.previous
+                .copy_from_slice(&unprocessed[unprocessed.len() - STRIPE_LENGTH_BYTES..]);
+            self.unprocessed = unprocessed;
+            self.unprocessed.clear();
+            data = remainder;
+        }
+
+        // Internal buffer is empty, incoming data can be processed without buffering except of the last stripe, which is processed differently.
+        let count = (data.len() - 1) / STRIPE_LENGTH_BYTES * STRIPE_LENGTH_BYTES;
+        let (stripes, remainder) = data.split_at(count);
+        for stripe in stripes.chunks_exact(STRIPE_LENGTH_BYTES) {
+            (self.state, self.stripes) = self.consume(self.state, self.stripes, stripe);
+        }
+        if !stripes.is_empty() {
+            self.processed = self.processed.wrapping_add(stripes.len() as u64);
+            self.previous
+                .copy_from_slice(&stripes[stripes.len() - STRIPE_LENGTH_BYTES..]);
+        }
+        self.unprocessed.extend_from_slice(remainder);
+    }
+
+    /// Processes the remaining data and returns everything needed to produce the result.
+    pub(crate) fn finalize(&self) -> Output<'_> {
+        if self.processed == 0 && self.unprocessed.len() <= MIDSIZE_MAX_LENGTH_BYTES {
+            // A seeded hash uses the default secret, a custom secret is never combined with a seed.
+            let secret = if self.seed == 0 {
+                &self.secret[..]
+            } else {
+                &DEFAULT_SECRET[..]
+            };
+            return Output::Short {
+                data: &self.unprocessed,
+                secret,
+                seed: self.seed,
+            };
+        }
+
+        assert!(
+            !self.unprocessed.is_empty(),
+            "unprocessed data should contain at least the last byte of a long input"
+        );
+
+        let length = self.unprocessed.len();
+        let count = (length - 1) / STRIPE_LENGTH_BYTES * STRIPE_LENGTH_BYTES;
+        let (state, _) = self.unprocessed[..count]
+            .chunks_exact(STRIPE_LENGTH_BYTES)
+            .fold((self.state, self.stripes), |(state, stripes), stripe| {
+                self.consume(state, stripes, stripe)
+            });
+
+        // The last stripe always consists of the last 64 bytes of the input, so it may overlap with already processed data.
+        let mut stripe = [0u8; STRIPE_LENGTH_BYTES];
+        if length >= STRIPE_LENGTH_BYTES {
+            stripe.copy_from_slice(&self.unprocessed[length - STRIPE_LENGTH_BYTES..]);
+        } else {
+            let (previous, unprocessed) = stripe.split_at_mut(STRIPE_LENGTH_BYTES - length);
+            previous.copy_from_slice(&self.previous[length..]);
+            unprocessed.copy_from_slice(&self.unprocessed);
+        }
+        let state = state.accumulate(&stripe, &self.secret[self.secret.len() - SECRET_LAST_STRIPE_OFFSET..]);
+
+        Output::Long {
+            state,
+            secret: &self.secret,
+            length: self.processed.wrapping_add(length as u64),
+        }
+    }
+
+    /// Generates an engine with an arbitrary seed or secret, accumulators and buffered data.
+    #[cfg(feature = "arbitrary")]
+    pub(crate) fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut engine = if u.arbitrary()? {
+            Self::with_seed(u.arbitrary()?)
+        } else {
+            let length = u.int_in_range(SECRET_MIN_LENGTH_BYTES..=DEFAULT_SECRET.len())?;
+            Self::with_secret(u.bytes(length)?)
+        };
+        let processed = u.arbitrary::<u64>()?;
+        let processed = processed - processed % STRIPE_LENGTH_BYTES as u64;
+        if processed == 0 {
+            // Short inputs are buffered as a whole.
+            let buffered = u.int_in_range(0..=MIDSIZE_MAX_LENGTH_BYTES)?;
+            engine.unprocessed = Buffer::from_slice(u.bytes(buffered)?);
+        } else {
+            // The last stripe is processed only by the finalization, so at least one byte is buffered.
+            let buffered = u.int_in_range(1..=STRIPE_LENGTH_BYTES)?;
+            engine.unprocessed = Buffer::from_slice(u.bytes(buffered)?);
+            engine.state = u.arbitrary()?;
+            engine.processed = processed;
+            let stripes_per_block = (engine.secret.len() - STRIPE_LENGTH_BYTES) / SECRET_CONSUME_RATE;
+            #[allow(clippy::cast_possible_truncation)] // the remainder is less than the number of stripes per block
+            let stripes = (processed / STRIPE_LENGTH_BYTES as u64 % stripes_per_block as u64) as usize;
+            engine.stripes = stripes;
+            engine.previous = u.arbitrary()?;
+        }
+        Ok(engine)
+    }
+
+    /// Resets the internal state to default values.
+    pub(crate) fn reset(&mut self) {
+        self.state = self.state.reset();
+        self.unprocessed.clear();
+        self.processed = 0;
+        self.stripes = 0;
+        self.previous = [0u8; STRIPE_LENGTH_BYTES];
+    }
+
+    /// Returns a new engine with the same seed and secret.
+    pub(crate) fn fresh(&self) -> Self {
+        Self::from_parts(self.secret.clone(), self.seed)
+    }
+
+    /// Accumulates a stripe and scrambles the accumulators after each full block of stripes.
+    fn consume(&self, state: State, stripes: usize, stripe: &[u8]) -> (State, usize) {
+        let state = state.accumulate(stripe, &self.secret[stripes * SECRET_CONSUME_RATE..]);
+        let stripes = stripes + 1;
+        if stripes == (self.secret.len() - STRIPE_LENGTH_BYTES) / SECRET_CONSUME_RATE {
+            let state = state.scramble(&self.secret[self.secret.len() - STRIPE_LENGTH_BYTES..]);
+            (state, 0)
+        } else {
+            (state, stripes)
+        }
+    }
+}
+
+/// Reads a little-endian 32-bit word at the given offset.
+pub(crate) const fn read_u32(data: &[u8], offset: usize) -> u64 {
+    u32::from_le_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]]) as u64
+}
+
+/// Reads a little-endian 64-bit word at the given offset.
+pub(crate) const fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+        data[offset + 4],
+        data[offset + 5],
+        data[offset + 6],
+        data[offset + 7],
+    ])
+}
+
+/// Multiplies two words into a 128-bit product and folds its halves together.
+pub(crate) const fn mul128_fold64(left: u64, right: u64) -> u64 {
+    let product = left as u128 * right as u128;
+    (product as u64) ^ (product >> 64) as u64
+}
+
+/// The final mix of XXH3.
+pub(crate) const fn avalanche(mut value: u64) -> u64 {
+    value ^= value >> 37;
+    value = value.wrapping_mul(0x1656_6791_9E37_79F9);
+    value ^ (value >> 32)
+}
+
+/// The final mix of XXH64.
+pub(crate) const fn avalanche_xxh64(mut value: u64) -> u64 {
+    value ^= value >> 33;
+    value = value.wrapping_mul(PRIME64_2);
+    value ^= value >> 29;
+    value = value.wrapping_mul(PRIME64_3);
+    value ^ (value >> 32)
+}
+
+/// Mixes 16 bytes of input with 16 bytes of secret.
+pub(crate) const fn mix16(data: &[u8], offset: usize, secret: &[u8], secret_offset: usize, seed: u64) -> u64 {
+    let low = read_u64(data, offset) ^ read_u64(secret, secret_offset).wrapping_add(seed);
+    let high = read_u64(data, offset + 8) ^ read_u64(secret, secret_offset + 8).wrapping_sub(seed);
+    mul128_fold64(low, high)
+}
+
+/// Merges the accumulators into a single word.
+pub(crate) const fn merge(state: &State, secret: &[u8], offset: usize, start: u64) -> u64 {
+    let accumulators = state.digest();
+    let mut result = start;
+    let mut i = 0;
+    while i < 4 {
+        result = result.wrapping_add(mul128_fold64(
+            accumulators[i * 2] ^ read_u64(secret, offset + i * 16),
+            accumulators[i * 2 + 1] ^ read_u64(secret, offset + i * 16 + 8),
+        ));
+        i += 1;
+    }
+    avalanche(result)
+}
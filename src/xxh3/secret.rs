@@ -0,0 +1,71 @@
+//! Module containing items related to the XXH3 secret.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::xxh3::secret;
+//! use chksum_hash::xxh3_64;
+//!
+//! // For long inputs a seeded hash equals the hash with the derived secret
+//! let data = [0x5A; 1024];
+//! let seeded = xxh3_64::Update::with_seed(42).update(data).digest();
+//! let secret = secret::from_seed(42);
+//! let customized = xxh3_64::Update::with_secret(secret).update(data).digest();
+//! assert_eq!(seeded, customized);
+//! ```
+
+/// Default secret length in bytes.
+pub const DEFAULT_LENGTH_BYTES: usize = 192;
+/// Minimum secret length in bytes.
+pub const MIN_LENGTH_BYTES: usize = 136;
+
+/// The default secret, used when neither a seed nor a custom secret is given.
+#[rustfmt::skip]
+pub const DEFAULT_SECRET: [u8; DEFAULT_LENGTH_BYTES] = [
+    0xB8, 0xFE, 0x6C, 0x39, 0x23, 0xA4, 0x4B, 0xBE, 0x7C, 0x01, 0x81, 0x2C, 0xF7, 0x21, 0xAD, 0x1C,
+    0xDE, 0xD4, 0x6D, 0xE9, 0x83, 0x90, 0x97, 0xDB, 0x72, 0x40, 0xA4, 0xA4, 0xB7, 0xB3, 0x67, 0x1F,
+    0xCB, 0x79, 0xE6, 0x4E, 0xCC, 0xC0, 0xE5, 0x78, 0x82, 0x5A, 0xD0, 0x7D, 0xCC, 0xFF, 0x72, 0x21,
+    0xB8, 0x08, 0x46, 0x74, 0xF7, 0x43, 0x24, 0x8E, 0xE0, 0x35, 0x90, 0xE6, 0x81, 0x3A, 0x26, 0x4C,
+    0x3C, 0x28, 0x52, 0xBB, 0x91, 0xC3, 0x00, 0xCB, 0x88, 0xD0, 0x65, 0x8B, 0x1B, 0x53, 0x2E, 0xA3,
+    0x71, 0x64, 0x48, 0x97, 0xA2, 0x0D, 0xF9, 0x4E, 0x38, 0x19, 0xEF, 0x46, 0xA9, 0xDE, 0xAC, 0xD8,
+    0xA8, 0xFA, 0x76, 0x3F, 0xE3, 0x9C, 0x34, 0x3F, 0xF9, 0xDC, 0xBB, 0xC7, 0xC7, 0x0B, 0x4F, 0x1D,
+    0x8A, 0x51, 0xE0, 0x4B, 0xCD, 0xB4, 0x59, 0x31, 0xC8, 0x9F, 0x7E, 0xC9, 0xD9, 0x78, 0x73, 0x64,
+    0xEA, 0xC5, 0xAC, 0x83, 0x34, 0xD3, 0xEB, 0xC3, 0xC5, 0x81, 0xA0, 0xFF, 0xFA, 0x13, 0x63, 0xEB,
+    0x17, 0x0D, 0xDD, 0x51, 0xB7, 0xF0, 0xDA, 0x49, 0xD3, 0x16, 0x55, 0x26, 0x29, 0xD4, 0x68, 0x9E,
+    0x2B, 0x16, 0xBE, 0x58, 0x7D, 0x47, 0xA1, 0xFC, 0x8F, 0xF8, 0xB8, 0xD1, 0x7A, 0xD0, 0x31, 0xCE,
+    0x45, 0xCB, 0x3A, 0x8F, 0x95, 0x16, 0x04, 0x28, 0xAF, 0xD7, 0xFB, 0xCA, 0xBB, 0x4B, 0x40, 0x7E,
+];
+
+/// Derives a secret from the given seed.
+///
+/// The seed is added to the first and subtracted from the second 64-bit word of each 16-byte lane of the default secret.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3::secret;
+///
+/// assert_eq!(secret::from_seed(0), secret::DEFAULT_SECRET);
+/// assert_eq!(secret::from_seed(1)[..4], [0xB9, 0xFE, 0x6C, 0x39]);
+/// ```
+#[must_use]
+pub const fn from_seed(seed: u64) -> [u8; DEFAULT_LENGTH_BYTES] {
+    let mut secret = [0u8; DEFAULT_LENGTH_BYTES];
+    let mut i = 0;
+    while i < DEFAULT_LENGTH_BYTES {
+        let word = super::read_u64(&DEFAULT_SECRET, i);
+        let word = if i % 16 == 0 {
+            word.wrapping_add(seed)
+        } else {
+            word.wrapping_sub(seed)
+        };
+        let bytes = word.to_le_bytes();
+        let mut j = 0;
+        while j < 8 {
+            secret[i + j] = bytes[j];
+            j += 1;
+        }
+        i += 8;
+    }
+    secret
+}
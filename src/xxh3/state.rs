@@ -0,0 +1,132 @@
+//! Module contains items related to the [`State`] structure which allows to the direct XXH3 state manipulation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chksum_hash::xxh3::{self, DEFAULT_SECRET};
+//!
+//! // Create new state
+//! let mut state = xxh3::state::new();
+//!
+//! // Accumulate a stripe of data with the matching part of the secret and own new value
+//! let stripe = [0u8; xxh3::STRIPE_LENGTH_BYTES];
+//! state = state.accumulate(&stripe, &DEFAULT_SECRET);
+//!
+//! // Scramble the accumulators after a block of stripes
+//! state = state.scramble(&DEFAULT_SECRET[DEFAULT_SECRET.len() - xxh3::STRIPE_LENGTH_BYTES..]);
+//! assert_eq!(state.digest()[0], 0xA16B3D918FB580D0);
+//!
+//! // Reset state to initial values
+//! state = state.reset();
+//! assert_eq!(state, xxh3::state::new());
+//! ```
+//!
+//! # Warning
+//!
+//! The [`State`] structure does not modify internal state, each function returns a new state that must be used.
+
+use super::{read_u64, PRIME32_1, PRIME32_2, PRIME32_3, PRIME64_1, PRIME64_2, PRIME64_3, PRIME64_4, PRIME64_5};
+
+/// State length in quadruple words (octuple bytes).
+pub const LENGTH_QWORDS: usize = 8;
+
+/// Initial values of the accumulators.
+#[rustfmt::skip]
+const INITIAL_ACCUMULATORS: [u64; LENGTH_QWORDS] = [
+    PRIME32_3, PRIME64_1, PRIME64_2, PRIME64_3,
+    PRIME64_4, PRIME32_2, PRIME64_5, PRIME32_1,
+];
+
+/// Create a new state.
+#[must_use]
+pub const fn new() -> State {
+    State::new()
+}
+
+/// Creates a default state.
+#[must_use]
+pub fn default() -> State {
+    State::default()
+}
+
+/// A low-level hash state.
+///
+/// The state is made of eight 64-bit accumulators. The data is processed in stripes of 64 bytes, each of them is combined with 64 bytes of the secret starting at an offset which advances by 8 bytes with each stripe of a block.
+///
+/// Check [`state`](self) module for usage examples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct State {
+    accumulators: [u64; LENGTH_QWORDS],
+}
+
+impl State {
+    /// Creates a new state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_raw(INITIAL_ACCUMULATORS)
+    }
+
+    /// Creates a state from the raw accumulators, e.g. custom initial accumulators.
+    #[must_use]
+    pub const fn from_raw(accumulators: [u64; LENGTH_QWORDS]) -> Self {
+        Self { accumulators }
+    }
+
+    /// Returns modified state with the passed stripe.
+    ///
+    /// The lanes are independent of each other, so the loop is easily vectorized by the compiler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the stripe or the secret is shorter than 64 bytes; only the first 64 bytes of both are used.
+    #[must_use]
+    pub const fn accumulate(&self, stripe: &[u8], secret: &[u8]) -> Self {
+        let mut accumulators = self.accumulators;
+        let mut i = 0;
+        while i < LENGTH_QWORDS {
+            let value = read_u64(stripe, i * 8);
+            let key = value ^ read_u64(secret, i * 8);
+            // The input is added to the neighboring lane so that it is not lost by the 32-bit multiplication.
+            accumulators[i ^ 1] = accumulators[i ^ 1].wrapping_add(value);
+            accumulators[i] = accumulators[i].wrapping_add((key & 0xFFFF_FFFF) * (key >> 32));
+            i += 1;
+        }
+        Self::from_raw(accumulators)
+    }
+
+    /// Returns modified state with scrambled accumulators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the secret is shorter than 64 bytes; only the first 64 bytes are used.
+    #[must_use]
+    pub const fn scramble(&self, secret: &[u8]) -> Self {
+        let mut accumulators = self.accumulators;
+        let mut i = 0;
+        while i < LENGTH_QWORDS {
+            let accumulator = accumulators[i] ^ (accumulators[i] >> 47) ^ read_u64(secret, i * 8);
+            accumulators[i] = accumulator.wrapping_mul(PRIME32_1);
+            i += 1;
+        }
+        Self::from_raw(accumulators)
+    }
+
+    /// Returns a new state with initial values.
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Returns the accumulators.
+    #[must_use]
+    pub const fn digest(&self) -> [u64; LENGTH_QWORDS] {
+        self.accumulators
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,538 @@
+//! This module is optional and can be enabled using the `xxh3-128` Cargo feature.
+//!
+//! An implementation of the 128-bit XXH3 hash function.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::xxh3_128;
+//!
+//! let digest = xxh3_128::hash("example data");
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "f3c20656bd27147872c71e4cece53b20"
+//! );
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::xxh3_128;
+//!
+//! let digest = xxh3_128::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "f3c20656bd27147872c71e4cece53b20"
+//! );
+//! ```
+//!
+//! # Customization
+//!
+//! Use [`Update::with_seed`] or [`Update::with_secret`] to customize the hash, see [`xxh3`](crate::xxh3) for details.
+//!
+//! ```rust
+//! use chksum_hash::xxh3_128;
+//!
+//! let digest = xxh3_128::Update::with_seed(42)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "42831e94ed8b901f58b340d75d3624b8"
+//! );
+//!
+//! let secret: Vec<u8> = (0..136).collect();
+//! let digest = xxh3_128::Update::with_secret(secret)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(
+//!     digest.to_hex_lowercase(),
+//!     "f7d4b16210eb6ac519a010efd4a1d6db"
+//! );
+//! ```
+//!
+//! # Disclaimer
+//!
+//! XXH3 is not a cryptographic hash function. It is designed for hash tables, deduplication and checksumming, and it must not be used where resistance to deliberate collisions is required.
+
+pub mod digest;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+use crate::error::ParameterError;
+use crate::verify::Expected;
+use crate::xxh3::{
+    avalanche,
+    avalanche_xxh64,
+    merge,
+    mix16,
+    read_u32,
+    read_u64,
+    Engine,
+    Output,
+    PRIME32_2,
+    PRIME64_1,
+    PRIME64_2,
+    PRIME64_4,
+    SECRET_MERGE_OFFSET,
+    SECRET_MIN_LENGTH_BYTES,
+};
+#[doc(inline)]
+pub use crate::xxh3::{State, STRIPE_LENGTH_BYTES};
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_128;
+///
+/// let digest = xxh3_128::new().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "99aa06d3014798d86001c324468d497f"
+/// );
+///
+/// let digest = xxh3_128::new().update("data").digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "c044be4073bb71f1340036b3f23aa86d"
+/// );
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_128;
+///
+/// let digest = xxh3_128::default().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "99aa06d3014798d86001c324468d497f"
+/// );
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_128;
+///
+/// let digest = xxh3_128::hash("abc");
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "06b05ab6733a618578af5f94892f3950"
+/// );
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_128;
+///
+/// let digest = xxh3_128::hash("data");
+/// assert!(xxh3_128::verify("data", digest));
+/// assert!(xxh3_128::verify("data", digest.to_hex_uppercase()));
+/// assert!(!xxh3_128::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_128;
+///
+/// // Create a new hash instance
+/// let mut hash = xxh3_128::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "c044be4073bb71f1340036b3f23aa86d"
+/// );
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "99aa06d3014798d86001c324468d497f"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    inner: Engine,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Creates a new hash with the given seed.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        let inner = Engine::with_seed(seed);
+        Self { inner }
+    }
+
+    /// Creates a new hash with the given secret.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the secret is shorter than [`SECRET_MIN_LENGTH_BYTES`](crate::xxh3::SECRET_MIN_LENGTH_BYTES).
+    #[must_use]
+    pub fn with_secret(secret: impl AsRef<[u8]>) -> Self {
+        let inner = Engine::with_secret(secret.as_ref());
+        Self { inner }
+    }
+
+    /// Creates a new hash with the given secret, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secret is shorter than [`SECRET_MIN_LENGTH_BYTES`](crate::xxh3::SECRET_MIN_LENGTH_BYTES).
+    pub fn try_with_secret(secret: impl AsRef<[u8]>) -> Result<Self, ParameterError> {
+        let secret = secret.as_ref();
+        if secret.len() >= crate::xxh3::SECRET_MIN_LENGTH_BYTES {
+            Ok(Self::with_secret(secret))
+        } else {
+            Err(ParameterError { parameter: "secret" })
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// Inputs of up to 240 bytes are buffered, longer inputs are processed in stripes of 64 bytes. To achieve maximum performance, the length of incoming data parts should be a multiple of the stripe length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.update(data.as_ref());
+        self
+    }
+
+    /// Processes the remaining data and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let hash = match self.inner.finalize() {
+            Output::Short { data, secret, seed } => hash_short(data, secret, seed),
+            Output::Long { state, secret, length } => {
+                let low = merge(&state, secret, SECRET_MERGE_OFFSET, length.wrapping_mul(PRIME64_1));
+                let high = merge(
+                    &state,
+                    secret,
+                    secret.len() - STRIPE_LENGTH_BYTES - SECRET_MERGE_OFFSET,
+                    !length.wrapping_mul(PRIME64_2),
+                );
+                combine(low, high)
+            },
+        };
+        let inner = self.inner.fresh();
+        Finalize { hash, inner }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The seed and the secret are kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.inner.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let inner = Engine::arbitrary(u)?;
+        Ok(Self { inner })
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_128;
+///
+/// // Create a new hash instance
+/// let mut hash = xxh3_128::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(
+///     digest.to_hex_lowercase(),
+///     "06b05ab6733a618578af5f94892f3950"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    hash: u128,
+    inner: Engine,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        Digest::new(self.hash.to_be_bytes())
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        *out = self.hash.to_be_bytes();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The seed and the secret are kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        let inner = self.inner.fresh();
+        Update { inner }
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+/// Hashes an input of up to 240 bytes.
+fn hash_short(data: &[u8], secret: &[u8], seed: u64) -> u128 {
+    let length = data.len() as u64;
+    match data.len() {
+        0 => {
+            let flip_low = read_u64(secret, 64) ^ read_u64(secret, 72);
+            let flip_high = read_u64(secret, 80) ^ read_u64(secret, 88);
+            combine(avalanche_xxh64(seed ^ flip_low), avalanche_xxh64(seed ^ flip_high))
+        },
+        1..=3 => {
+            let combined = (u32::from(data[0]) << 16)
+                | (u32::from(data[data.len() >> 1]) << 24)
+                | u32::from(data[data.len() - 1])
+                | ((data.len() as u32) << 8);
+            let low = u64::from(combined);
+            let high = u64::from(combined.swap_bytes().rotate_left(13));
+            let flip_low = (read_u32(secret, 0) ^ read_u32(secret, 4)).wrapping_add(seed);
+            let flip_high = (read_u32(secret, 8) ^ read_u32(secret, 12)).wrapping_sub(seed);
+            combine(avalanche_xxh64(low ^ flip_low), avalanche_xxh64(high ^ flip_high))
+        },
+        4..=8 => {
+            let seed = seed ^ (u64::from((seed as u32).swap_bytes()) << 32);
+            let flip = (read_u64(secret, 16) ^ read_u64(secret, 24)).wrapping_add(seed);
+            let input = read_u32(data, 0).wrapping_add(read_u32(data, data.len() - 4) << 32);
+            let (mut low, mut high) = multiply(input ^ flip, PRIME64_1.wrapping_add(length << 2));
+            high = high.wrapping_add(low << 1);
+            low ^= high >> 3;
+            low ^= low >> 35;
+            low = low.wrapping_mul(0x9FB2_1C65_1E98_DF25);
+            low ^= low >> 28;
+            combine(low, avalanche(high))
+        },
+        9..=16 => {
+            let flip_low = (read_u64(secret, 32) ^ read_u64(secret, 40)).wrapping_sub(seed);
+            let flip_high = (read_u64(secret, 48) ^ read_u64(secret, 56)).wrapping_add(seed);
+            let input_low = read_u64(data, 0);
+            let input_high = read_u64(data, data.len() - 8);
+            let (mut low, mut high) = multiply(input_low ^ input_high ^ flip_low, PRIME64_1);
+            let input_high = input_high ^ flip_high;
+            low = low.wrapping_add((length - 1) << 54);
+            high = high
+                .wrapping_add(input_high & 0xFFFF_FFFF_0000_0000)
+                .wrapping_add((input_high & 0xFFFF_FFFF) * PRIME32_2);
+            low ^= high.swap_bytes();
+            let (result_low, result_high) = multiply(low, PRIME64_2);
+            let result_high = result_high.wrapping_add(high.wrapping_mul(PRIME64_2));
+            combine(avalanche(result_low), avalanche(result_high))
+        },
+        17..=128 => {
+            let mut accumulators = (length.wrapping_mul(PRIME64_1), 0);
+            // Pairs of 16-byte chunks are taken from both ends of the input, the innermost pair is mixed first.
+            let mut i = (data.len() - 1) / 32 + 1;
+            while i > 0 {
+                i -= 1;
+                accumulators = mix32(
+                    accumulators,
+                    data,
+                    16 * i,
+                    data.len() - 16 * (i + 1),
+                    secret,
+                    32 * i,
+                    seed,
+                );
+            }
+            finish(accumulators, length, seed)
+        },
+        _ => {
+            let mut accumulators = (length.wrapping_mul(PRIME64_1), 0);
+            let mut i = 0;
+            while i < 4 {
+                accumulators = mix32(accumulators, data, 32 * i, 32 * i + 16, secret, 32 * i, seed);
+                i += 1;
+            }
+            accumulators = (avalanche(accumulators.0), avalanche(accumulators.1));
+            while i < data.len() / 32 {
+                accumulators = mix32(accumulators, data, 32 * i, 32 * i + 16, secret, 32 * (i - 4) + 3, seed);
+                i += 1;
+            }
+            accumulators = mix32(
+                accumulators,
+                data,
+                data.len() - 16,
+                data.len() - 32,
+                secret,
+                SECRET_MIN_LENGTH_BYTES - 17 - 16,
+                seed.wrapping_neg(),
+            );
+            finish(accumulators, length, seed)
+        },
+    }
+}
+
+/// Mixes two 16-byte chunks of input into a pair of accumulators.
+const fn mix32(
+    accumulators: (u64, u64),
+    data: &[u8],
+    first: usize,
+    second: usize,
+    secret: &[u8],
+    secret_offset: usize,
+    seed: u64,
+) -> (u64, u64) {
+    let low = accumulators
+        .0
+        .wrapping_add(mix16(data, first, secret, secret_offset, seed))
+        ^ read_u64(data, second).wrapping_add(read_u64(data, second + 8));
+    let high = accumulators
+        .1
+        .wrapping_add(mix16(data, second, secret, secret_offset + 16, seed))
+        ^ read_u64(data, first).wrapping_add(read_u64(data, first + 8));
+    (low, high)
+}
+
+/// Produces the result from the accumulators of inputs longer than 16 bytes.
+const fn finish((low, high): (u64, u64), length: u64, seed: u64) -> u128 {
+    let result_low = low.wrapping_add(high);
+    let result_high = low
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(high.wrapping_mul(PRIME64_4))
+        .wrapping_add(length.wrapping_sub(seed).wrapping_mul(PRIME64_2));
+    combine(avalanche(result_low), avalanche(result_high).wrapping_neg())
+}
+
+/// Multiplies two words into a 128-bit product, returned as its low and high halves.
+const fn multiply(left: u64, right: u64) -> (u64, u64) {
+    let product = left as u128 * right as u128;
+    (product as u64, (product >> 64) as u64)
+}
+
+/// Combines both halves of the hash.
+const fn combine(low: u64, high: u64) -> u128 {
+    (high as u128) << 64 | low as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vector from the xxHash reference implementation.
+    #[test]
+    fn reference() {
+        assert_eq!(hash("").to_hex_lowercase(), "99aa06d3014798d86001c324468d497f");
+    }
+}
@@ -0,0 +1,429 @@
+//! This module is optional and can be enabled using the `xxh3-64` Cargo feature.
+//!
+//! An implementation of the 64-bit XXH3 hash function.
+//!
+//! # Batch Processing
+//!
+//! The digest of known-size data can be calculated with the [`hash`] function.
+//!
+//! ```rust
+//! use chksum_hash::xxh3_64;
+//!
+//! let digest = xxh3_64::hash("example data");
+//! assert_eq!(digest.to_hex_lowercase(), "713550694c4d35de");
+//! ```
+//!
+//! # Stream Processing
+//!
+//! The digest of data streams can be calculated chunk-by-chunk with a consumer created by calling the [`default`] function.
+//!
+//! ```rust
+//! use chksum_hash::xxh3_64;
+//!
+//! let digest = xxh3_64::default()
+//!     .update("example")
+//!     .update(" ")
+//!     .update(b"data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "713550694c4d35de");
+//! ```
+//!
+//! # Customization
+//!
+//! Use [`Update::with_seed`] or [`Update::with_secret`] to customize the hash, see [`xxh3`](crate::xxh3) for details.
+//!
+//! ```rust
+//! use chksum_hash::xxh3_64;
+//!
+//! let digest = xxh3_64::Update::with_seed(42)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "1af70613c1639085");
+//!
+//! let secret: Vec<u8> = (0..136).collect();
+//! let digest = xxh3_64::Update::with_secret(secret)
+//!     .update("example data")
+//!     .digest();
+//! assert_eq!(digest.to_hex_lowercase(), "e93101e9d598b882");
+//! ```
+//!
+//! # Disclaimer
+//!
+//! XXH3 is not a cryptographic hash function. It is designed for hash tables, deduplication and checksumming, and it must not be used where resistance to deliberate collisions is required.
+
+pub mod digest;
+
+use chksum_hash_core as core;
+
+#[doc(inline)]
+pub use self::digest::{Digest, LENGTH_BYTES as DIGEST_LENGTH_BYTES};
+use crate::error::ParameterError;
+use crate::verify::Expected;
+use crate::xxh3::{
+    avalanche,
+    avalanche_xxh64,
+    merge,
+    mix16,
+    mul128_fold64,
+    read_u32,
+    read_u64,
+    Engine,
+    Output,
+    PRIME64_1,
+    SECRET_MERGE_OFFSET,
+    SECRET_MIN_LENGTH_BYTES,
+};
+#[doc(inline)]
+pub use crate::xxh3::{State, STRIPE_LENGTH_BYTES};
+
+/// Creates a new hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_64;
+///
+/// let digest = xxh3_64::new().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "2d06800538d394c2");
+///
+/// let digest = xxh3_64::new().update("data").digest();
+/// assert_eq!(digest.to_hex_lowercase(), "e4b7943fd57d5d1e");
+/// ```
+#[must_use]
+pub fn new() -> Update {
+    Update::new()
+}
+
+/// Creates a default hash.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_64;
+///
+/// let digest = xxh3_64::default().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "2d06800538d394c2");
+/// ```
+#[must_use]
+pub fn default() -> Update {
+    core::default()
+}
+
+/// Computes the hash of the given input.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_64;
+///
+/// let digest = xxh3_64::hash("abc");
+/// assert_eq!(digest.to_hex_lowercase(), "78af5f94892f3950");
+/// ```
+pub fn hash(data: impl AsRef<[u8]>) -> Digest {
+    core::hash::<Update>(data)
+}
+
+/// Computes the hash of the given input and compares it with the expected digest in constant time.
+///
+/// The expected digest can be a digest, a byte slice or array, or a hexadecimal string, see [`Expected`].
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_64;
+///
+/// let digest = xxh3_64::hash("data");
+/// assert!(xxh3_64::verify("data", digest));
+/// assert!(xxh3_64::verify("data", digest.to_hex_uppercase()));
+/// assert!(!xxh3_64::verify("other data", digest.as_ref()));
+/// ```
+#[must_use]
+pub fn verify(data: impl AsRef<[u8]>, expected: impl Expected) -> bool {
+    expected.matches_digest(hash(data).as_ref())
+}
+
+/// A hash state containing an internal buffer that can handle an unknown amount of input data.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_64;
+///
+/// // Create a new hash instance
+/// let mut hash = xxh3_64::Update::new();
+///
+/// // Fill with data
+/// hash.update("data");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "e4b7943fd57d5d1e");
+///
+/// // Reset to default values
+/// hash.reset();
+///
+/// // Produce a hash digest using internal finalization
+/// let digest = hash.digest();
+/// assert_eq!(digest.to_hex_lowercase(), "2d06800538d394c2");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Update {
+    inner: Engine,
+}
+
+impl Update {
+    /// Creates a new hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Creates a new hash with the given seed.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        let inner = Engine::with_seed(seed);
+        Self { inner }
+    }
+
+    /// Creates a new hash with the given secret.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the secret is shorter than [`SECRET_MIN_LENGTH_BYTES`](crate::xxh3::SECRET_MIN_LENGTH_BYTES).
+    #[must_use]
+    pub fn with_secret(secret: impl AsRef<[u8]>) -> Self {
+        let inner = Engine::with_secret(secret.as_ref());
+        Self { inner }
+    }
+
+    /// Creates a new hash with the given secret, e.g. read from untrusted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secret is shorter than [`SECRET_MIN_LENGTH_BYTES`](crate::xxh3::SECRET_MIN_LENGTH_BYTES).
+    pub fn try_with_secret(secret: impl AsRef<[u8]>) -> Result<Self, ParameterError> {
+        let secret = secret.as_ref();
+        if secret.len() >= crate::xxh3::SECRET_MIN_LENGTH_BYTES {
+            Ok(Self::with_secret(secret))
+        } else {
+            Err(ParameterError { parameter: "secret" })
+        }
+    }
+
+    /// Updates the internal state with an input data.
+    ///
+    /// # Performance issues
+    ///
+    /// Inputs of up to 240 bytes are buffered, longer inputs are processed in stripes of 64 bytes. To achieve maximum performance, the length of incoming data parts should be a multiple of the stripe length.
+    ///
+    /// In any other case, an internal buffer is used, which can cause a speed decrease in performance.
+    pub fn update<T>(&mut self, data: T) -> &mut Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner.update(data.as_ref());
+        self
+    }
+
+    /// Processes the remaining data and produces the finalized state.
+    #[must_use]
+    pub fn finalize(&self) -> Finalize {
+        let hash = match self.inner.finalize() {
+            Output::Short { data, secret, seed } => hash_short(data, secret, seed),
+            Output::Long { state, secret, length } => {
+                merge(&state, secret, SECRET_MERGE_OFFSET, length.wrapping_mul(PRIME64_1))
+            },
+        };
+        let inner = self.inner.fresh();
+        Finalize { hash, inner }
+    }
+
+    /// Resets the internal state to default values.
+    ///
+    /// The seed and the secret are kept.
+    pub fn reset(&mut self) -> &mut Self {
+        self.inner.reset();
+        self
+    }
+
+    /// Produces the hash digest using internal finalization.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        self.finalize().digest()
+    }
+
+    /// Produces the hash digest and resets the internal state to default values.
+    #[must_use]
+    pub fn digest_reset(&mut self) -> Digest {
+        let digest = self.digest();
+        self.reset();
+        digest
+    }
+}
+
+impl core::Update for Update {
+    type Digest = Digest;
+    type Finalize = Finalize;
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.update(data);
+    }
+
+    fn finalize(&self) -> Self::Finalize {
+        self.finalize()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Update {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let inner = Engine::arbitrary(u)?;
+        Ok(Self { inner })
+    }
+}
+
+impl Default for Update {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A finalized hash state.
+///
+/// # Example
+///
+/// ```rust
+/// use chksum_hash::xxh3_64;
+///
+/// // Create a new hash instance
+/// let mut hash = xxh3_64::Update::new();
+///
+/// // Fill with data
+/// hash.update("abc");
+///
+/// // Finalize and create a digest
+/// let digest = hash.finalize().digest();
+/// assert_eq!(digest.to_hex_lowercase(), "78af5f94892f3950");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    hash: u64,
+    inner: Engine,
+}
+
+impl Finalize {
+    /// Creates and returns the hash digest.
+    #[must_use]
+    pub fn digest(&self) -> Digest {
+        Digest::new(self.hash.to_be_bytes())
+    }
+
+    /// Writes the hash digest into the given buffer, e.g. a part of a packet.
+    pub fn digest_into(&self, out: &mut [u8; DIGEST_LENGTH_BYTES]) {
+        *out = self.hash.to_be_bytes();
+    }
+
+    /// Resets the hash state to the in-progress state.
+    ///
+    /// The seed and the secret are kept.
+    #[must_use]
+    pub fn reset(&self) -> Update {
+        let inner = self.inner.fresh();
+        Update { inner }
+    }
+}
+
+impl core::Finalize for Finalize {
+    type Digest = Digest;
+    type Update = Update;
+
+    fn digest(&self) -> Self::Digest {
+        self.digest()
+    }
+
+    fn reset(&self) -> Self::Update {
+        self.reset()
+    }
+}
+
+/// Hashes an input of up to 240 bytes.
+fn hash_short(data: &[u8], secret: &[u8], seed: u64) -> u64 {
+    let length = data.len() as u64;
+    match data.len() {
+        0 => avalanche_xxh64(seed ^ read_u64(secret, 56) ^ read_u64(secret, 64)),
+        1..=3 => {
+            let combined = (u64::from(data[0]) << 16)
+                | (u64::from(data[data.len() >> 1]) << 24)
+                | u64::from(data[data.len() - 1])
+                | (length << 8);
+            let flip = (read_u32(secret, 0) ^ read_u32(secret, 4)).wrapping_add(seed);
+            avalanche_xxh64(combined ^ flip)
+        },
+        4..=8 => {
+            let seed = seed ^ (u64::from((seed as u32).swap_bytes()) << 32);
+            let flip = (read_u64(secret, 8) ^ read_u64(secret, 16)).wrapping_sub(seed);
+            let input = read_u32(data, data.len() - 4).wrapping_add(read_u32(data, 0) << 32);
+            let mut value = input ^ flip;
+            value ^= value.rotate_left(49) ^ value.rotate_left(24);
+            value = value.wrapping_mul(0x9FB2_1C65_1E98_DF25);
+            value ^= (value >> 35).wrapping_add(length);
+            value = value.wrapping_mul(0x9FB2_1C65_1E98_DF25);
+            value ^ (value >> 28)
+        },
+        9..=16 => {
+            let flip_low = (read_u64(secret, 24) ^ read_u64(secret, 32)).wrapping_add(seed);
+            let flip_high = (read_u64(secret, 40) ^ read_u64(secret, 48)).wrapping_sub(seed);
+            let low = read_u64(data, 0) ^ flip_low;
+            let high = read_u64(data, data.len() - 8) ^ flip_high;
+            let value = length
+                .wrapping_add(low.swap_bytes())
+                .wrapping_add(high)
+                .wrapping_add(mul128_fold64(low, high));
+            avalanche(value)
+        },
+        17..=128 => {
+            let mut value = length.wrapping_mul(PRIME64_1);
+            // Pairs of 16-byte chunks are taken from both ends of the input towards its middle.
+            let pairs = (data.len() - 1) / 32;
+            let mut i = 0;
+            while i <= pairs {
+                value = value
+                    .wrapping_add(mix16(data, 16 * i, secret, 32 * i, seed))
+                    .wrapping_add(mix16(data, data.len() - 16 * (i + 1), secret, 32 * i + 16, seed));
+                i += 1;
+            }
+            avalanche(value)
+        },
+        _ => {
+            let mut value = length.wrapping_mul(PRIME64_1);
+            let mut i = 0;
+            while i < 8 {
+                value = value.wrapping_add(mix16(data, 16 * i, secret, 16 * i, seed));
+                i += 1;
+            }
+            value = avalanche(value);
+            while i < data.len() / 16 {
+                value = value.wrapping_add(mix16(data, 16 * i, secret, 16 * (i - 8) + 3, seed));
+                i += 1;
+            }
+            value = value.wrapping_add(mix16(data, data.len() - 16, secret, SECRET_MIN_LENGTH_BYTES - 17, seed));
+            avalanche(value)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from the xxHash reference implementation.
+    #[test]
+    fn reference() {
+        assert_eq!(hash("").to_hex_lowercase(), "2d06800538d394c2");
+        assert_eq!(hash("abc").to_hex_lowercase(), "78af5f94892f3950");
+    }
+}
@@ -0,0 +1,26 @@
+//! Module containing items related to the XXH3-64 digest.
+
+/// Digest length in bits.
+pub const LENGTH_BITS: usize = 64;
+/// Digest length in bytes.
+pub const LENGTH_BYTES: usize = LENGTH_BITS / 8;
+/// Digest length in hexadecimal format.
+pub const LENGTH_HEX: usize = LENGTH_BYTES * 2;
+
+/// Marker type of the XXH3-64 digest.
+///
+/// It tags [`Digest`] so XXH3-64 digests are distinct from digests of other hash functions with the same length.
+pub enum Xxh3_64 {}
+
+/// A hash digest.
+///
+/// The bytes of the digest are the hash in the big-endian byte order, as it is usually printed.
+///
+/// Check [`xxh3_64`](super) for more information.
+pub type Digest = crate::digest::Digest<Xxh3_64, LENGTH_BYTES>;
+
+impl From<Digest> for u64 {
+    fn from(digest: Digest) -> Self {
+        Self::from_be_bytes(digest.into_inner())
+    }
+}